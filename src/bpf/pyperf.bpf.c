@@ -11,6 +11,9 @@
 //   ╚═════════════════════════════════════════════════════════════════════════╝
 //
 const volatile bool verbose = false;
+// How many times the stack walker program runs, this bounds the stack depth to
+// `PYTHON_STACK_FRAMES_PER_PROG * stack_walker_prog_cnt` frames.
+const volatile u32 stack_walker_prog_cnt = PYTHON_STACK_PROG_CNT;
 
 #define MAX_STACK_DEPTH 127
 #define MAX_STACK_TRACES_ENTRIES 64000
@@ -259,7 +262,8 @@ int on_event(struct bpf_perf_event_data *ctx) {
     state->frame_ptr = 0;
     state->stack_walker_prog_call_count = 0;
 
-    state->sample = (Sample){0};
+    // Every field is set below. The frames aren't cleared: they are 2 KiB, more than the BPF
+    // stack or an inlined memset can take, and only the first `stack.len` of them are read.
     state->sample.timestamp = bpf_ktime_get_ns();
     state->sample.tid = tid;
     state->sample.pid = pid;
@@ -274,9 +278,7 @@ int on_event(struct bpf_perf_event_data *ctx) {
     state->sample.stack_status = STACK_ERROR;
     state->sample.error_code = ERROR_NONE;
 
-    state->sample.stack = (Stack){0};
     state->sample.stack.len = 0;
-    __builtin_memset((void *)state->sample.stack.frames, 0, sizeof(state->sample.stack.frames));
 
     u64 *scount = bpf_map_lookup_or_try_init(&stack_counts, &state->sample.native_stack_count_key, &zero);
     if (scount) {
@@ -329,6 +331,7 @@ int on_event(struct bpf_perf_event_data *ctx) {
                         state->thread_state + offsets->py_thread_state.thread_id);
     if (pthread_created == 0) {
         LOG("[error] pthread_created was NULL");
+        state->sample.error_code = ERROR_BAD_THREAD_STATE;
         goto submit_event;
     }
     LOG("pthread_created 0x%llx", pthread_created);
@@ -338,6 +341,7 @@ int on_event(struct bpf_perf_event_data *ctx) {
     bpf_probe_read_user(&pthread_self, sizeof(pthread_self), (void *)tls_base + 0x10);
     if (pthread_self == 0) {
         LOG("[error] pthread_self was NULL");
+        state->sample.error_code = ERROR_BAD_FSBASE;
         goto submit_event;
     }
     LOG("pthread_self 0x%llx", pthread_self);
@@ -357,6 +361,7 @@ int on_event(struct bpf_perf_event_data *ctx) {
     // TODO(kakkoyun): FRAME POINTER.
     if (state->thread_state == 0) {
         LOG("[error] thread_state was NULL");
+        state->sample.error_code = ERROR_THREAD_STATE_NULL;
         goto submit_event;
     }
 
@@ -554,7 +559,8 @@ int walk_python_stack(struct bpf_perf_event_data *ctx) {
     LOG("[iteration] frame_count %d", frame_count);
 
     LOG("state->stack_walker_prog_call_count %d", state->stack_walker_prog_call_count);
-    if (state->stack_walker_prog_call_count < PYTHON_STACK_PROG_CNT) {
+    if (state->stack_walker_prog_call_count < stack_walker_prog_cnt &&
+        state->stack_walker_prog_call_count < PYTHON_STACK_PROG_CNT) {
         LOG("[continue] walk_python_stack");
         bpf_tail_call(ctx, &programs, PYPERF_STACK_WALKING_PROGRAM_IDX);
         state->sample.error_code = ERROR_CALL_FAILED;
@@ -3,9 +3,12 @@
 #define PYPERF_STACK_WALKING_PROGRAM_IDX 0
 // #define PYPERF_THREAD_STATE_PROGRAM_IDX 1
 
-// Maximum Python stack frames: 16x5 = 80
+// Maximum Python stack frames: 16x32 = 512
+// The number of stack walker programs that actually run is configured at load time
+// through `stack_walker_prog_cnt`, so this is only an upper bound. The kernel allows
+// at most 33 tail calls, one of which is used by `on_event`.
 #define PYTHON_STACK_FRAMES_PER_PROG 16
-#define PYTHON_STACK_PROG_CNT 5
+#define PYTHON_STACK_PROG_CNT 32
 #define STACK_MAX_LEN (PYTHON_STACK_FRAMES_PER_PROG * PYTHON_STACK_PROG_CNT)
 // rbperf
 // #define MAX_STACKS_PER_PROGRAM 30
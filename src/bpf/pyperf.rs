@@ -1,5886 +0,0 @@
-#![allow(clippy::derive_partial_eq_without_eq)]
-// SPDX-License-Identifier: (LGPL-2.1 OR BSD-2-Clause)
-//
-// THIS FILE IS AUTOGENERATED BY CARGO-LIBBPF-GEN!
-
-pub use self::imp::*;
-
-#[allow(dead_code)]
-#[allow(non_snake_case)]
-#[allow(non_camel_case_types)]
-#[allow(clippy::transmute_ptr_to_ref)]
-#[allow(clippy::upper_case_acronyms)]
-#[warn(single_use_lifetimes)]
-mod imp {
-    use libbpf_rs::libbpf_sys;
-    use libbpf_rs::skel::OpenSkel;
-    use libbpf_rs::skel::Skel;
-    use libbpf_rs::skel::SkelBuilder;
-
-    fn build_skel_config(
-    ) -> libbpf_rs::Result<libbpf_rs::__internal_skel::ObjectSkeletonConfig<'static>> {
-        let mut builder = libbpf_rs::__internal_skel::ObjectSkeletonConfigBuilder::new(DATA);
-        builder
-            .name("pyperf_bpf")
-            .map("pid_to_process_info", false)
-            .map("global_state", false)
-            .map("stack_traces", false)
-            .map("stack_counts", false)
-            .map("version_specific_offsets", false)
-            .map("programs", false)
-            .map("events", false)
-            .map("stackmap", false)
-            .map("symbol_index", false)
-            .map("symbols", false)
-            .map("pyperf_b.rodata", true)
-            .map("pyperf_b.bss", true)
-            .prog("on_event")
-            .prog("walk_python_stack");
-
-        builder.build()
-    }
-
-    #[derive(Default)]
-    pub struct PyperfSkelBuilder {
-        pub obj_builder: libbpf_rs::ObjectBuilder,
-    }
-
-    impl<'a> SkelBuilder<'a> for PyperfSkelBuilder {
-        type Output = OpenPyperfSkel<'a>;
-        fn open(mut self) -> libbpf_rs::Result<OpenPyperfSkel<'a>> {
-            let mut skel_config = build_skel_config()?;
-            let open_opts = self.obj_builder.opts(std::ptr::null());
-
-            let ret =
-                unsafe { libbpf_sys::bpf_object__open_skeleton(skel_config.get(), &open_opts) };
-            if ret != 0 {
-                return Err(libbpf_rs::Error::System(-ret));
-            }
-
-            let obj = unsafe { libbpf_rs::OpenObject::from_ptr(skel_config.object_ptr())? };
-
-            Ok(OpenPyperfSkel { obj, skel_config })
-        }
-
-        fn open_opts(
-            self,
-            open_opts: libbpf_sys::bpf_object_open_opts,
-        ) -> libbpf_rs::Result<OpenPyperfSkel<'a>> {
-            let mut skel_config = build_skel_config()?;
-
-            let ret =
-                unsafe { libbpf_sys::bpf_object__open_skeleton(skel_config.get(), &open_opts) };
-            if ret != 0 {
-                return Err(libbpf_rs::Error::System(-ret));
-            }
-
-            let obj = unsafe { libbpf_rs::OpenObject::from_ptr(skel_config.object_ptr())? };
-
-            Ok(OpenPyperfSkel { obj, skel_config })
-        }
-
-        fn object_builder(&self) -> &libbpf_rs::ObjectBuilder {
-            &self.obj_builder
-        }
-        fn object_builder_mut(&mut self) -> &mut libbpf_rs::ObjectBuilder {
-            &mut self.obj_builder
-        }
-    }
-
-    pub struct OpenPyperfMaps<'a> {
-        inner: &'a libbpf_rs::OpenObject,
-    }
-
-    impl OpenPyperfMaps<'_> {
-        pub fn pid_to_process_info(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("pid_to_process_info").unwrap()
-        }
-
-        pub fn global_state(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("global_state").unwrap()
-        }
-
-        pub fn stack_traces(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("stack_traces").unwrap()
-        }
-
-        pub fn stack_counts(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("stack_counts").unwrap()
-        }
-
-        pub fn version_specific_offsets(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("version_specific_offsets").unwrap()
-        }
-
-        pub fn programs(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("programs").unwrap()
-        }
-
-        pub fn events(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("events").unwrap()
-        }
-
-        pub fn stackmap(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("stackmap").unwrap()
-        }
-
-        pub fn symbol_index(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("symbol_index").unwrap()
-        }
-
-        pub fn symbols(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("symbols").unwrap()
-        }
-
-        pub fn rodata(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("pyperf_b.rodata").unwrap()
-        }
-
-        pub fn bss(&self) -> &libbpf_rs::OpenMap {
-            self.inner.map("pyperf_b.bss").unwrap()
-        }
-    }
-
-    pub struct OpenPyperfMapsMut<'a> {
-        inner: &'a mut libbpf_rs::OpenObject,
-    }
-
-    impl OpenPyperfMapsMut<'_> {
-        pub fn pid_to_process_info(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("pid_to_process_info").unwrap()
-        }
-
-        pub fn global_state(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("global_state").unwrap()
-        }
-
-        pub fn stack_traces(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("stack_traces").unwrap()
-        }
-
-        pub fn stack_counts(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("stack_counts").unwrap()
-        }
-
-        pub fn version_specific_offsets(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("version_specific_offsets").unwrap()
-        }
-
-        pub fn programs(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("programs").unwrap()
-        }
-
-        pub fn events(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("events").unwrap()
-        }
-
-        pub fn stackmap(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("stackmap").unwrap()
-        }
-
-        pub fn symbol_index(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("symbol_index").unwrap()
-        }
-
-        pub fn symbols(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("symbols").unwrap()
-        }
-
-        pub fn rodata(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("pyperf_b.rodata").unwrap()
-        }
-
-        pub fn bss(&mut self) -> &mut libbpf_rs::OpenMap {
-            self.inner.map_mut("pyperf_b.bss").unwrap()
-        }
-    }
-
-    pub struct OpenPyperfProgs<'a> {
-        inner: &'a libbpf_rs::OpenObject,
-    }
-
-    impl OpenPyperfProgs<'_> {
-        pub fn on_event(&self) -> &libbpf_rs::OpenProgram {
-            self.inner.prog("on_event").unwrap()
-        }
-
-        pub fn walk_python_stack(&self) -> &libbpf_rs::OpenProgram {
-            self.inner.prog("walk_python_stack").unwrap()
-        }
-    }
-
-    pub struct OpenPyperfProgsMut<'a> {
-        inner: &'a mut libbpf_rs::OpenObject,
-    }
-
-    impl OpenPyperfProgsMut<'_> {
-        pub fn on_event(&mut self) -> &mut libbpf_rs::OpenProgram {
-            self.inner.prog_mut("on_event").unwrap()
-        }
-
-        pub fn walk_python_stack(&mut self) -> &mut libbpf_rs::OpenProgram {
-            self.inner.prog_mut("walk_python_stack").unwrap()
-        }
-    }
-
-    pub mod pyperf_rodata_types {
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct rodata {
-            pub verbose: bool,
-            __pad_1: [u8; 795],
-            pub bpf_metadata_name: [i8; 46],
-        }
-    }
-
-    pub mod pyperf_bss_types {
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct bss {
-            pub _event: event,
-        }
-        #[derive(Debug, Copy, Clone)]
-        #[repr(C)]
-        pub struct event {
-            pub tid: i32,
-            pub pid: i32,
-            pub uid: u32,
-            pub comm: [u8; 16],
-            pub kernel_stack_id: i32,
-            pub user_stack_id: i32,
-            pub msg: [u8; 256],
-        }
-        impl Default for event {
-            fn default() -> Self {
-                event {
-                    tid: i32::default(),
-                    pid: i32::default(),
-                    uid: u32::default(),
-                    comm: [u8::default(); 16],
-                    kernel_stack_id: i32::default(),
-                    user_stack_id: i32::default(),
-                    msg: [u8::default(); 256],
-                }
-            }
-        }
-    }
-
-    pub struct OpenPyperfSkel<'a> {
-        pub obj: libbpf_rs::OpenObject,
-        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'a>,
-    }
-
-    impl<'a> OpenSkel for OpenPyperfSkel<'a> {
-        type Output = PyperfSkel<'a>;
-        fn load(mut self) -> libbpf_rs::Result<PyperfSkel<'a>> {
-            let ret = unsafe { libbpf_sys::bpf_object__load_skeleton(self.skel_config.get()) };
-            if ret != 0 {
-                return Err(libbpf_rs::Error::System(-ret));
-            }
-
-            let obj = unsafe { libbpf_rs::Object::from_ptr(self.obj.take_ptr())? };
-
-            Ok(PyperfSkel {
-                obj,
-                skel_config: self.skel_config,
-                links: PyperfLinks::default(),
-            })
-        }
-
-        fn open_object(&self) -> &libbpf_rs::OpenObject {
-            &self.obj
-        }
-
-        fn open_object_mut(&mut self) -> &mut libbpf_rs::OpenObject {
-            &mut self.obj
-        }
-    }
-    impl OpenPyperfSkel<'_> {
-        pub fn progs(&self) -> OpenPyperfProgs<'_> {
-            OpenPyperfProgs { inner: &self.obj }
-        }
-
-        pub fn progs_mut(&mut self) -> OpenPyperfProgsMut<'_> {
-            OpenPyperfProgsMut {
-                inner: &mut self.obj,
-            }
-        }
-
-        pub fn maps(&self) -> OpenPyperfMaps<'_> {
-            OpenPyperfMaps { inner: &self.obj }
-        }
-
-        pub fn maps_mut(&mut self) -> OpenPyperfMapsMut<'_> {
-            OpenPyperfMapsMut {
-                inner: &mut self.obj,
-            }
-        }
-
-        pub fn rodata(&mut self) -> &'_ mut pyperf_rodata_types::rodata {
-            unsafe {
-                std::mem::transmute::<*mut std::ffi::c_void, &'_ mut pyperf_rodata_types::rodata>(
-                    self.skel_config.map_mmap_ptr(10).unwrap(),
-                )
-            }
-        }
-
-        pub fn bss(&mut self) -> &'_ mut pyperf_bss_types::bss {
-            unsafe {
-                std::mem::transmute::<*mut std::ffi::c_void, &'_ mut pyperf_bss_types::bss>(
-                    self.skel_config.map_mmap_ptr(11).unwrap(),
-                )
-            }
-        }
-    }
-
-    pub struct PyperfMaps<'a> {
-        inner: &'a libbpf_rs::Object,
-    }
-
-    impl PyperfMaps<'_> {
-        pub fn pid_to_process_info(&self) -> &libbpf_rs::Map {
-            self.inner.map("pid_to_process_info").unwrap()
-        }
-
-        pub fn global_state(&self) -> &libbpf_rs::Map {
-            self.inner.map("global_state").unwrap()
-        }
-
-        pub fn stack_traces(&self) -> &libbpf_rs::Map {
-            self.inner.map("stack_traces").unwrap()
-        }
-
-        pub fn stack_counts(&self) -> &libbpf_rs::Map {
-            self.inner.map("stack_counts").unwrap()
-        }
-
-        pub fn version_specific_offsets(&self) -> &libbpf_rs::Map {
-            self.inner.map("version_specific_offsets").unwrap()
-        }
-
-        pub fn programs(&self) -> &libbpf_rs::Map {
-            self.inner.map("programs").unwrap()
-        }
-
-        pub fn events(&self) -> &libbpf_rs::Map {
-            self.inner.map("events").unwrap()
-        }
-
-        pub fn stackmap(&self) -> &libbpf_rs::Map {
-            self.inner.map("stackmap").unwrap()
-        }
-
-        pub fn symbol_index(&self) -> &libbpf_rs::Map {
-            self.inner.map("symbol_index").unwrap()
-        }
-
-        pub fn symbols(&self) -> &libbpf_rs::Map {
-            self.inner.map("symbols").unwrap()
-        }
-
-        pub fn rodata(&self) -> &libbpf_rs::Map {
-            self.inner.map("pyperf_b.rodata").unwrap()
-        }
-
-        pub fn bss(&self) -> &libbpf_rs::Map {
-            self.inner.map("pyperf_b.bss").unwrap()
-        }
-    }
-
-    pub struct PyperfMapsMut<'a> {
-        inner: &'a mut libbpf_rs::Object,
-    }
-
-    impl PyperfMapsMut<'_> {
-        pub fn pid_to_process_info(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("pid_to_process_info").unwrap()
-        }
-
-        pub fn global_state(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("global_state").unwrap()
-        }
-
-        pub fn stack_traces(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("stack_traces").unwrap()
-        }
-
-        pub fn stack_counts(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("stack_counts").unwrap()
-        }
-
-        pub fn version_specific_offsets(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("version_specific_offsets").unwrap()
-        }
-
-        pub fn programs(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("programs").unwrap()
-        }
-
-        pub fn events(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("events").unwrap()
-        }
-
-        pub fn stackmap(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("stackmap").unwrap()
-        }
-
-        pub fn symbol_index(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("symbol_index").unwrap()
-        }
-
-        pub fn symbols(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("symbols").unwrap()
-        }
-
-        pub fn rodata(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("pyperf_b.rodata").unwrap()
-        }
-
-        pub fn bss(&mut self) -> &mut libbpf_rs::Map {
-            self.inner.map_mut("pyperf_b.bss").unwrap()
-        }
-    }
-
-    pub struct PyperfProgs<'a> {
-        inner: &'a libbpf_rs::Object,
-    }
-
-    impl PyperfProgs<'_> {
-        pub fn on_event(&self) -> &libbpf_rs::Program {
-            self.inner.prog("on_event").unwrap()
-        }
-
-        pub fn walk_python_stack(&self) -> &libbpf_rs::Program {
-            self.inner.prog("walk_python_stack").unwrap()
-        }
-    }
-
-    pub struct PyperfProgsMut<'a> {
-        inner: &'a mut libbpf_rs::Object,
-    }
-
-    impl PyperfProgsMut<'_> {
-        pub fn on_event(&mut self) -> &mut libbpf_rs::Program {
-            self.inner.prog_mut("on_event").unwrap()
-        }
-
-        pub fn walk_python_stack(&mut self) -> &mut libbpf_rs::Program {
-            self.inner.prog_mut("walk_python_stack").unwrap()
-        }
-    }
-
-    #[derive(Default)]
-    pub struct PyperfLinks {
-        pub on_event: Option<libbpf_rs::Link>,
-        pub walk_python_stack: Option<libbpf_rs::Link>,
-    }
-
-    pub struct PyperfSkel<'a> {
-        pub obj: libbpf_rs::Object,
-        skel_config: libbpf_rs::__internal_skel::ObjectSkeletonConfig<'a>,
-        pub links: PyperfLinks,
-    }
-
-    unsafe impl Send for PyperfSkel<'_> {}
-    unsafe impl Sync for PyperfSkel<'_> {}
-
-    impl Skel for PyperfSkel<'_> {
-        fn object(&self) -> &libbpf_rs::Object {
-            &self.obj
-        }
-
-        fn object_mut(&mut self) -> &mut libbpf_rs::Object {
-            &mut self.obj
-        }
-
-        fn attach(&mut self) -> libbpf_rs::Result<()> {
-            let ret = unsafe { libbpf_sys::bpf_object__attach_skeleton(self.skel_config.get()) };
-            if ret != 0 {
-                return Err(libbpf_rs::Error::System(-ret));
-            }
-
-            self.links = PyperfLinks {
-                on_event: (|| {
-                    Ok(core::ptr::NonNull::new(self.skel_config.prog_link_ptr(0)?)
-                        .map(|ptr| unsafe { libbpf_rs::Link::from_ptr(ptr) }))
-                })()?,
-                walk_python_stack: (|| {
-                    Ok(core::ptr::NonNull::new(self.skel_config.prog_link_ptr(1)?)
-                        .map(|ptr| unsafe { libbpf_rs::Link::from_ptr(ptr) }))
-                })()?,
-            };
-
-            Ok(())
-        }
-    }
-    impl PyperfSkel<'_> {
-        pub fn progs(&self) -> PyperfProgs<'_> {
-            PyperfProgs { inner: &self.obj }
-        }
-
-        pub fn progs_mut(&mut self) -> PyperfProgsMut<'_> {
-            PyperfProgsMut {
-                inner: &mut self.obj,
-            }
-        }
-
-        pub fn maps(&self) -> PyperfMaps<'_> {
-            PyperfMaps { inner: &self.obj }
-        }
-
-        pub fn maps_mut(&mut self) -> PyperfMapsMut<'_> {
-            PyperfMapsMut {
-                inner: &mut self.obj,
-            }
-        }
-
-        pub fn rodata(&mut self) -> &'_ pyperf_rodata_types::rodata {
-            unsafe {
-                std::mem::transmute::<*mut std::ffi::c_void, &'_ pyperf_rodata_types::rodata>(
-                    self.skel_config.map_mmap_ptr(10).unwrap(),
-                )
-            }
-        }
-
-        pub fn bss(&mut self) -> &'_ mut pyperf_bss_types::bss {
-            unsafe {
-                std::mem::transmute::<*mut std::ffi::c_void, &'_ mut pyperf_bss_types::bss>(
-                    self.skel_config.map_mmap_ptr(11).unwrap(),
-                )
-            }
-        }
-    }
-
-    const DATA: &[u8] = &[
-        127, 69, 76, 70, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 247, 0, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0,
-        64, 0, 12, 0, 1, 0, 0, 46, 115, 116, 114, 116, 97, 98, 0, 46, 115, 121, 109, 116, 97, 98,
-        0, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 0, 46, 114, 111, 100, 97, 116, 97, 0,
-        46, 98, 115, 115, 0, 46, 109, 97, 112, 115, 0, 118, 101, 114, 115, 105, 111, 110, 0, 108,
-        105, 99, 101, 110, 115, 101, 0, 112, 121, 112, 101, 114, 102, 46, 98, 112, 102, 46, 99, 0,
-        76, 66, 66, 48, 95, 53, 55, 0, 76, 66, 66, 48, 95, 52, 0, 111, 110, 95, 101, 118, 101, 110,
-        116, 46, 95, 95, 95, 95, 102, 109, 116, 0, 76, 66, 66, 48, 95, 54, 0, 111, 110, 95, 101,
-        118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 0, 76, 66, 66, 48, 95, 57,
-        0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 0, 76,
-        66, 66, 48, 95, 49, 53, 0, 76, 66, 66, 48, 95, 49, 52, 0, 76, 66, 66, 48, 95, 49, 54, 0,
-        98, 112, 102, 95, 109, 97, 112, 95, 108, 111, 111, 107, 117, 112, 95, 111, 114, 95, 116,
-        114, 121, 95, 105, 110, 105, 116, 46, 95, 95, 95, 95, 102, 109, 116, 0, 76, 66, 66, 48, 95,
-        49, 56, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46,
-        51, 0, 76, 66, 66, 48, 95, 50, 48, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95,
-        95, 95, 102, 109, 116, 46, 52, 0, 76, 66, 66, 48, 95, 50, 50, 0, 111, 110, 95, 101, 118,
-        101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 53, 0, 76, 66, 66, 48, 95, 50, 54, 0,
-        76, 66, 66, 48, 95, 53, 50, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95,
-        102, 109, 116, 46, 54, 0, 76, 66, 66, 48, 95, 50, 56, 0, 111, 110, 95, 101, 118, 101, 110,
-        116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 55, 0, 76, 66, 66, 48, 95, 51, 50, 0, 111, 110,
-        95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 56, 0, 76, 66, 66, 48,
-        95, 51, 49, 0, 76, 66, 66, 48, 95, 51, 52, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46,
-        95, 95, 95, 95, 102, 109, 116, 46, 57, 0, 76, 66, 66, 48, 95, 51, 55, 0, 111, 110, 95, 101,
-        118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 48, 0, 76, 66, 66, 48, 95,
-        51, 57, 0, 76, 66, 66, 48, 95, 51, 56, 0, 76, 66, 66, 48, 95, 52, 57, 0, 76, 66, 66, 48,
-        95, 52, 54, 0, 76, 66, 66, 48, 95, 53, 49, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46,
-        95, 95, 95, 95, 102, 109, 116, 46, 49, 52, 0, 76, 66, 66, 48, 95, 52, 51, 0, 76, 66, 66,
-        48, 95, 52, 50, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109,
-        116, 46, 49, 49, 0, 76, 66, 66, 48, 95, 52, 56, 0, 111, 110, 95, 101, 118, 101, 110, 116,
-        46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 51, 0, 76, 66, 66, 48, 95, 53, 52, 0, 115, 117,
-        98, 109, 105, 116, 95, 115, 97, 109, 112, 108, 101, 46, 95, 95, 95, 95, 102, 109, 116, 0,
-        76, 66, 66, 48, 95, 53, 54, 0, 115, 117, 98, 109, 105, 116, 95, 115, 97, 109, 112, 108,
-        101, 46, 95, 95, 95, 95, 102, 109, 116, 46, 51, 49, 0, 76, 66, 66, 48, 95, 52, 53, 0, 111,
-        110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 50, 0, 76, 66,
-        66, 49, 95, 53, 57, 48, 0, 76, 66, 66, 49, 95, 52, 0, 119, 97, 108, 107, 95, 112, 121, 116,
-        104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 0, 76, 66, 66,
-        49, 95, 53, 54, 55, 0, 76, 66, 66, 49, 95, 55, 0, 76, 66, 66, 49, 95, 49, 48, 0, 76, 66,
-        66, 49, 95, 56, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97,
-        99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 53, 0, 76, 66, 66, 49, 95, 53, 54, 57,
-        0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95,
-        95, 95, 95, 102, 109, 116, 46, 50, 52, 0, 76, 66, 66, 49, 95, 53, 55, 49, 0, 119, 97, 108,
-        107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102,
-        109, 116, 46, 50, 53, 0, 76, 66, 66, 49, 95, 53, 55, 53, 0, 76, 66, 66, 49, 95, 53, 55, 52,
-        0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95,
-        95, 95, 95, 102, 109, 116, 46, 50, 54, 0, 76, 66, 66, 49, 95, 53, 56, 51, 0, 76, 66, 66,
-        49, 95, 53, 55, 55, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116,
-        97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 55, 0, 76, 66, 66, 49, 95, 53, 55,
-        57, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46,
-        95, 95, 95, 95, 102, 109, 116, 46, 50, 56, 0, 76, 66, 66, 49, 95, 49, 50, 0, 119, 97, 108,
-        107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102,
-        109, 116, 46, 49, 54, 0, 76, 66, 66, 49, 95, 49, 52, 0, 119, 97, 108, 107, 95, 112, 121,
-        116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49,
-        55, 0, 76, 66, 66, 49, 95, 49, 54, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110,
-        95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 56, 0, 76, 66, 66,
-        49, 95, 49, 56, 0, 76, 66, 66, 49, 95, 50, 49, 0, 76, 66, 66, 49, 95, 50, 48, 0, 76, 66,
-        66, 49, 95, 50, 51, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116,
-        97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 57, 0, 76, 66, 66, 49, 95, 50, 53,
-        0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95,
-        95, 95, 95, 102, 109, 116, 46, 50, 48, 0, 76, 66, 66, 49, 95, 50, 55, 0, 119, 97, 108, 107,
-        95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109,
-        116, 46, 50, 49, 0, 76, 66, 66, 49, 95, 50, 57, 0, 119, 97, 108, 107, 95, 112, 121, 116,
-        104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 50, 0,
-        76, 66, 66, 49, 95, 51, 49, 0, 76, 66, 66, 49, 95, 51, 54, 0, 76, 66, 66, 49, 95, 51, 53,
-        0, 103, 101, 116, 95, 115, 121, 109, 98, 111, 108, 95, 105, 100, 46, 95, 95, 95, 95, 102,
-        109, 116, 0, 76, 66, 66, 49, 95, 52, 48, 0, 76, 66, 66, 49, 95, 51, 57, 0, 119, 97, 108,
-        107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102,
-        109, 116, 46, 50, 51, 0, 76, 66, 66, 49, 95, 53, 56, 48, 0, 76, 66, 66, 49, 95, 52, 52, 0,
-        76, 66, 66, 49, 95, 52, 55, 0, 76, 66, 66, 49, 95, 52, 57, 0, 76, 66, 66, 49, 95, 53, 49,
-        0, 76, 66, 66, 49, 95, 53, 51, 0, 76, 66, 66, 49, 95, 53, 54, 0, 76, 66, 66, 49, 95, 53,
-        53, 0, 76, 66, 66, 49, 95, 53, 56, 0, 76, 66, 66, 49, 95, 54, 48, 0, 76, 66, 66, 49, 95,
-        54, 50, 0, 76, 66, 66, 49, 95, 54, 52, 0, 76, 66, 66, 49, 95, 54, 54, 0, 76, 66, 66, 49,
-        95, 55, 49, 0, 76, 66, 66, 49, 95, 55, 48, 0, 76, 66, 66, 49, 95, 55, 53, 0, 76, 66, 66,
-        49, 95, 55, 52, 0, 76, 66, 66, 49, 95, 55, 57, 0, 76, 66, 66, 49, 95, 56, 50, 0, 76, 66,
-        66, 49, 95, 56, 52, 0, 76, 66, 66, 49, 95, 56, 54, 0, 76, 66, 66, 49, 95, 56, 56, 0, 76,
-        66, 66, 49, 95, 57, 49, 0, 76, 66, 66, 49, 95, 57, 48, 0, 76, 66, 66, 49, 95, 57, 51, 0,
-        76, 66, 66, 49, 95, 57, 53, 0, 76, 66, 66, 49, 95, 57, 55, 0, 76, 66, 66, 49, 95, 57, 57,
-        0, 76, 66, 66, 49, 95, 49, 48, 49, 0, 76, 66, 66, 49, 95, 49, 48, 54, 0, 76, 66, 66, 49,
-        95, 49, 48, 53, 0, 76, 66, 66, 49, 95, 49, 49, 48, 0, 76, 66, 66, 49, 95, 49, 48, 57, 0,
-        76, 66, 66, 49, 95, 49, 49, 52, 0, 76, 66, 66, 49, 95, 49, 49, 55, 0, 76, 66, 66, 49, 95,
-        49, 49, 57, 0, 76, 66, 66, 49, 95, 49, 50, 49, 0, 76, 66, 66, 49, 95, 49, 50, 51, 0, 76,
-        66, 66, 49, 95, 49, 50, 54, 0, 76, 66, 66, 49, 95, 49, 50, 53, 0, 76, 66, 66, 49, 95, 49,
-        50, 56, 0, 76, 66, 66, 49, 95, 49, 51, 48, 0, 76, 66, 66, 49, 95, 49, 51, 50, 0, 76, 66,
-        66, 49, 95, 49, 51, 52, 0, 76, 66, 66, 49, 95, 49, 51, 54, 0, 76, 66, 66, 49, 95, 49, 52,
-        49, 0, 76, 66, 66, 49, 95, 49, 52, 48, 0, 76, 66, 66, 49, 95, 49, 52, 53, 0, 76, 66, 66,
-        49, 95, 49, 52, 52, 0, 76, 66, 66, 49, 95, 49, 52, 57, 0, 76, 66, 66, 49, 95, 49, 53, 50,
-        0, 76, 66, 66, 49, 95, 49, 53, 52, 0, 76, 66, 66, 49, 95, 49, 53, 54, 0, 76, 66, 66, 49,
-        95, 49, 53, 56, 0, 76, 66, 66, 49, 95, 49, 54, 49, 0, 76, 66, 66, 49, 95, 49, 54, 48, 0,
-        76, 66, 66, 49, 95, 49, 54, 51, 0, 76, 66, 66, 49, 95, 49, 54, 53, 0, 76, 66, 66, 49, 95,
-        49, 54, 55, 0, 76, 66, 66, 49, 95, 49, 54, 57, 0, 76, 66, 66, 49, 95, 49, 55, 49, 0, 76,
-        66, 66, 49, 95, 49, 55, 54, 0, 76, 66, 66, 49, 95, 49, 55, 53, 0, 76, 66, 66, 49, 95, 49,
-        56, 48, 0, 76, 66, 66, 49, 95, 49, 55, 57, 0, 76, 66, 66, 49, 95, 49, 56, 52, 0, 76, 66,
-        66, 49, 95, 49, 56, 55, 0, 76, 66, 66, 49, 95, 49, 56, 57, 0, 76, 66, 66, 49, 95, 49, 57,
-        49, 0, 76, 66, 66, 49, 95, 49, 57, 51, 0, 76, 66, 66, 49, 95, 49, 57, 54, 0, 76, 66, 66,
-        49, 95, 49, 57, 53, 0, 76, 66, 66, 49, 95, 49, 57, 56, 0, 76, 66, 66, 49, 95, 50, 48, 48,
-        0, 76, 66, 66, 49, 95, 50, 48, 50, 0, 76, 66, 66, 49, 95, 50, 48, 52, 0, 76, 66, 66, 49,
-        95, 50, 48, 54, 0, 76, 66, 66, 49, 95, 50, 49, 49, 0, 76, 66, 66, 49, 95, 50, 49, 48, 0,
-        76, 66, 66, 49, 95, 50, 49, 53, 0, 76, 66, 66, 49, 95, 50, 49, 52, 0, 76, 66, 66, 49, 95,
-        50, 49, 57, 0, 76, 66, 66, 49, 95, 50, 50, 50, 0, 76, 66, 66, 49, 95, 50, 50, 52, 0, 76,
-        66, 66, 49, 95, 50, 50, 54, 0, 76, 66, 66, 49, 95, 50, 50, 56, 0, 76, 66, 66, 49, 95, 50,
-        51, 49, 0, 76, 66, 66, 49, 95, 50, 51, 48, 0, 76, 66, 66, 49, 95, 50, 51, 51, 0, 76, 66,
-        66, 49, 95, 50, 51, 53, 0, 76, 66, 66, 49, 95, 50, 51, 55, 0, 76, 66, 66, 49, 95, 50, 51,
-        57, 0, 76, 66, 66, 49, 95, 50, 52, 49, 0, 76, 66, 66, 49, 95, 50, 52, 54, 0, 76, 66, 66,
-        49, 95, 50, 52, 53, 0, 76, 66, 66, 49, 95, 50, 53, 48, 0, 76, 66, 66, 49, 95, 50, 52, 57,
-        0, 76, 66, 66, 49, 95, 50, 53, 52, 0, 76, 66, 66, 49, 95, 50, 53, 55, 0, 76, 66, 66, 49,
-        95, 50, 53, 57, 0, 76, 66, 66, 49, 95, 50, 54, 49, 0, 76, 66, 66, 49, 95, 50, 54, 51, 0,
-        76, 66, 66, 49, 95, 50, 54, 54, 0, 76, 66, 66, 49, 95, 50, 54, 53, 0, 76, 66, 66, 49, 95,
-        50, 54, 56, 0, 76, 66, 66, 49, 95, 50, 55, 48, 0, 76, 66, 66, 49, 95, 50, 55, 50, 0, 76,
-        66, 66, 49, 95, 50, 55, 52, 0, 76, 66, 66, 49, 95, 50, 55, 54, 0, 76, 66, 66, 49, 95, 50,
-        56, 49, 0, 76, 66, 66, 49, 95, 50, 56, 48, 0, 76, 66, 66, 49, 95, 50, 56, 53, 0, 76, 66,
-        66, 49, 95, 50, 56, 52, 0, 76, 66, 66, 49, 95, 50, 56, 57, 0, 76, 66, 66, 49, 95, 50, 57,
-        50, 0, 76, 66, 66, 49, 95, 50, 57, 52, 0, 76, 66, 66, 49, 95, 50, 57, 54, 0, 76, 66, 66,
-        49, 95, 50, 57, 56, 0, 76, 66, 66, 49, 95, 51, 48, 49, 0, 76, 66, 66, 49, 95, 51, 48, 48,
-        0, 76, 66, 66, 49, 95, 51, 48, 51, 0, 76, 66, 66, 49, 95, 51, 48, 53, 0, 76, 66, 66, 49,
-        95, 51, 48, 55, 0, 76, 66, 66, 49, 95, 51, 48, 57, 0, 76, 66, 66, 49, 95, 51, 49, 49, 0,
-        76, 66, 66, 49, 95, 51, 49, 54, 0, 76, 66, 66, 49, 95, 51, 49, 53, 0, 76, 66, 66, 49, 95,
-        51, 50, 48, 0, 76, 66, 66, 49, 95, 51, 49, 57, 0, 76, 66, 66, 49, 95, 51, 50, 52, 0, 76,
-        66, 66, 49, 95, 51, 50, 55, 0, 76, 66, 66, 49, 95, 51, 50, 57, 0, 76, 66, 66, 49, 95, 51,
-        51, 49, 0, 76, 66, 66, 49, 95, 51, 51, 51, 0, 76, 66, 66, 49, 95, 51, 51, 54, 0, 76, 66,
-        66, 49, 95, 51, 51, 53, 0, 76, 66, 66, 49, 95, 51, 51, 56, 0, 76, 66, 66, 49, 95, 51, 52,
-        48, 0, 76, 66, 66, 49, 95, 51, 52, 50, 0, 76, 66, 66, 49, 95, 51, 52, 52, 0, 76, 66, 66,
-        49, 95, 51, 52, 54, 0, 76, 66, 66, 49, 95, 51, 53, 49, 0, 76, 66, 66, 49, 95, 51, 53, 48,
-        0, 76, 66, 66, 49, 95, 51, 53, 53, 0, 76, 66, 66, 49, 95, 51, 53, 52, 0, 76, 66, 66, 49,
-        95, 51, 53, 57, 0, 76, 66, 66, 49, 95, 51, 54, 50, 0, 76, 66, 66, 49, 95, 51, 54, 52, 0,
-        76, 66, 66, 49, 95, 51, 54, 54, 0, 76, 66, 66, 49, 95, 51, 54, 56, 0, 76, 66, 66, 49, 95,
-        51, 55, 49, 0, 76, 66, 66, 49, 95, 51, 55, 48, 0, 76, 66, 66, 49, 95, 51, 55, 51, 0, 76,
-        66, 66, 49, 95, 51, 55, 53, 0, 76, 66, 66, 49, 95, 51, 55, 55, 0, 76, 66, 66, 49, 95, 51,
-        55, 57, 0, 76, 66, 66, 49, 95, 51, 56, 49, 0, 76, 66, 66, 49, 95, 51, 56, 54, 0, 76, 66,
-        66, 49, 95, 51, 56, 53, 0, 76, 66, 66, 49, 95, 51, 57, 48, 0, 76, 66, 66, 49, 95, 51, 56,
-        57, 0, 76, 66, 66, 49, 95, 51, 57, 52, 0, 76, 66, 66, 49, 95, 51, 57, 55, 0, 76, 66, 66,
-        49, 95, 51, 57, 57, 0, 76, 66, 66, 49, 95, 52, 48, 49, 0, 76, 66, 66, 49, 95, 52, 48, 51,
-        0, 76, 66, 66, 49, 95, 52, 48, 54, 0, 76, 66, 66, 49, 95, 52, 48, 53, 0, 76, 66, 66, 49,
-        95, 52, 48, 56, 0, 76, 66, 66, 49, 95, 52, 49, 48, 0, 76, 66, 66, 49, 95, 52, 49, 50, 0,
-        76, 66, 66, 49, 95, 52, 49, 52, 0, 76, 66, 66, 49, 95, 52, 49, 54, 0, 76, 66, 66, 49, 95,
-        52, 50, 49, 0, 76, 66, 66, 49, 95, 52, 50, 48, 0, 76, 66, 66, 49, 95, 52, 50, 53, 0, 76,
-        66, 66, 49, 95, 52, 50, 52, 0, 76, 66, 66, 49, 95, 52, 50, 57, 0, 76, 66, 66, 49, 95, 52,
-        51, 50, 0, 76, 66, 66, 49, 95, 52, 51, 52, 0, 76, 66, 66, 49, 95, 52, 51, 54, 0, 76, 66,
-        66, 49, 95, 52, 51, 56, 0, 76, 66, 66, 49, 95, 52, 52, 49, 0, 76, 66, 66, 49, 95, 52, 52,
-        48, 0, 76, 66, 66, 49, 95, 52, 52, 51, 0, 76, 66, 66, 49, 95, 52, 52, 53, 0, 76, 66, 66,
-        49, 95, 52, 52, 55, 0, 76, 66, 66, 49, 95, 52, 52, 57, 0, 76, 66, 66, 49, 95, 52, 53, 49,
-        0, 76, 66, 66, 49, 95, 52, 53, 54, 0, 76, 66, 66, 49, 95, 52, 53, 53, 0, 76, 66, 66, 49,
-        95, 52, 54, 48, 0, 76, 66, 66, 49, 95, 52, 53, 57, 0, 76, 66, 66, 49, 95, 52, 54, 52, 0,
-        76, 66, 66, 49, 95, 52, 54, 55, 0, 76, 66, 66, 49, 95, 52, 54, 57, 0, 76, 66, 66, 49, 95,
-        52, 55, 49, 0, 76, 66, 66, 49, 95, 52, 55, 51, 0, 76, 66, 66, 49, 95, 52, 55, 54, 0, 76,
-        66, 66, 49, 95, 52, 55, 53, 0, 76, 66, 66, 49, 95, 52, 55, 56, 0, 76, 66, 66, 49, 95, 52,
-        56, 48, 0, 76, 66, 66, 49, 95, 52, 56, 50, 0, 76, 66, 66, 49, 95, 52, 56, 52, 0, 76, 66,
-        66, 49, 95, 52, 56, 54, 0, 76, 66, 66, 49, 95, 52, 57, 49, 0, 76, 66, 66, 49, 95, 52, 57,
-        48, 0, 76, 66, 66, 49, 95, 52, 57, 53, 0, 76, 66, 66, 49, 95, 52, 57, 52, 0, 76, 66, 66,
-        49, 95, 52, 57, 57, 0, 76, 66, 66, 49, 95, 53, 48, 50, 0, 76, 66, 66, 49, 95, 53, 48, 52,
-        0, 76, 66, 66, 49, 95, 53, 48, 54, 0, 76, 66, 66, 49, 95, 53, 48, 56, 0, 76, 66, 66, 49,
-        95, 53, 49, 49, 0, 76, 66, 66, 49, 95, 53, 49, 48, 0, 76, 66, 66, 49, 95, 53, 49, 51, 0,
-        76, 66, 66, 49, 95, 53, 49, 53, 0, 76, 66, 66, 49, 95, 53, 49, 55, 0, 76, 66, 66, 49, 95,
-        53, 49, 57, 0, 76, 66, 66, 49, 95, 53, 50, 49, 0, 76, 66, 66, 49, 95, 53, 50, 54, 0, 76,
-        66, 66, 49, 95, 53, 50, 53, 0, 76, 66, 66, 49, 95, 53, 51, 48, 0, 76, 66, 66, 49, 95, 53,
-        50, 57, 0, 76, 66, 66, 49, 95, 53, 51, 52, 0, 76, 66, 66, 49, 95, 53, 51, 55, 0, 76, 66,
-        66, 49, 95, 53, 51, 57, 0, 76, 66, 66, 49, 95, 53, 52, 49, 0, 76, 66, 66, 49, 95, 53, 52,
-        51, 0, 76, 66, 66, 49, 95, 53, 52, 54, 0, 76, 66, 66, 49, 95, 53, 52, 53, 0, 76, 66, 66,
-        49, 95, 53, 52, 56, 0, 76, 66, 66, 49, 95, 53, 53, 48, 0, 76, 66, 66, 49, 95, 53, 53, 50,
-        0, 76, 66, 66, 49, 95, 53, 53, 52, 0, 76, 66, 66, 49, 95, 53, 53, 54, 0, 76, 66, 66, 49,
-        95, 53, 54, 49, 0, 76, 66, 66, 49, 95, 53, 54, 48, 0, 76, 66, 66, 49, 95, 53, 54, 53, 0,
-        76, 66, 66, 49, 95, 53, 54, 52, 0, 76, 66, 66, 49, 95, 53, 56, 50, 0, 119, 97, 108, 107,
-        95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109,
-        116, 46, 50, 57, 0, 76, 66, 66, 49, 95, 53, 56, 53, 0, 119, 97, 108, 107, 95, 112, 121,
-        116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 51,
-        48, 0, 76, 66, 66, 49, 95, 53, 56, 55, 0, 76, 66, 66, 49, 95, 53, 56, 57, 0, 111, 110, 95,
-        101, 118, 101, 110, 116, 0, 112, 105, 100, 95, 116, 111, 95, 112, 114, 111, 99, 101, 115,
-        115, 95, 105, 110, 102, 111, 0, 118, 101, 114, 98, 111, 115, 101, 0, 103, 108, 111, 98, 97,
-        108, 95, 115, 116, 97, 116, 101, 0, 115, 116, 97, 99, 107, 95, 116, 114, 97, 99, 101, 115,
-        0, 115, 116, 97, 99, 107, 95, 99, 111, 117, 110, 116, 115, 0, 118, 101, 114, 115, 105, 111,
-        110, 95, 115, 112, 101, 99, 105, 102, 105, 99, 95, 111, 102, 102, 115, 101, 116, 115, 0,
-        112, 114, 111, 103, 114, 97, 109, 115, 0, 101, 118, 101, 110, 116, 115, 0, 119, 97, 108,
-        107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 0, 115, 121, 109, 98,
-        111, 108, 115, 0, 115, 121, 109, 98, 111, 108, 95, 105, 110, 100, 101, 120, 0, 95, 101,
-        118, 101, 110, 116, 0, 98, 112, 102, 95, 109, 101, 116, 97, 100, 97, 116, 97, 95, 110, 97,
-        109, 101, 0, 86, 69, 82, 83, 73, 79, 78, 0, 76, 73, 67, 69, 78, 83, 69, 0, 115, 116, 97,
-        99, 107, 109, 97, 112, 0, 46, 114, 101, 108, 112, 101, 114, 102, 95, 101, 118, 101, 110,
-        116, 0, 46, 66, 84, 70, 0, 46, 66, 84, 70, 46, 101, 120, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 4, 0, 241, 255, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 3, 0, 200, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 84, 0, 0, 0, 0, 0, 3, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0,
-        1, 0, 4, 0, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 3, 0, 240,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 1, 0, 4, 0, 9, 0, 0, 0, 0, 0, 0,
-        0, 22, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 3, 0, 72, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 141, 0, 0, 0, 1, 0, 4, 0, 31, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0,
-        160, 0, 0, 0, 0, 0, 3, 0, 24, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0,
-        0, 3, 0, 240, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 3, 0, 40, 8,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 1, 0, 4, 0, 74, 3, 0, 0, 0, 0, 0,
-        0, 48, 0, 0, 0, 0, 0, 0, 0, 219, 0, 0, 0, 0, 0, 3, 0, 152, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 227, 0, 0, 0, 1, 0, 4, 0, 79, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0,
-        246, 0, 0, 0, 0, 0, 3, 0, 208, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 0, 0,
-        1, 0, 4, 0, 118, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 17, 1, 0, 0, 0, 0, 3, 0, 96,
-        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 1, 0, 0, 1, 0, 4, 0, 138, 0, 0, 0, 0, 0,
-        0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 44, 1, 0, 0, 0, 0, 3, 0, 48, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 52, 1, 0, 0, 0, 0, 3, 0, 16, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        60, 1, 0, 0, 1, 0, 4, 0, 154, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 79, 1, 0, 0, 0,
-        0, 3, 0, 88, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 1, 0, 0, 1, 0, 4, 0, 187, 0,
-        0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 106, 1, 0, 0, 0, 0, 3, 0, 216, 10, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 1, 0, 4, 0, 210, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0,
-        0, 0, 0, 0, 0, 133, 1, 0, 0, 0, 0, 3, 0, 72, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        141, 1, 0, 0, 0, 0, 3, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 1, 0, 0, 1,
-        0, 4, 0, 240, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 168, 1, 0, 0, 0, 0, 3, 0, 96,
-        11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 1, 0, 0, 1, 0, 4, 0, 4, 1, 0, 0, 0, 0,
-        0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 196, 1, 0, 0, 0, 0, 3, 0, 56, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 204, 1, 0, 0, 0, 0, 3, 0, 136, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        212, 1, 0, 0, 0, 0, 3, 0, 224, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 1, 0, 0,
-        0, 0, 3, 0, 216, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 1, 0, 0, 0, 0, 3, 0, 8,
-        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 236, 1, 0, 0, 1, 0, 4, 0, 99, 1, 0, 0, 0, 0,
-        0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0, 216, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 3, 0, 184, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        16, 2, 0, 0, 1, 0, 4, 0, 34, 1, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 36, 2, 0, 0, 0,
-        0, 3, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 2, 0, 0, 1, 0, 4, 0, 72, 1,
-        0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 64, 2, 0, 0, 0, 0, 3, 0, 80, 13, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 2, 0, 0, 1, 0, 4, 0, 122, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0,
-        0, 0, 0, 0, 94, 2, 0, 0, 0, 0, 3, 0, 136, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        102, 2, 0, 0, 1, 0, 4, 0, 129, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 127, 2, 0, 0,
-        0, 0, 3, 0, 8, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 2, 0, 0, 1, 0, 4, 0, 58,
-        1, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 155, 2, 0, 0, 0, 0, 3, 0, 64, 153, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 2, 0, 0, 0, 0, 3, 0, 232, 14, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 171, 2, 0, 0, 1, 0, 4, 0, 116, 1, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0,
-        0, 197, 2, 0, 0, 0, 0, 3, 0, 232, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 206, 2, 0,
-        0, 0, 0, 3, 0, 168, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 213, 2, 0, 0, 0, 0, 3, 0,
-        200, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 221, 2, 0, 0, 0, 0, 3, 0, 184, 15, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 2, 0, 0, 1, 0, 4, 0, 142, 1, 0, 0, 0, 0, 0, 0, 35,
-        0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 3, 0, 48, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 10, 3, 0, 0, 1, 0, 4, 0, 41, 2, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 39, 3, 0, 0,
-        0, 0, 3, 0, 120, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 3, 0, 0, 1, 0, 4, 0, 68,
-        2, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 77, 3, 0, 0, 0, 0, 3, 0, 24, 17, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 3, 0, 0, 0, 0, 3, 0, 216, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 95, 3, 0, 0, 1, 0, 4, 0, 107, 2, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0,
-        124, 3, 0, 0, 0, 0, 3, 0, 72, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 3, 0, 0,
-        0, 0, 3, 0, 64, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 3, 0, 0, 1, 0, 4, 0,
-        136, 2, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 171, 3, 0, 0, 0, 0, 3, 0, 160, 17, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 3, 0, 0, 1, 0, 4, 0, 172, 2, 0, 0, 0, 0, 0, 0, 44,
-        0, 0, 0, 0, 0, 0, 0, 209, 3, 0, 0, 0, 0, 3, 0, 248, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 217, 3, 0, 0, 1, 0, 4, 0, 177, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 246, 3,
-        0, 0, 0, 0, 3, 0, 88, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 3, 0, 0, 1, 0, 4,
-        0, 186, 1, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 27, 4, 0, 0, 0, 0, 3, 0, 144, 18, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 4, 0, 0, 1, 0, 4, 0, 207, 1, 0, 0, 0, 0, 0, 0,
-        20, 0, 0, 0, 0, 0, 0, 0, 64, 4, 0, 0, 0, 0, 3, 0, 120, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 72, 4, 0, 0, 0, 0, 3, 0, 72, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 4,
-        0, 0, 0, 0, 3, 0, 240, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 4, 0, 0, 0, 0, 3,
-        0, 160, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 4, 0, 0, 1, 0, 4, 0, 227, 1, 0,
-        0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 125, 4, 0, 0, 0, 0, 3, 0, 216, 22, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 4, 0, 0, 1, 0, 4, 0, 239, 1, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0,
-        0, 0, 0, 0, 162, 4, 0, 0, 0, 0, 3, 0, 32, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        170, 4, 0, 0, 1, 0, 4, 0, 252, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 199, 4, 0, 0,
-        0, 0, 3, 0, 88, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 207, 4, 0, 0, 1, 0, 4, 0, 6,
-        2, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 236, 4, 0, 0, 0, 0, 3, 0, 168, 23, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 244, 4, 0, 0, 0, 0, 3, 0, 184, 24, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 252, 4, 0, 0, 0, 0, 3, 0, 176, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 4, 5, 0, 0, 1, 0, 4, 0, 130, 3, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 26, 5, 0, 0,
-        0, 0, 3, 0, 176, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 5, 0, 0, 0, 0, 3, 0,
-        120, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 5, 0, 0, 1, 0, 4, 0, 18, 2, 0, 0, 0,
-        0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 71, 5, 0, 0, 0, 0, 3, 0, 192, 151, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 80, 5, 0, 0, 0, 0, 3, 0, 136, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 88, 5, 0, 0, 0, 0, 3, 0, 200, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 5, 0,
-        0, 0, 0, 3, 0, 16, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 5, 0, 0, 0, 0, 3, 0,
-        72, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 5, 0, 0, 0, 0, 3, 0, 248, 28, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 5, 0, 0, 0, 0, 3, 0, 200, 29, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 128, 5, 0, 0, 0, 0, 3, 0, 112, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 136, 5, 0, 0, 0, 0, 3, 0, 32, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 5,
-        0, 0, 0, 0, 3, 0, 96, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 5, 0, 0, 0, 0, 3,
-        0, 168, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 5, 0, 0, 0, 0, 3, 0, 224, 31, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 5, 0, 0, 0, 0, 3, 0, 32, 32, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 176, 5, 0, 0, 0, 0, 3, 0, 40, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 184, 5, 0, 0, 0, 0, 3, 0, 32, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192,
-        5, 0, 0, 0, 0, 3, 0, 240, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 5, 0, 0, 0, 0,
-        3, 0, 184, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 208, 5, 0, 0, 0, 0, 3, 0, 192, 34,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 5, 0, 0, 0, 0, 3, 0, 8, 35, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 224, 5, 0, 0, 0, 0, 3, 0, 80, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 232, 5, 0, 0, 0, 0, 3, 0, 136, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        240, 5, 0, 0, 0, 0, 3, 0, 56, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 5, 0, 0,
-        0, 0, 3, 0, 8, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 3, 0, 176,
-        37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 6, 0, 0, 0, 0, 3, 0, 96, 39, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 6, 0, 0, 0, 0, 3, 0, 160, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 24, 6, 0, 0, 0, 0, 3, 0, 232, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        32, 6, 0, 0, 0, 0, 3, 0, 32, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 6, 0, 0, 0,
-        0, 3, 0, 96, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 6, 0, 0, 0, 0, 3, 0, 104,
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 6, 0, 0, 0, 0, 3, 0, 96, 41, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 6, 0, 0, 0, 0, 3, 0, 48, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 76, 6, 0, 0, 0, 0, 3, 0, 248, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        85, 6, 0, 0, 0, 0, 3, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 6, 0, 0, 0,
-        0, 3, 0, 72, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 6, 0, 0, 0, 0, 3, 0, 144,
-        43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 6, 0, 0, 0, 0, 3, 0, 200, 43, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 6, 0, 0, 0, 0, 3, 0, 120, 45, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 130, 6, 0, 0, 0, 0, 3, 0, 72, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 139, 6, 0, 0, 0, 0, 3, 0, 240, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 6, 0,
-        0, 0, 0, 3, 0, 160, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 6, 0, 0, 0, 0, 3, 0,
-        224, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 6, 0, 0, 0, 0, 3, 0, 40, 48, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 175, 6, 0, 0, 0, 0, 3, 0, 96, 48, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 184, 6, 0, 0, 0, 0, 3, 0, 160, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 193, 6, 0, 0, 0, 0, 3, 0, 168, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 202, 6,
-        0, 0, 0, 0, 3, 0, 160, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 211, 6, 0, 0, 0, 0, 3,
-        0, 112, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 6, 0, 0, 0, 0, 3, 0, 56, 50, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 6, 0, 0, 0, 0, 3, 0, 64, 51, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 238, 6, 0, 0, 0, 0, 3, 0, 136, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 247, 6, 0, 0, 0, 0, 3, 0, 208, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7,
-        0, 0, 0, 0, 3, 0, 8, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 7, 0, 0, 0, 0, 3, 0,
-        184, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 7, 0, 0, 0, 0, 3, 0, 136, 54, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 7, 0, 0, 0, 0, 3, 0, 48, 54, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 36, 7, 0, 0, 0, 0, 3, 0, 224, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 45, 7, 0, 0, 0, 0, 3, 0, 24, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 7, 0,
-        0, 0, 0, 3, 0, 96, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 7, 0, 0, 0, 0, 3, 0,
-        152, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 7, 0, 0, 0, 0, 3, 0, 216, 56, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 7, 0, 0, 0, 0, 3, 0, 224, 57, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 90, 7, 0, 0, 0, 0, 3, 0, 216, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 99, 7, 0, 0, 0, 0, 3, 0, 168, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 7,
-        0, 0, 0, 0, 3, 0, 112, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 7, 0, 0, 0, 0, 3,
-        0, 120, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 7, 0, 0, 0, 0, 3, 0, 192, 59, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 7, 0, 0, 0, 0, 3, 0, 8, 60, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 144, 7, 0, 0, 0, 0, 3, 0, 64, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 153, 7, 0, 0, 0, 0, 3, 0, 240, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162,
-        7, 0, 0, 0, 0, 3, 0, 200, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 7, 0, 0, 0, 0,
-        3, 0, 112, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 7, 0, 0, 0, 0, 3, 0, 40, 64,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 7, 0, 0, 0, 0, 3, 0, 96, 64, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 198, 7, 0, 0, 0, 0, 3, 0, 168, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 207, 7, 0, 0, 0, 0, 3, 0, 224, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        216, 7, 0, 0, 0, 0, 3, 0, 32, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 225, 7, 0, 0,
-        0, 0, 3, 0, 48, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 234, 7, 0, 0, 0, 0, 3, 0, 40,
-        66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 7, 0, 0, 0, 0, 3, 0, 248, 66, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 252, 7, 0, 0, 0, 0, 3, 0, 192, 66, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 5, 8, 0, 0, 0, 0, 3, 0, 208, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 14, 8, 0, 0, 0, 0, 3, 0, 32, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 8, 0, 0,
-        0, 0, 3, 0, 104, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 8, 0, 0, 0, 0, 3, 0,
-        160, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 8, 0, 0, 0, 0, 3, 0, 88, 70, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 8, 0, 0, 0, 0, 3, 0, 64, 71, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 59, 8, 0, 0, 0, 0, 3, 0, 224, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 68, 8, 0, 0, 0, 0, 3, 0, 160, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 8, 0,
-        0, 0, 0, 3, 0, 216, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 8, 0, 0, 0, 0, 3, 0,
-        32, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 95, 8, 0, 0, 0, 0, 3, 0, 88, 73, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 8, 0, 0, 0, 0, 3, 0, 152, 73, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 113, 8, 0, 0, 0, 0, 3, 0, 160, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 122, 8, 0, 0, 0, 0, 3, 0, 152, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 8, 0,
-        0, 0, 0, 3, 0, 104, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 8, 0, 0, 0, 0, 3, 0,
-        48, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 8, 0, 0, 0, 0, 3, 0, 64, 76, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 158, 8, 0, 0, 0, 0, 3, 0, 144, 76, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 167, 8, 0, 0, 0, 0, 3, 0, 216, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 176, 8, 0, 0, 0, 0, 3, 0, 16, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 8,
-        0, 0, 0, 0, 3, 0, 200, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 194, 8, 0, 0, 0, 0, 3,
-        0, 176, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 203, 8, 0, 0, 0, 0, 3, 0, 80, 79, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 8, 0, 0, 0, 0, 3, 0, 16, 81, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 221, 8, 0, 0, 0, 0, 3, 0, 72, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 230, 8, 0, 0, 0, 0, 3, 0, 144, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239,
-        8, 0, 0, 0, 0, 3, 0, 200, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 8, 0, 0, 0, 0,
-        3, 0, 8, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 9, 0, 0, 0, 0, 3, 0, 16, 83, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 9, 0, 0, 0, 0, 3, 0, 8, 83, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 19, 9, 0, 0, 0, 0, 3, 0, 216, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 28, 9, 0, 0, 0, 0, 3, 0, 160, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 9, 0,
-        0, 0, 0, 3, 0, 184, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 9, 0, 0, 0, 0, 3, 0,
-        0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 9, 0, 0, 0, 0, 3, 0, 72, 85, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 9, 0, 0, 0, 0, 3, 0, 128, 85, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 73, 9, 0, 0, 0, 0, 3, 0, 56, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 82, 9, 0, 0, 0, 0, 3, 0, 32, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 9, 0, 0,
-        0, 0, 3, 0, 192, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 9, 0, 0, 0, 0, 3, 0,
-        128, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 109, 9, 0, 0, 0, 0, 3, 0, 184, 89, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 9, 0, 0, 0, 0, 3, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 127, 9, 0, 0, 0, 0, 3, 0, 56, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 136, 9, 0, 0, 0, 0, 3, 0, 120, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 145, 9,
-        0, 0, 0, 0, 3, 0, 128, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 9, 0, 0, 0, 0, 3,
-        0, 120, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 9, 0, 0, 0, 0, 3, 0, 72, 92, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 172, 9, 0, 0, 0, 0, 3, 0, 16, 92, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 181, 9, 0, 0, 0, 0, 3, 0, 32, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 190, 9, 0, 0, 0, 0, 3, 0, 112, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 199,
-        9, 0, 0, 0, 0, 3, 0, 184, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 208, 9, 0, 0, 0, 0,
-        3, 0, 240, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 9, 0, 0, 0, 0, 3, 0, 168, 95,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 226, 9, 0, 0, 0, 0, 3, 0, 144, 96, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 235, 9, 0, 0, 0, 0, 3, 0, 48, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 244, 9, 0, 0, 0, 0, 3, 0, 240, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        253, 9, 0, 0, 0, 0, 3, 0, 40, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 10, 0, 0, 0,
-        0, 3, 0, 112, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 10, 0, 0, 0, 0, 3, 0, 168,
-        98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 10, 0, 0, 0, 0, 3, 0, 232, 98, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 10, 0, 0, 0, 0, 3, 0, 240, 99, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 42, 10, 0, 0, 0, 0, 3, 0, 232, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 51, 10, 0, 0, 0, 0, 3, 0, 184, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 10, 0,
-        0, 0, 0, 3, 0, 128, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 10, 0, 0, 0, 0, 3,
-        0, 144, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 10, 0, 0, 0, 0, 3, 0, 224, 101,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 10, 0, 0, 0, 0, 3, 0, 40, 102, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 10, 0, 0, 0, 0, 3, 0, 96, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 105, 10, 0, 0, 0, 0, 3, 0, 24, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        114, 10, 0, 0, 0, 0, 3, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 10, 0, 0,
-        0, 0, 3, 0, 160, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 10, 0, 0, 0, 0, 3, 0,
-        96, 106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 141, 10, 0, 0, 0, 0, 3, 0, 152, 106, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 10, 0, 0, 0, 0, 3, 0, 224, 106, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 10, 0, 0, 0, 0, 3, 0, 24, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 168, 10, 0, 0, 0, 0, 3, 0, 88, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 177, 10, 0, 0, 0, 0, 3, 0, 96, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 10,
-        0, 0, 0, 0, 3, 0, 88, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 195, 10, 0, 0, 0, 0,
-        3, 0, 40, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 204, 10, 0, 0, 0, 0, 3, 0, 240,
-        108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 213, 10, 0, 0, 0, 0, 3, 0, 0, 110, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 222, 10, 0, 0, 0, 0, 3, 0, 80, 110, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 231, 10, 0, 0, 0, 0, 3, 0, 152, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 240, 10, 0, 0, 0, 0, 3, 0, 208, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        249, 10, 0, 0, 0, 0, 3, 0, 136, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0,
-        0, 0, 3, 0, 112, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 11, 0, 0, 0, 0, 3, 0,
-        16, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 11, 0, 0, 0, 0, 3, 0, 208, 114, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 11, 0, 0, 0, 0, 3, 0, 8, 115, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 38, 11, 0, 0, 0, 0, 3, 0, 80, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 47, 11, 0, 0, 0, 0, 3, 0, 136, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56,
-        11, 0, 0, 0, 0, 3, 0, 200, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 11, 0, 0, 0,
-        0, 3, 0, 208, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 11, 0, 0, 0, 0, 3, 0, 200,
-        116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 11, 0, 0, 0, 0, 3, 0, 152, 117, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 11, 0, 0, 0, 0, 3, 0, 96, 117, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 101, 11, 0, 0, 0, 0, 3, 0, 112, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 110, 11, 0, 0, 0, 0, 3, 0, 192, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119,
-        11, 0, 0, 0, 0, 3, 0, 8, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 11, 0, 0, 0,
-        0, 3, 0, 64, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 11, 0, 0, 0, 0, 3, 0, 248,
-        120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 11, 0, 0, 0, 0, 3, 0, 224, 121, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 11, 0, 0, 0, 0, 3, 0, 128, 121, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 164, 11, 0, 0, 0, 0, 3, 0, 64, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 173, 11, 0, 0, 0, 0, 3, 0, 120, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        182, 11, 0, 0, 0, 0, 3, 0, 192, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 11, 0,
-        0, 0, 0, 3, 0, 248, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 11, 0, 0, 0, 0, 3,
-        0, 56, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 209, 11, 0, 0, 0, 0, 3, 0, 64, 125,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 218, 11, 0, 0, 0, 0, 3, 0, 56, 125, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 227, 11, 0, 0, 0, 0, 3, 0, 8, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 236, 11, 0, 0, 0, 0, 3, 0, 208, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 245, 11, 0, 0, 0, 0, 3, 0, 224, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 11,
-        0, 0, 0, 0, 3, 0, 48, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 12, 0, 0, 0, 0, 3,
-        0, 120, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 12, 0, 0, 0, 0, 3, 0, 176, 127,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 12, 0, 0, 0, 0, 3, 0, 104, 129, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 12, 0, 0, 0, 0, 3, 0, 80, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 43, 12, 0, 0, 0, 0, 3, 0, 240, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 52, 12, 0, 0, 0, 0, 3, 0, 176, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 12, 0,
-        0, 0, 0, 3, 0, 232, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 12, 0, 0, 0, 0, 3,
-        0, 48, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 12, 0, 0, 0, 0, 3, 0, 104, 132,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 12, 0, 0, 0, 0, 3, 0, 168, 132, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97, 12, 0, 0, 0, 0, 3, 0, 176, 133, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 106, 12, 0, 0, 0, 0, 3, 0, 168, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 115, 12, 0, 0, 0, 0, 3, 0, 120, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124,
-        12, 0, 0, 0, 0, 3, 0, 64, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 12, 0, 0, 0,
-        0, 3, 0, 80, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 12, 0, 0, 0, 0, 3, 0, 160,
-        135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 12, 0, 0, 0, 0, 3, 0, 232, 135, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 12, 0, 0, 0, 0, 3, 0, 32, 136, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 169, 12, 0, 0, 0, 0, 3, 0, 216, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 178, 12, 0, 0, 0, 0, 3, 0, 192, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        187, 12, 0, 0, 0, 0, 3, 0, 96, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 196, 12, 0,
-        0, 0, 0, 3, 0, 32, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 205, 12, 0, 0, 0, 0, 3,
-        0, 88, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 214, 12, 0, 0, 0, 0, 3, 0, 160, 140,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 223, 12, 0, 0, 0, 0, 3, 0, 216, 140, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 12, 0, 0, 0, 0, 3, 0, 24, 141, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 241, 12, 0, 0, 0, 0, 3, 0, 32, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 250, 12, 0, 0, 0, 0, 3, 0, 24, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 13,
-        0, 0, 0, 0, 3, 0, 232, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 13, 0, 0, 0, 0,
-        3, 0, 176, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 13, 0, 0, 0, 0, 3, 0, 192,
-        143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 13, 0, 0, 0, 0, 3, 0, 16, 144, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 13, 0, 0, 0, 0, 3, 0, 88, 144, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 48, 13, 0, 0, 0, 0, 3, 0, 144, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 57, 13, 0, 0, 0, 0, 3, 0, 72, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 13,
-        0, 0, 0, 0, 3, 0, 48, 147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 75, 13, 0, 0, 0, 0, 3,
-        0, 208, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 13, 0, 0, 0, 0, 3, 0, 144, 148,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 13, 0, 0, 0, 0, 3, 0, 200, 148, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 13, 0, 0, 0, 0, 3, 0, 16, 149, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 111, 13, 0, 0, 0, 0, 3, 0, 72, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 120, 13, 0, 0, 0, 0, 3, 0, 136, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129,
-        13, 0, 0, 0, 0, 3, 0, 144, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 13, 0, 0, 0,
-        0, 3, 0, 136, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 13, 0, 0, 0, 0, 3, 0, 88,
-        151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 13, 0, 0, 0, 0, 3, 0, 32, 151, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 165, 13, 0, 0, 0, 0, 3, 0, 32, 152, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 174, 13, 0, 0, 1, 0, 4, 0, 216, 2, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0,
-        0, 0, 0, 203, 13, 0, 0, 0, 0, 3, 0, 136, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        212, 13, 0, 0, 1, 0, 4, 0, 3, 3, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 241, 13, 0, 0,
-        0, 0, 3, 0, 184, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 250, 13, 0, 0, 0, 0, 3, 0,
-        248, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 14, 0, 0, 18, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 14,
-        0, 0, 0, 0, 0, 0, 12, 14, 0, 0, 17, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0,
-        0, 32, 14, 0, 0, 17, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 40, 14, 0, 0,
-        17, 0, 6, 0, 32, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 53, 14, 0, 0, 17, 0, 6, 0,
-        64, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 66, 14, 0, 0, 17, 0, 6, 0, 96, 0, 0, 0,
-        0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 79, 14, 0, 0, 17, 0, 6, 0, 128, 0, 0, 0, 0, 0, 0, 0,
-        32, 0, 0, 0, 0, 0, 0, 0, 104, 14, 0, 0, 17, 0, 6, 0, 160, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0,
-        0, 0, 0, 0, 113, 14, 0, 0, 17, 0, 6, 0, 192, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0,
-        120, 14, 0, 0, 18, 0, 3, 0, 24, 14, 0, 0, 0, 0, 0, 0, 56, 139, 0, 0, 0, 0, 0, 0, 138, 14,
-        0, 0, 17, 0, 6, 0, 32, 1, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 146, 14, 0, 0, 17, 0,
-        6, 0, 0, 1, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 159, 14, 0, 0, 17, 0, 5, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 36, 1, 0, 0, 0, 0, 0, 0, 166, 14, 0, 0, 17, 0, 4, 0, 28, 3, 0, 0, 0, 0, 0,
-        0, 46, 0, 0, 0, 0, 0, 0, 0, 184, 14, 0, 0, 17, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
-        0, 0, 0, 0, 192, 14, 0, 0, 17, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
-        200, 14, 0, 0, 17, 0, 6, 0, 224, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 191, 23, 0,
-        0, 0, 0, 0, 0, 133, 0, 0, 0, 14, 0, 0, 0, 191, 9, 0, 0, 0, 0, 0, 0, 191, 145, 0, 0, 0, 0,
-        0, 0, 119, 1, 0, 0, 32, 0, 0, 0, 99, 26, 252, 255, 0, 0, 0, 0, 21, 1, 178, 1, 0, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 252, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 191, 8, 0, 0, 0, 0, 0, 0, 21, 8, 171, 1, 0, 0,
-        0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 4,
-        0, 0, 0, 0, 0, 24, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 97, 163, 252,
-        255, 0, 0, 0, 0, 24, 1, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 22, 0, 0,
-        0, 191, 148, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 129, 8, 0, 0, 0, 0, 0, 85, 1,
-        9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0,
-        0, 21, 1, 149, 1, 0, 0, 0, 0, 24, 1, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 48, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 5, 0, 144, 1, 0, 0, 0, 0, 123, 138, 216, 255, 0,
-        0, 0, 0, 123, 122, 208, 255, 0, 0, 0, 0, 183, 8, 0, 0, 0, 0, 0, 0, 99, 138, 248, 255, 0, 0,
-        0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 191, 7, 0, 0, 0, 0, 0, 0, 21, 7, 133, 1,
-        0, 0, 0, 0, 123, 135, 16, 0, 0, 0, 0, 0, 123, 135, 8, 0, 0, 0, 0, 0, 123, 135, 0, 0, 0, 0,
-        0, 0, 121, 162, 216, 255, 0, 0, 0, 0, 121, 33, 0, 0, 0, 0, 0, 0, 123, 23, 0, 0, 0, 0, 0, 0,
-        121, 33, 8, 0, 0, 0, 0, 0, 123, 23, 8, 0, 0, 0, 0, 0, 121, 33, 16, 0, 0, 0, 0, 0, 123, 23,
-        16, 0, 0, 0, 0, 0, 123, 135, 24, 0, 0, 0, 0, 0, 123, 135, 32, 0, 0, 0, 0, 0, 99, 135, 40,
-        0, 0, 0, 0, 0, 123, 135, 48, 0, 0, 0, 0, 0, 123, 135, 56, 0, 0, 0, 0, 0, 123, 135, 64, 0,
-        0, 0, 0, 0, 123, 135, 72, 0, 0, 0, 0, 0, 123, 135, 80, 0, 0, 0, 0, 0, 123, 135, 88, 0, 0,
-        0, 0, 0, 123, 135, 96, 0, 0, 0, 0, 0, 123, 135, 104, 0, 0, 0, 0, 0, 123, 135, 112, 0, 0, 0,
-        0, 0, 123, 135, 120, 0, 0, 0, 0, 0, 123, 135, 128, 0, 0, 0, 0, 0, 123, 135, 136, 0, 0, 0,
-        0, 0, 123, 135, 144, 0, 0, 0, 0, 0, 123, 135, 152, 0, 0, 0, 0, 0, 123, 135, 160, 0, 0, 0,
-        0, 0, 123, 135, 168, 0, 0, 0, 0, 0, 123, 135, 176, 0, 0, 0, 0, 0, 123, 135, 184, 0, 0, 0,
-        0, 0, 123, 135, 192, 0, 0, 0, 0, 0, 123, 135, 200, 0, 0, 0, 0, 0, 123, 135, 208, 0, 0, 0,
-        0, 0, 123, 135, 216, 0, 0, 0, 0, 0, 123, 135, 224, 0, 0, 0, 0, 0, 123, 135, 232, 0, 0, 0,
-        0, 0, 123, 135, 240, 0, 0, 0, 0, 0, 123, 135, 248, 0, 0, 0, 0, 0, 123, 135, 0, 1, 0, 0, 0,
-        0, 123, 135, 8, 1, 0, 0, 0, 0, 123, 135, 16, 1, 0, 0, 0, 0, 123, 135, 24, 1, 0, 0, 0, 0,
-        123, 135, 32, 1, 0, 0, 0, 0, 123, 135, 40, 1, 0, 0, 0, 0, 123, 135, 48, 1, 0, 0, 0, 0, 123,
-        135, 56, 1, 0, 0, 0, 0, 123, 135, 64, 1, 0, 0, 0, 0, 123, 135, 72, 1, 0, 0, 0, 0, 123, 135,
-        80, 1, 0, 0, 0, 0, 123, 135, 88, 1, 0, 0, 0, 0, 123, 135, 96, 1, 0, 0, 0, 0, 123, 135, 104,
-        1, 0, 0, 0, 0, 123, 135, 112, 1, 0, 0, 0, 0, 123, 135, 120, 1, 0, 0, 0, 0, 123, 135, 128,
-        1, 0, 0, 0, 0, 123, 135, 136, 1, 0, 0, 0, 0, 123, 135, 144, 1, 0, 0, 0, 0, 123, 135, 152,
-        1, 0, 0, 0, 0, 123, 135, 160, 1, 0, 0, 0, 0, 123, 135, 168, 1, 0, 0, 0, 0, 133, 0, 0, 0, 5,
-        0, 0, 0, 123, 7, 48, 0, 0, 0, 0, 0, 99, 151, 64, 0, 0, 0, 0, 0, 97, 161, 252, 255, 0, 0, 0,
-        0, 99, 23, 60, 0, 0, 0, 0, 0, 133, 0, 0, 0, 8, 0, 0, 0, 99, 7, 56, 0, 0, 0, 0, 0, 191, 113,
-        0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 68, 0, 0, 0, 183, 2, 0, 0, 16, 0, 0, 0, 133, 0, 0, 0, 16, 0,
-        0, 0, 97, 161, 252, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 121, 166, 208, 255, 0,
-        0, 0, 0, 191, 97, 0, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        3, 0, 0, 0, 1, 0, 0, 133, 0, 0, 0, 27, 0, 0, 0, 123, 10, 192, 255, 0, 0, 0, 0, 191, 97, 0,
-        0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 3, 0, 0, 0, 0, 0, 0,
-        133, 0, 0, 0, 27, 0, 0, 0, 183, 1, 0, 0, 2, 0, 0, 0, 99, 23, 100, 0, 0, 0, 0, 0, 99, 7, 96,
-        0, 0, 0, 0, 0, 121, 161, 192, 255, 0, 0, 0, 0, 99, 23, 92, 0, 0, 0, 0, 0, 99, 151, 88, 0,
-        0, 0, 0, 0, 121, 161, 200, 255, 0, 0, 0, 0, 99, 23, 84, 0, 0, 0, 0, 0, 99, 135, 112, 0, 0,
-        0, 0, 0, 99, 135, 120, 0, 0, 0, 0, 0, 99, 135, 116, 0, 0, 0, 0, 0, 99, 135, 128, 0, 0, 0,
-        0, 0, 99, 135, 124, 0, 0, 0, 0, 0, 99, 135, 136, 0, 0, 0, 0, 0, 99, 135, 132, 0, 0, 0, 0,
-        0, 99, 135, 144, 0, 0, 0, 0, 0, 99, 135, 140, 0, 0, 0, 0, 0, 99, 135, 152, 0, 0, 0, 0, 0,
-        99, 135, 148, 0, 0, 0, 0, 0, 99, 135, 160, 0, 0, 0, 0, 0, 99, 135, 156, 0, 0, 0, 0, 0, 99,
-        135, 168, 0, 0, 0, 0, 0, 99, 135, 164, 0, 0, 0, 0, 0, 99, 135, 176, 0, 0, 0, 0, 0, 99, 135,
-        172, 0, 0, 0, 0, 0, 99, 135, 184, 0, 0, 0, 0, 0, 99, 135, 180, 0, 0, 0, 0, 0, 99, 135, 192,
-        0, 0, 0, 0, 0, 99, 135, 188, 0, 0, 0, 0, 0, 99, 135, 200, 0, 0, 0, 0, 0, 99, 135, 196, 0,
-        0, 0, 0, 0, 99, 135, 208, 0, 0, 0, 0, 0, 99, 135, 204, 0, 0, 0, 0, 0, 99, 135, 216, 0, 0,
-        0, 0, 0, 99, 135, 212, 0, 0, 0, 0, 0, 99, 135, 224, 0, 0, 0, 0, 0, 99, 135, 220, 0, 0, 0,
-        0, 0, 99, 135, 232, 0, 0, 0, 0, 0, 99, 135, 228, 0, 0, 0, 0, 0, 99, 135, 240, 0, 0, 0, 0,
-        0, 99, 135, 236, 0, 0, 0, 0, 0, 99, 135, 248, 0, 0, 0, 0, 0, 99, 135, 244, 0, 0, 0, 0, 0,
-        99, 135, 0, 1, 0, 0, 0, 0, 99, 135, 252, 0, 0, 0, 0, 0, 99, 135, 8, 1, 0, 0, 0, 0, 99, 135,
-        4, 1, 0, 0, 0, 0, 99, 135, 16, 1, 0, 0, 0, 0, 99, 135, 12, 1, 0, 0, 0, 0, 99, 135, 24, 1,
-        0, 0, 0, 0, 99, 135, 20, 1, 0, 0, 0, 0, 99, 135, 32, 1, 0, 0, 0, 0, 99, 135, 28, 1, 0, 0,
-        0, 0, 99, 135, 40, 1, 0, 0, 0, 0, 99, 135, 36, 1, 0, 0, 0, 0, 99, 135, 48, 1, 0, 0, 0, 0,
-        99, 135, 44, 1, 0, 0, 0, 0, 99, 135, 56, 1, 0, 0, 0, 0, 99, 135, 52, 1, 0, 0, 0, 0, 99,
-        135, 64, 1, 0, 0, 0, 0, 99, 135, 60, 1, 0, 0, 0, 0, 99, 135, 72, 1, 0, 0, 0, 0, 99, 135,
-        68, 1, 0, 0, 0, 0, 99, 135, 80, 1, 0, 0, 0, 0, 99, 135, 76, 1, 0, 0, 0, 0, 99, 135, 88, 1,
-        0, 0, 0, 0, 99, 135, 84, 1, 0, 0, 0, 0, 99, 135, 96, 1, 0, 0, 0, 0, 99, 135, 92, 1, 0, 0,
-        0, 0, 99, 135, 104, 1, 0, 0, 0, 0, 99, 135, 100, 1, 0, 0, 0, 0, 99, 135, 112, 1, 0, 0, 0,
-        0, 99, 135, 108, 1, 0, 0, 0, 0, 99, 135, 120, 1, 0, 0, 0, 0, 99, 135, 116, 1, 0, 0, 0, 0,
-        99, 135, 128, 1, 0, 0, 0, 0, 99, 135, 124, 1, 0, 0, 0, 0, 99, 135, 136, 1, 0, 0, 0, 0, 99,
-        135, 132, 1, 0, 0, 0, 0, 99, 135, 144, 1, 0, 0, 0, 0, 99, 135, 140, 1, 0, 0, 0, 0, 99, 135,
-        152, 1, 0, 0, 0, 0, 99, 135, 148, 1, 0, 0, 0, 0, 99, 135, 160, 1, 0, 0, 0, 0, 99, 135, 156,
-        1, 0, 0, 0, 0, 99, 135, 168, 1, 0, 0, 0, 0, 99, 135, 164, 1, 0, 0, 0, 0, 123, 135, 104, 0,
-        0, 0, 0, 0, 99, 135, 172, 1, 0, 0, 0, 0, 191, 118, 0, 0, 0, 0, 0, 0, 7, 6, 0, 0, 84, 0, 0,
-        0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 98, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 1, 0, 0, 0, 191, 121, 0, 0, 0, 0, 0, 0, 7, 9, 0, 0, 24, 0, 0, 0, 85, 0, 23, 0, 0, 0, 0,
-        0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 191, 98, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 1, 0, 0, 0, 133, 0, 0, 0, 2,
-        0, 0, 0, 21, 0, 10, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        17, 0, 0, 0, 0, 0, 0, 21, 1, 13, 0, 0, 0, 0, 0, 24, 1, 0, 0, 74, 3, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 48, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 5, 0,
-        7, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 98, 0, 0, 0, 0, 0,
-        0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0,
-        0, 0, 0, 0, 0, 121, 168, 216, 255, 0, 0, 0, 0, 121, 131, 8, 0, 0, 0, 0, 0, 191, 145, 0, 0,
-        0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 131, 8, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 39, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 147, 0,
-        0, 0, 0, 0, 0, 24, 1, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 133, 0, 0, 0, 35, 0, 0, 0, 183, 1, 0, 0, 104, 36, 0, 0, 15,
-        16, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2,
-        0, 0, 8, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 113, 0, 0, 0, 121, 166, 240, 255,
-        0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21,
-        1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 16,
-        0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 114, 0, 0, 0, 0, 0, 0,
-        7, 2, 0, 0, 16, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1,
-        0, 0, 0, 191, 8, 0, 0, 0, 0, 0, 0, 21, 8, 134, 0, 0, 0, 0, 0, 121, 129, 72, 0, 0, 0, 0, 0,
-        121, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0,
-        0, 232, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 121, 163, 232, 255, 0, 0, 0,
-        0, 85, 3, 7, 0, 0, 0, 0, 0, 121, 168, 208, 255, 0, 0, 0, 0, 21, 1, 97, 0, 0, 0, 0, 0, 24,
-        1, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 33, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 5, 0, 92, 0, 0, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 187, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 7, 6, 0, 0, 16, 0,
-        0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0,
-        191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 85, 3, 5, 0, 0, 0,
-        0, 0, 121, 168, 208, 255, 0, 0, 0, 0, 21, 1, 74, 0, 0, 0, 0, 0, 24, 1, 0, 0, 210, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 14, 0, 0, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 240,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121,
-        147, 0, 0, 0, 0, 0, 0, 85, 3, 10, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 121, 168, 208, 255, 0, 0, 0, 0, 21, 1, 59, 0, 0, 0, 0, 0,
-        24, 1, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 30, 0, 0, 0, 133, 0, 0, 0,
-        6, 0, 0, 0, 5, 0, 54, 0, 0, 0, 0, 0, 191, 118, 0, 0, 0, 0, 0, 0, 7, 6, 0, 0, 32, 0, 0, 0,
-        121, 129, 64, 0, 0, 0, 0, 0, 183, 2, 0, 0, 0, 0, 0, 0, 109, 18, 22, 0, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 97, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112,
-        0, 0, 0, 121, 168, 208, 255, 0, 0, 0, 0, 121, 99, 0, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 85, 3, 1, 0, 0, 0, 0, 0, 5, 0, 31, 0,
-        0, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 99, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        2, 0, 0, 17, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 12, 0, 0, 0, 5, 0,
-        27, 0, 0, 0, 0, 0, 121, 129, 88, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0,
-        0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0,
-        0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 121, 163,
-        224, 255, 0, 0, 0, 0, 85, 3, 41, 0, 0, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 34,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 24, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183,
-        1, 0, 0, 13, 0, 0, 0, 99, 23, 104, 0, 0, 0, 0, 0, 121, 168, 208, 255, 0, 0, 0, 0, 5, 0, 7,
-        0, 0, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 72, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 27, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 6, 0, 0, 0, 99, 23, 104,
-        0, 0, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0,
-        21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 122, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        7, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 7, 7, 0, 0, 48, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0,
-        21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 129, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        1, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 24, 3, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 191, 116,
-        0, 0, 0, 0, 0, 0, 183, 5, 0, 0, 128, 1, 0, 0, 133, 0, 0, 0, 25, 0, 0, 0, 183, 0, 0, 0, 0,
-        0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 58, 1, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 14, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 163, 224, 255,
-        0, 0, 0, 0, 121, 129, 96, 0, 0, 0, 0, 0, 5, 0, 174, 255, 0, 0, 0, 0, 191, 22, 0, 0, 0, 0,
-        0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 236, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0,
-        7, 2, 0, 0, 236, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 1, 0, 0, 0, 191, 7, 0, 0, 0, 0, 0, 0, 21, 7, 91, 17, 0, 0, 0, 0, 191, 114, 0, 0, 0, 0,
-        0, 0, 7, 2, 0, 0, 16, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 1, 0, 0, 0, 191, 8, 0, 0, 0, 0, 0, 0, 21, 8, 84, 17, 0, 0, 0, 0, 123, 106, 224, 254, 0,
-        0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1,
-        4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 116, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 26, 0,
-        0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 97, 113, 40, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0, 99, 23,
-        40, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 191, 113, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 108,
-        0, 0, 0, 123, 26, 232, 254, 0, 0, 0, 0, 123, 122, 240, 254, 0, 0, 0, 0, 121, 121, 32, 0, 0,
-        0, 0, 0, 21, 9, 22, 0, 0, 0, 0, 0, 121, 129, 128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0,
-        0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0,
-        21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 85, 3,
-        66, 0, 0, 0, 0, 0, 87, 1, 0, 0, 1, 0, 0, 0, 85, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 142, 1,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 35, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 8,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 129, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 41, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 27, 0, 0, 0, 191, 99, 0,
-        0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 129, 0, 0, 0, 0, 0, 0, 121, 168, 224, 254, 0,
-        0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 115, 40, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 68, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 39, 0, 0, 0, 133, 0, 0,
-        0, 6, 0, 0, 0, 97, 114, 40, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        113, 17, 0, 0, 0, 0, 0, 0, 103, 2, 0, 0, 32, 0, 0, 0, 199, 2, 0, 0, 32, 0, 0, 0, 101, 2,
-        13, 0, 4, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 107, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 183, 2, 0, 0, 29, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 24, 2,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 12, 0, 0,
-        0, 183, 1, 0, 0, 12, 0, 0, 0, 99, 23, 104, 0, 0, 0, 0, 0, 5, 0, 230, 16, 0, 0, 0, 0, 21, 1,
-        4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 136, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 36, 0,
-        0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17,
-        0, 0, 0, 0, 0, 0, 21, 1, 8, 0, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 19, 0, 0,
-        0, 0, 0, 0, 103, 3, 0, 0, 48, 0, 0, 0, 199, 3, 0, 0, 48, 0, 0, 0, 24, 1, 0, 0, 172, 2, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 44, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0,
-        0, 1, 0, 0, 0, 99, 23, 100, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 23, 104, 0, 0, 0,
-        0, 0, 5, 0, 208, 16, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0,
-        0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 152, 255, 255, 255, 123, 26, 216, 254, 0, 0,
-        0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5,
-        0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0,
-        0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1,
-        5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 216, 255, 255, 255, 123, 26, 192, 254, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 120, 255, 255, 255, 123, 26, 208, 254, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0,
-        99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0,
-        123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0,
-        0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0,
-        0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0,
-        0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255,
-        0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255,
-        0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255,
-        0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255,
-        0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0,
-        0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0,
-        0, 0, 0, 121, 129, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0,
-        191, 135, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255,
-        191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113,
-        184, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0,
-        0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0,
-        0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0,
-        21, 8, 1, 0, 115, 101, 108, 102, 85, 8, 26, 0, 99, 108, 115, 0, 121, 113, 144, 0, 0, 0, 0,
-        0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1,
-        0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 7,
-        0, 115, 101, 108, 102, 121, 113, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255,
-        255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0,
-        121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121,
-        113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0,
-        0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8,
-        0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0,
-        0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255,
-        183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191,
-        99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0,
-        0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121,
-        161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0,
-        0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24,
-        1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0,
-        0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0,
-        21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0,
-        0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0,
-        0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 133, 0, 0, 0, 1, 0, 0, 0, 123, 122, 200, 254, 0, 0, 0, 0, 21, 0, 3, 0, 0, 0, 0, 0, 97,
-        6, 0, 0, 0, 0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 5, 0, 34, 0, 0, 0, 0, 0, 183, 6, 0, 0,
-        0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240,
-        255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0,
-        121, 167, 240, 254, 0, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219,
-        16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0,
-        0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255, 24, 1,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0,
-        0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0, 21, 1,
-        9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0,
-        0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248, 255, 0,
-        0, 0, 0, 191, 113, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 112, 0, 0, 0, 123, 26, 176, 254, 0, 0, 0,
-        0, 191, 113, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 32, 0, 0, 0, 123, 26, 184, 254, 0, 0, 0, 0, 121,
-        161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0,
-        0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 121, 167, 232, 254, 0, 0, 0, 0, 21,
-        2, 7, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0,
-        0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        105, 113, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15,
-        130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0, 107, 23, 0, 0, 0,
-        0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 120, 0,
-        0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        121, 129, 0, 0, 0, 0, 0, 0, 21, 1, 184, 15, 0, 0, 0, 0, 183, 6, 0, 0, 1, 0, 0, 0, 121, 137,
-        0, 0, 0, 0, 0, 0, 21, 9, 186, 254, 0, 0, 0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191, 147, 0,
-        0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255,
-        255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 1, 0, 0, 0, 183, 1, 0, 0, 1,
-        0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0,
-        21, 3, 164, 254, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 1, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0,
-        0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0, 0,
-        191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5,
-        0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99,
-        26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0,
-        123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0,
-        0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0,
-        0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0,
-        0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255,
-        0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255,
-        0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255,
-        0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255,
-        0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0,
-        0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0,
-        0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0,
-        191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121,
-        163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255,
-        0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0,
-        0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101,
-        108, 102, 85, 8, 26, 0, 99, 108, 115, 0, 121, 113, 144, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255,
-        255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 7, 0, 115, 101, 108, 102,
-        121, 113, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 121, 113, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0,
-        0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0,
-        0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 121, 161, 208, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0,
-        191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0,
-        0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0,
-        112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0,
-        0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0,
-        0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0,
-        0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114,
-        0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24,
-        6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0,
-        0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0,
-        0, 0, 0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 239,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254, 0, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0,
-        0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1, 0, 0,
-        6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0, 0,
-        0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0, 191,
-        162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0,
-        219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2,
-        0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255, 24,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2, 0,
-        0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0, 21,
-        1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0,
-        0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2,
-        0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248, 255,
-        0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 48, 0,
-        0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 121, 167, 232,
-        254, 0, 0, 0, 0, 21, 2, 7, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0,
-        0, 0, 6, 0, 0, 0, 105, 113, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176, 254,
-        0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0,
-        107, 23, 0, 0, 0, 0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0,
-        121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0,
-        191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0,
-        0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 21, 1, 176, 14, 0, 0, 0, 0, 183, 6, 0, 0,
-        2, 0, 0, 0, 121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 178, 253, 0, 0, 0, 0, 121, 113, 128, 0, 0,
-        0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0,
-        0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 2, 0, 0, 0, 121,
-        163, 224, 255, 0, 0, 0, 0, 21, 3, 156, 253, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0,
-        0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 2, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0,
-        0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97,
-        0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0,
-        207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123,
-        26, 200, 255, 0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0,
-        123, 26, 176, 255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0,
-        0, 123, 26, 152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0,
-        0, 0, 123, 26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0,
-        0, 0, 0, 123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0,
-        0, 0, 0, 123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0,
-        0, 0, 0, 123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0,
-        0, 0, 0, 123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0,
-        0, 0, 0, 123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0,
-        0, 0, 121, 166, 224, 255, 0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0,
-        0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255,
-        191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113,
-        184, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0,
-        0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0,
-        0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0,
-        21, 8, 1, 0, 115, 101, 108, 102, 85, 8, 26, 0, 99, 108, 115, 0, 121, 113, 144, 0, 0, 0, 0,
-        0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1,
-        0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 7,
-        0, 115, 101, 108, 102, 121, 113, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255,
-        255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0,
-        121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121,
-        113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0,
-        0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8,
-        0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0,
-        0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255,
-        183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191,
-        99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0,
-        0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121,
-        161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0,
-        0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24,
-        1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163,
-        208, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0,
-        6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0,
-        0, 24, 1, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0,
-        0, 6, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6,
-        0, 0, 0, 0, 0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0,
-        0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0,
-        0, 1, 0, 0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0,
-        0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255,
-        255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133,
-        0, 0, 0, 2, 0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32,
-        0, 0, 0, 21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17,
-        0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 183, 2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166,
-        248, 255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0,
-        0, 48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 121, 167,
-        232, 254, 0, 0, 0, 0, 21, 2, 7, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 105, 113, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162,
-        176, 254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1,
-        0, 0, 0, 107, 23, 0, 0, 0, 0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 121, 167, 200, 254, 0,
-        0, 0, 0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0,
-        0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0,
-        133, 0, 0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 21, 1, 168, 13, 0, 0, 0, 0, 183, 6,
-        0, 0, 3, 0, 0, 0, 121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 170, 252, 0, 0, 0, 0, 121, 113, 128,
-        0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0,
-        0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0,
-        1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 3, 0, 0, 0,
-        121, 163, 224, 255, 0, 0, 0, 0, 21, 3, 148, 252, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24,
-        1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 3,
-        0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0,
-        207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123,
-        26, 200, 255, 0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0,
-        123, 26, 176, 255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0,
-        0, 123, 26, 152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0,
-        0, 0, 123, 26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0,
-        0, 0, 0, 123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0,
-        0, 0, 0, 123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0,
-        0, 0, 0, 123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0,
-        0, 0, 0, 123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0,
-        0, 0, 0, 123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0,
-        0, 0, 121, 166, 224, 255, 0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0,
-        0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255,
-        191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113,
-        184, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0,
-        0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0,
-        0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0,
-        21, 8, 1, 0, 115, 101, 108, 102, 85, 8, 26, 0, 99, 108, 115, 0, 121, 113, 144, 0, 0, 0, 0,
-        0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1,
-        0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 7,
-        0, 115, 101, 108, 102, 121, 113, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255,
-        255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0,
-        121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121,
-        113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0,
-        0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8,
-        0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0,
-        0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255,
-        183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191,
-        99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0,
-        0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121,
-        161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0,
-        0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24,
-        1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163,
-        208, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0,
-        6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0,
-        0, 24, 1, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0,
-        0, 6, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6,
-        0, 0, 0, 0, 0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0,
-        0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0,
-        0, 1, 0, 0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0,
-        0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255,
-        255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133,
-        0, 0, 0, 2, 0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32,
-        0, 0, 0, 21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17,
-        0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 183, 2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166,
-        248, 255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0,
-        0, 48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 121, 167,
-        232, 254, 0, 0, 0, 0, 21, 2, 7, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 105, 113, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162,
-        176, 254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1,
-        0, 0, 0, 107, 23, 0, 0, 0, 0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 121, 167, 200, 254, 0,
-        0, 0, 0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0,
-        0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0,
-        133, 0, 0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 21, 1, 160, 12, 0, 0, 0, 0, 183, 6,
-        0, 0, 4, 0, 0, 0, 121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 162, 251, 0, 0, 0, 0, 121, 113, 128,
-        0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0,
-        0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0,
-        1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 4, 0, 0, 0,
-        121, 163, 224, 255, 0, 0, 0, 0, 21, 3, 140, 251, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24,
-        1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 4,
-        0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0,
-        207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123,
-        26, 200, 255, 0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0,
-        123, 26, 176, 255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0,
-        0, 123, 26, 152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0,
-        0, 0, 123, 26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0,
-        0, 0, 0, 123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0,
-        0, 0, 0, 123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0,
-        0, 0, 0, 123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0,
-        0, 0, 0, 123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0,
-        0, 0, 0, 123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0,
-        0, 0, 121, 166, 224, 255, 0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0,
-        0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255,
-        191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113,
-        184, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0,
-        0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0,
-        0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0,
-        21, 8, 1, 0, 115, 101, 108, 102, 85, 8, 26, 0, 99, 108, 115, 0, 121, 113, 144, 0, 0, 0, 0,
-        0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1,
-        0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 7,
-        0, 115, 101, 108, 102, 121, 113, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255,
-        255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0,
-        121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121,
-        113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0,
-        0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8,
-        0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0,
-        0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255,
-        183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191,
-        99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0,
-        0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121,
-        161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0,
-        0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24,
-        1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0,
-        0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0,
-        21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0,
-        0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0,
-        0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 5, 0, 33, 0,
-        0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0,
-        0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133,
-        0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0, 0, 1,
-        0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254,
-        255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 191, 1, 0,
-        0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0, 21, 1, 9, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 31, 0, 0, 0,
-        191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248, 255, 0, 0, 0, 0, 121,
-        161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0,
-        0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0, 0, 0, 0, 0, 24, 1, 0,
-        0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0,
-        0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0,
-        105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15,
-        130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0, 121, 162, 232,
-        254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 120,
-        0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        121, 129, 0, 0, 0, 0, 0, 0, 21, 1, 153, 11, 0, 0, 0, 0, 183, 6, 0, 0, 5, 0, 0, 0, 121, 137,
-        0, 0, 0, 0, 0, 0, 21, 9, 155, 250, 0, 0, 0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191, 147, 0,
-        0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255,
-        255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21, 2, 1, 0, 0,
-        0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 5, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0,
-        0, 21, 3, 133, 250, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 5, 0, 0, 0, 133, 0, 0, 0, 6, 0,
-        0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5,
-        0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0,
-        0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1,
-        5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99,
-        26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0,
-        123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0,
-        0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0,
-        0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0,
-        0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255,
-        0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255,
-        0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255,
-        0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255,
-        0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0,
-        0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0,
-        0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0,
-        191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121,
-        163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255,
-        0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0,
-        0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101,
-        108, 102, 85, 8, 27, 0, 99, 108, 115, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 144, 0,
-        0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0,
-        0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        85, 8, 7, 0, 115, 101, 108, 102, 121, 113, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0,
-        0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 40, 0, 0, 0, 0, 0, 121,
-        163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0,
-        0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240,
-        255, 0, 0, 0, 0, 121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0,
-        114, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0,
-        0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255,
-        255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0, 0, 0, 133, 0,
-        0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0,
-        0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0,
-        0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0,
-        121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121,
-        113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0, 0, 0, 183, 2,
-        0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163,
-        0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0,
-        21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        13, 0, 0, 0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0,
-        0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0,
-        0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97,
-        163, 216, 255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254,
-        255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0,
-        2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 5, 0, 34, 0, 0, 0, 0, 0, 121, 167, 240, 254, 0,
-        0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0,
-        0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0,
-        0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0, 0, 1, 0,
-        0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255,
-        255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 191, 1, 0, 0,
-        0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0, 21, 1, 9, 0, 0, 0, 0, 0,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0,
-        0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 31, 0, 0, 0, 191,
-        3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248, 255, 0, 0, 0, 0, 121, 161,
-        232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0, 0,
-        48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0, 0, 0, 0, 0, 24, 1, 0,
-        0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0,
-        0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0,
-        105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15,
-        130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0, 121, 162, 232,
-        254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 120,
-        0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        121, 129, 0, 0, 0, 0, 0, 0, 21, 1, 143, 10, 0, 0, 0, 0, 183, 6, 0, 0, 6, 0, 0, 0, 121, 137,
-        0, 0, 0, 0, 0, 0, 21, 9, 145, 249, 0, 0, 0, 0, 121, 161, 240, 254, 0, 0, 0, 0, 121, 113,
-        128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0,
-        0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0,
-        0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1,
-        0, 0, 1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 6, 0, 0,
-        0, 121, 163, 224, 255, 0, 0, 0, 0, 121, 164, 240, 254, 0, 0, 0, 0, 21, 3, 121, 249, 0, 0,
-        0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2,
-        0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 6, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0,
-        186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163,
-        224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20,
-        0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0,
-        123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0,
-        0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0,
-        0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0,
-        0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255,
-        0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255,
-        0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255,
-        0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255,
-        0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255,
-        0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0,
-        0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0, 0, 0, 0, 121, 167, 200, 254,
-        0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0,
-        0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121,
-        163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255,
-        0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0,
-        0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101,
-        108, 102, 85, 8, 29, 0, 99, 108, 115, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 144, 0,
-        0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0,
-        0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        85, 8, 8, 0, 115, 101, 108, 102, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 16, 0, 0, 0, 0,
-        0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7,
-        1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121,
-        161, 200, 254, 0, 0, 0, 0, 121, 17, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15,
-        19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2,
-        0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 121, 161,
-        208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 167, 200,
-        254, 0, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0,
-        0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0,
-        121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1,
-        0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113,
-        160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0,
-        121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0,
-        183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15,
-        22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0,
-        0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0,
-        248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0,
-        0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1,
-        0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254,
-        0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1,
-        0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0,
-        0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0,
-        0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0,
-        0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0,
-        7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2,
-        0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0,
-        21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0,
-        0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248,
-        255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0,
-        48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0,
-        191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161,
-        232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176,
-        254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0,
-        0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0,
-        0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0,
-        191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0,
-        0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 121, 162, 240, 254, 0, 0, 0, 0, 21, 1, 128,
-        9, 0, 0, 0, 0, 183, 6, 0, 0, 7, 0, 0, 0, 121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 130, 248, 0, 0,
-        0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0,
-        191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133,
-        0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0,
-        0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183,
-        6, 0, 0, 7, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 121, 164, 240, 254, 0, 0, 0, 0, 21, 3,
-        107, 248, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 7, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191, 147,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0,
-        0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216,
-        255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 123, 26,
-        192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0, 0, 123,
-        26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0, 0, 0,
-        123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0, 0, 0,
-        0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255, 0, 0,
-        0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255, 0, 0,
-        0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255, 0, 0,
-        0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255, 0, 0,
-        0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0, 0, 0,
-        0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0, 0, 0,
-        0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191,
-        129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184,
-        0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0,
-        121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0,
-        183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21,
-        8, 1, 0, 115, 101, 108, 102, 85, 8, 29, 0, 99, 108, 115, 0, 121, 161, 200, 254, 0, 0, 0, 0,
-        121, 17, 144, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 85, 8, 8, 0, 115, 101, 108, 102, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17,
-        16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0,
-        0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112,
-        0, 0, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255,
-        255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0,
-        121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121,
-        167, 200, 254, 0, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0,
-        0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0,
-        0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0,
-        121, 113, 160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24,
-        0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216,
-        254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0,
-        0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0,
-        0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0,
-        0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5,
-        0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0,
-        0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216,
-        255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0,
-        0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99,
-        106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0,
-        0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7,
-        3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0,
-        0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0,
-        119, 1, 0, 0, 32, 0, 0, 0, 21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 121, 166, 248, 255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0,
-        0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0,
-        0, 0, 0, 0, 21, 2, 8, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0,
-        6, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0,
-        0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 1, 0, 0, 0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121,
-        167, 200, 254, 0, 0, 0, 0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 183, 6,
-        0, 0, 8, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0,
-        0, 0, 121, 162, 240, 254, 0, 0, 0, 0, 21, 1, 113, 8, 0, 0, 0, 0, 121, 137, 0, 0, 0, 0, 0,
-        0, 21, 9, 116, 247, 0, 0, 0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183,
-        6, 0, 0, 8, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21,
-        2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 121, 164,
-        240, 254, 0, 0, 0, 0, 21, 3, 93, 247, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0,
-        177, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 8, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0,
-        0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97,
-        0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0,
-        207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123,
-        26, 200, 255, 0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0,
-        123, 26, 176, 255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0,
-        0, 123, 26, 152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0,
-        0, 0, 123, 26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0,
-        0, 0, 0, 123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0,
-        0, 0, 0, 123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0,
-        0, 0, 0, 123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0,
-        0, 0, 0, 123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0,
-        0, 0, 0, 123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0,
-        0, 0, 121, 166, 224, 255, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 168, 0, 0,
-        0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0,
-        7, 8, 0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133,
-        0, 0, 0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15,
-        19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0,
-        112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0,
-        0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0,
-        0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101, 108, 102, 85, 8, 29, 0, 99,
-        108, 115, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 144, 0, 0, 0, 0, 0, 191, 147, 0, 0,
-        0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255,
-        255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 8, 0, 115, 101, 108,
-        102, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0,
-        0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121,
-        17, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161,
-        0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0,
-        112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0,
-        32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 152, 0,
-        0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0,
-        0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0,
-        133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255, 183,
-        2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191, 99,
-        0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0,
-        0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0,
-        0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0,
-        133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121,
-        161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0,
-        0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24,
-        1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0,
-        0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0,
-        21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0,
-        10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0,
-        0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0,
-        0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 5, 0, 33, 0,
-        0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0,
-        0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133,
-        0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0, 0, 1,
-        0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254,
-        255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 191, 1, 0,
-        0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0, 21, 1, 9, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 31, 0, 0, 0,
-        191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248, 255, 0, 0, 0, 0, 121,
-        161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0,
-        0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0, 0, 0, 0, 0, 24, 1, 0,
-        0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0,
-        0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0,
-        105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15,
-        130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0, 121, 162, 232,
-        254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 120,
-        0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        121, 129, 0, 0, 0, 0, 0, 0, 121, 162, 240, 254, 0, 0, 0, 0, 21, 1, 100, 7, 0, 0, 0, 0, 183,
-        6, 0, 0, 9, 0, 0, 0, 121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 102, 246, 0, 0, 0, 0, 121, 113,
-        128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0,
-        0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0,
-        0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1,
-        0, 0, 1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 9, 0, 0,
-        0, 121, 163, 224, 255, 0, 0, 0, 0, 121, 164, 240, 254, 0, 0, 0, 0, 21, 3, 79, 246, 0, 0, 0,
-        0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 9, 0, 0, 0, 183, 3, 0, 0, 9, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0,
-        186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163,
-        224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20,
-        0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0,
-        123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0,
-        0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0,
-        0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0,
-        0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255,
-        0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255,
-        0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255,
-        0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255,
-        0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255,
-        0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0,
-        0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0, 0, 0, 0, 121, 167, 200, 254,
-        0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0,
-        0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121,
-        163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255,
-        0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0,
-        0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101,
-        108, 102, 85, 8, 29, 0, 99, 108, 115, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 144, 0,
-        0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0,
-        0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        85, 8, 8, 0, 115, 101, 108, 102, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 16, 0, 0, 0, 0,
-        0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7,
-        1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121,
-        161, 200, 254, 0, 0, 0, 0, 121, 17, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15,
-        19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2,
-        0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 121, 161,
-        208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 167, 200,
-        254, 0, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0,
-        0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0,
-        121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1,
-        0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113,
-        160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0,
-        121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0,
-        183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15,
-        22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0,
-        0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0,
-        248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0,
-        0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1,
-        0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254,
-        0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1,
-        0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0,
-        0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0,
-        0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0,
-        0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0,
-        7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2,
-        0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0,
-        21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0,
-        0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248,
-        255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0,
-        48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0,
-        191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161,
-        232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176,
-        254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0,
-        0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0,
-        0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0,
-        191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0,
-        0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 121, 162, 240, 254, 0, 0, 0, 0, 21, 1, 86,
-        6, 0, 0, 0, 0, 183, 6, 0, 0, 10, 0, 0, 0, 121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 88, 245, 0, 0,
-        0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0,
-        191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133,
-        0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0,
-        0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183,
-        6, 0, 0, 10, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 121, 164, 240, 254, 0, 0, 0, 0, 21,
-        3, 65, 245, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 10, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24,
-        6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0,
-        0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191,
-        147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0,
-        0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216,
-        255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 123, 26,
-        192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0, 0, 123,
-        26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0, 0, 0,
-        123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0, 0, 0,
-        0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255, 0, 0,
-        0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255, 0, 0,
-        0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255, 0, 0,
-        0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255, 0, 0,
-        0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0, 0, 0,
-        0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0, 0, 0,
-        0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191,
-        129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184,
-        0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0,
-        121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0,
-        183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21,
-        8, 1, 0, 115, 101, 108, 102, 85, 8, 29, 0, 99, 108, 115, 0, 121, 161, 200, 254, 0, 0, 0, 0,
-        121, 17, 144, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 85, 8, 8, 0, 115, 101, 108, 102, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17,
-        16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0,
-        0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112,
-        0, 0, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255,
-        255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0,
-        121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121,
-        167, 200, 254, 0, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0,
-        0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0,
-        0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0,
-        121, 113, 160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24,
-        0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216,
-        254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0,
-        0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0,
-        0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0,
-        0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5,
-        0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0,
-        0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216,
-        255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0,
-        0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99,
-        106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0,
-        0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7,
-        3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0,
-        0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0,
-        119, 1, 0, 0, 32, 0, 0, 0, 21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 121, 166, 248, 255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0,
-        0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0,
-        0, 0, 0, 0, 21, 2, 8, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0,
-        6, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0,
-        0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 1, 0, 0, 0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121,
-        167, 200, 254, 0, 0, 0, 0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121,
-        168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 121, 162, 240,
-        254, 0, 0, 0, 0, 21, 1, 72, 5, 0, 0, 0, 0, 183, 6, 0, 0, 11, 0, 0, 0, 121, 137, 0, 0, 0, 0,
-        0, 0, 21, 9, 74, 244, 0, 0, 0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183,
-        2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1,
-        0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 11, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 121, 164, 240,
-        254, 0, 0, 0, 0, 21, 3, 51, 244, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 11, 0, 0, 0, 133, 0,
-        0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0,
-        0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0,
-        0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0,
-        0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255,
-        0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176,
-        255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26,
-        152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123,
-        26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0,
-        123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0,
-        123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0,
-        123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0,
-        123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0,
-        123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0,
-        121, 166, 224, 255, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0,
-        0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8,
-        0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0,
-        0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0,
-        0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0,
-        0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0,
-        97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101, 108, 102, 85, 8, 29, 0, 99, 108, 115,
-        0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 144, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183,
-        2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 8, 0, 115, 101, 108, 102, 121, 161,
-        200, 254, 0, 0, 0, 0, 121, 17, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 40, 0, 0,
-        0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0,
-        0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        121, 163, 240, 255, 0, 0, 0, 0, 121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0,
-        133, 0, 0, 0, 114, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0,
-        191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0,
-        0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0,
-        112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0,
-        0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0,
-        0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0,
-        0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114,
-        0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24,
-        6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0,
-        0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0,
-        0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163,
-        216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0,
-        0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0,
-        0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0,
-        0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0,
-        0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240,
-        255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0,
-        21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26,
-        248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 191, 163,
-        0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103,
-        1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0, 21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1,
-        0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0,
-        0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248, 255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0,
-        0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37,
-        8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0,
-        0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0,
-        0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0,
-        0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0,
-        99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18,
-        0, 0, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0,
-        0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8,
-        0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0,
-        0, 121, 162, 240, 254, 0, 0, 0, 0, 21, 1, 58, 4, 0, 0, 0, 0, 183, 6, 0, 0, 12, 0, 0, 0,
-        121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 60, 243, 0, 0, 0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191,
-        147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0,
-        224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21,
-        2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 12, 0, 0, 0, 121, 163, 224,
-        255, 0, 0, 0, 0, 121, 164, 240, 254, 0, 0, 0, 0, 21, 3, 37, 243, 0, 0, 0, 0, 21, 2, 5, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0,
-        183, 3, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0,
-        0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133,
-        0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208,
-        255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0, 0, 123, 26,
-        184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0, 0, 0, 123,
-        26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0, 0, 0, 0,
-        123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255, 0, 0, 0,
-        0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0,
-        0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0,
-        0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0,
-        0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0,
-        0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0,
-        123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0,
-        0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2,
-        0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121, 163, 248,
-        255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0,
-        0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0,
-        0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0,
-        133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101, 108, 102,
-        85, 8, 29, 0, 99, 108, 115, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 144, 0, 0, 0, 0, 0,
-        191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0,
-        0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 8, 0,
-        115, 101, 108, 102, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 16, 0, 0, 0, 0, 0, 121, 163,
-        240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0,
-        240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 161, 200,
-        254, 0, 0, 0, 0, 121, 17, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0,
-        0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0,
-        0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 121, 161, 208, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0,
-        0, 121, 113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2,
-        0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240,
-        255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248,
-        254, 255, 255, 183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0,
-        0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121,
-        163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183,
-        2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0,
-        0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0,
-        0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248,
-        254, 255, 255, 24, 1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0,
-        0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254, 0,
-        0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1,
-        0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0,
-        0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0,
-        0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0,
-        0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0,
-        7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2,
-        0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0,
-        21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0,
-        0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248,
-        255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0,
-        48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0,
-        191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161,
-        232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176,
-        254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0,
-        0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0,
-        0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0,
-        191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0,
-        0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 121, 162, 240, 254, 0, 0, 0, 0, 21, 1, 44,
-        3, 0, 0, 0, 0, 183, 6, 0, 0, 13, 0, 0, 0, 121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 46, 242, 0, 0,
-        0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0,
-        191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133,
-        0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0,
-        0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183,
-        6, 0, 0, 13, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 121, 164, 240, 254, 0, 0, 0, 0, 21,
-        3, 23, 242, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 13, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24,
-        6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0,
-        0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191,
-        147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0,
-        0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216,
-        255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 123, 26,
-        192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0, 0, 123,
-        26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0, 0, 0,
-        123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0, 0, 0,
-        0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255, 0, 0,
-        0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255, 0, 0,
-        0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255, 0, 0,
-        0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255, 0, 0,
-        0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0, 0, 0,
-        0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0, 0, 0,
-        0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191,
-        129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184,
-        0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0,
-        0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0,
-        121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0,
-        183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21,
-        8, 1, 0, 115, 101, 108, 102, 85, 8, 29, 0, 99, 108, 115, 0, 121, 161, 200, 254, 0, 0, 0, 0,
-        121, 17, 144, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 85, 8, 8, 0, 115, 101, 108, 102, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17,
-        16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0,
-        0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112,
-        0, 0, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0,
-        0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255,
-        255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0,
-        121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121,
-        167, 200, 254, 0, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0,
-        0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0,
-        0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0,
-        121, 113, 160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24,
-        0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216,
-        254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0,
-        0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0,
-        0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0,
-        0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5,
-        0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0,
-        0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0,
-        133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216,
-        255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0,
-        0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99,
-        106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0,
-        0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7,
-        3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0,
-        0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0,
-        119, 1, 0, 0, 32, 0, 0, 0, 21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6,
-        0, 0, 0, 121, 166, 248, 255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0,
-        0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0,
-        0, 0, 0, 0, 21, 2, 8, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0,
-        6, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0,
-        0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0,
-        7, 1, 0, 0, 1, 0, 0, 0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121,
-        167, 200, 254, 0, 0, 0, 0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121,
-        168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0, 0, 121, 162, 240,
-        254, 0, 0, 0, 0, 21, 1, 30, 2, 0, 0, 0, 0, 183, 6, 0, 0, 14, 0, 0, 0, 121, 137, 0, 0, 0, 0,
-        0, 0, 21, 9, 32, 241, 0, 0, 0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 224, 255, 255, 255, 183,
-        2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21, 2, 1, 0, 0, 0, 0, 0, 183, 1,
-        0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 14, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 121, 164, 240,
-        254, 0, 0, 0, 0, 21, 3, 9, 241, 0, 0, 0, 0, 21, 2, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 177, 1,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0, 183, 3, 0, 0, 14, 0, 0, 0, 133, 0,
-        0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0,
-        0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0,
-        0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0,
-        0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0, 0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0,
-        0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208, 255, 0, 0, 0, 0, 123, 26, 200, 255,
-        0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0, 0, 123, 26, 184, 255, 0, 0, 0, 0, 123, 26, 176,
-        255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0, 0, 0, 123, 26, 160, 255, 0, 0, 0, 0, 123, 26,
-        152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0, 0, 0, 0, 123, 26, 136, 255, 0, 0, 0, 0, 123,
-        26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255, 0, 0, 0, 0, 123, 26, 112, 255, 0, 0, 0, 0,
-        123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0, 0, 123, 26, 88, 255, 0, 0, 0, 0,
-        123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0, 0, 123, 26, 64, 255, 0, 0, 0, 0,
-        123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0, 0, 123, 26, 40, 255, 0, 0, 0, 0,
-        123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0, 0, 123, 26, 16, 255, 0, 0, 0, 0,
-        123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0, 123, 26, 248, 254, 0, 0, 0, 0,
-        121, 166, 224, 255, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 168, 0, 0, 0, 0,
-        0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8,
-        0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0,
-        0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0,
-        0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0,
-        0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0,
-        97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101, 108, 102, 85, 8, 29, 0, 99, 108, 115,
-        0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 144, 0, 0, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183,
-        2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 8, 0, 115, 101, 108, 102, 121, 161,
-        200, 254, 0, 0, 0, 0, 121, 17, 16, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19,
-        0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0,
-        8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 40, 0, 0,
-        0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0,
-        0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0,
-        121, 163, 240, 255, 0, 0, 0, 0, 121, 161, 208, 254, 0, 0, 0, 0, 183, 2, 0, 0, 32, 0, 0, 0,
-        133, 0, 0, 0, 114, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 152, 0, 0, 0, 0, 0,
-        191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 168, 0, 0, 0, 0, 0, 0, 7, 8, 0,
-        0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0,
-        112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0,
-        0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248, 254, 255, 255, 183, 2, 0, 0, 128, 0,
-        0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0,
-        15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0,
-        0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114,
-        0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24,
-        6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0,
-        0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 227, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0,
-        0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0,
-        0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163,
-        216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0,
-        0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0,
-        0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0,
-        0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0,
-        0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240,
-        255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0,
-        21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26,
-        248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 191, 163,
-        0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2, 0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103,
-        1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0, 21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1,
-        0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0,
-        0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248, 255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0,
-        0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37,
-        8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0,
-        0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0, 0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0, 191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0,
-        0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0,
-        0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176, 254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0,
-        99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0, 0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18,
-        0, 0, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0, 0, 121, 113, 120, 0, 0, 0, 0, 0, 15, 25, 0,
-        0, 0, 0, 0, 0, 121, 168, 184, 254, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8,
-        0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 129, 0, 0, 0, 0, 0,
-        0, 121, 162, 240, 254, 0, 0, 0, 0, 21, 1, 16, 1, 0, 0, 0, 0, 183, 6, 0, 0, 15, 0, 0, 0,
-        121, 137, 0, 0, 0, 0, 0, 0, 21, 9, 18, 240, 0, 0, 0, 0, 121, 113, 128, 0, 0, 0, 0, 0, 191,
-        147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0,
-        224, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0, 0, 0, 21,
-        2, 1, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 183, 6, 0, 0, 15, 0, 0, 0, 121, 163, 224,
-        255, 0, 0, 0, 0, 121, 164, 240, 254, 0, 0, 0, 0, 21, 3, 251, 239, 0, 0, 0, 0, 21, 2, 5, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 9, 0, 0, 0,
-        183, 3, 0, 0, 15, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 186, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 21, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 121, 163, 224, 255, 0,
-        0, 0, 0, 24, 1, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 20, 0, 0, 0, 133,
-        0, 0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 99, 26, 216, 255, 0, 0, 0, 0, 123, 26, 208,
-        255, 0, 0, 0, 0, 123, 26, 200, 255, 0, 0, 0, 0, 123, 26, 192, 255, 0, 0, 0, 0, 123, 26,
-        184, 255, 0, 0, 0, 0, 123, 26, 176, 255, 0, 0, 0, 0, 123, 26, 168, 255, 0, 0, 0, 0, 123,
-        26, 160, 255, 0, 0, 0, 0, 123, 26, 152, 255, 0, 0, 0, 0, 123, 26, 144, 255, 0, 0, 0, 0,
-        123, 26, 136, 255, 0, 0, 0, 0, 123, 26, 128, 255, 0, 0, 0, 0, 123, 26, 120, 255, 0, 0, 0,
-        0, 123, 26, 112, 255, 0, 0, 0, 0, 123, 26, 104, 255, 0, 0, 0, 0, 123, 26, 96, 255, 0, 0, 0,
-        0, 123, 26, 88, 255, 0, 0, 0, 0, 123, 26, 80, 255, 0, 0, 0, 0, 123, 26, 72, 255, 0, 0, 0,
-        0, 123, 26, 64, 255, 0, 0, 0, 0, 123, 26, 56, 255, 0, 0, 0, 0, 123, 26, 48, 255, 0, 0, 0,
-        0, 123, 26, 40, 255, 0, 0, 0, 0, 123, 26, 32, 255, 0, 0, 0, 0, 123, 26, 24, 255, 0, 0, 0,
-        0, 123, 26, 16, 255, 0, 0, 0, 0, 123, 26, 8, 255, 0, 0, 0, 0, 123, 26, 0, 255, 0, 0, 0, 0,
-        123, 26, 248, 254, 0, 0, 0, 0, 121, 166, 224, 255, 0, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0,
-        0, 121, 113, 168, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 248, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2,
-        0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 184, 0, 0, 0, 0, 0, 121, 163, 248,
-        255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0,
-        0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 248, 255, 0, 0, 0,
-        0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183, 2, 0, 0, 64, 0, 0, 0,
-        133, 0, 0, 0, 114, 0, 0, 0, 97, 168, 152, 255, 0, 0, 0, 0, 21, 8, 1, 0, 115, 101, 108, 102,
-        85, 8, 29, 0, 99, 108, 115, 0, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 144, 0, 0, 0, 0, 0,
-        191, 147, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0,
-        0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 85, 8, 8, 0,
-        115, 101, 108, 102, 121, 161, 200, 254, 0, 0, 0, 0, 121, 17, 16, 0, 0, 0, 0, 0, 121, 163,
-        240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0,
-        240, 255, 255, 255, 183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 161, 200,
-        254, 0, 0, 0, 0, 121, 17, 40, 0, 0, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0,
-        0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 240, 255, 255, 255, 183, 2, 0, 0, 8, 0,
-        0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 163, 240, 255, 0, 0, 0, 0, 121, 161, 208, 254, 0, 0,
-        0, 0, 183, 2, 0, 0, 32, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 167, 200, 254, 0, 0, 0,
-        0, 121, 113, 152, 0, 0, 0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191,
-        168, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 240, 255, 255, 255, 191, 129, 0, 0, 0, 0, 0, 0, 183, 2,
-        0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121, 163, 240,
-        255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 161, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 248,
-        254, 255, 255, 183, 2, 0, 0, 128, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 160, 0, 0,
-        0, 0, 0, 191, 99, 0, 0, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0,
-        183, 2, 0, 0, 8, 0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 121, 113, 24, 0, 0, 0, 0, 0, 121,
-        163, 240, 255, 0, 0, 0, 0, 15, 19, 0, 0, 0, 0, 0, 0, 121, 161, 216, 254, 0, 0, 0, 0, 183,
-        2, 0, 0, 64, 0, 0, 0, 133, 0, 0, 0, 114, 0, 0, 0, 121, 113, 176, 0, 0, 0, 0, 0, 15, 22, 0,
-        0, 0, 0, 0, 0, 121, 161, 192, 254, 0, 0, 0, 0, 183, 2, 0, 0, 4, 0, 0, 0, 191, 99, 0, 0, 0,
-        0, 0, 0, 133, 0, 0, 0, 112, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 6, 0, 0, 0, 0, 0, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248,
-        254, 255, 255, 24, 1, 0, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0,
-        0, 133, 0, 0, 0, 6, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0,
-        0, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 13, 0, 0, 0, 121, 163, 208, 254, 0,
-        0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113,
-        97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 183, 2, 0, 0, 10, 0, 0, 0, 121, 163, 216, 254, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 97, 163, 216, 255, 0, 0, 0, 0, 24, 1,
-        0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 12, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0,
-        0, 191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 248, 254, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 2, 0, 0, 0, 0, 0, 97, 6, 0, 0, 0, 0,
-        0, 0, 5, 0, 33, 0, 0, 0, 0, 0, 183, 6, 0, 0, 0, 0, 0, 0, 99, 106, 240, 255, 0, 0, 0, 0,
-        191, 162, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 240, 255, 255, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 1, 0, 0, 0, 21, 0, 25, 0, 0, 0, 0, 0, 183, 1, 0, 0, 1, 0,
-        0, 0, 219, 16, 0, 0, 1, 0, 0, 0, 123, 26, 248, 255, 0, 0, 0, 0, 191, 162, 0, 0, 0, 0, 0, 0,
-        7, 2, 0, 0, 248, 254, 255, 255, 191, 163, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 248, 255, 255, 255,
-        24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 2,
-        0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 32, 0, 0, 0, 119, 1, 0, 0, 32, 0, 0, 0,
-        21, 1, 9, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0,
-        0, 0, 0, 21, 1, 5, 0, 0, 0, 0, 0, 24, 1, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183,
-        2, 0, 0, 31, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 166, 248,
-        255, 0, 0, 0, 0, 121, 161, 232, 254, 0, 0, 0, 0, 105, 24, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0,
-        48, 0, 0, 0, 199, 8, 0, 0, 48, 0, 0, 0, 37, 8, 20, 0, 79, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 113, 18, 0, 0, 0, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 21, 2, 8, 0,
-        0, 0, 0, 0, 24, 1, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 23, 0, 0, 0,
-        191, 131, 0, 0, 0, 0, 0, 0, 191, 100, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 121, 161,
-        232, 254, 0, 0, 0, 0, 105, 17, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 2, 0, 0, 0, 121, 162, 176,
-        254, 0, 0, 0, 0, 15, 130, 0, 0, 0, 0, 0, 0, 99, 98, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 1, 0, 0,
-        0, 121, 162, 232, 254, 0, 0, 0, 0, 107, 18, 0, 0, 0, 0, 0, 0, 121, 161, 200, 254, 0, 0, 0,
-        0, 121, 17, 120, 0, 0, 0, 0, 0, 15, 25, 0, 0, 0, 0, 0, 0, 121, 166, 184, 254, 0, 0, 0, 0,
-        191, 97, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 8, 0, 0, 0, 191, 147, 0, 0, 0, 0, 0, 0, 133, 0, 0,
-        0, 112, 0, 0, 0, 121, 97, 0, 0, 0, 0, 0, 0, 121, 162, 240, 254, 0, 0, 0, 0, 21, 1, 2, 0, 0,
-        0, 0, 0, 183, 6, 0, 0, 16, 0, 0, 0, 5, 0, 5, 239, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 8, 0, 0, 0, 0, 0, 121, 161, 232,
-        254, 0, 0, 0, 0, 105, 19, 0, 0, 0, 0, 0, 0, 103, 3, 0, 0, 48, 0, 0, 0, 199, 3, 0, 0, 48, 0,
-        0, 0, 24, 1, 0, 0, 216, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 43, 0, 0, 0, 133, 0,
-        0, 0, 6, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 121, 167, 240, 254, 0, 0, 0, 0, 99, 23, 100, 0,
-        0, 0, 0, 0, 99, 23, 104, 0, 0, 0, 0, 0, 121, 168, 224, 254, 0, 0, 0, 0, 24, 6, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0,
-        0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 25, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0,
-        113, 97, 0, 0, 0, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 122, 3, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 183, 2, 0, 0, 7, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 113, 17, 0, 0, 0, 0, 0, 0, 21, 1, 4, 0, 0, 0, 0, 0, 24, 1, 0, 0, 129,
-        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 1, 0, 0, 0, 133, 0, 0, 0, 6, 0, 0, 0, 7, 7,
-        0, 0, 48, 0, 0, 0, 191, 129, 0, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 24, 3, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 191, 116, 0, 0, 0, 0, 0, 0,
-        183, 5, 0, 0, 128, 1, 0, 0, 133, 0, 0, 0, 25, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0,
-        0, 0, 0, 0, 0, 0, 91, 115, 116, 97, 114, 116, 93, 0, 91, 101, 118, 101, 110, 116, 93, 32,
-        112, 105, 100, 61, 37, 100, 32, 116, 105, 100, 61, 37, 100, 0, 91, 101, 114, 114, 111, 114,
-        93, 32, 112, 114, 111, 99, 101, 115, 115, 95, 105, 110, 102, 111, 46, 116, 104, 114, 101,
-        97, 100, 95, 115, 116, 97, 116, 101, 95, 97, 100, 100, 114, 32, 119, 97, 115, 32, 78, 85,
-        76, 76, 0, 112, 114, 111, 99, 101, 115, 115, 95, 105, 110, 102, 111, 45, 62, 116, 104, 114,
-        101, 97, 100, 95, 115, 116, 97, 116, 101, 95, 97, 100, 100, 114, 32, 48, 120, 37, 108, 108,
-        120, 0, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 32, 48, 120, 37, 108, 108,
-        120, 0, 116, 108, 115, 95, 98, 97, 115, 101, 32, 48, 120, 37, 108, 108, 120, 0, 91, 101,
-        114, 114, 111, 114, 93, 32, 112, 116, 104, 114, 101, 97, 100, 95, 99, 114, 101, 97, 116,
-        101, 100, 32, 119, 97, 115, 32, 78, 85, 76, 76, 0, 112, 116, 104, 114, 101, 97, 100, 95,
-        99, 114, 101, 97, 116, 101, 100, 32, 48, 120, 37, 108, 108, 120, 0, 91, 101, 114, 114, 111,
-        114, 93, 32, 112, 116, 104, 114, 101, 97, 100, 95, 115, 101, 108, 102, 32, 119, 97, 115,
-        32, 78, 85, 76, 76, 0, 112, 116, 104, 114, 101, 97, 100, 95, 115, 101, 108, 102, 32, 48,
-        120, 37, 108, 108, 120, 0, 91, 101, 114, 114, 111, 114, 93, 32, 116, 104, 114, 101, 97,
-        100, 95, 115, 116, 97, 116, 101, 32, 119, 97, 115, 32, 78, 85, 76, 76, 0, 91, 101, 114,
-        114, 111, 114, 93, 32, 99, 102, 114, 97, 109, 101, 32, 119, 97, 115, 32, 78, 85, 76, 76, 0,
-        99, 102, 114, 97, 109, 101, 32, 48, 120, 37, 108, 108, 120, 0, 91, 101, 114, 114, 111, 114,
-        93, 32, 102, 114, 97, 109, 101, 95, 112, 116, 114, 32, 119, 97, 115, 32, 78, 85, 76, 76, 0,
-        102, 114, 97, 109, 101, 95, 112, 116, 114, 32, 48, 120, 37, 108, 108, 120, 0, 91, 115, 116,
-        97, 114, 116, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116,
-        97, 99, 107, 0, 91, 101, 114, 114, 111, 114, 93, 32, 98, 112, 102, 95, 112, 114, 111, 98,
-        101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 32, 102, 97, 105, 108, 101, 100, 0,
-        102, 114, 97, 109, 101, 32, 37, 100, 0, 99, 117, 114, 95, 102, 114, 97, 109, 101, 95, 112,
-        116, 114, 32, 48, 120, 37, 108, 108, 120, 0, 99, 117, 114, 95, 99, 111, 100, 101, 95, 112,
-        116, 114, 32, 48, 120, 37, 108, 108, 120, 0, 115, 121, 109, 46, 102, 105, 108, 101, 32, 37,
-        115, 0, 115, 121, 109, 46, 99, 108, 97, 115, 115, 32, 37, 115, 0, 115, 121, 109, 46, 102,
-        110, 32, 37, 115, 0, 115, 121, 109, 46, 108, 105, 110, 101, 32, 37, 100, 0, 115, 116, 97,
-        99, 107, 45, 62, 102, 114, 97, 109, 101, 115, 91, 37, 100, 93, 32, 61, 32, 37, 100, 0, 91,
-        105, 116, 101, 114, 97, 116, 105, 111, 110, 93, 32, 102, 114, 97, 109, 101, 95, 99, 111,
-        117, 110, 116, 32, 37, 100, 0, 115, 116, 97, 116, 101, 45, 62, 115, 116, 97, 99, 107, 95,
-        119, 97, 108, 107, 101, 114, 95, 112, 114, 111, 103, 95, 99, 97, 108, 108, 95, 99, 111,
-        117, 110, 116, 32, 37, 100, 0, 91, 99, 111, 110, 116, 105, 110, 117, 101, 93, 32, 119, 97,
-        108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 0, 91, 101, 114,
-        114, 111, 114, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116,
-        97, 99, 107, 32, 84, 82, 85, 78, 67, 65, 84, 69, 68, 0, 91, 116, 114, 117, 110, 99, 97,
-        116, 101, 100, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116,
-        97, 99, 107, 44, 32, 115, 116, 97, 99, 107, 95, 108, 101, 110, 61, 37, 100, 0, 91, 99, 111,
-        109, 112, 108, 101, 116, 101, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110,
-        95, 115, 116, 97, 99, 107, 44, 32, 115, 116, 97, 99, 107, 95, 108, 101, 110, 61, 37, 100,
-        0, 91, 115, 116, 111, 112, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95,
-        115, 116, 97, 99, 107, 0, 112, 121, 45, 112, 101, 114, 102, 32, 40, 104, 116, 116, 112,
-        115, 58, 47, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 47, 107, 97, 107, 107, 111,
-        121, 117, 110, 47, 112, 121, 45, 112, 101, 114, 102, 41, 0, 91, 101, 114, 114, 111, 114,
-        93, 32, 98, 112, 102, 95, 109, 97, 112, 95, 108, 111, 111, 107, 117, 112, 95, 111, 114, 95,
-        116, 114, 121, 95, 105, 110, 105, 116, 32, 119, 105, 116, 104, 32, 114, 101, 116, 58, 32,
-        37, 100, 0, 91, 115, 116, 111, 112, 93, 0, 0, 91, 101, 114, 114, 111, 114, 93, 32, 115,
-        121, 109, 98, 111, 108, 115, 32, 102, 97, 105, 108, 101, 100, 32, 119, 105, 116, 104, 32,
-        37, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 71, 80, 76, 0, 72, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 86, 1, 0, 0, 112, 0, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        200, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87,
-        1, 0, 0, 32, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 120, 1, 0, 0, 0, 0, 0, 0, 1, 0,
-        0, 0, 88, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 89, 1, 0, 0, 48, 4, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 89, 1, 0, 0, 40, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 90, 1, 0, 0, 112, 7, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 90, 1, 0, 0, 160, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0,
-        192, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 240, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        90, 1, 0, 0, 80, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 120, 8, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 84, 1, 0, 0, 176, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 24, 9, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 56, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 112, 9, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 0, 91, 1, 0, 0, 208, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0,
-        8, 10, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 56, 10, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 136, 10, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 192, 10, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 84, 1, 0, 0, 224, 10, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 16, 11, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 56, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 184,
-        11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 232, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 16, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 92, 1, 0, 0, 104, 12, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 87, 1, 0, 0, 152, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 224, 12, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 16, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 48,
-        13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 104, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 144, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 93, 1, 0, 0, 224, 13, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 84, 1, 0, 0, 64, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 88, 1, 0, 0, 120, 14, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 91, 1, 0, 0, 168, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 200,
-        14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 112, 15, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87,
-        1, 0, 0, 200, 15, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 232, 15, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 87, 1, 0, 0, 8, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 88, 16, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 128, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 184, 16,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 224, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 92, 1, 0,
-        0, 32, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 64, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 128, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 208, 17, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 16, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 48, 18, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 112, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        80, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 128, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        84, 1, 0, 0, 176, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 216, 22, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 87, 1, 0, 0, 248, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 56, 23, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 104, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0,
-        200, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 40, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        95, 1, 0, 0, 104, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 136, 24, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 16, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 64, 25, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 80, 26, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0,
-        160, 26, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 200, 26, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 232, 26, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 40, 27, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 208, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 0, 31, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 56, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 96,
-        31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 128, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 192, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 240, 31, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 95, 1, 0, 0, 64, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 152, 32, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 216, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 248,
-        32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 80, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1,
-        0, 0, 128, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 144, 34, 0, 0, 0, 0, 0, 0, 1, 0,
-        0, 0, 87, 1, 0, 0, 224, 34, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 8, 35, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 40, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 104, 35,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 16, 39, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0,
-        0, 64, 39, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 120, 39, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 84, 1, 0, 0, 160, 39, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 192, 39, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 84, 1, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 48, 40, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 128, 40, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0,
-        216, 40, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 24, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 56, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 144, 41, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 87, 1, 0, 0, 192, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 208, 42, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 32, 43, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 72,
-        43, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 104, 43, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 168, 43, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 80, 47, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 87, 1, 0, 0, 128, 47, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 184, 47, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 224, 47, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 0,
-        48, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 64, 48, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1,
-        0, 0, 112, 48, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 192, 48, 0, 0, 0, 0, 0, 0, 1, 0,
-        0, 0, 96, 1, 0, 0, 24, 49, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 88, 49, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 120, 49, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 208, 49,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0,
-        0, 16, 51, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 96, 51, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        84, 1, 0, 0, 136, 51, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 168, 51, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 232, 51, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 144, 55, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 192, 55, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        240, 55, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 24, 56, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 56, 56, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 120, 56, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 168, 56, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 248, 56, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 80, 57, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0,
-        144, 57, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 176, 57, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        84, 1, 0, 0, 8, 58, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 48, 58, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 84, 1, 0, 0, 72, 59, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 152, 59, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 192, 59, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 224,
-        59, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 32, 60, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1,
-        0, 0, 216, 63, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 8, 64, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 84, 1, 0, 0, 56, 64, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 96, 64, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 87, 1, 0, 0, 128, 64, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 192, 64, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 240, 64, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0,
-        72, 65, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 160, 65, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        95, 1, 0, 0, 224, 65, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 88, 66, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 128, 66, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 160, 67, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0,
-        248, 67, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 32, 68, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 64, 68, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 128, 68, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 80, 72, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 128, 72, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 176, 72, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        216, 72, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 248, 72, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        84, 1, 0, 0, 56, 73, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 104, 73, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 95, 1, 0, 0, 184, 73, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 16, 74, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 80, 74, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0,
-        112, 74, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 200, 74, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 240, 74, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 16, 76, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 87, 1, 0, 0, 104, 76, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 144, 76, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 176, 76, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        240, 76, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 192, 80, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 240, 80, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 32, 81, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 72, 81, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 104, 81, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 168, 81, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        216, 81, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 40, 82, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        96, 1, 0, 0, 128, 82, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 192, 82, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 87, 1, 0, 0, 224, 82, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 56, 83, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 96, 83, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        136, 84, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 216, 84, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        84, 1, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 32, 85, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 84, 1, 0, 0, 96, 85, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 48, 89, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 96, 89, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 144,
-        89, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 184, 89, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87,
-        1, 0, 0, 216, 89, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 24, 90, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 84, 1, 0, 0, 72, 90, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 152, 90, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 240, 90, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 48,
-        91, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 80, 91, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1,
-        0, 0, 168, 91, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 208, 91, 0, 0, 0, 0, 0, 0, 1, 0,
-        0, 0, 84, 1, 0, 0, 240, 92, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 72, 93, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 112, 93, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 144, 93,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 208, 93, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0,
-        0, 160, 97, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 208, 97, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 84, 1, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 40, 98, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 87, 1, 0, 0, 72, 98, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 136, 98, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 184, 98, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 8,
-        99, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 96, 99, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1,
-        0, 0, 160, 99, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 192, 99, 0, 0, 0, 0, 0, 0, 1, 0,
-        0, 0, 84, 1, 0, 0, 24, 100, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 64, 100, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 96, 101, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 184,
-        101, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 224, 101, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87,
-        1, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 64, 102, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 84, 1, 0, 0, 16, 106, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 64, 106, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 112, 106, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 152,
-        106, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 184, 106, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 248, 106, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 40, 107, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 95, 1, 0, 0, 120, 107, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 208, 107, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 16, 108, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0,
-        48, 108, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 136, 108, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 176, 108, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 208, 109, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 87, 1, 0, 0, 40, 110, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 80, 110, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 112, 110, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0,
-        0, 176, 110, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 128, 114, 0, 0, 0, 0, 0, 0, 1, 0,
-        0, 0, 87, 1, 0, 0, 176, 114, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 224, 114, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 8, 115, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 40,
-        115, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 104, 115, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 152, 115, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 232, 115, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 96, 1, 0, 0, 64, 116, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 128, 116, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 160, 116, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0,
-        0, 248, 116, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 32, 117, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 84, 1, 0, 0, 64, 118, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 152, 118, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 192, 118, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 224,
-        118, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 32, 119, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 240, 122, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 32, 123, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 84, 1, 0, 0, 80, 123, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 120, 123, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 152, 123, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        216, 123, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 8, 124, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        95, 1, 0, 0, 88, 124, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 176, 124, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 95, 1, 0, 0, 240, 124, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 16, 125,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 104, 125, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1,
-        0, 0, 144, 125, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 176, 126, 0, 0, 0, 0, 0, 0, 1,
-        0, 0, 0, 87, 1, 0, 0, 8, 127, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 48, 127, 0, 0, 0,
-        0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 80, 127, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 144,
-        127, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 96, 131, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87,
-        1, 0, 0, 144, 131, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 192, 131, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 232, 131, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 8, 132, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 72, 132, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0,
-        120, 132, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 200, 132, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 96, 1, 0, 0, 32, 133, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 96, 133, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 87, 1, 0, 0, 128, 133, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 216, 133,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0,
-        0, 32, 135, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 120, 135, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 84, 1, 0, 0, 160, 135, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 192, 135, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 208, 139,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0,
-        0, 48, 140, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 88, 140, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 87, 1, 0, 0, 120, 140, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 184, 140, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 232, 140, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 56,
-        141, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0, 144, 141, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95,
-        1, 0, 0, 208, 141, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 240, 141, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 72, 142, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 112, 142, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 144, 143, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0,
-        0, 232, 143, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 16, 144, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 87, 1, 0, 0, 48, 144, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 112, 144, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 64, 148, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 112,
-        148, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 160, 148, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84,
-        1, 0, 0, 200, 148, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 232, 148, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 84, 1, 0, 0, 40, 149, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 88, 149, 0, 0,
-        0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 168, 149, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 1, 0, 0,
-        0, 150, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 1, 0, 0, 64, 150, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
-        87, 1, 0, 0, 96, 150, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 184, 150, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 87, 1, 0, 0, 224, 150, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 192, 151,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0,
-        0, 72, 152, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 104, 152, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        0, 84, 1, 0, 0, 152, 152, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 184, 152, 0, 0, 0, 0,
-        0, 0, 1, 0, 0, 0, 87, 1, 0, 0, 216, 152, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 1, 0, 0, 8, 153,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 93, 1, 0, 0, 159, 235, 1, 0, 24, 0, 0, 0, 0, 0, 0, 0, 196,
-        85, 0, 0, 196, 85, 0, 0, 208, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 1, 0, 0, 0, 0,
-        0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0,
-        0, 1, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 6,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 2, 8, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 8, 9, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 8, 2,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 11, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 8, 12, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 4, 24, 0, 0, 0, 58, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 13, 0, 0,
-        0, 64, 0, 0, 0, 93, 0, 0, 0, 16, 0, 0, 0, 128, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 8, 14, 0, 0,
-        0, 108, 0, 0, 0, 0, 0, 0, 8, 15, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 64, 0, 0,
-        0, 133, 0, 0, 0, 0, 0, 0, 8, 17, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 8, 18, 0, 0, 0, 143, 0, 0,
-        0, 0, 0, 0, 1, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 156, 0, 0, 0,
-        1, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 5, 0, 0, 0, 64, 0, 0, 0, 173, 0, 0, 0, 7, 0, 0, 0,
-        128, 0, 0, 0, 177, 0, 0, 0, 10, 0, 0, 0, 192, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 14, 19, 0, 0,
-        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2,
-        0, 0, 0, 4, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        2, 25, 0, 0, 0, 203, 0, 0, 0, 0, 0, 0, 8, 26, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 4, 176, 1, 0,
-        0, 209, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 222, 0, 0, 0, 27, 0, 0, 0, 192, 0, 0, 0, 235, 0,
-        0, 0, 27, 0, 0, 0, 0, 1, 0, 0, 245, 0, 0, 0, 2, 0, 0, 0, 64, 1, 0, 0, 18, 1, 0, 0, 28, 0,
-        0, 0, 128, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 25, 1, 0, 0, 0, 0, 0, 8, 29, 0, 0,
-        0, 0, 0, 0, 0, 9, 0, 0, 4, 128, 1, 0, 0, 32, 1, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 42, 1, 0, 0,
-        16, 0, 0, 0, 64, 0, 0, 0, 46, 1, 0, 0, 30, 0, 0, 0, 96, 0, 0, 0, 50, 1, 0, 0, 30, 0, 0, 0,
-        128, 0, 0, 0, 54, 1, 0, 0, 34, 0, 0, 0, 160, 0, 0, 0, 59, 1, 0, 0, 35, 0, 0, 0, 32, 1, 0,
-        0, 82, 1, 0, 0, 37, 0, 0, 0, 160, 1, 0, 0, 95, 1, 0, 0, 38, 0, 0, 0, 192, 1, 0, 0, 106, 1,
-        0, 0, 39, 0, 0, 0, 224, 1, 0, 0, 25, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 112, 1, 0, 0, 0, 0,
-        0, 8, 32, 0, 0, 0, 115, 1, 0, 0, 0, 0, 0, 8, 33, 0, 0, 0, 120, 1, 0, 0, 0, 0, 0, 1, 1, 0,
-        0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 31, 0, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0,
-        134, 1, 0, 0, 0, 0, 0, 8, 36, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 16, 0, 0, 0, 46, 1, 0, 0,
-        30, 0, 0, 0, 0, 0, 0, 0, 50, 1, 0, 0, 30, 0, 0, 0, 32, 0, 0, 0, 152, 1, 0, 0, 2, 0, 0, 0,
-        64, 0, 0, 0, 166, 1, 0, 0, 2, 0, 0, 0, 96, 0, 0, 0, 82, 1, 0, 0, 3, 0, 0, 6, 4, 0, 0, 0,
-        182, 1, 0, 0, 0, 0, 0, 0, 197, 1, 0, 0, 1, 0, 0, 0, 213, 1, 0, 0, 2, 0, 0, 0, 95, 1, 0, 0,
-        13, 0, 0, 6, 4, 0, 0, 0, 225, 1, 0, 0, 0, 0, 0, 0, 236, 1, 0, 0, 1, 0, 0, 0, 2, 2, 0, 0, 2,
-        0, 0, 0, 26, 2, 0, 0, 3, 0, 0, 0, 49, 2, 0, 0, 4, 0, 0, 0, 72, 2, 0, 0, 5, 0, 0, 0, 101, 2,
-        0, 0, 6, 0, 0, 0, 119, 2, 0, 0, 8, 0, 0, 0, 136, 2, 0, 0, 9, 0, 0, 0, 164, 2, 0, 0, 10, 0,
-        0, 0, 193, 2, 0, 0, 11, 0, 0, 0, 216, 2, 0, 0, 12, 0, 0, 0, 234, 2, 0, 0, 13, 0, 0, 0, 6,
-        3, 0, 0, 0, 0, 0, 8, 40, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 68, 1, 0, 0, 12, 3, 0, 0, 41, 0,
-        0, 0, 0, 0, 0, 0, 16, 3, 0, 0, 44, 0, 0, 0, 32, 0, 0, 0, 23, 3, 0, 0, 0, 0, 0, 8, 42, 0, 0,
-        0, 27, 3, 0, 0, 0, 0, 0, 8, 43, 0, 0, 0, 33, 3, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 16, 0, 0, 1,
-        0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 4, 0,
-        0, 4, 32, 0, 0, 0, 156, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 1, 0, 0, 0, 64, 0,
-        0, 0, 173, 0, 0, 0, 23, 0, 0, 0, 128, 0, 0, 0, 177, 0, 0, 0, 24, 0, 0, 0, 192, 0, 0, 0, 39,
-        3, 0, 0, 0, 0, 0, 14, 45, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 48, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 250, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 52, 0, 0, 0, 52, 3, 0, 0, 0, 0, 0, 8, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 3, 0, 0, 0, 0, 13, 0, 0, 0, 4, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0,
-        0, 156, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 49, 0, 0, 0, 64, 0, 0, 0, 173, 0,
-        0, 0, 23, 0, 0, 0, 128, 0, 0, 0, 177, 0, 0, 0, 51, 0, 0, 0, 192, 0, 0, 0, 69, 3, 0, 0, 0,
-        0, 0, 14, 54, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 35, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 13, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 156, 0, 0, 0, 1,
-        0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 56, 0, 0, 0, 64, 0, 0, 0, 173, 0, 0, 0, 58, 0, 0, 0,
-        128, 0, 0, 0, 177, 0, 0, 0, 59, 0, 0, 0, 192, 0, 0, 0, 82, 3, 0, 0, 0, 0, 0, 14, 60, 0, 0,
-        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2,
-        0, 0, 0, 4, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 65, 0, 0, 0, 95, 3, 0, 0, 0, 0,
-        0, 8, 66, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 4, 192, 0, 0, 0, 116, 3, 0, 0, 16, 0, 0, 0, 0, 0,
-        0, 0, 130, 3, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 144, 3, 0, 0, 16, 0, 0, 0, 64, 0, 0, 0, 158,
-        3, 0, 0, 67, 0, 0, 0, 128, 0, 0, 0, 168, 3, 0, 0, 72, 0, 0, 0, 192, 0, 0, 0, 178, 3, 0, 0,
-        74, 0, 0, 0, 64, 1, 0, 0, 193, 3, 0, 0, 76, 0, 0, 0, 128, 1, 0, 0, 209, 3, 0, 0, 78, 0, 0,
-        0, 0, 3, 0, 0, 219, 3, 0, 0, 80, 0, 0, 0, 64, 3, 0, 0, 240, 3, 0, 0, 82, 0, 0, 0, 128, 3,
-        0, 0, 1, 4, 0, 0, 84, 0, 0, 0, 192, 3, 0, 0, 17, 4, 0, 0, 86, 0, 0, 0, 192, 4, 0, 0, 32, 4,
-        0, 0, 88, 0, 0, 0, 192, 5, 0, 0, 48, 4, 0, 0, 0, 0, 0, 8, 68, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        4, 8, 0, 0, 0, 57, 4, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 65, 4, 0, 0, 0, 0, 0, 8, 70, 0, 0, 0,
-        69, 4, 0, 0, 0, 0, 0, 8, 71, 0, 0, 0, 75, 4, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 64, 0, 0, 1, 85,
-        4, 0, 0, 0, 0, 0, 8, 73, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 94, 4, 0, 0, 69, 0,
-        0, 0, 0, 0, 0, 0, 99, 4, 0, 0, 69, 0, 0, 0, 64, 0, 0, 0, 104, 4, 0, 0, 0, 0, 0, 8, 75, 0,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 117, 4, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 125, 4, 0,
-        0, 0, 0, 0, 8, 77, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 4, 48, 0, 0, 0, 139, 4, 0, 0, 69, 0, 0, 0,
-        0, 0, 0, 0, 144, 4, 0, 0, 69, 0, 0, 0, 64, 0, 0, 0, 151, 4, 0, 0, 69, 0, 0, 0, 128, 0, 0,
-        0, 157, 4, 0, 0, 69, 0, 0, 0, 192, 0, 0, 0, 167, 4, 0, 0, 69, 0, 0, 0, 0, 1, 0, 0, 184, 4,
-        0, 0, 69, 0, 0, 0, 64, 1, 0, 0, 191, 4, 0, 0, 0, 0, 0, 8, 79, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        4, 8, 0, 0, 0, 200, 4, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 214, 4, 0, 0, 0, 0, 0, 8, 81, 0, 0,
-        0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 233, 4, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 245, 4, 0, 0,
-        0, 0, 0, 8, 83, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 4, 5, 0, 0, 69, 0, 0, 0, 0, 0,
-        0, 0, 16, 5, 0, 0, 0, 0, 0, 8, 85, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 30, 5, 0,
-        0, 69, 0, 0, 0, 0, 0, 0, 0, 37, 5, 0, 0, 69, 0, 0, 0, 64, 0, 0, 0, 44, 5, 0, 0, 69, 0, 0,
-        0, 128, 0, 0, 0, 53, 5, 0, 0, 69, 0, 0, 0, 192, 0, 0, 0, 66, 5, 0, 0, 0, 0, 0, 8, 87, 0, 0,
-        0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 79, 5, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 91, 5, 0, 0,
-        69, 0, 0, 0, 64, 0, 0, 0, 99, 5, 0, 0, 69, 0, 0, 0, 128, 0, 0, 0, 111, 5, 0, 0, 69, 0, 0,
-        0, 192, 0, 0, 0, 126, 5, 0, 0, 0, 0, 0, 8, 89, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0,
-        140, 5, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 156, 0, 0, 0,
-        1, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 62, 0, 0, 0, 64, 0, 0, 0, 173, 0, 0, 0, 23, 0, 0, 0,
-        128, 0, 0, 0, 177, 0, 0, 0, 64, 0, 0, 0, 192, 0, 0, 0, 148, 5, 0, 0, 0, 0, 0, 14, 90, 0, 0,
-        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2,
-        0, 0, 0, 4, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 156, 0, 0, 0, 92, 0,
-        0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 92, 0, 0, 0, 64, 0, 0, 0, 173, 0, 0, 0, 23, 0, 0, 0, 128,
-        0, 0, 0, 177, 0, 0, 0, 23, 0, 0, 0, 192, 0, 0, 0, 173, 5, 0, 0, 0, 0, 0, 14, 94, 0, 0, 0,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0,
-        0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
-        0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 156,
-        0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 182, 5, 0, 0, 96, 0, 0, 0, 64, 0, 0, 0, 191, 5, 0, 0, 96,
-        0, 0, 0, 128, 0, 0, 0, 161, 0, 0, 0, 98, 0, 0, 0, 192, 0, 0, 0, 202, 5, 0, 0, 0, 0, 0, 14,
-        100, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 39,
-        0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 156, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 161, 0,
-        0, 0, 1, 0, 0, 0, 64, 0, 0, 0, 173, 0, 0, 0, 102, 0, 0, 0, 128, 0, 0, 0, 177, 0, 0, 0, 103,
-        0, 0, 0, 192, 0, 0, 0, 209, 5, 0, 0, 0, 0, 0, 14, 104, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0,
-        0, 0, 0, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 156, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0,
-        0, 1, 0, 0, 0, 64, 0, 0, 0, 173, 0, 0, 0, 23, 0, 0, 0, 128, 0, 0, 0, 177, 0, 0, 0, 59, 0,
-        0, 0, 192, 0, 0, 0, 218, 5, 0, 0, 0, 0, 0, 14, 108, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 111, 0, 0, 0, 231, 5, 0, 0, 0, 0, 0, 8, 112, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 228, 0,
-        0, 0, 238, 5, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 243, 5, 0, 0, 115, 0, 0, 0, 0, 4, 0, 0, 249,
-        5, 0, 0, 116, 0, 0, 0, 0, 5, 0, 0, 254, 5, 0, 0, 16, 0, 0, 0, 0, 7, 0, 0, 3, 6, 0, 0, 0, 0,
-        0, 1, 1, 0, 0, 0, 8, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 113, 0, 0, 0, 4, 0, 0, 0,
-        128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 113, 0, 0, 0, 4, 0, 0, 0, 32, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 113, 0, 0, 0, 4, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 4, 0,
-        0, 4, 32, 0, 0, 0, 156, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 49, 0, 0, 0, 64, 0,
-        0, 0, 173, 0, 0, 0, 110, 0, 0, 0, 128, 0, 0, 0, 177, 0, 0, 0, 102, 0, 0, 0, 192, 0, 0, 0,
-        8, 6, 0, 0, 0, 0, 0, 14, 117, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 120, 0, 0, 0,
-        16, 6, 0, 0, 3, 0, 0, 4, 184, 0, 0, 0, 36, 6, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 41, 6, 0, 0,
-        14, 0, 0, 0, 64, 5, 0, 0, 55, 6, 0, 0, 14, 0, 0, 0, 128, 5, 0, 0, 60, 6, 0, 0, 0, 0, 0, 8,
-        122, 0, 0, 0, 79, 6, 0, 0, 21, 0, 0, 4, 168, 0, 0, 0, 87, 6, 0, 0, 123, 0, 0, 0, 0, 0, 0,
-        0, 91, 6, 0, 0, 123, 0, 0, 0, 64, 0, 0, 0, 95, 6, 0, 0, 123, 0, 0, 0, 128, 0, 0, 0, 99, 6,
-        0, 0, 123, 0, 0, 0, 192, 0, 0, 0, 103, 6, 0, 0, 123, 0, 0, 0, 0, 1, 0, 0, 106, 6, 0, 0,
-        123, 0, 0, 0, 64, 1, 0, 0, 109, 6, 0, 0, 123, 0, 0, 0, 128, 1, 0, 0, 113, 6, 0, 0, 123, 0,
-        0, 0, 192, 1, 0, 0, 117, 6, 0, 0, 123, 0, 0, 0, 0, 2, 0, 0, 120, 6, 0, 0, 123, 0, 0, 0, 64,
-        2, 0, 0, 123, 6, 0, 0, 123, 0, 0, 0, 128, 2, 0, 0, 126, 6, 0, 0, 123, 0, 0, 0, 192, 2, 0,
-        0, 129, 6, 0, 0, 123, 0, 0, 0, 0, 3, 0, 0, 132, 6, 0, 0, 123, 0, 0, 0, 64, 3, 0, 0, 135, 6,
-        0, 0, 123, 0, 0, 0, 128, 3, 0, 0, 138, 6, 0, 0, 123, 0, 0, 0, 192, 3, 0, 0, 146, 6, 0, 0,
-        123, 0, 0, 0, 0, 4, 0, 0, 149, 6, 0, 0, 123, 0, 0, 0, 64, 4, 0, 0, 152, 6, 0, 0, 123, 0, 0,
-        0, 128, 4, 0, 0, 158, 6, 0, 0, 123, 0, 0, 0, 192, 4, 0, 0, 161, 6, 0, 0, 123, 0, 0, 0, 0,
-        5, 0, 0, 164, 6, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 2, 0,
-        0, 0, 178, 6, 0, 0, 119, 0, 0, 0, 182, 6, 0, 0, 1, 0, 0, 12, 124, 0, 0, 0, 191, 6, 0, 0, 0,
-        1, 0, 132, 128, 53, 0, 0, 203, 6, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 215, 6, 0, 0, 18, 0, 0,
-        0, 192, 0, 0, 0, 106, 1, 0, 0, 27, 0, 0, 0, 0, 1, 0, 0, 223, 6, 0, 0, 128, 0, 0, 0, 64, 1,
-        0, 0, 152, 6, 0, 0, 18, 0, 0, 0, 96, 1, 0, 0, 229, 6, 0, 0, 18, 0, 0, 0, 128, 1, 0, 0, 236,
-        6, 0, 0, 2, 0, 0, 0, 160, 1, 0, 0, 243, 6, 0, 0, 132, 0, 0, 0, 192, 1, 0, 0, 254, 6, 0, 0,
-        18, 0, 0, 0, 64, 2, 0, 0, 10, 7, 0, 0, 123, 0, 0, 0, 128, 2, 0, 0, 30, 7, 0, 0, 139, 0, 0,
-        0, 192, 2, 0, 0, 41, 7, 0, 0, 2, 0, 0, 0, 0, 3, 0, 0, 57, 7, 0, 0, 2, 0, 0, 0, 32, 3, 0, 0,
-        66, 7, 0, 0, 2, 0, 0, 0, 64, 3, 0, 0, 72, 7, 0, 0, 2, 0, 0, 0, 96, 3, 0, 0, 77, 7, 0, 0, 2,
-        0, 0, 0, 128, 3, 0, 0, 89, 7, 0, 0, 2, 0, 0, 0, 160, 3, 0, 0, 101, 7, 0, 0, 18, 0, 0, 0,
-        192, 3, 0, 0, 113, 7, 0, 0, 140, 0, 0, 0, 0, 4, 0, 0, 116, 7, 0, 0, 150, 0, 0, 0, 0, 12, 0,
-        0, 119, 7, 0, 0, 152, 0, 0, 0, 128, 13, 0, 0, 122, 7, 0, 0, 162, 0, 0, 0, 128, 20, 0, 0,
-        134, 7, 0, 0, 142, 0, 0, 0, 192, 20, 0, 0, 144, 7, 0, 0, 123, 0, 0, 0, 128, 21, 0, 0, 156,
-        7, 0, 0, 18, 0, 0, 0, 192, 21, 0, 0, 172, 7, 0, 0, 164, 0, 0, 0, 0, 22, 0, 0, 189, 7, 0, 0,
-        165, 0, 0, 0, 0, 24, 0, 0, 195, 7, 0, 0, 166, 0, 0, 0, 0, 32, 0, 0, 213, 7, 0, 0, 18, 0, 0,
-        0, 64, 32, 0, 0, 224, 7, 0, 0, 18, 0, 0, 0, 96, 32, 0, 0, 231, 7, 0, 0, 2, 0, 0, 0, 128,
-        32, 0, 0, 247, 7, 0, 0, 168, 0, 0, 0, 192, 32, 0, 0, 0, 8, 0, 0, 171, 0, 0, 0, 0, 33, 0, 0,
-        14, 8, 0, 0, 170, 0, 0, 0, 64, 33, 0, 0, 24, 8, 0, 0, 27, 0, 0, 0, 64, 65, 0, 0, 42, 8, 0,
-        0, 138, 0, 0, 0, 128, 65, 0, 0, 61, 8, 0, 0, 138, 0, 0, 0, 144, 65, 0, 0, 77, 8, 0, 0, 2,
-        0, 0, 0, 160, 65, 0, 0, 99, 8, 0, 0, 174, 0, 0, 0, 192, 65, 0, 0, 123, 8, 0, 0, 144, 0, 0,
-        0, 0, 66, 0, 0, 138, 8, 0, 0, 176, 0, 0, 0, 128, 66, 0, 0, 155, 8, 0, 0, 123, 0, 0, 0, 192,
-        66, 0, 0, 171, 8, 0, 0, 31, 0, 0, 0, 0, 67, 0, 0, 189, 8, 0, 0, 31, 0, 0, 0, 8, 67, 0, 0,
-        203, 8, 0, 0, 2, 0, 0, 0, 32, 67, 0, 0, 222, 8, 0, 0, 144, 0, 0, 0, 64, 67, 0, 0, 245, 8,
-        0, 0, 2, 0, 0, 0, 192, 67, 0, 0, 8, 9, 0, 0, 2, 0, 0, 0, 224, 67, 0, 0, 23, 9, 0, 0, 174,
-        0, 0, 0, 0, 68, 0, 0, 42, 9, 0, 0, 144, 0, 0, 0, 64, 68, 0, 0, 59, 9, 0, 0, 144, 0, 0, 0,
-        192, 68, 0, 0, 73, 9, 0, 0, 2, 0, 0, 0, 64, 69, 0, 0, 86, 9, 0, 0, 177, 0, 0, 0, 128, 69,
-        0, 0, 97, 9, 0, 0, 144, 0, 0, 0, 128, 70, 0, 0, 103, 9, 0, 0, 178, 0, 0, 0, 0, 71, 0, 0,
-        118, 9, 0, 0, 142, 0, 0, 0, 64, 72, 0, 0, 136, 9, 0, 0, 179, 0, 0, 0, 0, 73, 0, 0, 139, 9,
-        0, 0, 179, 0, 0, 0, 64, 73, 0, 0, 149, 9, 0, 0, 180, 0, 0, 0, 128, 73, 0, 0, 158, 9, 0, 0,
-        200, 0, 0, 0, 192, 74, 0, 0, 167, 9, 0, 0, 2, 0, 0, 0, 96, 75, 0, 0, 178, 9, 0, 0, 2, 0, 0,
-        0, 128, 75, 0, 0, 188, 9, 0, 0, 2, 0, 0, 0, 160, 75, 0, 0, 200, 9, 0, 0, 2, 0, 0, 0, 192,
-        75, 0, 0, 214, 9, 0, 0, 123, 0, 0, 0, 0, 76, 0, 0, 221, 9, 0, 0, 18, 0, 0, 0, 64, 76, 0, 0,
-        233, 9, 0, 0, 18, 0, 0, 0, 96, 76, 0, 1, 253, 9, 0, 0, 18, 0, 0, 0, 97, 76, 0, 1, 23, 10,
-        0, 0, 18, 0, 0, 0, 98, 76, 0, 1, 38, 10, 0, 0, 18, 0, 0, 0, 99, 76, 0, 1, 61, 10, 0, 0, 18,
-        0, 0, 0, 128, 76, 0, 1, 81, 10, 0, 0, 18, 0, 0, 0, 129, 76, 0, 1, 91, 10, 0, 0, 18, 0, 0,
-        0, 130, 76, 0, 1, 101, 10, 0, 0, 18, 0, 0, 0, 131, 76, 0, 1, 117, 10, 0, 0, 18, 0, 0, 0,
-        132, 76, 0, 1, 131, 10, 0, 0, 18, 0, 0, 0, 133, 76, 0, 1, 151, 10, 0, 0, 18, 0, 0, 0, 134,
-        76, 0, 1, 158, 10, 0, 0, 18, 0, 0, 0, 135, 76, 0, 1, 171, 10, 0, 0, 18, 0, 0, 0, 136, 76,
-        0, 1, 183, 10, 0, 0, 18, 0, 0, 0, 137, 76, 0, 1, 197, 10, 0, 0, 18, 0, 0, 0, 138, 76, 0, 1,
-        208, 10, 0, 0, 18, 0, 0, 0, 139, 76, 0, 1, 224, 10, 0, 0, 18, 0, 0, 0, 140, 76, 0, 1, 244,
-        10, 0, 0, 123, 0, 0, 0, 192, 76, 0, 0, 1, 11, 0, 0, 201, 0, 0, 0, 0, 77, 0, 0, 46, 1, 0, 0,
-        8, 0, 0, 0, 192, 78, 0, 0, 15, 11, 0, 0, 8, 0, 0, 0, 224, 78, 0, 0, 20, 11, 0, 0, 123, 0,
-        0, 0, 0, 79, 0, 0, 33, 11, 0, 0, 139, 0, 0, 0, 64, 79, 0, 0, 45, 11, 0, 0, 139, 0, 0, 0,
-        128, 79, 0, 0, 52, 11, 0, 0, 144, 0, 0, 0, 192, 79, 0, 0, 61, 11, 0, 0, 144, 0, 0, 0, 64,
-        80, 0, 0, 69, 11, 0, 0, 139, 0, 0, 0, 192, 80, 0, 0, 82, 11, 0, 0, 144, 0, 0, 0, 0, 81, 0,
-        0, 90, 11, 0, 0, 144, 0, 0, 0, 128, 81, 0, 0, 103, 11, 0, 0, 217, 0, 0, 0, 0, 82, 0, 0,
-        114, 11, 0, 0, 220, 0, 0, 0, 64, 82, 0, 0, 124, 11, 0, 0, 144, 0, 0, 0, 64, 84, 0, 0, 137,
-        11, 0, 0, 144, 0, 0, 0, 192, 84, 0, 0, 149, 11, 0, 0, 221, 0, 0, 0, 64, 85, 0, 0, 160, 11,
-        0, 0, 102, 0, 0, 0, 128, 85, 0, 0, 174, 11, 0, 0, 102, 0, 0, 0, 192, 85, 0, 0, 190, 11, 0,
-        0, 27, 0, 0, 0, 0, 86, 0, 0, 205, 11, 0, 0, 13, 0, 0, 0, 64, 86, 0, 0, 211, 11, 0, 0, 13,
-        0, 0, 0, 128, 86, 0, 0, 217, 11, 0, 0, 13, 0, 0, 0, 192, 86, 0, 0, 223, 11, 0, 0, 222, 0,
-        0, 0, 0, 87, 0, 0, 236, 11, 0, 0, 230, 0, 0, 0, 192, 87, 0, 0, 242, 11, 0, 0, 130, 0, 0, 0,
-        64, 89, 0, 0, 0, 12, 0, 0, 123, 0, 0, 0, 128, 89, 0, 0, 6, 12, 0, 0, 123, 0, 0, 0, 192, 89,
-        0, 0, 13, 12, 0, 0, 13, 0, 0, 0, 0, 90, 0, 0, 24, 12, 0, 0, 13, 0, 0, 0, 64, 90, 0, 0, 39,
-        12, 0, 0, 123, 0, 0, 0, 128, 90, 0, 0, 47, 12, 0, 0, 123, 0, 0, 0, 192, 90, 0, 0, 55, 12,
-        0, 0, 234, 0, 0, 0, 0, 91, 0, 0, 71, 12, 0, 0, 240, 0, 0, 0, 128, 93, 0, 0, 92, 12, 0, 0,
-        245, 0, 0, 0, 64, 94, 0, 0, 105, 12, 0, 0, 245, 0, 0, 0, 128, 94, 0, 0, 115, 12, 0, 0, 245,
-        0, 0, 0, 192, 94, 0, 0, 120, 12, 0, 0, 247, 0, 0, 0, 0, 95, 0, 0, 54, 1, 0, 0, 248, 0, 0,
-        0, 64, 95, 0, 0, 141, 12, 0, 0, 249, 0, 0, 0, 192, 95, 0, 0, 151, 12, 0, 0, 250, 0, 0, 0,
-        0, 96, 0, 0, 159, 12, 0, 0, 252, 0, 0, 0, 64, 96, 0, 0, 167, 12, 0, 0, 253, 0, 0, 0, 192,
-        96, 0, 0, 170, 12, 0, 0, 254, 0, 0, 0, 0, 97, 0, 0, 176, 12, 0, 0, 255, 0, 0, 0, 64, 97, 0,
-        0, 185, 12, 0, 0, 0, 1, 0, 0, 128, 97, 0, 0, 193, 12, 0, 0, 1, 1, 0, 0, 192, 97, 0, 0, 200,
-        12, 0, 0, 2, 1, 0, 0, 0, 98, 0, 0, 208, 12, 0, 0, 3, 1, 0, 0, 64, 98, 0, 0, 216, 12, 0, 0,
-        3, 1, 0, 0, 128, 98, 0, 0, 229, 12, 0, 0, 3, 1, 0, 0, 192, 98, 0, 0, 243, 12, 0, 0, 6, 1,
-        0, 0, 0, 99, 0, 0, 251, 12, 0, 0, 123, 0, 0, 0, 192, 99, 0, 0, 5, 13, 0, 0, 7, 1, 0, 0, 0,
-        100, 0, 0, 17, 13, 0, 0, 18, 0, 0, 0, 64, 100, 0, 0, 30, 13, 0, 0, 242, 0, 0, 0, 128, 100,
-        0, 0, 41, 13, 0, 0, 10, 1, 0, 0, 192, 100, 0, 0, 55, 13, 0, 0, 11, 1, 0, 0, 0, 101, 0, 0,
-        64, 13, 0, 0, 18, 0, 0, 0, 32, 101, 0, 0, 74, 13, 0, 0, 15, 1, 0, 0, 64, 101, 0, 0, 82, 13,
-        0, 0, 17, 1, 0, 0, 192, 101, 0, 0, 99, 13, 0, 0, 13, 0, 0, 0, 192, 102, 0, 0, 114, 13, 0,
-        0, 13, 0, 0, 0, 0, 103, 0, 0, 127, 13, 0, 0, 21, 1, 0, 0, 64, 103, 0, 0, 138, 13, 0, 0,
-        223, 0, 0, 0, 96, 103, 0, 0, 146, 13, 0, 0, 24, 1, 0, 0, 128, 103, 0, 0, 153, 13, 0, 0,
-        237, 0, 0, 0, 192, 103, 0, 0, 164, 13, 0, 0, 139, 0, 0, 0, 64, 104, 0, 0, 176, 13, 0, 0,
-        26, 1, 0, 0, 128, 104, 0, 0, 190, 13, 0, 0, 27, 0, 0, 0, 192, 104, 0, 0, 203, 13, 0, 0, 27,
-        1, 0, 0, 0, 105, 0, 0, 212, 13, 0, 0, 28, 1, 0, 0, 64, 105, 0, 0, 217, 13, 0, 0, 29, 1, 0,
-        0, 128, 105, 0, 0, 231, 13, 0, 0, 30, 1, 0, 0, 192, 105, 0, 0, 248, 13, 0, 0, 31, 1, 0, 0,
-        0, 106, 0, 0, 3, 14, 0, 0, 32, 1, 0, 0, 64, 106, 0, 0, 19, 14, 0, 0, 123, 0, 0, 0, 128,
-        106, 0, 0, 34, 14, 0, 0, 33, 1, 0, 0, 192, 106, 0, 0, 47, 14, 0, 0, 35, 1, 0, 0, 0, 107, 0,
-        0, 52, 14, 0, 0, 18, 0, 0, 0, 192, 108, 0, 0, 62, 14, 0, 0, 13, 0, 0, 0, 0, 109, 0, 0, 76,
-        14, 0, 0, 13, 0, 0, 0, 64, 109, 0, 0, 89, 14, 0, 0, 13, 0, 0, 0, 128, 109, 0, 0, 102, 14,
-        0, 0, 36, 1, 0, 0, 192, 109, 0, 0, 115, 14, 0, 0, 39, 1, 0, 0, 192, 113, 0, 0, 132, 14, 0,
-        0, 2, 0, 0, 0, 224, 113, 0, 0, 156, 14, 0, 0, 2, 0, 0, 0, 0, 114, 0, 0, 181, 14, 0, 0, 41,
-        1, 0, 0, 64, 114, 0, 0, 189, 14, 0, 0, 144, 0, 0, 0, 128, 114, 0, 0, 197, 14, 0, 0, 16, 0,
-        0, 0, 0, 115, 0, 0, 204, 14, 0, 0, 16, 0, 0, 0, 32, 115, 0, 0, 209, 14, 0, 0, 42, 1, 0, 0,
-        64, 115, 0, 0, 221, 14, 0, 0, 43, 1, 0, 0, 128, 115, 0, 0, 240, 14, 0, 0, 144, 0, 0, 0,
-        192, 115, 0, 0, 254, 14, 0, 0, 44, 1, 0, 0, 64, 116, 0, 0, 13, 15, 0, 0, 45, 1, 0, 0, 128,
-        116, 0, 0, 30, 15, 0, 0, 18, 0, 0, 0, 128, 117, 0, 0, 42, 15, 0, 0, 53, 1, 0, 0, 192, 117,
-        0, 0, 58, 15, 0, 0, 45, 1, 0, 0, 64, 118, 0, 0, 75, 15, 0, 0, 144, 0, 0, 0, 64, 119, 0, 0,
-        91, 15, 0, 0, 123, 0, 0, 0, 192, 119, 0, 0, 110, 15, 0, 0, 196, 0, 0, 0, 0, 120, 0, 0, 120,
-        15, 0, 0, 43, 0, 0, 0, 64, 120, 0, 0, 128, 15, 0, 0, 43, 0, 0, 0, 80, 120, 0, 0, 143, 15,
-        0, 0, 2, 0, 0, 0, 96, 120, 0, 0, 157, 15, 0, 0, 18, 0, 0, 0, 128, 120, 0, 0, 174, 15, 0, 0,
-        18, 0, 0, 0, 160, 120, 0, 0, 195, 15, 0, 0, 2, 0, 0, 0, 192, 120, 0, 0, 214, 15, 0, 0, 123,
-        0, 0, 0, 0, 121, 0, 0, 233, 15, 0, 0, 13, 0, 0, 0, 64, 121, 0, 0, 244, 15, 0, 0, 13, 0, 0,
-        0, 128, 121, 0, 0, 13, 16, 0, 0, 13, 0, 0, 0, 192, 121, 0, 0, 35, 16, 0, 0, 241, 0, 0, 0,
-        0, 122, 0, 0, 45, 16, 0, 0, 54, 1, 0, 0, 128, 122, 0, 0, 56, 16, 0, 0, 55, 1, 0, 0, 192,
-        122, 0, 0, 68, 16, 0, 0, 123, 0, 0, 0, 0, 123, 0, 0, 86, 16, 0, 0, 56, 1, 0, 0, 64, 123, 0,
-        0, 107, 16, 0, 0, 123, 0, 0, 0, 0, 124, 0, 0, 127, 16, 0, 0, 57, 1, 0, 0, 64, 124, 0, 0,
-        132, 16, 0, 0, 16, 0, 0, 0, 128, 124, 0, 0, 141, 16, 0, 0, 123, 0, 0, 0, 192, 124, 0, 0,
-        157, 16, 0, 0, 58, 1, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 60, 1, 0, 0, 64, 157, 0, 0, 165, 16,
-        0, 0, 61, 1, 0, 0, 192, 157, 0, 0, 177, 16, 0, 0, 62, 1, 0, 0, 0, 158, 0, 0, 187, 16, 0, 0,
-        64, 1, 0, 0, 128, 158, 0, 0, 194, 16, 0, 0, 2, 0, 0, 0, 192, 158, 0, 0, 205, 16, 0, 0, 2,
-        0, 0, 0, 224, 158, 0, 0, 222, 16, 0, 0, 123, 0, 0, 0, 0, 159, 0, 0, 240, 16, 0, 0, 2, 0, 0,
-        0, 64, 159, 0, 0, 5, 17, 0, 0, 67, 1, 0, 0, 128, 159, 0, 0, 20, 17, 0, 0, 13, 0, 0, 0, 128,
-        23, 1, 0, 35, 17, 0, 0, 13, 0, 0, 0, 192, 23, 1, 0, 58, 17, 0, 0, 68, 1, 0, 0, 0, 24, 1, 0,
-        69, 17, 0, 0, 2, 0, 0, 0, 64, 24, 1, 0, 84, 17, 0, 0, 2, 0, 0, 0, 96, 24, 1, 0, 99, 17, 0,
-        0, 69, 1, 0, 0, 128, 24, 1, 0, 109, 17, 0, 0, 15, 0, 0, 0, 192, 24, 1, 0, 126, 17, 0, 0,
-        130, 0, 0, 0, 0, 25, 1, 0, 140, 17, 0, 0, 130, 0, 0, 0, 32, 25, 1, 0, 160, 17, 0, 0, 123,
-        0, 0, 0, 64, 25, 1, 0, 166, 17, 0, 0, 123, 0, 0, 0, 128, 25, 1, 0, 182, 17, 0, 0, 70, 1, 0,
-        0, 192, 25, 1, 0, 195, 17, 0, 0, 71, 1, 0, 0, 0, 26, 1, 0, 214, 17, 0, 0, 2, 0, 0, 0, 32,
-        26, 1, 0, 230, 17, 0, 0, 18, 0, 0, 0, 64, 26, 1, 0, 255, 17, 0, 0, 70, 1, 0, 0, 128, 26, 1,
-        0, 12, 18, 0, 0, 72, 1, 0, 0, 192, 26, 1, 0, 27, 18, 0, 0, 73, 1, 0, 0, 0, 27, 1, 0, 33,
-        18, 0, 0, 18, 0, 0, 0, 64, 27, 1, 0, 47, 18, 0, 0, 18, 0, 0, 0, 96, 27, 1, 0, 65, 18, 0, 0,
-        74, 1, 0, 0, 128, 27, 1, 0, 75, 18, 0, 0, 2, 0, 0, 0, 128, 27, 1, 0, 94, 18, 0, 0, 139, 0,
-        0, 0, 192, 27, 1, 0, 110, 18, 0, 0, 75, 1, 0, 0, 0, 28, 1, 0, 127, 18, 0, 0, 79, 1, 0, 0,
-        64, 29, 1, 0, 141, 18, 0, 0, 128, 0, 0, 0, 128, 29, 1, 0, 156, 18, 0, 0, 2, 0, 0, 0, 160,
-        29, 1, 0, 168, 18, 0, 0, 27, 0, 0, 0, 192, 29, 1, 0, 177, 18, 0, 0, 80, 1, 0, 0, 0, 30, 1,
-        0, 189, 18, 0, 0, 81, 1, 0, 0, 64, 30, 1, 0, 197, 18, 0, 0, 27, 0, 0, 0, 128, 30, 1, 0,
-        207, 18, 0, 0, 14, 0, 0, 0, 192, 30, 1, 0, 218, 18, 0, 0, 13, 0, 0, 0, 0, 31, 1, 0, 227,
-        18, 0, 0, 14, 0, 0, 0, 64, 31, 1, 1, 236, 18, 0, 0, 14, 0, 0, 0, 65, 31, 1, 1, 251, 18, 0,
-        0, 14, 0, 0, 0, 66, 31, 1, 62, 10, 19, 0, 0, 241, 0, 0, 0, 128, 31, 1, 0, 22, 19, 0, 0, 2,
-        0, 0, 0, 0, 32, 1, 0, 32, 19, 0, 0, 82, 1, 0, 0, 64, 32, 1, 0, 52, 19, 0, 0, 82, 1, 0, 0,
-        128, 32, 1, 0, 61, 19, 0, 0, 241, 0, 0, 0, 192, 32, 1, 0, 76, 19, 0, 0, 83, 1, 0, 0, 0, 34,
-        1, 0, 203, 6, 0, 0, 4, 0, 0, 4, 24, 0, 0, 0, 152, 6, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 83,
-        19, 0, 0, 123, 0, 0, 0, 64, 0, 0, 0, 96, 19, 0, 0, 16, 0, 0, 0, 128, 0, 0, 0, 42, 1, 0, 0,
-        16, 0, 0, 0, 160, 0, 0, 0, 103, 19, 0, 0, 0, 0, 0, 8, 129, 0, 0, 0, 114, 19, 0, 0, 1, 0, 0,
-        4, 4, 0, 0, 0, 130, 19, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 135, 19, 0, 0, 0, 0, 0, 8, 131, 0,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 144, 19, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 152, 19,
-        0, 0, 4, 0, 0, 4, 16, 0, 0, 0, 171, 19, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0,
-        0, 0, 64, 0, 0, 0, 177, 19, 0, 0, 136, 0, 0, 0, 96, 0, 0, 0, 181, 19, 0, 0, 136, 0, 0, 0,
-        112, 0, 0, 0, 185, 19, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 139, 4, 0, 0, 134, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 2, 133, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 4, 0, 0, 0, 196, 19, 0, 0,
-        18, 0, 0, 0, 0, 0, 0, 0, 204, 19, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 212, 19, 0, 0, 0, 0, 0,
-        8, 137, 0, 0, 0, 216, 19, 0, 0, 0, 0, 0, 8, 138, 0, 0, 0, 222, 19, 0, 0, 0, 0, 0, 1, 2, 0,
-        0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 126, 0, 0, 0, 237, 19, 0, 0, 15, 0, 0, 4, 0, 1,
-        0, 0, 250, 19, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 255, 19, 0, 0, 142, 0, 0, 0, 128, 0, 0, 0,
-        8, 20, 0, 0, 144, 0, 0, 0, 64, 1, 0, 0, 66, 7, 0, 0, 18, 0, 0, 0, 192, 1, 0, 0, 19, 20, 0,
-        0, 13, 0, 0, 0, 0, 2, 0, 0, 30, 20, 0, 0, 13, 0, 0, 0, 64, 2, 0, 0, 47, 20, 0, 0, 13, 0, 0,
-        0, 128, 2, 0, 0, 56, 20, 0, 0, 13, 0, 0, 0, 192, 2, 0, 0, 78, 20, 0, 0, 13, 0, 0, 0, 0, 3,
-        0, 0, 92, 20, 0, 0, 2, 0, 0, 0, 64, 3, 0, 0, 45, 11, 0, 0, 146, 0, 0, 0, 128, 3, 0, 0, 98,
-        20, 0, 0, 147, 0, 0, 0, 192, 3, 0, 0, 105, 20, 0, 0, 147, 0, 0, 0, 0, 4, 0, 0, 110, 20, 0,
-        0, 123, 0, 0, 0, 64, 4, 0, 0, 126, 20, 0, 0, 148, 0, 0, 0, 0, 6, 0, 0, 130, 20, 0, 0, 2, 0,
-        0, 4, 16, 0, 0, 0, 142, 20, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 149, 20, 0, 0, 16, 0, 0, 0, 64,
-        0, 0, 0, 160, 20, 0, 0, 3, 0, 0, 4, 24, 0, 0, 0, 168, 20, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0,
-        186, 20, 0, 0, 143, 0, 0, 0, 64, 0, 0, 0, 195, 20, 0, 0, 143, 0, 0, 0, 128, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 2, 142, 0, 0, 0, 203, 20, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 139, 4, 0, 0, 145,
-        0, 0, 0, 0, 0, 0, 0, 213, 20, 0, 0, 145, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 144,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 18, 2, 0, 0, 218,
-        20, 0, 0, 9, 0, 0, 4, 64, 0, 0, 0, 228, 20, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 245, 20, 0, 0,
-        13, 0, 0, 0, 64, 0, 0, 0, 254, 20, 0, 0, 13, 0, 0, 0, 128, 0, 0, 0, 11, 21, 0, 0, 16, 0, 0,
-        0, 192, 0, 0, 0, 20, 21, 0, 0, 16, 0, 0, 0, 224, 0, 0, 0, 35, 21, 0, 0, 123, 0, 0, 0, 0, 1,
-        0, 0, 44, 21, 0, 0, 123, 0, 0, 0, 64, 1, 0, 0, 57, 21, 0, 0, 123, 0, 0, 0, 128, 1, 0, 0,
-        66, 21, 0, 0, 149, 0, 0, 0, 192, 1, 0, 0, 66, 21, 0, 0, 2, 0, 0, 4, 8, 0, 0, 0, 75, 21, 0,
-        0, 18, 0, 0, 0, 0, 0, 0, 0, 84, 21, 0, 0, 18, 0, 0, 0, 32, 0, 0, 0, 89, 21, 0, 0, 7, 0, 0,
-        4, 48, 0, 0, 0, 105, 21, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 114, 21, 0, 0, 123, 0, 0, 0, 128,
-        0, 0, 0, 122, 21, 0, 0, 123, 0, 0, 0, 192, 0, 0, 0, 137, 21, 0, 0, 18, 0, 0, 0, 0, 1, 0, 0,
-        66, 7, 0, 0, 138, 0, 0, 0, 32, 1, 0, 0, 148, 21, 0, 0, 138, 0, 0, 0, 48, 1, 0, 0, 156, 21,
-        0, 0, 151, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 150, 0, 0, 0, 161, 21, 0, 0, 16,
-        0, 0, 132, 224, 0, 0, 0, 160, 20, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 177, 21, 0, 0, 13, 0, 0,
-        0, 192, 0, 0, 0, 188, 21, 0, 0, 13, 0, 0, 0, 0, 1, 0, 0, 200, 21, 0, 0, 13, 0, 0, 0, 64, 1,
-        0, 0, 210, 21, 0, 0, 13, 0, 0, 0, 128, 1, 0, 0, 216, 21, 0, 0, 13, 0, 0, 0, 192, 1, 0, 0,
-        227, 21, 0, 0, 69, 0, 0, 0, 0, 2, 0, 0, 235, 21, 0, 0, 13, 0, 0, 0, 64, 2, 0, 0, 152, 6, 0,
-        0, 18, 0, 0, 0, 128, 2, 0, 0, 244, 21, 0, 0, 18, 0, 0, 0, 160, 2, 0, 1, 1, 22, 0, 0, 18, 0,
-        0, 0, 161, 2, 0, 1, 12, 22, 0, 0, 18, 0, 0, 0, 162, 2, 0, 1, 30, 22, 0, 0, 18, 0, 0, 0,
-        163, 2, 0, 1, 41, 22, 0, 0, 153, 0, 0, 0, 192, 2, 0, 0, 50, 22, 0, 0, 153, 0, 0, 0, 192, 4,
-        0, 0, 65, 22, 0, 0, 161, 0, 0, 0, 192, 6, 0, 0, 71, 22, 0, 0, 8, 0, 0, 4, 64, 0, 0, 0, 79,
-        22, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 84, 22, 0, 0, 155, 0, 0, 0, 0, 1, 0, 0, 97, 22, 0, 0,
-        156, 0, 0, 0, 64, 1, 0, 0, 106, 22, 0, 0, 160, 0, 0, 0, 128, 1, 0, 0, 111, 22, 0, 0, 31, 0,
-        0, 0, 192, 1, 0, 0, 117, 22, 0, 0, 31, 0, 0, 0, 200, 1, 0, 0, 124, 22, 0, 0, 31, 0, 0, 0,
-        208, 1, 0, 0, 132, 22, 0, 0, 31, 0, 0, 0, 216, 1, 0, 0, 140, 22, 0, 0, 2, 0, 0, 4, 32, 0,
-        0, 0, 79, 22, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 156, 22, 0, 0, 155, 0, 0, 0, 192, 0, 0, 0,
-        164, 22, 0, 0, 0, 0, 0, 8, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 157, 0, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 13, 158, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 172, 22, 0, 0, 2, 0, 0, 6, 4, 0, 0, 0,
-        188, 22, 0, 0, 0, 0, 0, 0, 206, 22, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 153, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 31, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 152, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 64, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 70, 2,
-        0, 0, 222, 22, 0, 0, 29, 0, 0, 4, 0, 1, 0, 0, 239, 22, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 250,
-        22, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 3, 23, 0, 0, 13, 0, 0, 0, 128, 0, 0, 0, 14, 23, 0, 0,
-        13, 0, 0, 0, 192, 0, 0, 0, 23, 23, 0, 0, 13, 0, 0, 0, 0, 1, 0, 0, 36, 23, 0, 0, 13, 0, 0,
-        0, 64, 1, 0, 0, 47, 23, 0, 0, 13, 0, 0, 0, 128, 1, 0, 0, 59, 23, 0, 0, 13, 0, 0, 0, 192, 1,
-        0, 0, 69, 23, 0, 0, 69, 0, 0, 0, 0, 2, 0, 0, 87, 23, 0, 0, 13, 0, 0, 0, 64, 2, 0, 0, 99,
-        23, 0, 0, 13, 0, 0, 0, 128, 2, 0, 0, 109, 23, 0, 0, 69, 0, 0, 0, 192, 2, 0, 0, 127, 23, 0,
-        0, 13, 0, 0, 0, 0, 3, 0, 0, 136, 23, 0, 0, 13, 0, 0, 0, 64, 3, 0, 0, 146, 23, 0, 0, 13, 0,
-        0, 0, 128, 3, 0, 0, 165, 23, 0, 0, 13, 0, 0, 0, 192, 3, 0, 0, 193, 23, 0, 0, 13, 0, 0, 0,
-        0, 4, 0, 0, 222, 23, 0, 0, 13, 0, 0, 0, 64, 4, 0, 0, 247, 23, 0, 0, 13, 0, 0, 0, 128, 4, 0,
-        0, 12, 24, 0, 0, 13, 0, 0, 0, 192, 4, 0, 0, 23, 24, 0, 0, 13, 0, 0, 0, 0, 5, 0, 0, 39, 24,
-        0, 0, 13, 0, 0, 0, 64, 5, 0, 0, 58, 24, 0, 0, 13, 0, 0, 0, 128, 5, 0, 0, 75, 24, 0, 0, 13,
-        0, 0, 0, 192, 5, 0, 0, 93, 24, 0, 0, 13, 0, 0, 0, 0, 6, 0, 0, 111, 24, 0, 0, 13, 0, 0, 0,
-        64, 6, 0, 0, 138, 24, 0, 0, 13, 0, 0, 0, 128, 6, 0, 0, 157, 24, 0, 0, 13, 0, 0, 0, 192, 6,
-        0, 0, 173, 24, 0, 0, 13, 0, 0, 0, 0, 7, 0, 0, 192, 24, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 203,
-        24, 0, 0, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 218, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 170, 0, 0, 0, 209, 24, 0, 0, 0, 0, 0, 8, 172,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 170, 0, 0, 0, 219, 24, 0, 0, 1, 0, 0, 4, 0, 4, 0, 0, 227,
-        24, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 123, 0, 0, 0, 4, 0,
-        0, 0, 128, 0, 0, 0, 232, 24, 0, 0, 2, 0, 0, 5, 4, 0, 0, 0, 244, 24, 0, 0, 175, 0, 0, 0, 0,
-        0, 0, 0, 246, 24, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 4, 0, 0, 0, 208,
-        12, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 248, 24, 0, 0, 31, 0, 0, 0, 8, 0, 0, 0, 0, 25, 0, 0, 31,
-        0, 0, 0, 16, 0, 0, 0, 9, 25, 0, 0, 31, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 58, 2,
-        0, 0, 86, 9, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 17, 25, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 24, 25,
-        0, 0, 15, 0, 0, 0, 64, 0, 0, 0, 34, 25, 0, 0, 15, 0, 0, 0, 128, 0, 0, 0, 47, 25, 0, 0, 15,
-        0, 0, 0, 192, 0, 0, 0, 59, 25, 0, 0, 3, 0, 0, 4, 40, 0, 0, 0, 72, 7, 0, 0, 2, 0, 0, 0, 0,
-        0, 0, 0, 70, 25, 0, 0, 144, 0, 0, 0, 64, 0, 0, 0, 80, 25, 0, 0, 144, 0, 0, 0, 192, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 41, 2, 0, 0, 149, 9, 0, 0, 2, 0, 0, 4, 40, 0, 0, 0, 90, 25, 0, 0,
-        13, 0, 0, 0, 0, 0, 0, 0, 97, 25, 0, 0, 199, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        182, 0, 0, 0, 102, 25, 0, 0, 19, 0, 0, 4, 200, 0, 0, 0, 117, 25, 0, 0, 123, 0, 0, 0, 0, 0,
-        0, 0, 126, 25, 0, 0, 123, 0, 0, 0, 64, 0, 0, 0, 133, 25, 0, 0, 181, 0, 0, 0, 128, 0, 0, 0,
-        141, 25, 0, 0, 181, 0, 0, 0, 192, 0, 0, 0, 149, 25, 0, 0, 142, 0, 0, 0, 0, 1, 0, 0, 155,
-        25, 0, 0, 123, 0, 0, 0, 192, 1, 0, 0, 170, 25, 0, 0, 179, 0, 0, 0, 0, 2, 0, 0, 176, 25, 0,
-        0, 183, 0, 0, 0, 64, 2, 0, 0, 189, 25, 0, 0, 123, 0, 0, 0, 128, 2, 0, 0, 0, 0, 0, 0, 186,
-        0, 0, 0, 192, 2, 0, 0, 198, 25, 0, 0, 144, 0, 0, 0, 192, 3, 0, 0, 213, 25, 0, 0, 189, 0, 0,
-        0, 64, 4, 0, 0, 222, 25, 0, 0, 190, 0, 0, 0, 128, 4, 0, 0, 229, 25, 0, 0, 123, 0, 0, 0,
-        192, 4, 0, 0, 238, 25, 0, 0, 192, 0, 0, 0, 0, 5, 0, 0, 246, 25, 0, 0, 27, 0, 0, 0, 64, 5,
-        0, 0, 6, 26, 0, 0, 193, 0, 0, 0, 128, 5, 0, 0, 26, 26, 0, 0, 196, 0, 0, 0, 192, 5, 0, 0,
-        36, 26, 0, 0, 197, 0, 0, 0, 0, 6, 0, 0, 55, 26, 0, 0, 0, 0, 0, 8, 184, 0, 0, 0, 64, 26, 0,
-        0, 1, 0, 0, 4, 8, 0, 0, 0, 64, 26, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 71, 26, 0, 0, 0, 0, 0,
-        8, 123, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 32, 0, 0, 0, 83, 26, 0, 0, 187, 0, 0, 0, 0, 0, 0,
-        0, 90, 26, 0, 0, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 32, 0, 0, 0, 100, 26, 0,
-        0, 142, 0, 0, 0, 0, 0, 0, 0, 103, 26, 0, 0, 123, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 9, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 8, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 191, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 10, 74, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 25, 2, 0, 0, 119, 26, 0, 0,
-        0, 0, 0, 8, 194, 0, 0, 0, 133, 26, 0, 0, 0, 0, 0, 8, 195, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4,
-        8, 0, 0, 0, 144, 19, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 40, 2, 0, 0,
-        36, 26, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 178, 6, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 73, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 181, 0, 0, 0, 4, 0, 0, 0, 4,
-        0, 0, 0, 144, 26, 0, 0, 2, 0, 0, 4, 20, 0, 0, 0, 202, 5, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 158,
-        26, 0, 0, 97, 0, 0, 0, 32, 0, 0, 0, 1, 11, 0, 0, 3, 0, 0, 4, 56, 0, 0, 0, 164, 26, 0, 0,
-        123, 0, 0, 0, 0, 0, 0, 0, 174, 26, 0, 0, 202, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 206, 0, 0,
-        0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 203, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 204, 0, 0,
-        0, 0, 0, 0, 0, 205, 0, 0, 0, 177, 26, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 64, 0, 0, 1, 0, 0, 0,
-        0, 0, 0, 0, 2, 201, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 5, 40, 0, 0, 0, 182, 26, 0, 0, 207, 0, 0,
-        0, 0, 0, 0, 0, 188, 26, 0, 0, 208, 0, 0, 0, 0, 0, 0, 0, 198, 26, 0, 0, 215, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 6, 0, 0, 4, 40, 0, 0, 0, 203, 26, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 209, 26,
-        0, 0, 16, 0, 0, 0, 64, 0, 0, 0, 152, 6, 0, 0, 16, 0, 0, 0, 96, 0, 0, 0, 213, 26, 0, 0, 16,
-        0, 0, 0, 128, 0, 0, 0, 220, 26, 0, 0, 13, 0, 0, 0, 192, 0, 0, 0, 225, 26, 0, 0, 23, 0, 0,
-        0, 0, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 24, 0, 0, 0, 232, 26, 0, 0, 209, 0, 0, 0, 0, 0, 0,
-        0, 156, 0, 0, 0, 211, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 64, 0, 0, 0, 156, 22,
-        0, 0, 13, 0, 0, 0, 128, 0, 0, 0, 240, 26, 0, 0, 0, 0, 0, 8, 210, 0, 0, 0, 250, 26, 0, 0, 0,
-        0, 0, 8, 2, 0, 0, 0, 13, 27, 0, 0, 3, 0, 0, 6, 4, 0, 0, 0, 27, 27, 0, 0, 0, 0, 0, 0, 35,
-        27, 0, 0, 1, 0, 0, 0, 45, 27, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 8, 0, 0, 0, 55, 27,
-        0, 0, 213, 0, 0, 0, 0, 0, 0, 0, 60, 27, 0, 0, 214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 45, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 4, 32, 0, 0, 0,
-        72, 27, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 77, 27, 0, 0, 2, 0, 0, 0, 64, 0, 0, 0, 82, 27, 0,
-        0, 2, 0, 0, 0, 96, 0, 0, 0, 94, 27, 0, 0, 123, 0, 0, 0, 128, 0, 0, 0, 101, 27, 0, 0, 123,
-        0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 57, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 53, 2,
-        0, 0, 109, 27, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 139, 4, 0, 0, 167, 0, 0, 0, 0, 0, 0, 0, 120,
-        27, 0, 0, 219, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 167, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 0, 0, 218, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 20, 2,
-        0, 0, 223, 11, 0, 0, 3, 0, 0, 4, 24, 0, 0, 0, 205, 11, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 211,
-        11, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 126, 27, 0, 0, 223, 0, 0, 0, 128, 0, 0, 0, 131, 27, 0,
-        0, 0, 0, 0, 8, 224, 0, 0, 0, 146, 27, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 159, 27, 0, 0, 225, 0,
-        0, 0, 0, 0, 0, 0, 168, 27, 0, 0, 0, 0, 0, 8, 226, 0, 0, 0, 184, 27, 0, 0, 1, 0, 0, 4, 4, 0,
-        0, 0, 0, 0, 0, 0, 227, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 5, 4, 0, 0, 0, 209, 26, 0,
-        0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 2, 0, 0, 0, 194, 27, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0,
-        243, 12, 0, 0, 31, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 4, 0, 0, 0, 201, 27, 0, 0,
-        136, 0, 0, 0, 0, 0, 0, 0, 216, 27, 0, 0, 136, 0, 0, 0, 16, 0, 0, 0, 236, 11, 0, 0, 7, 0, 0,
-        4, 48, 0, 0, 0, 221, 27, 0, 0, 231, 0, 0, 0, 0, 0, 0, 0, 230, 27, 0, 0, 15, 0, 0, 0, 64, 0,
-        0, 0, 111, 22, 0, 0, 233, 0, 0, 0, 128, 0, 0, 0, 42, 1, 0, 0, 18, 0, 0, 0, 160, 0, 0, 0,
-        205, 11, 0, 0, 13, 0, 0, 0, 192, 0, 0, 0, 211, 11, 0, 0, 13, 0, 0, 0, 0, 1, 0, 0, 217, 11,
-        0, 0, 13, 0, 0, 0, 64, 1, 0, 0, 240, 27, 0, 0, 0, 0, 0, 8, 232, 0, 0, 0, 221, 27, 0, 0, 1,
-        0, 0, 4, 4, 0, 0, 0, 251, 27, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 4, 28, 0, 0, 5, 0, 0, 6, 4, 0,
-        0, 0, 16, 28, 0, 0, 0, 0, 0, 0, 31, 28, 0, 0, 1, 0, 0, 0, 42, 28, 0, 0, 2, 0, 0, 0, 52, 28,
-        0, 0, 3, 0, 0, 0, 63, 28, 0, 0, 4, 0, 0, 0, 55, 12, 0, 0, 3, 0, 0, 4, 80, 0, 0, 0, 75, 28,
-        0, 0, 239, 0, 0, 0, 0, 0, 0, 0, 81, 28, 0, 0, 18, 0, 0, 0, 64, 2, 0, 0, 95, 28, 0, 0, 18,
-        0, 0, 0, 96, 2, 0, 0, 109, 28, 0, 0, 2, 0, 0, 4, 24, 0, 0, 0, 129, 28, 0, 0, 13, 0, 0, 0,
-        0, 0, 0, 0, 137, 28, 0, 0, 236, 0, 0, 0, 64, 0, 0, 0, 144, 28, 0, 0, 1, 0, 0, 4, 16, 0, 0,
-        0, 160, 28, 0, 0, 237, 0, 0, 0, 0, 0, 0, 0, 168, 28, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 160,
-        28, 0, 0, 238, 0, 0, 0, 0, 0, 0, 0, 183, 28, 0, 0, 143, 0, 0, 0, 64, 0, 0, 0, 160, 28, 0,
-        0, 1, 0, 0, 4, 8, 0, 0, 0, 160, 20, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
-        0, 0, 0, 0, 235, 0, 0, 0, 4, 0, 0, 0, 3, 0, 0, 0, 71, 12, 0, 0, 2, 0, 0, 4, 24, 0, 0, 0,
-        195, 28, 0, 0, 241, 0, 0, 0, 0, 0, 0, 0, 200, 28, 0, 0, 18, 0, 0, 0, 128, 0, 0, 0, 210, 28,
-        0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 139, 4, 0, 0, 242, 0, 0, 0, 0, 0, 0, 0, 249, 5, 0, 0, 243,
-        0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 244, 0,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 242, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        2, 246, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 21, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 36, 2, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 113, 0, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 42, 2, 0, 0, 224, 28, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 233, 28, 0, 0, 251, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 66, 2, 0, 0, 243, 28, 0, 0, 1, 0, 0, 4, 16, 0, 0, 0,
-        252, 28, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 27, 2, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 26, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 34, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 43,
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 68, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 67, 2, 0, 0, 6, 29,
-        0, 0, 0, 0, 0, 8, 4, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 15, 29, 0, 0, 5, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 123, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 19,
-        29, 0, 0, 2, 0, 0, 4, 24, 0, 0, 0, 30, 29, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 193, 12, 0, 0,
-        3, 1, 0, 0, 128, 0, 0, 0, 35, 29, 0, 0, 0, 0, 0, 8, 8, 1, 0, 0, 42, 29, 0, 0, 0, 0, 0, 8,
-        9, 1, 0, 0, 58, 29, 0, 0, 0, 0, 0, 8, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 10, 2, 0, 0,
-        75, 29, 0, 0, 0, 0, 0, 8, 12, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 209, 26, 0, 0,
-        13, 1, 0, 0, 0, 0, 0, 0, 82, 29, 0, 0, 0, 0, 0, 8, 14, 1, 0, 0, 88, 29, 0, 0, 0, 0, 0, 8,
-        18, 0, 0, 0, 74, 13, 0, 0, 3, 0, 0, 4, 16, 0, 0, 0, 105, 29, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
-        110, 29, 0, 0, 130, 0, 0, 0, 32, 0, 0, 0, 123, 29, 0, 0, 16, 1, 0, 0, 64, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 2, 65, 2, 0, 0, 130, 29, 0, 0, 4, 0, 0, 4, 32, 0, 0, 0, 152, 29, 0, 0, 18, 1,
-        0, 0, 0, 0, 0, 0, 161, 29, 0, 0, 123, 0, 0, 0, 64, 0, 0, 0, 12, 3, 0, 0, 123, 0, 0, 0, 128,
-        0, 0, 0, 168, 29, 0, 0, 19, 1, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 113, 0, 0, 0,
-        180, 29, 0, 0, 0, 0, 0, 8, 20, 1, 0, 0, 185, 29, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 8, 0, 0, 4,
-        191, 29, 0, 0, 0, 0, 0, 8, 22, 1, 0, 0, 202, 29, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 0,
-        23, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 5, 4, 0, 0, 0, 211, 29, 0, 0, 224, 0, 0, 0,
-        0, 0, 0, 0, 217, 29, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 139, 4, 0, 0, 25, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 63, 2, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 2, 12, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 13, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 59, 2, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 2, 11, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 33, 2, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 2, 17, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 34, 1, 0, 0, 229, 29, 0, 0, 0, 0, 0, 8, 35,
-        2, 0, 0, 246, 29, 0, 0, 7, 0, 0, 4, 56, 0, 0, 0, 9, 30, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 15,
-        30, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 21, 30, 0, 0, 13, 0, 0, 0, 128, 0, 0, 0, 27, 30, 0, 0,
-        13, 0, 0, 0, 192, 0, 0, 0, 33, 30, 0, 0, 13, 0, 0, 0, 0, 1, 0, 0, 44, 30, 0, 0, 13, 0, 0,
-        0, 64, 1, 0, 0, 56, 30, 0, 0, 13, 0, 0, 0, 128, 1, 0, 0, 78, 30, 0, 0, 0, 0, 0, 8, 37, 1,
-        0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 128, 0, 0, 0, 227, 24, 0, 0, 38, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 123, 0, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0, 89, 30, 0, 0, 0, 0, 0,
-        8, 40, 1, 0, 0, 109, 30, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 221, 27, 0, 0, 231, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 22, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 61, 2, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 19, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 30, 2, 0, 0, 127, 30, 0, 0, 4, 0, 0, 4,
-        32, 0, 0, 0, 133, 30, 0, 0, 193, 0, 0, 0, 0, 0, 0, 0, 139, 30, 0, 0, 223, 0, 0, 0, 64, 0,
-        0, 0, 149, 30, 0, 0, 46, 1, 0, 0, 96, 0, 0, 0, 153, 30, 0, 0, 144, 0, 0, 0, 128, 0, 0, 0,
-        163, 30, 0, 0, 1, 0, 0, 4, 4, 0, 0, 0, 216, 27, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 48, 1, 0, 0, 185, 30, 0, 0, 30, 0, 0, 4, 24, 1, 0, 0, 204, 30, 0, 0, 49, 1, 0,
-        0, 0, 0, 0, 0, 126, 27, 0, 0, 223, 0, 0, 0, 64, 0, 0, 0, 127, 30, 0, 0, 45, 1, 0, 0, 128,
-        0, 0, 0, 208, 30, 0, 0, 144, 0, 0, 0, 128, 1, 0, 0, 224, 30, 0, 0, 50, 1, 0, 0, 0, 2, 0, 0,
-        238, 30, 0, 0, 50, 1, 0, 0, 128, 2, 0, 0, 254, 30, 0, 0, 144, 0, 0, 0, 0, 3, 0, 0, 9, 31,
-        0, 0, 144, 0, 0, 0, 128, 3, 0, 0, 23, 31, 0, 0, 144, 0, 0, 0, 0, 4, 0, 0, 39, 31, 0, 0, 2,
-        0, 0, 0, 128, 4, 0, 0, 49, 31, 0, 0, 2, 0, 0, 0, 160, 4, 0, 0, 59, 31, 0, 0, 2, 0, 0, 0,
-        192, 4, 0, 0, 67, 31, 0, 0, 2, 0, 0, 0, 224, 4, 0, 0, 77, 31, 0, 0, 2, 0, 0, 0, 0, 5, 0, 0,
-        85, 31, 0, 0, 2, 0, 0, 0, 32, 5, 0, 0, 93, 31, 0, 0, 2, 0, 0, 0, 64, 5, 0, 0, 108, 31, 0,
-        0, 2, 0, 0, 0, 96, 5, 0, 0, 125, 31, 0, 0, 128, 0, 0, 0, 128, 5, 0, 0, 134, 31, 0, 0, 139,
-        0, 0, 0, 192, 5, 0, 0, 220, 26, 0, 0, 13, 0, 0, 0, 0, 6, 0, 0, 32, 1, 0, 0, 13, 0, 0, 0,
-        64, 6, 0, 0, 139, 31, 0, 0, 13, 0, 0, 0, 128, 6, 0, 0, 150, 31, 0, 0, 47, 1, 0, 0, 192, 6,
-        0, 0, 161, 31, 0, 0, 13, 0, 0, 0, 0, 7, 0, 0, 172, 31, 0, 0, 13, 0, 0, 0, 64, 7, 0, 0, 183,
-        31, 0, 0, 2, 0, 0, 0, 128, 7, 0, 0, 193, 31, 0, 0, 2, 0, 0, 0, 160, 7, 0, 0, 204, 31, 0, 0,
-        27, 0, 0, 0, 192, 7, 0, 0, 210, 28, 0, 0, 241, 0, 0, 0, 0, 8, 0, 0, 218, 31, 0, 0, 51, 1,
-        0, 0, 128, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 56, 2, 0, 0, 229, 31, 0, 0, 2, 0, 0, 4, 16, 0,
-        0, 0, 247, 31, 0, 0, 238, 0, 0, 0, 0, 0, 0, 0, 252, 31, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 2,
-        32, 0, 0, 0, 0, 0, 8, 52, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 10, 32, 0, 0, 193,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 47, 1, 0, 0, 4, 0, 0, 0, 2, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 2, 44, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 123, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 3, 0, 0, 0, 0, 123, 0, 0, 0, 4, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 62,
-        2, 0, 0, 12, 32, 0, 0, 3, 0, 0, 4, 8, 4, 0, 0, 33, 32, 0, 0, 59, 1, 0, 0, 0, 0, 0, 0, 38,
-        32, 0, 0, 19, 1, 0, 0, 0, 32, 0, 0, 53, 32, 0, 0, 19, 1, 0, 0, 8, 32, 0, 0, 62, 32, 0, 0,
-        1, 0, 0, 4, 0, 4, 0, 0, 219, 24, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5,
-        16, 0, 0, 0, 88, 32, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 98, 32, 0, 0, 241, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 2, 55, 2, 0, 0, 102, 32, 0, 0, 3, 0, 0, 4, 16, 0, 0, 0, 112, 32, 0,
-        0, 63, 1, 0, 0, 0, 0, 0, 0, 161, 29, 0, 0, 17, 0, 0, 0, 64, 0, 0, 0, 99, 4, 0, 0, 17, 0, 0,
-        0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 46, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 69, 2, 0, 0,
-        5, 17, 0, 0, 4, 0, 0, 4, 120, 0, 0, 0, 117, 32, 0, 0, 66, 1, 0, 0, 0, 0, 0, 0, 158, 26, 0,
-        0, 18, 0, 0, 0, 0, 3, 0, 0, 220, 26, 0, 0, 123, 0, 0, 0, 64, 3, 0, 0, 127, 32, 0, 0, 123,
-        0, 0, 0, 128, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 123, 0, 0, 0, 4, 0, 0, 0, 12, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 65, 1, 0, 0, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 37, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 29, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 39,
-        2, 0, 0, 131, 32, 0, 0, 0, 0, 0, 8, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 60, 2, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 2, 72, 2, 0, 0, 65, 18, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 137, 32, 0, 0, 4, 0,
-        0, 4, 40, 0, 0, 0, 148, 32, 0, 0, 218, 0, 0, 0, 0, 0, 0, 0, 156, 22, 0, 0, 123, 0, 0, 0,
-        128, 0, 0, 0, 97, 22, 0, 0, 76, 1, 0, 0, 192, 0, 0, 0, 152, 6, 0, 0, 16, 0, 0, 0, 0, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 2, 77, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0,
-        78, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 75, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 75, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 14, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 16, 2, 0, 0, 154, 32, 0, 0, 1, 0,
-        0, 4, 8, 0, 0, 0, 203, 24, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 165, 32, 0, 0, 20, 0, 0, 132,
-        64, 17, 0, 0, 179, 32, 0, 0, 85, 1, 0, 0, 0, 0, 0, 0, 158, 6, 0, 0, 123, 0, 0, 0, 192, 0,
-        0, 0, 189, 32, 0, 0, 138, 0, 0, 0, 0, 1, 0, 0, 192, 32, 0, 0, 138, 0, 0, 0, 16, 1, 0, 0,
-        195, 32, 0, 0, 138, 0, 0, 0, 32, 1, 0, 0, 203, 32, 0, 0, 138, 0, 0, 0, 48, 1, 0, 0, 211,
-        32, 0, 0, 123, 0, 0, 0, 64, 1, 0, 0, 218, 32, 0, 0, 123, 0, 0, 0, 128, 1, 0, 0, 225, 32, 0,
-        0, 160, 1, 0, 0, 192, 1, 0, 0, 236, 32, 0, 0, 123, 0, 0, 0, 192, 2, 0, 0, 248, 32, 0, 0,
-        123, 0, 0, 0, 0, 3, 0, 0, 3, 33, 0, 0, 123, 0, 0, 0, 64, 3, 0, 0, 7, 33, 0, 0, 123, 0, 0,
-        0, 128, 3, 0, 0, 95, 1, 0, 0, 123, 0, 0, 0, 192, 3, 0, 0, 15, 33, 0, 0, 161, 1, 0, 0, 0, 4,
-        0, 0, 25, 33, 0, 0, 123, 0, 0, 0, 64, 4, 0, 0, 35, 33, 0, 0, 18, 0, 0, 0, 128, 4, 0, 1, 45,
-        33, 0, 0, 18, 0, 0, 0, 129, 4, 0, 1, 64, 33, 0, 0, 16, 0, 0, 0, 160, 4, 0, 0, 69, 33, 0, 0,
-        162, 1, 0, 0, 0, 6, 0, 0, 73, 33, 0, 0, 13, 0, 0, 132, 8, 0, 0, 0, 85, 33, 0, 0, 136, 0, 0,
-        0, 0, 0, 0, 0, 92, 33, 0, 0, 136, 0, 0, 0, 16, 0, 0, 0, 98, 33, 0, 0, 136, 0, 0, 0, 32, 0,
-        0, 8, 156, 0, 0, 0, 136, 0, 0, 0, 40, 0, 0, 4, 246, 24, 0, 0, 136, 0, 0, 0, 44, 0, 0, 1,
-        104, 33, 0, 0, 136, 0, 0, 0, 45, 0, 0, 2, 108, 33, 0, 0, 136, 0, 0, 0, 47, 0, 0, 1, 110,
-        33, 0, 0, 136, 0, 0, 0, 48, 0, 0, 4, 117, 33, 0, 0, 136, 0, 0, 0, 52, 0, 0, 1, 121, 33, 0,
-        0, 136, 0, 0, 0, 53, 0, 0, 1, 123, 33, 0, 0, 136, 0, 0, 0, 54, 0, 0, 1, 125, 33, 0, 0, 136,
-        0, 0, 0, 55, 0, 0, 1, 127, 33, 0, 0, 136, 0, 0, 0, 56, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 3, 0,
-        0, 0, 0, 84, 1, 0, 0, 4, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 87, 1, 0, 0, 133, 33,
-        0, 0, 75, 0, 0, 4, 208, 4, 0, 0, 144, 33, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 156, 33, 0, 0,
-        144, 0, 0, 0, 128, 0, 0, 0, 169, 33, 0, 0, 144, 0, 0, 0, 0, 1, 0, 0, 8, 20, 0, 0, 142, 0,
-        0, 0, 128, 1, 0, 0, 181, 33, 0, 0, 13, 0, 0, 0, 64, 2, 0, 0, 193, 33, 0, 0, 144, 0, 0, 0,
-        128, 2, 0, 0, 207, 33, 0, 0, 218, 0, 0, 0, 0, 3, 0, 0, 219, 33, 0, 0, 144, 0, 0, 0, 128, 3,
-        0, 0, 232, 33, 0, 0, 2, 0, 0, 0, 0, 4, 0, 0, 244, 33, 0, 0, 2, 0, 0, 0, 32, 4, 0, 0, 255,
-        33, 0, 0, 2, 0, 0, 0, 64, 4, 0, 0, 69, 11, 0, 0, 86, 1, 0, 0, 128, 4, 0, 0, 204, 30, 0, 0,
-        49, 1, 0, 0, 192, 4, 0, 0, 10, 34, 0, 0, 27, 0, 0, 0, 0, 5, 0, 0, 111, 22, 0, 0, 88, 1, 0,
-        0, 64, 5, 0, 0, 22, 34, 0, 0, 18, 0, 0, 0, 96, 5, 0, 0, 158, 26, 0, 0, 89, 1, 0, 0, 128, 5,
-        0, 0, 35, 34, 0, 0, 194, 0, 0, 0, 192, 5, 0, 0, 47, 34, 0, 0, 13, 0, 0, 0, 0, 6, 0, 0, 66,
-        34, 0, 0, 13, 0, 0, 0, 64, 6, 0, 0, 85, 34, 0, 0, 13, 0, 0, 0, 128, 6, 0, 0, 92, 34, 0, 0,
-        91, 1, 0, 0, 192, 6, 0, 0, 97, 34, 0, 0, 136, 0, 0, 0, 192, 10, 0, 0, 109, 34, 0, 0, 136,
-        0, 0, 0, 208, 10, 0, 0, 124, 34, 0, 0, 136, 0, 0, 0, 224, 10, 0, 0, 134, 34, 0, 0, 97, 1,
-        0, 0, 0, 11, 0, 0, 178, 6, 0, 0, 47, 1, 0, 0, 0, 17, 0, 0, 125, 31, 0, 0, 193, 0, 0, 0, 64,
-        17, 0, 0, 137, 34, 0, 0, 194, 0, 0, 0, 128, 17, 0, 0, 162, 34, 0, 0, 194, 0, 0, 0, 192, 17,
-        0, 0, 187, 34, 0, 0, 45, 1, 0, 0, 0, 18, 0, 0, 199, 34, 0, 0, 144, 0, 0, 0, 0, 19, 0, 0,
-        45, 11, 0, 0, 86, 1, 0, 0, 128, 19, 0, 0, 210, 34, 0, 0, 2, 0, 0, 0, 192, 19, 0, 0, 42, 1,
-        0, 0, 2, 0, 0, 0, 224, 19, 0, 0, 216, 34, 0, 0, 144, 0, 0, 0, 0, 20, 0, 0, 133, 30, 0, 0,
-        139, 0, 0, 0, 128, 20, 0, 0, 228, 34, 0, 0, 45, 1, 0, 0, 192, 20, 0, 0, 239, 34, 0, 0, 130,
-        0, 0, 0, 192, 21, 0, 0, 100, 26, 0, 0, 110, 1, 0, 0, 0, 22, 0, 0, 250, 34, 0, 0, 144, 0, 0,
-        0, 64, 22, 0, 0, 3, 35, 0, 0, 123, 0, 0, 0, 192, 22, 0, 0, 15, 35, 0, 0, 2, 0, 0, 0, 0, 23,
-        0, 0, 27, 35, 0, 0, 111, 1, 0, 0, 64, 23, 0, 0, 33, 35, 0, 0, 113, 1, 0, 0, 0, 24, 0, 0,
-        40, 35, 0, 0, 18, 0, 0, 0, 64, 24, 0, 0, 55, 35, 0, 0, 18, 0, 0, 0, 96, 24, 0, 0, 68, 35,
-        0, 0, 18, 0, 0, 0, 128, 24, 0, 0, 84, 35, 0, 0, 18, 0, 0, 0, 160, 24, 0, 0, 100, 35, 0, 0,
-        123, 0, 0, 0, 192, 24, 0, 0, 113, 35, 0, 0, 114, 1, 0, 0, 0, 25, 0, 0, 125, 35, 0, 0, 241,
-        0, 0, 0, 0, 26, 0, 0, 138, 35, 0, 0, 18, 0, 0, 0, 128, 26, 0, 0, 151, 35, 0, 0, 130, 0, 0,
-        0, 160, 26, 0, 0, 163, 35, 0, 0, 119, 1, 0, 0, 192, 26, 0, 0, 176, 35, 0, 0, 120, 1, 0, 0,
-        128, 27, 0, 0, 195, 35, 0, 0, 123, 0, 0, 0, 192, 27, 0, 0, 212, 35, 0, 0, 86, 1, 0, 0, 0,
-        28, 0, 0, 222, 35, 0, 0, 121, 1, 0, 0, 64, 28, 0, 0, 210, 28, 0, 0, 241, 0, 0, 0, 128, 28,
-        0, 0, 230, 35, 0, 0, 123, 1, 0, 0, 0, 29, 0, 0, 233, 35, 0, 0, 13, 0, 0, 0, 64, 29, 0, 0,
-        236, 35, 0, 0, 194, 0, 0, 0, 128, 29, 0, 0, 249, 35, 0, 0, 124, 1, 0, 0, 192, 29, 0, 0,
-        255, 35, 0, 0, 126, 1, 0, 0, 0, 30, 0, 0, 16, 36, 0, 0, 27, 0, 0, 0, 64, 30, 0, 0, 41, 36,
-        0, 0, 126, 1, 0, 0, 128, 30, 0, 0, 63, 36, 0, 0, 142, 1, 0, 0, 192, 30, 0, 0, 68, 36, 0, 0,
-        13, 0, 0, 0, 0, 31, 0, 0, 79, 36, 0, 0, 143, 1, 0, 0, 64, 31, 0, 0, 123, 29, 0, 0, 144, 1,
-        0, 0, 128, 31, 0, 0, 88, 36, 0, 0, 145, 1, 0, 0, 192, 31, 0, 0, 99, 36, 0, 0, 159, 1, 0, 0,
-        128, 37, 0, 0, 168, 18, 0, 0, 27, 0, 0, 0, 192, 37, 0, 0, 104, 36, 0, 0, 144, 0, 0, 0, 0,
-        38, 0, 0, 112, 36, 0, 0, 6, 0, 0, 6, 4, 0, 0, 0, 129, 36, 0, 0, 252, 255, 255, 255, 151,
-        36, 0, 0, 253, 255, 255, 255, 173, 36, 0, 0, 254, 255, 255, 255, 196, 36, 0, 0, 255, 255,
-        255, 255, 217, 36, 0, 0, 0, 0, 0, 0, 243, 36, 0, 0, 1, 0, 0, 0, 11, 37, 0, 0, 0, 0, 0, 8,
-        90, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 8, 0, 0, 0, 10, 32, 0, 0, 51, 1, 0, 0, 0, 0, 0, 0, 21,
-        37, 0, 0, 59, 0, 0, 132, 128, 0, 0, 0, 156, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 99, 4, 0, 0,
-        17, 0, 0, 0, 32, 0, 0, 0, 37, 37, 0, 0, 14, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 92, 1, 0, 0,
-        128, 0, 0, 0, 44, 37, 0, 0, 14, 0, 0, 0, 192, 0, 0, 0, 56, 37, 0, 0, 14, 0, 0, 0, 0, 1, 0,
-        0, 68, 37, 0, 0, 14, 0, 0, 0, 64, 1, 0, 1, 77, 37, 0, 0, 14, 0, 0, 0, 65, 1, 0, 1, 85, 37,
-        0, 0, 14, 0, 0, 0, 66, 1, 0, 1, 92, 37, 0, 0, 14, 0, 0, 0, 67, 1, 0, 1, 102, 37, 0, 0, 14,
-        0, 0, 0, 68, 1, 0, 1, 115, 37, 0, 0, 14, 0, 0, 0, 69, 1, 0, 1, 130, 37, 0, 0, 14, 0, 0, 0,
-        70, 1, 0, 1, 141, 37, 0, 0, 14, 0, 0, 0, 71, 1, 0, 1, 154, 37, 0, 0, 14, 0, 0, 0, 72, 1, 0,
-        1, 54, 1, 0, 0, 14, 0, 0, 0, 73, 1, 0, 1, 159, 37, 0, 0, 14, 0, 0, 0, 74, 1, 0, 1, 164, 37,
-        0, 0, 14, 0, 0, 0, 75, 1, 0, 1, 177, 37, 0, 0, 14, 0, 0, 0, 76, 1, 0, 1, 134, 31, 0, 0, 14,
-        0, 0, 0, 77, 1, 0, 1, 192, 37, 0, 0, 14, 0, 0, 0, 78, 1, 0, 1, 202, 37, 0, 0, 14, 0, 0, 0,
-        79, 1, 0, 2, 213, 37, 0, 0, 14, 0, 0, 0, 81, 1, 0, 1, 223, 37, 0, 0, 14, 0, 0, 0, 82, 1, 0,
-        1, 237, 37, 0, 0, 14, 0, 0, 0, 83, 1, 0, 1, 250, 37, 0, 0, 14, 0, 0, 0, 84, 1, 0, 1, 8, 38,
-        0, 0, 14, 0, 0, 0, 85, 1, 0, 1, 33, 38, 0, 0, 14, 0, 0, 0, 86, 1, 0, 1, 56, 38, 0, 0, 14,
-        0, 0, 0, 87, 1, 0, 1, 62, 38, 0, 0, 14, 0, 0, 0, 88, 1, 0, 1, 72, 38, 0, 0, 14, 0, 0, 0,
-        89, 1, 0, 1, 84, 38, 0, 0, 14, 0, 0, 0, 90, 1, 0, 1, 99, 38, 0, 0, 14, 0, 0, 0, 91, 1, 0,
-        1, 114, 38, 0, 0, 14, 0, 0, 0, 92, 1, 0, 1, 125, 38, 0, 0, 14, 0, 0, 0, 93, 1, 0, 1, 133,
-        38, 0, 0, 14, 0, 0, 0, 94, 1, 0, 1, 143, 38, 0, 0, 14, 0, 0, 0, 95, 1, 0, 1, 154, 38, 0, 0,
-        14, 0, 0, 0, 96, 1, 0, 1, 161, 38, 0, 0, 14, 0, 0, 0, 97, 1, 0, 1, 171, 38, 0, 0, 14, 0, 0,
-        0, 98, 1, 0, 1, 180, 38, 0, 0, 14, 0, 0, 0, 99, 1, 0, 1, 195, 38, 0, 0, 14, 0, 0, 0, 100,
-        1, 0, 1, 210, 38, 0, 0, 14, 0, 0, 0, 101, 1, 0, 1, 218, 38, 0, 0, 14, 0, 0, 0, 102, 1, 0,
-        26, 0, 0, 0, 0, 93, 1, 0, 0, 128, 1, 0, 0, 231, 38, 0, 0, 17, 0, 0, 0, 160, 1, 0, 0, 0, 0,
-        0, 0, 94, 1, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 95, 1, 0, 0, 0, 2, 0, 0, 239, 38, 0, 0, 14, 0,
-        0, 0, 64, 2, 0, 0, 2, 39, 0, 0, 14, 0, 0, 0, 128, 2, 0, 0, 19, 39, 0, 0, 17, 0, 0, 0, 192,
-        2, 0, 0, 232, 26, 0, 0, 96, 1, 0, 0, 224, 2, 0, 0, 37, 39, 0, 0, 14, 0, 0, 0, 0, 3, 0, 0,
-        54, 39, 0, 0, 17, 0, 0, 0, 64, 3, 0, 0, 68, 39, 0, 0, 137, 0, 0, 0, 96, 3, 0, 0, 85, 39, 0,
-        0, 137, 0, 0, 0, 112, 3, 0, 0, 98, 39, 0, 0, 17, 0, 0, 0, 128, 3, 0, 0, 114, 39, 0, 0, 17,
-        0, 0, 0, 160, 3, 0, 0, 127, 39, 0, 0, 14, 0, 0, 0, 192, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5, 8,
-        0, 0, 0, 41, 6, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 136, 39, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 0, 0, 5, 4, 0, 0, 0, 148, 39, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 162, 39, 0, 0, 17,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 5, 8, 0, 0, 0, 179, 39, 0, 0, 14, 0, 0, 0, 0, 0,
-        0, 0, 187, 39, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 199, 39, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 211,
-        39, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 5, 8, 0, 0, 0, 219, 39, 0, 0, 14,
-        0, 0, 0, 0, 0, 0, 0, 226, 39, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 238, 39, 0, 0, 14, 0, 0, 0, 0,
-        0, 0, 0, 251, 39, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 3, 40, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 9,
-        40, 0, 0, 12, 0, 0, 4, 192, 0, 0, 0, 0, 0, 0, 0, 98, 1, 0, 0, 0, 0, 0, 0, 23, 40, 0, 0,
-        139, 0, 0, 0, 0, 3, 0, 0, 163, 35, 0, 0, 27, 0, 0, 0, 64, 3, 0, 0, 195, 35, 0, 0, 123, 0,
-        0, 0, 128, 3, 0, 0, 111, 22, 0, 0, 2, 0, 0, 0, 192, 3, 0, 0, 30, 40, 0, 0, 89, 1, 0, 0, 0,
-        4, 0, 0, 41, 6, 0, 0, 13, 0, 0, 0, 64, 4, 0, 0, 0, 0, 0, 0, 107, 1, 0, 0, 128, 4, 0, 0, 41,
-        40, 0, 0, 13, 0, 0, 0, 0, 5, 0, 0, 56, 40, 0, 0, 13, 0, 0, 0, 64, 5, 0, 0, 67, 40, 0, 0,
-        13, 0, 0, 0, 128, 5, 0, 0, 83, 40, 0, 0, 13, 0, 0, 0, 192, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 5,
-        96, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 102, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 4, 96, 0,
-        0, 0, 37, 37, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 100, 40, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 109,
-        40, 0, 0, 123, 0, 0, 0, 128, 0, 0, 0, 121, 40, 0, 0, 123, 0, 0, 0, 192, 0, 0, 0, 132, 40,
-        0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 149, 40, 0, 0, 2, 0, 0, 0, 32, 1, 0, 0, 153, 40, 0, 0, 2, 0,
-        0, 0, 64, 1, 0, 0, 152, 6, 0, 0, 2, 0, 0, 0, 96, 1, 0, 0, 162, 40, 0, 0, 100, 1, 0, 0, 128,
-        1, 0, 0, 172, 40, 0, 0, 100, 1, 0, 0, 64, 2, 0, 0, 183, 40, 0, 0, 4, 0, 0, 4, 24, 0, 0, 0,
-        37, 37, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 203, 40, 0, 0, 18, 0, 0, 0, 64, 0, 0, 0, 207, 40, 0,
-        0, 2, 0, 0, 0, 96, 0, 0, 0, 149, 40, 0, 0, 2, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
-        4, 64, 0, 0, 0, 71, 22, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 4, 16, 0, 0,
-        0, 213, 40, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 221, 40,
-        0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 229, 40, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 2, 0,
-        0, 4, 40, 0, 0, 0, 234, 40, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 239, 40, 0, 0, 144, 0, 0, 0,
-        192, 0, 0, 0, 247, 40, 0, 0, 4, 0, 0, 4, 24, 0, 0, 0, 10, 41, 0, 0, 123, 0, 0, 0, 0, 0, 0,
-        0, 18, 41, 0, 0, 123, 0, 0, 0, 64, 0, 0, 0, 12, 3, 0, 0, 31, 0, 0, 0, 128, 0, 0, 0, 156, 0,
-        0, 0, 31, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 4, 24, 0, 0, 0, 23, 41, 0, 0, 31, 0,
-        0, 0, 0, 0, 0, 0, 34, 41, 0, 0, 31, 0, 0, 0, 8, 0, 0, 0, 45, 41, 0, 0, 136, 0, 0, 0, 16, 0,
-        0, 0, 53, 41, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 58, 41, 0, 0, 13, 0, 0, 0, 128, 0, 0, 0, 0,
-        0, 0, 0, 2, 0, 0, 5, 16, 0, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 109, 1,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 64, 41, 0, 0, 13, 0, 0, 0, 0, 0, 0,
-        0, 76, 41, 0, 0, 89, 1, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 88, 41, 0,
-        0, 13, 0, 0, 0, 0, 0, 0, 0, 101, 41, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        2, 49, 2, 0, 0, 113, 41, 0, 0, 0, 0, 0, 8, 112, 1, 0, 0, 131, 41, 0, 0, 2, 0, 0, 4, 24, 0,
-        0, 0, 126, 27, 0, 0, 21, 1, 0, 0, 0, 0, 0, 0, 147, 41, 0, 0, 144, 0, 0, 0, 64, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 24, 2, 0, 0, 152, 41, 0, 0, 3, 0, 0, 4, 32, 0, 0, 0, 79, 22, 0, 0,
-        132, 0, 0, 0, 0, 0, 0, 0, 249, 5, 0, 0, 115, 1, 0, 0, 128, 0, 0, 0, 161, 41, 0, 0, 118, 1,
-        0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 116, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0,
-        0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 114, 1, 0, 0, 169, 41, 0, 0, 1, 0, 0,
-        4, 8, 0, 0, 0, 134, 31, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 177, 41, 0, 0, 3, 0, 0, 4, 24, 0,
-        0, 0, 30, 29, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 126, 27, 0, 0, 223, 0, 0, 0, 128, 0, 0, 0,
-        200, 41, 0, 0, 18, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 47, 2, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 122, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 86, 1, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 54, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 125, 1, 0, 0, 0, 0, 0, 0, 1, 0,
-        0, 13, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 41, 0, 0, 0, 0, 0, 8, 127, 1, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 2, 128, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 86, 1, 0,
-        0, 0, 0, 0, 0, 129, 1, 0, 0, 0, 0, 0, 0, 141, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 130, 1, 0,
-        0, 240, 41, 0, 0, 23, 0, 0, 4, 0, 1, 0, 0, 55, 6, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 1, 42, 0,
-        0, 131, 1, 0, 0, 64, 0, 0, 0, 5, 42, 0, 0, 132, 1, 0, 0, 128, 0, 0, 0, 14, 42, 0, 0, 13, 0,
-        0, 0, 192, 0, 0, 0, 142, 20, 0, 0, 133, 1, 0, 0, 0, 1, 0, 0, 21, 42, 0, 0, 13, 0, 0, 0, 64,
-        1, 0, 0, 25, 42, 0, 0, 135, 1, 0, 0, 128, 1, 0, 0, 156, 0, 0, 0, 13, 0, 0, 0, 192, 1, 0, 0,
-        146, 6, 0, 0, 13, 0, 0, 0, 0, 2, 0, 0, 34, 42, 0, 0, 137, 1, 0, 0, 64, 2, 0, 0, 220, 26, 0,
-        0, 13, 0, 0, 0, 128, 2, 0, 0, 233, 35, 0, 0, 13, 0, 0, 0, 192, 2, 0, 0, 44, 42, 0, 0, 13,
-        0, 0, 0, 0, 3, 0, 0, 54, 42, 0, 0, 138, 1, 0, 0, 64, 3, 0, 0, 64, 42, 0, 0, 139, 1, 0, 0,
-        128, 3, 0, 0, 74, 42, 0, 0, 13, 0, 0, 0, 192, 3, 0, 0, 83, 42, 0, 0, 140, 1, 0, 0, 0, 4, 0,
-        0, 93, 42, 0, 0, 140, 1, 0, 0, 128, 4, 0, 0, 103, 42, 0, 0, 13, 0, 0, 0, 0, 5, 0, 0, 119,
-        42, 0, 0, 13, 0, 0, 0, 64, 5, 0, 0, 154, 38, 0, 0, 13, 0, 0, 0, 128, 5, 0, 0, 129, 42, 0,
-        0, 13, 0, 0, 0, 192, 5, 0, 0, 144, 42, 0, 0, 13, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        2, 52, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 48, 2, 0, 0, 159, 42, 0, 0, 2, 0, 0, 5, 8, 0, 0, 0,
-        178, 42, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        3, 0, 0, 4, 8, 0, 0, 0, 183, 42, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 191, 42, 0, 0, 137, 0, 0,
-        0, 32, 0, 0, 0, 198, 42, 0, 0, 137, 0, 0, 0, 48, 0, 0, 0, 205, 42, 0, 0, 2, 0, 0, 5, 8, 0,
-        0, 0, 209, 26, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 11, 0, 0, 132, 8, 0, 0, 0, 223, 42, 0, 0, 14, 0, 0, 0, 0, 0, 0, 5, 230, 42, 0, 0, 14,
-        0, 0, 0, 5, 0, 0, 14, 238, 42, 0, 0, 14, 0, 0, 0, 19, 0, 0, 5, 248, 42, 0, 0, 14, 0, 0, 0,
-        24, 0, 0, 2, 1, 43, 0, 0, 14, 0, 0, 0, 26, 0, 0, 7, 10, 43, 0, 0, 14, 0, 0, 0, 33, 0, 0, 4,
-        22, 43, 0, 0, 14, 0, 0, 0, 37, 0, 0, 1, 33, 43, 0, 0, 14, 0, 0, 0, 38, 0, 0, 2, 44, 43, 0,
-        0, 14, 0, 0, 0, 40, 0, 0, 3, 52, 43, 0, 0, 14, 0, 0, 0, 43, 0, 0, 3, 61, 43, 0, 0, 14, 0,
-        0, 0, 46, 0, 0, 18, 0, 0, 0, 0, 2, 0, 0, 4, 8, 0, 0, 0, 46, 1, 0, 0, 16, 0, 0, 0, 0, 0, 0,
-        0, 50, 1, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 4, 8, 0, 0, 0, 42, 1, 0, 0,
-        16, 0, 0, 0, 0, 0, 0, 0, 70, 43, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
-        50, 2, 0, 0, 79, 43, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 89, 43, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
-        36, 6, 0, 0, 141, 1, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 122, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 15, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 71, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 23,
-        2, 0, 0, 88, 36, 0, 0, 12, 0, 0, 4, 184, 0, 0, 0, 249, 5, 0, 0, 146, 1, 0, 0, 0, 0, 0, 0,
-        139, 4, 0, 0, 149, 1, 0, 0, 64, 0, 0, 0, 152, 6, 0, 0, 123, 0, 0, 0, 128, 0, 0, 0, 93, 43,
-        0, 0, 27, 0, 0, 0, 192, 0, 0, 0, 101, 43, 0, 0, 146, 1, 0, 0, 0, 1, 0, 0, 112, 43, 0, 0,
-        152, 1, 0, 0, 64, 1, 0, 0, 123, 43, 0, 0, 154, 1, 0, 0, 192, 2, 0, 0, 133, 43, 0, 0, 152,
-        1, 0, 0, 0, 3, 0, 0, 142, 43, 0, 0, 123, 0, 0, 0, 128, 4, 0, 0, 153, 43, 0, 0, 123, 0, 0,
-        0, 192, 4, 0, 0, 30, 29, 0, 0, 144, 0, 0, 0, 0, 5, 0, 0, 169, 43, 0, 0, 155, 1, 0, 0, 128,
-        5, 0, 0, 178, 43, 0, 0, 0, 0, 0, 8, 147, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 148, 1, 0, 0, 0,
-        0, 0, 0, 4, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0,
-        0, 0, 149, 1, 0, 0, 0, 0, 0, 0, 150, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 145, 1, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 2, 151, 1, 0, 0, 192, 43, 0, 0, 1, 0, 0, 4, 168, 0, 0, 0, 36, 6, 0, 0, 122,
-        0, 0, 0, 0, 0, 0, 0, 204, 43, 0, 0, 3, 0, 0, 4, 48, 0, 0, 0, 220, 43, 0, 0, 153, 1, 0, 0,
-        0, 0, 0, 0, 233, 43, 0, 0, 153, 1, 0, 0, 64, 0, 0, 0, 245, 43, 0, 0, 45, 1, 0, 0, 128, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 28, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 152, 1, 0, 0, 0, 44, 0,
-        0, 0, 0, 0, 8, 156, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 157, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 13,
-        2, 0, 0, 0, 0, 0, 0, 0, 149, 1, 0, 0, 0, 0, 0, 0, 158, 1, 0, 0, 18, 44, 0, 0, 3, 0, 0, 6,
-        4, 0, 0, 0, 33, 44, 0, 0, 0, 0, 0, 0, 75, 44, 0, 0, 1, 0, 0, 0, 117, 44, 0, 0, 2, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 2, 51, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 86, 1, 0, 0, 4, 0,
-        0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 32, 2, 0, 0, 69, 33, 0, 0, 7, 0, 0, 4, 128, 16,
-        0, 0, 153, 40, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 160, 44, 0, 0, 123, 0, 0, 0, 64, 0, 0, 0,
-        177, 44, 0, 0, 163, 1, 0, 0, 128, 0, 0, 0, 185, 44, 0, 0, 163, 1, 0, 0, 192, 0, 0, 0, 200,
-        44, 0, 0, 164, 1, 0, 0, 0, 1, 0, 0, 205, 44, 0, 0, 164, 1, 0, 0, 128, 1, 0, 0, 216, 44, 0,
-        0, 165, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 165, 1, 0, 0, 226, 44, 0, 0, 3, 0, 0,
-        4, 16, 0, 0, 0, 241, 44, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 254, 44, 0, 0, 18, 0, 0, 0, 64, 0,
-        0, 0, 11, 45, 0, 0, 18, 0, 0, 0, 96, 0, 0, 0, 177, 44, 0, 0, 10, 0, 0, 132, 64, 16, 0, 0,
-        99, 4, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 29, 45, 0, 0, 18, 0, 0, 0, 32, 0, 0, 0, 39, 45, 0, 0,
-        13, 0, 0, 0, 64, 0, 0, 0, 49, 45, 0, 0, 13, 0, 0, 0, 128, 0, 0, 0, 64, 45, 0, 0, 13, 0, 0,
-        0, 192, 0, 0, 0, 68, 45, 0, 0, 18, 0, 0, 0, 0, 1, 0, 1, 78, 45, 0, 0, 18, 0, 0, 0, 1, 1, 0,
-        1, 87, 45, 0, 0, 18, 0, 0, 0, 2, 1, 0, 1, 103, 45, 0, 0, 18, 0, 0, 0, 3, 1, 0, 1, 36, 6, 0,
-        0, 166, 1, 0, 0, 0, 2, 0, 0, 110, 45, 0, 0, 5, 0, 0, 5, 0, 16, 0, 0, 123, 45, 0, 0, 167, 1,
-        0, 0, 0, 0, 0, 0, 129, 45, 0, 0, 169, 1, 0, 0, 0, 0, 0, 0, 136, 45, 0, 0, 177, 1, 0, 0, 0,
-        0, 0, 0, 141, 45, 0, 0, 179, 1, 0, 0, 0, 0, 0, 0, 147, 45, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0,
-        157, 45, 0, 0, 9, 0, 0, 4, 112, 0, 0, 0, 169, 45, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 173, 45,
-        0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 177, 45, 0, 0, 16, 0, 0, 0, 64, 0, 0, 0, 181, 45, 0, 0, 16,
-        0, 0, 0, 96, 0, 0, 0, 185, 45, 0, 0, 16, 0, 0, 0, 128, 0, 0, 0, 189, 45, 0, 0, 16, 0, 0, 0,
-        160, 0, 0, 0, 193, 45, 0, 0, 16, 0, 0, 0, 192, 0, 0, 0, 197, 45, 0, 0, 168, 1, 0, 0, 224,
-        0, 0, 0, 96, 19, 0, 0, 16, 0, 0, 0, 96, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 16, 0,
-        0, 0, 4, 0, 0, 0, 20, 0, 0, 0, 206, 45, 0, 0, 11, 0, 0, 4, 0, 2, 0, 0, 169, 45, 0, 0, 136,
-        0, 0, 0, 0, 0, 0, 0, 173, 45, 0, 0, 136, 0, 0, 0, 16, 0, 0, 0, 177, 45, 0, 0, 136, 0, 0, 0,
-        32, 0, 0, 0, 219, 45, 0, 0, 136, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 170, 1, 0, 0, 64, 0, 0,
-        0, 223, 45, 0, 0, 16, 0, 0, 0, 192, 0, 0, 0, 229, 45, 0, 0, 16, 0, 0, 0, 224, 0, 0, 0, 197,
-        45, 0, 0, 173, 1, 0, 0, 0, 1, 0, 0, 240, 45, 0, 0, 174, 1, 0, 0, 0, 5, 0, 0, 45, 41, 0, 0,
-        175, 1, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 176, 1, 0, 0, 128, 14, 0, 0, 0, 0, 0, 0, 2, 0, 0, 5,
-        16, 0, 0, 0, 0, 0, 0, 0, 171, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 172, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 2, 0, 0, 4, 16, 0, 0, 0, 250, 45, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 254, 45, 0, 0,
-        13, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 4, 16, 0, 0, 0, 181, 45, 0, 0, 16, 0, 0, 0,
-        0, 0, 0, 0, 185, 45, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 189, 45, 0, 0, 16, 0, 0, 0, 64, 0, 0,
-        0, 193, 45, 0, 0, 16, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 16, 0, 0,
-        0, 4, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0,
-        64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 12, 0, 0, 0, 0,
-        0, 0, 0, 2, 0, 0, 5, 48, 0, 0, 0, 2, 46, 0, 0, 175, 1, 0, 0, 0, 0, 0, 0, 11, 46, 0, 0, 175,
-        1, 0, 0, 0, 0, 0, 0, 23, 46, 0, 0, 16, 0, 0, 4, 136, 0, 0, 0, 169, 45, 0, 0, 16, 0, 0, 0,
-        0, 0, 0, 0, 173, 45, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 177, 45, 0, 0, 16, 0, 0, 0, 64, 0, 0,
-        0, 181, 45, 0, 0, 16, 0, 0, 0, 96, 0, 0, 0, 185, 45, 0, 0, 16, 0, 0, 0, 128, 0, 0, 0, 189,
-        45, 0, 0, 16, 0, 0, 0, 160, 0, 0, 0, 193, 45, 0, 0, 16, 0, 0, 0, 192, 0, 0, 0, 197, 45, 0,
-        0, 168, 1, 0, 0, 224, 0, 0, 0, 36, 46, 0, 0, 31, 0, 0, 0, 96, 3, 0, 0, 41, 46, 0, 0, 31, 0,
-        0, 0, 104, 3, 0, 0, 49, 46, 0, 0, 31, 0, 0, 0, 112, 3, 0, 0, 59, 46, 0, 0, 31, 0, 0, 0,
-        120, 3, 0, 0, 69, 46, 0, 0, 31, 0, 0, 0, 128, 3, 0, 0, 72, 46, 0, 0, 31, 0, 0, 0, 136, 3,
-        0, 0, 234, 40, 0, 0, 178, 1, 0, 0, 192, 3, 0, 0, 79, 46, 0, 0, 16, 0, 0, 0, 0, 4, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 2, 38, 2, 0, 0, 89, 46, 0, 0, 3, 0, 0, 4, 64, 2, 0, 0, 101, 46, 0, 0,
-        169, 1, 0, 0, 0, 0, 0, 0, 106, 46, 0, 0, 180, 1, 0, 0, 0, 16, 0, 0, 113, 46, 0, 0, 182, 1,
-        0, 0, 0, 18, 0, 0, 133, 46, 0, 0, 3, 0, 0, 4, 64, 0, 0, 0, 39, 45, 0, 0, 13, 0, 0, 0, 0, 0,
-        0, 0, 147, 46, 0, 0, 13, 0, 0, 0, 64, 0, 0, 0, 70, 43, 0, 0, 181, 1, 0, 0, 128, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 13, 0, 0, 0, 4, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        3, 0, 0, 0, 0, 31, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 31,
-        0, 0, 0, 4, 0, 0, 0, 0, 16, 0, 0, 156, 46, 0, 0, 1, 0, 0, 12, 124, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 10, 186, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 19, 1, 0, 0, 174, 46, 0, 0, 0, 0, 0, 14,
-        185, 1, 0, 0, 1, 0, 0, 0, 182, 46, 0, 0, 7, 0, 0, 4, 36, 1, 0, 0, 50, 1, 0, 0, 8, 0, 0, 0,
-        0, 0, 0, 0, 46, 1, 0, 0, 8, 0, 0, 0, 32, 0, 0, 0, 188, 46, 0, 0, 13, 1, 0, 0, 64, 0, 0, 0,
-        54, 1, 0, 0, 34, 0, 0, 0, 96, 0, 0, 0, 166, 1, 0, 0, 2, 0, 0, 0, 224, 0, 0, 0, 152, 1, 0,
-        0, 2, 0, 0, 0, 0, 1, 0, 0, 192, 46, 0, 0, 189, 1, 0, 0, 32, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        3, 0, 0, 0, 0, 31, 0, 0, 0, 4, 0, 0, 0, 0, 1, 0, 0, 196, 46, 0, 0, 0, 0, 0, 14, 188, 1, 0,
-        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
-        191, 1, 0, 0, 4, 0, 0, 0, 8, 0, 0, 0, 203, 46, 0, 0, 0, 0, 0, 14, 192, 1, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 22, 0, 0, 0, 220, 46, 0, 0,
-        0, 0, 0, 14, 194, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4,
-        0, 0, 0, 48, 0, 0, 0, 239, 46, 0, 0, 0, 0, 0, 14, 196, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 39, 0, 0, 0, 2, 47, 0, 0, 0, 0, 0, 14, 198,
-        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 20, 0,
-        0, 0, 21, 47, 0, 0, 0, 0, 0, 14, 200, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0,
-        0, 191, 1, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0, 40, 47, 0, 0, 0, 0, 0, 14, 202, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 33, 0, 0, 0, 59, 47, 0, 0,
-        0, 0, 0, 14, 204, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4,
-        0, 0, 0, 23, 0, 0, 0, 78, 47, 0, 0, 0, 0, 0, 14, 206, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 30, 0, 0, 0, 97, 47, 0, 0, 0, 0, 0, 14, 208,
-        1, 0, 0, 0, 0, 0, 0, 116, 47, 0, 0, 0, 0, 0, 14, 200, 1, 0, 0, 0, 0, 0, 0, 135, 47, 0, 0,
-        0, 0, 0, 14, 208, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4,
-        0, 0, 0, 24, 0, 0, 0, 155, 47, 0, 0, 0, 0, 0, 14, 212, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 14, 0, 0, 0, 175, 47, 0, 0, 0, 0, 0, 14,
-        214, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 27,
-        0, 0, 0, 195, 47, 0, 0, 0, 0, 0, 14, 216, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0,
-        0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 17, 0, 0, 0, 215, 47, 0, 0, 0, 0, 0, 14, 218, 1, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 26, 0, 0, 0, 235,
-        47, 0, 0, 0, 0, 0, 14, 220, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191,
-        1, 0, 0, 4, 0, 0, 0, 35, 0, 0, 0, 5, 48, 0, 0, 0, 0, 0, 14, 222, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 9, 0, 0, 0, 34, 48, 0, 0, 0, 0, 0,
-        14, 224, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0,
-        21, 0, 0, 0, 63, 48, 0, 0, 0, 0, 0, 14, 226, 1, 0, 0, 0, 0, 0, 0, 92, 48, 0, 0, 0, 0, 0,
-        14, 200, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0,
-        12, 0, 0, 0, 121, 48, 0, 0, 0, 0, 0, 14, 229, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
-        0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 13, 0, 0, 0, 150, 48, 0, 0, 0, 0, 0, 14, 231, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 10, 0, 0, 0,
-        179, 48, 0, 0, 0, 0, 0, 14, 233, 1, 0, 0, 0, 0, 0, 0, 208, 48, 0, 0, 0, 0, 0, 14, 229, 1,
-        0, 0, 0, 0, 0, 0, 237, 48, 0, 0, 0, 0, 0, 14, 206, 1, 0, 0, 0, 0, 0, 0, 10, 49, 0, 0, 0, 0,
-        0, 14, 216, 1, 0, 0, 0, 0, 0, 0, 39, 49, 0, 0, 0, 0, 0, 14, 198, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 29, 0, 0, 0, 68, 49, 0, 0, 0, 0, 0,
-        14, 239, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0,
-        36, 0, 0, 0, 97, 49, 0, 0, 0, 0, 0, 14, 241, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
-        0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 44, 0, 0, 0, 126, 49, 0, 0, 0, 0, 0, 14, 243, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 43, 0, 0, 0,
-        155, 49, 0, 0, 0, 0, 0, 14, 245, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
-        191, 1, 0, 0, 4, 0, 0, 0, 25, 0, 0, 0, 184, 49, 0, 0, 0, 0, 0, 14, 247, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 10, 250, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 113, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 3, 0, 0, 0, 0, 249, 1, 0, 0, 4, 0, 0, 0, 46, 0, 0, 0, 213, 49, 0, 0, 0, 0, 0, 14,
-        251, 1, 0, 0, 1, 0, 0, 0, 231, 49, 0, 0, 0, 0, 0, 14, 18, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 3, 0, 0, 0, 0, 113, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 239, 49, 0, 0, 0, 0, 0, 14,
-        254, 1, 0, 0, 1, 0, 0, 0, 247, 49, 0, 0, 0, 0, 0, 14, 196, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 7, 0, 0, 0, 26, 50, 0, 0, 0, 0, 0, 14, 1,
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 191, 1, 0, 0, 4, 0, 0, 0, 1, 0, 0,
-        0, 48, 50, 0, 0, 0, 0, 0, 14, 3, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
-        191, 1, 0, 0, 4, 0, 0, 0, 31, 0, 0, 0, 73, 50, 0, 0, 0, 0, 0, 14, 5, 2, 0, 0, 0, 0, 0, 0,
-        95, 50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 213, 25, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 113, 50, 0, 0,
-        0, 0, 0, 7, 0, 0, 0, 0, 41, 13, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 231, 13, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 203, 13, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 127, 50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 136,
-        50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 154, 50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 163, 50, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 3, 14, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 98, 20, 0, 0, 0, 0, 0, 7, 0, 0,
-        0, 0, 175, 50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 199, 50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 115,
-        12, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 210, 50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 218, 50, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 231, 50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 238, 5, 0, 0, 0, 0, 0, 7, 0, 0,
-        0, 0, 245, 50, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 2, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 12, 51,
-        0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 24, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 41, 51, 0, 0, 0, 0, 0,
-        7, 0, 0, 0, 0, 56, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 15, 33, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
-        248, 13, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 75, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 89, 51, 0, 0,
-        0, 0, 0, 7, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 104, 51, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 110, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 124, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 110,
-        15, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 135, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 141, 12, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 185, 12, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 45, 16, 0, 0, 0, 0, 0, 7, 0, 0,
-        0, 0, 145, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 112, 32, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 160,
-        51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 183, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 201, 51, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 213, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 234, 51, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 246, 51, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 46, 1, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 6, 52,
-        0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 20, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 204, 30, 0, 0, 0, 0, 0,
-        7, 0, 0, 0, 0, 36, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 43, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
-        217, 13, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 52, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 66, 52, 0, 0,
-        0, 0, 0, 7, 0, 0, 0, 0, 127, 16, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 83, 52, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 122, 7, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 99, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 114,
-        52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 128, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 143, 52, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 157, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 173, 52, 0, 0, 0, 0, 0, 7, 0,
-        0, 0, 0, 184, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 201, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 213,
-        52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 229, 52, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 250, 52, 0, 0, 0,
-        0, 0, 7, 0, 0, 0, 0, 173, 80, 0, 0, 38, 0, 0, 15, 161, 3, 0, 0, 187, 1, 0, 0, 0, 0, 0, 0,
-        1, 0, 0, 0, 193, 1, 0, 0, 1, 0, 0, 0, 8, 0, 0, 0, 195, 1, 0, 0, 9, 0, 0, 0, 22, 0, 0, 0,
-        197, 1, 0, 0, 31, 0, 0, 0, 48, 0, 0, 0, 199, 1, 0, 0, 79, 0, 0, 0, 39, 0, 0, 0, 201, 1, 0,
-        0, 118, 0, 0, 0, 20, 0, 0, 0, 203, 1, 0, 0, 138, 0, 0, 0, 16, 0, 0, 0, 205, 1, 0, 0, 154,
-        0, 0, 0, 33, 0, 0, 0, 207, 1, 0, 0, 187, 0, 0, 0, 23, 0, 0, 0, 209, 1, 0, 0, 210, 0, 0, 0,
-        30, 0, 0, 0, 210, 1, 0, 0, 240, 0, 0, 0, 20, 0, 0, 0, 211, 1, 0, 0, 4, 1, 0, 0, 30, 0, 0,
-        0, 213, 1, 0, 0, 34, 1, 0, 0, 24, 0, 0, 0, 215, 1, 0, 0, 58, 1, 0, 0, 14, 0, 0, 0, 217, 1,
-        0, 0, 72, 1, 0, 0, 27, 0, 0, 0, 219, 1, 0, 0, 99, 1, 0, 0, 17, 0, 0, 0, 221, 1, 0, 0, 116,
-        1, 0, 0, 26, 0, 0, 0, 223, 1, 0, 0, 142, 1, 0, 0, 35, 0, 0, 0, 225, 1, 0, 0, 177, 1, 0, 0,
-        9, 0, 0, 0, 227, 1, 0, 0, 186, 1, 0, 0, 21, 0, 0, 0, 228, 1, 0, 0, 207, 1, 0, 0, 20, 0, 0,
-        0, 230, 1, 0, 0, 227, 1, 0, 0, 12, 0, 0, 0, 232, 1, 0, 0, 239, 1, 0, 0, 13, 0, 0, 0, 234,
-        1, 0, 0, 252, 1, 0, 0, 10, 0, 0, 0, 235, 1, 0, 0, 6, 2, 0, 0, 12, 0, 0, 0, 236, 1, 0, 0,
-        18, 2, 0, 0, 23, 0, 0, 0, 237, 1, 0, 0, 41, 2, 0, 0, 27, 0, 0, 0, 238, 1, 0, 0, 68, 2, 0,
-        0, 39, 0, 0, 0, 240, 1, 0, 0, 107, 2, 0, 0, 29, 0, 0, 0, 242, 1, 0, 0, 136, 2, 0, 0, 36, 0,
-        0, 0, 244, 1, 0, 0, 172, 2, 0, 0, 44, 0, 0, 0, 246, 1, 0, 0, 216, 2, 0, 0, 43, 0, 0, 0,
-        248, 1, 0, 0, 3, 3, 0, 0, 25, 0, 0, 0, 252, 1, 0, 0, 28, 3, 0, 0, 46, 0, 0, 0, 0, 2, 0, 0,
-        74, 3, 0, 0, 48, 0, 0, 0, 2, 2, 0, 0, 122, 3, 0, 0, 7, 0, 0, 0, 4, 2, 0, 0, 129, 3, 0, 0,
-        1, 0, 0, 0, 6, 2, 0, 0, 130, 3, 0, 0, 31, 0, 0, 0, 181, 80, 0, 0, 1, 0, 0, 15, 36, 1, 0, 0,
-        190, 1, 0, 0, 0, 0, 0, 0, 36, 1, 0, 0, 186, 80, 0, 0, 10, 0, 0, 15, 64, 1, 0, 0, 20, 0, 0,
-        0, 0, 0, 0, 0, 32, 0, 0, 0, 46, 0, 0, 0, 32, 0, 0, 0, 32, 0, 0, 0, 55, 0, 0, 0, 64, 0, 0,
-        0, 32, 0, 0, 0, 61, 0, 0, 0, 96, 0, 0, 0, 32, 0, 0, 0, 91, 0, 0, 0, 128, 0, 0, 0, 32, 0, 0,
-        0, 95, 0, 0, 0, 160, 0, 0, 0, 32, 0, 0, 0, 101, 0, 0, 0, 192, 0, 0, 0, 32, 0, 0, 0, 105, 0,
-        0, 0, 224, 0, 0, 0, 32, 0, 0, 0, 109, 0, 0, 0, 0, 1, 0, 0, 32, 0, 0, 0, 118, 0, 0, 0, 32,
-        1, 0, 0, 32, 0, 0, 0, 192, 80, 0, 0, 1, 0, 0, 15, 4, 0, 0, 0, 253, 1, 0, 0, 0, 0, 0, 0, 4,
-        0, 0, 0, 200, 80, 0, 0, 1, 0, 0, 15, 4, 0, 0, 0, 255, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
-        105, 110, 116, 0, 95, 95, 65, 82, 82, 65, 89, 95, 83, 73, 90, 69, 95, 84, 89, 80, 69, 95,
-        95, 0, 112, 105, 100, 95, 116, 0, 95, 95, 107, 101, 114, 110, 101, 108, 95, 112, 105, 100,
-        95, 116, 0, 80, 114, 111, 99, 101, 115, 115, 73, 110, 102, 111, 0, 105, 110, 116, 101, 114,
-        112, 114, 101, 116, 101, 114, 95, 97, 100, 100, 114, 0, 116, 104, 114, 101, 97, 100, 95,
-        115, 116, 97, 116, 101, 95, 97, 100, 100, 114, 0, 112, 121, 95, 118, 101, 114, 115, 105,
-        111, 110, 0, 117, 54, 52, 0, 95, 95, 117, 54, 52, 0, 117, 110, 115, 105, 103, 110, 101,
-        100, 32, 108, 111, 110, 103, 32, 108, 111, 110, 103, 0, 117, 51, 50, 0, 95, 95, 117, 51,
-        50, 0, 117, 110, 115, 105, 103, 110, 101, 100, 32, 105, 110, 116, 0, 116, 121, 112, 101, 0,
-        109, 97, 120, 95, 101, 110, 116, 114, 105, 101, 115, 0, 107, 101, 121, 0, 118, 97, 108,
-        117, 101, 0, 112, 105, 100, 95, 116, 111, 95, 112, 114, 111, 99, 101, 115, 115, 95, 105,
-        110, 102, 111, 0, 83, 116, 97, 116, 101, 0, 112, 114, 111, 99, 101, 115, 115, 95, 105, 110,
-        102, 111, 0, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 0, 102, 114, 97, 109,
-        101, 95, 112, 116, 114, 0, 115, 116, 97, 99, 107, 95, 119, 97, 108, 107, 101, 114, 95, 112,
-        114, 111, 103, 95, 99, 97, 108, 108, 95, 99, 111, 117, 110, 116, 0, 115, 97, 109, 112, 108,
-        101, 0, 83, 97, 109, 112, 108, 101, 0, 116, 105, 109, 101, 115, 116, 97, 109, 112, 0, 99,
-        112, 117, 0, 112, 105, 100, 0, 116, 105, 100, 0, 99, 111, 109, 109, 0, 110, 97, 116, 105,
-        118, 101, 95, 115, 116, 97, 99, 107, 95, 99, 111, 117, 110, 116, 95, 107, 101, 121, 0, 115,
-        116, 97, 99, 107, 95, 115, 116, 97, 116, 117, 115, 0, 101, 114, 114, 111, 114, 95, 99, 111,
-        100, 101, 0, 115, 116, 97, 99, 107, 0, 117, 56, 0, 95, 95, 117, 56, 0, 117, 110, 115, 105,
-        103, 110, 101, 100, 32, 99, 104, 97, 114, 0, 115, 116, 97, 99, 107, 95, 99, 111, 117, 110,
-        116, 95, 107, 101, 121, 95, 116, 0, 117, 115, 101, 114, 95, 115, 116, 97, 99, 107, 95, 105,
-        100, 0, 107, 101, 114, 110, 101, 108, 95, 115, 116, 97, 99, 107, 95, 105, 100, 0, 83, 84,
-        65, 67, 75, 95, 67, 79, 77, 80, 76, 69, 84, 69, 0, 83, 84, 65, 67, 75, 95, 84, 82, 85, 78,
-        67, 65, 84, 69, 68, 0, 83, 84, 65, 67, 75, 95, 69, 82, 82, 79, 82, 0, 69, 82, 82, 79, 82,
-        95, 78, 79, 78, 69, 0, 69, 82, 82, 79, 82, 95, 77, 73, 83, 83, 73, 78, 71, 95, 80, 89, 83,
-        84, 65, 84, 69, 0, 69, 82, 82, 79, 82, 95, 84, 72, 82, 69, 65, 68, 95, 83, 84, 65, 84, 69,
-        95, 78, 85, 76, 76, 0, 69, 82, 82, 79, 82, 95, 73, 78, 84, 69, 82, 80, 82, 69, 84, 69, 82,
-        95, 78, 85, 76, 76, 0, 69, 82, 82, 79, 82, 95, 84, 79, 79, 95, 77, 65, 78, 89, 95, 84, 72,
-        82, 69, 65, 68, 83, 0, 69, 82, 82, 79, 82, 95, 84, 72, 82, 69, 65, 68, 95, 83, 84, 65, 84,
-        69, 95, 78, 79, 84, 95, 70, 79, 85, 78, 68, 0, 69, 82, 82, 79, 82, 95, 69, 77, 80, 84, 89,
-        95, 83, 84, 65, 67, 75, 0, 69, 82, 82, 79, 82, 95, 66, 65, 68, 95, 70, 83, 66, 65, 83, 69,
-        0, 69, 82, 82, 79, 82, 95, 73, 78, 86, 65, 76, 73, 68, 95, 80, 84, 72, 82, 69, 65, 68, 83,
-        95, 73, 77, 80, 76, 0, 69, 82, 82, 79, 82, 95, 84, 72, 82, 69, 65, 68, 95, 83, 84, 65, 84,
-        69, 95, 72, 69, 65, 68, 95, 78, 85, 76, 76, 0, 69, 82, 82, 79, 82, 95, 66, 65, 68, 95, 84,
-        72, 82, 69, 65, 68, 95, 83, 84, 65, 84, 69, 0, 69, 82, 82, 79, 82, 95, 67, 65, 76, 76, 95,
-        70, 65, 73, 76, 69, 68, 0, 69, 82, 82, 79, 82, 95, 84, 83, 84, 65, 84, 69, 95, 67, 70, 82,
-        65, 77, 69, 95, 73, 83, 95, 78, 85, 76, 76, 0, 83, 116, 97, 99, 107, 0, 108, 101, 110, 0,
-        102, 114, 97, 109, 101, 115, 0, 115, 49, 54, 0, 95, 95, 115, 49, 54, 0, 115, 104, 111, 114,
-        116, 0, 103, 108, 111, 98, 97, 108, 95, 115, 116, 97, 116, 101, 0, 115, 116, 97, 99, 107,
-        95, 116, 114, 97, 99, 101, 95, 116, 121, 112, 101, 0, 115, 116, 97, 99, 107, 95, 116, 114,
-        97, 99, 101, 115, 0, 115, 116, 97, 99, 107, 95, 99, 111, 117, 110, 116, 115, 0, 80, 121,
-        116, 104, 111, 110, 86, 101, 114, 115, 105, 111, 110, 79, 102, 102, 115, 101, 116, 115, 0,
-        109, 97, 106, 111, 114, 95, 118, 101, 114, 115, 105, 111, 110, 0, 109, 105, 110, 111, 114,
-        95, 118, 101, 114, 115, 105, 111, 110, 0, 112, 97, 116, 99, 104, 95, 118, 101, 114, 115,
-        105, 111, 110, 0, 112, 121, 95, 111, 98, 106, 101, 99, 116, 0, 112, 121, 95, 115, 116, 114,
-        105, 110, 103, 0, 112, 121, 95, 116, 121, 112, 101, 95, 111, 98, 106, 101, 99, 116, 0, 112,
-        121, 95, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 0, 112, 121, 95, 99, 102,
-        114, 97, 109, 101, 0, 112, 121, 95, 105, 110, 116, 101, 114, 112, 114, 101, 116, 101, 114,
-        95, 115, 116, 97, 116, 101, 0, 112, 121, 95, 114, 117, 110, 116, 105, 109, 101, 95, 115,
-        116, 97, 116, 101, 0, 112, 121, 95, 102, 114, 97, 109, 101, 95, 111, 98, 106, 101, 99, 116,
-        0, 112, 121, 95, 99, 111, 100, 101, 95, 111, 98, 106, 101, 99, 116, 0, 112, 121, 95, 116,
-        117, 112, 108, 101, 95, 111, 98, 106, 101, 99, 116, 0, 80, 121, 79, 98, 106, 101, 99, 116,
-        0, 111, 98, 95, 116, 121, 112, 101, 0, 115, 54, 52, 0, 95, 95, 115, 54, 52, 0, 108, 111,
-        110, 103, 32, 108, 111, 110, 103, 0, 80, 121, 83, 116, 114, 105, 110, 103, 0, 100, 97, 116,
-        97, 0, 115, 105, 122, 101, 0, 80, 121, 84, 121, 112, 101, 79, 98, 106, 101, 99, 116, 0,
-        116, 112, 95, 110, 97, 109, 101, 0, 80, 121, 84, 104, 114, 101, 97, 100, 83, 116, 97, 116,
-        101, 0, 110, 101, 120, 116, 0, 105, 110, 116, 101, 114, 112, 0, 102, 114, 97, 109, 101, 0,
-        116, 104, 114, 101, 97, 100, 95, 105, 100, 0, 110, 97, 116, 105, 118, 101, 95, 116, 104,
-        114, 101, 97, 100, 95, 105, 100, 0, 99, 102, 114, 97, 109, 101, 0, 80, 121, 67, 70, 114,
-        97, 109, 101, 0, 99, 117, 114, 114, 101, 110, 116, 95, 102, 114, 97, 109, 101, 0, 80, 121,
-        73, 110, 116, 101, 114, 112, 114, 101, 116, 101, 114, 83, 116, 97, 116, 101, 0, 116, 115,
-        116, 97, 116, 101, 95, 104, 101, 97, 100, 0, 80, 121, 82, 117, 110, 116, 105, 109, 101, 83,
-        116, 97, 116, 101, 0, 105, 110, 116, 101, 114, 112, 95, 109, 97, 105, 110, 0, 80, 121, 70,
-        114, 97, 109, 101, 79, 98, 106, 101, 99, 116, 0, 102, 95, 98, 97, 99, 107, 0, 102, 95, 99,
-        111, 100, 101, 0, 102, 95, 108, 105, 110, 101, 110, 111, 0, 102, 95, 108, 111, 99, 97, 108,
-        115, 112, 108, 117, 115, 0, 80, 121, 67, 111, 100, 101, 79, 98, 106, 101, 99, 116, 0, 99,
-        111, 95, 102, 105, 108, 101, 110, 97, 109, 101, 0, 99, 111, 95, 110, 97, 109, 101, 0, 99,
-        111, 95, 118, 97, 114, 110, 97, 109, 101, 115, 0, 99, 111, 95, 102, 105, 114, 115, 116,
-        108, 105, 110, 101, 110, 111, 0, 80, 121, 84, 117, 112, 108, 101, 79, 98, 106, 101, 99,
-        116, 0, 111, 98, 95, 105, 116, 101, 109, 0, 118, 101, 114, 115, 105, 111, 110, 95, 115,
-        112, 101, 99, 105, 102, 105, 99, 95, 111, 102, 102, 115, 101, 116, 115, 0, 112, 114, 111,
-        103, 114, 97, 109, 115, 0, 107, 101, 121, 95, 115, 105, 122, 101, 0, 118, 97, 108, 117,
-        101, 95, 115, 105, 122, 101, 0, 101, 118, 101, 110, 116, 115, 0, 115, 116, 97, 99, 107,
-        109, 97, 112, 0, 115, 121, 109, 98, 111, 108, 95, 105, 110, 100, 101, 120, 0, 83, 121, 109,
-        98, 111, 108, 0, 102, 105, 108, 101, 0, 99, 108, 97, 115, 115, 0, 102, 117, 110, 99, 0,
-        108, 105, 110, 101, 0, 99, 104, 97, 114, 0, 115, 121, 109, 98, 111, 108, 115, 0, 98, 112,
-        102, 95, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 100, 97, 116, 97, 0, 114,
-        101, 103, 115, 0, 115, 97, 109, 112, 108, 101, 95, 112, 101, 114, 105, 111, 100, 0, 97,
-        100, 100, 114, 0, 98, 112, 102, 95, 117, 115, 101, 114, 95, 112, 116, 95, 114, 101, 103,
-        115, 95, 116, 0, 112, 116, 95, 114, 101, 103, 115, 0, 114, 49, 53, 0, 114, 49, 52, 0, 114,
-        49, 51, 0, 114, 49, 50, 0, 98, 112, 0, 98, 120, 0, 114, 49, 49, 0, 114, 49, 48, 0, 114, 57,
-        0, 114, 56, 0, 97, 120, 0, 99, 120, 0, 100, 120, 0, 115, 105, 0, 100, 105, 0, 111, 114,
-        105, 103, 95, 97, 120, 0, 105, 112, 0, 99, 115, 0, 102, 108, 97, 103, 115, 0, 115, 112, 0,
-        115, 115, 0, 117, 110, 115, 105, 103, 110, 101, 100, 32, 108, 111, 110, 103, 0, 99, 116,
-        120, 0, 111, 110, 95, 101, 118, 101, 110, 116, 0, 116, 97, 115, 107, 95, 115, 116, 114,
-        117, 99, 116, 0, 116, 104, 114, 101, 97, 100, 95, 105, 110, 102, 111, 0, 95, 95, 115, 116,
-        97, 116, 101, 0, 117, 115, 97, 103, 101, 0, 112, 116, 114, 97, 99, 101, 0, 111, 110, 95,
-        99, 112, 117, 0, 119, 97, 107, 101, 95, 101, 110, 116, 114, 121, 0, 119, 97, 107, 101, 101,
-        95, 102, 108, 105, 112, 115, 0, 119, 97, 107, 101, 101, 95, 102, 108, 105, 112, 95, 100,
-        101, 99, 97, 121, 95, 116, 115, 0, 108, 97, 115, 116, 95, 119, 97, 107, 101, 101, 0, 114,
-        101, 99, 101, 110, 116, 95, 117, 115, 101, 100, 95, 99, 112, 117, 0, 119, 97, 107, 101, 95,
-        99, 112, 117, 0, 111, 110, 95, 114, 113, 0, 112, 114, 105, 111, 0, 115, 116, 97, 116, 105,
-        99, 95, 112, 114, 105, 111, 0, 110, 111, 114, 109, 97, 108, 95, 112, 114, 105, 111, 0, 114,
-        116, 95, 112, 114, 105, 111, 114, 105, 116, 121, 0, 115, 101, 0, 114, 116, 0, 100, 108, 0,
-        115, 99, 104, 101, 100, 95, 99, 108, 97, 115, 115, 0, 99, 111, 114, 101, 95, 110, 111, 100,
-        101, 0, 99, 111, 114, 101, 95, 99, 111, 111, 107, 105, 101, 0, 99, 111, 114, 101, 95, 111,
-        99, 99, 117, 112, 97, 116, 105, 111, 110, 0, 115, 99, 104, 101, 100, 95, 116, 97, 115, 107,
-        95, 103, 114, 111, 117, 112, 0, 115, 116, 97, 116, 115, 0, 112, 114, 101, 101, 109, 112,
-        116, 95, 110, 111, 116, 105, 102, 105, 101, 114, 115, 0, 98, 116, 114, 97, 99, 101, 95,
-        115, 101, 113, 0, 112, 111, 108, 105, 99, 121, 0, 110, 114, 95, 99, 112, 117, 115, 95, 97,
-        108, 108, 111, 119, 101, 100, 0, 99, 112, 117, 115, 95, 112, 116, 114, 0, 117, 115, 101,
-        114, 95, 99, 112, 117, 115, 95, 112, 116, 114, 0, 99, 112, 117, 115, 95, 109, 97, 115, 107,
-        0, 109, 105, 103, 114, 97, 116, 105, 111, 110, 95, 112, 101, 110, 100, 105, 110, 103, 0,
-        109, 105, 103, 114, 97, 116, 105, 111, 110, 95, 100, 105, 115, 97, 98, 108, 101, 100, 0,
-        109, 105, 103, 114, 97, 116, 105, 111, 110, 95, 102, 108, 97, 103, 115, 0, 114, 99, 117,
-        95, 114, 101, 97, 100, 95, 108, 111, 99, 107, 95, 110, 101, 115, 116, 105, 110, 103, 0,
-        114, 99, 117, 95, 114, 101, 97, 100, 95, 117, 110, 108, 111, 99, 107, 95, 115, 112, 101,
-        99, 105, 97, 108, 0, 114, 99, 117, 95, 110, 111, 100, 101, 95, 101, 110, 116, 114, 121, 0,
-        114, 99, 117, 95, 98, 108, 111, 99, 107, 101, 100, 95, 110, 111, 100, 101, 0, 114, 99, 117,
-        95, 116, 97, 115, 107, 115, 95, 110, 118, 99, 115, 119, 0, 114, 99, 117, 95, 116, 97, 115,
-        107, 115, 95, 104, 111, 108, 100, 111, 117, 116, 0, 114, 99, 117, 95, 116, 97, 115, 107,
-        115, 95, 105, 100, 120, 0, 114, 99, 117, 95, 116, 97, 115, 107, 115, 95, 105, 100, 108,
-        101, 95, 99, 112, 117, 0, 114, 99, 117, 95, 116, 97, 115, 107, 115, 95, 104, 111, 108, 100,
-        111, 117, 116, 95, 108, 105, 115, 116, 0, 116, 114, 99, 95, 114, 101, 97, 100, 101, 114,
-        95, 110, 101, 115, 116, 105, 110, 103, 0, 116, 114, 99, 95, 105, 112, 105, 95, 116, 111,
-        95, 99, 112, 117, 0, 116, 114, 99, 95, 114, 101, 97, 100, 101, 114, 95, 115, 112, 101, 99,
-        105, 97, 108, 0, 116, 114, 99, 95, 104, 111, 108, 100, 111, 117, 116, 95, 108, 105, 115,
-        116, 0, 116, 114, 99, 95, 98, 108, 107, 100, 95, 110, 111, 100, 101, 0, 116, 114, 99, 95,
-        98, 108, 107, 100, 95, 99, 112, 117, 0, 115, 99, 104, 101, 100, 95, 105, 110, 102, 111, 0,
-        116, 97, 115, 107, 115, 0, 112, 117, 115, 104, 97, 98, 108, 101, 95, 116, 97, 115, 107,
-        115, 0, 112, 117, 115, 104, 97, 98, 108, 101, 95, 100, 108, 95, 116, 97, 115, 107, 115, 0,
-        109, 109, 0, 97, 99, 116, 105, 118, 101, 95, 109, 109, 0, 118, 109, 97, 99, 97, 99, 104,
-        101, 0, 114, 115, 115, 95, 115, 116, 97, 116, 0, 101, 120, 105, 116, 95, 115, 116, 97, 116,
-        101, 0, 101, 120, 105, 116, 95, 99, 111, 100, 101, 0, 101, 120, 105, 116, 95, 115, 105,
-        103, 110, 97, 108, 0, 112, 100, 101, 97, 116, 104, 95, 115, 105, 103, 110, 97, 108, 0, 106,
-        111, 98, 99, 116, 108, 0, 112, 101, 114, 115, 111, 110, 97, 108, 105, 116, 121, 0, 115, 99,
-        104, 101, 100, 95, 114, 101, 115, 101, 116, 95, 111, 110, 95, 102, 111, 114, 107, 0, 115,
-        99, 104, 101, 100, 95, 99, 111, 110, 116, 114, 105, 98, 117, 116, 101, 115, 95, 116, 111,
-        95, 108, 111, 97, 100, 0, 115, 99, 104, 101, 100, 95, 109, 105, 103, 114, 97, 116, 101,
-        100, 0, 115, 99, 104, 101, 100, 95, 112, 115, 105, 95, 119, 97, 107, 101, 95, 114, 101,
-        113, 117, 101, 117, 101, 0, 115, 99, 104, 101, 100, 95, 114, 101, 109, 111, 116, 101, 95,
-        119, 97, 107, 101, 117, 112, 0, 105, 110, 95, 101, 120, 101, 99, 118, 101, 0, 105, 110, 95,
-        105, 111, 119, 97, 105, 116, 0, 114, 101, 115, 116, 111, 114, 101, 95, 115, 105, 103, 109,
-        97, 115, 107, 0, 105, 110, 95, 117, 115, 101, 114, 95, 102, 97, 117, 108, 116, 0, 110, 111,
-        95, 99, 103, 114, 111, 117, 112, 95, 109, 105, 103, 114, 97, 116, 105, 111, 110, 0, 102,
-        114, 111, 122, 101, 110, 0, 117, 115, 101, 95, 109, 101, 109, 100, 101, 108, 97, 121, 0,
-        105, 110, 95, 109, 101, 109, 115, 116, 97, 108, 108, 0, 105, 110, 95, 112, 97, 103, 101,
-        95, 111, 119, 110, 101, 114, 0, 105, 110, 95, 101, 118, 101, 110, 116, 102, 100, 0, 112,
-        97, 115, 105, 100, 95, 97, 99, 116, 105, 118, 97, 116, 101, 100, 0, 114, 101, 112, 111,
-        114, 116, 101, 100, 95, 115, 112, 108, 105, 116, 95, 108, 111, 99, 107, 0, 97, 116, 111,
-        109, 105, 99, 95, 102, 108, 97, 103, 115, 0, 114, 101, 115, 116, 97, 114, 116, 95, 98, 108,
-        111, 99, 107, 0, 116, 103, 105, 100, 0, 115, 116, 97, 99, 107, 95, 99, 97, 110, 97, 114,
-        121, 0, 114, 101, 97, 108, 95, 112, 97, 114, 101, 110, 116, 0, 112, 97, 114, 101, 110, 116,
-        0, 99, 104, 105, 108, 100, 114, 101, 110, 0, 115, 105, 98, 108, 105, 110, 103, 0, 103, 114,
-        111, 117, 112, 95, 108, 101, 97, 100, 101, 114, 0, 112, 116, 114, 97, 99, 101, 100, 0, 112,
-        116, 114, 97, 99, 101, 95, 101, 110, 116, 114, 121, 0, 116, 104, 114, 101, 97, 100, 95,
-        112, 105, 100, 0, 112, 105, 100, 95, 108, 105, 110, 107, 115, 0, 116, 104, 114, 101, 97,
-        100, 95, 103, 114, 111, 117, 112, 0, 116, 104, 114, 101, 97, 100, 95, 110, 111, 100, 101,
-        0, 118, 102, 111, 114, 107, 95, 100, 111, 110, 101, 0, 115, 101, 116, 95, 99, 104, 105,
-        108, 100, 95, 116, 105, 100, 0, 99, 108, 101, 97, 114, 95, 99, 104, 105, 108, 100, 95, 116,
-        105, 100, 0, 119, 111, 114, 107, 101, 114, 95, 112, 114, 105, 118, 97, 116, 101, 0, 117,
-        116, 105, 109, 101, 0, 115, 116, 105, 109, 101, 0, 103, 116, 105, 109, 101, 0, 112, 114,
-        101, 118, 95, 99, 112, 117, 116, 105, 109, 101, 0, 118, 116, 105, 109, 101, 0, 116, 105,
-        99, 107, 95, 100, 101, 112, 95, 109, 97, 115, 107, 0, 110, 118, 99, 115, 119, 0, 110, 105,
-        118, 99, 115, 119, 0, 115, 116, 97, 114, 116, 95, 116, 105, 109, 101, 0, 115, 116, 97, 114,
-        116, 95, 98, 111, 111, 116, 116, 105, 109, 101, 0, 109, 105, 110, 95, 102, 108, 116, 0,
-        109, 97, 106, 95, 102, 108, 116, 0, 112, 111, 115, 105, 120, 95, 99, 112, 117, 116, 105,
-        109, 101, 114, 115, 0, 112, 111, 115, 105, 120, 95, 99, 112, 117, 116, 105, 109, 101, 114,
-        115, 95, 119, 111, 114, 107, 0, 112, 116, 114, 97, 99, 101, 114, 95, 99, 114, 101, 100, 0,
-        114, 101, 97, 108, 95, 99, 114, 101, 100, 0, 99, 114, 101, 100, 0, 99, 97, 99, 104, 101,
-        100, 95, 114, 101, 113, 117, 101, 115, 116, 101, 100, 95, 107, 101, 121, 0, 110, 97, 109,
-        101, 105, 100, 97, 116, 97, 0, 115, 121, 115, 118, 115, 101, 109, 0, 115, 121, 115, 118,
-        115, 104, 109, 0, 102, 115, 0, 102, 105, 108, 101, 115, 0, 105, 111, 95, 117, 114, 105,
-        110, 103, 0, 110, 115, 112, 114, 111, 120, 121, 0, 115, 105, 103, 110, 97, 108, 0, 115,
-        105, 103, 104, 97, 110, 100, 0, 98, 108, 111, 99, 107, 101, 100, 0, 114, 101, 97, 108, 95,
-        98, 108, 111, 99, 107, 101, 100, 0, 115, 97, 118, 101, 100, 95, 115, 105, 103, 109, 97,
-        115, 107, 0, 112, 101, 110, 100, 105, 110, 103, 0, 115, 97, 115, 95, 115, 115, 95, 115,
-        112, 0, 115, 97, 115, 95, 115, 115, 95, 115, 105, 122, 101, 0, 115, 97, 115, 95, 115, 115,
-        95, 102, 108, 97, 103, 115, 0, 116, 97, 115, 107, 95, 119, 111, 114, 107, 115, 0, 97, 117,
-        100, 105, 116, 95, 99, 111, 110, 116, 101, 120, 116, 0, 108, 111, 103, 105, 110, 117, 105,
-        100, 0, 115, 101, 115, 115, 105, 111, 110, 105, 100, 0, 115, 101, 99, 99, 111, 109, 112, 0,
-        115, 121, 115, 99, 97, 108, 108, 95, 100, 105, 115, 112, 97, 116, 99, 104, 0, 112, 97, 114,
-        101, 110, 116, 95, 101, 120, 101, 99, 95, 105, 100, 0, 115, 101, 108, 102, 95, 101, 120,
-        101, 99, 95, 105, 100, 0, 97, 108, 108, 111, 99, 95, 108, 111, 99, 107, 0, 112, 105, 95,
-        108, 111, 99, 107, 0, 119, 97, 107, 101, 95, 113, 0, 112, 105, 95, 119, 97, 105, 116, 101,
-        114, 115, 0, 112, 105, 95, 116, 111, 112, 95, 116, 97, 115, 107, 0, 112, 105, 95, 98, 108,
-        111, 99, 107, 101, 100, 95, 111, 110, 0, 106, 111, 117, 114, 110, 97, 108, 95, 105, 110,
-        102, 111, 0, 98, 105, 111, 95, 108, 105, 115, 116, 0, 112, 108, 117, 103, 0, 114, 101, 99,
-        108, 97, 105, 109, 95, 115, 116, 97, 116, 101, 0, 98, 97, 99, 107, 105, 110, 103, 95, 100,
-        101, 118, 95, 105, 110, 102, 111, 0, 105, 111, 95, 99, 111, 110, 116, 101, 120, 116, 0, 99,
-        97, 112, 116, 117, 114, 101, 95, 99, 111, 110, 116, 114, 111, 108, 0, 112, 116, 114, 97,
-        99, 101, 95, 109, 101, 115, 115, 97, 103, 101, 0, 108, 97, 115, 116, 95, 115, 105, 103,
-        105, 110, 102, 111, 0, 105, 111, 97, 99, 0, 112, 115, 105, 95, 102, 108, 97, 103, 115, 0,
-        97, 99, 99, 116, 95, 114, 115, 115, 95, 109, 101, 109, 49, 0, 97, 99, 99, 116, 95, 118,
-        109, 95, 109, 101, 109, 49, 0, 97, 99, 99, 116, 95, 116, 105, 109, 101, 120, 112, 100, 0,
-        109, 101, 109, 115, 95, 97, 108, 108, 111, 119, 101, 100, 0, 109, 101, 109, 115, 95, 97,
-        108, 108, 111, 119, 101, 100, 95, 115, 101, 113, 0, 99, 112, 117, 115, 101, 116, 95, 109,
-        101, 109, 95, 115, 112, 114, 101, 97, 100, 95, 114, 111, 116, 111, 114, 0, 99, 112, 117,
-        115, 101, 116, 95, 115, 108, 97, 98, 95, 115, 112, 114, 101, 97, 100, 95, 114, 111, 116,
-        111, 114, 0, 99, 103, 114, 111, 117, 112, 115, 0, 99, 103, 95, 108, 105, 115, 116, 0, 99,
-        108, 111, 115, 105, 100, 0, 114, 109, 105, 100, 0, 114, 111, 98, 117, 115, 116, 95, 108,
-        105, 115, 116, 0, 99, 111, 109, 112, 97, 116, 95, 114, 111, 98, 117, 115, 116, 95, 108,
-        105, 115, 116, 0, 112, 105, 95, 115, 116, 97, 116, 101, 95, 108, 105, 115, 116, 0, 112,
-        105, 95, 115, 116, 97, 116, 101, 95, 99, 97, 99, 104, 101, 0, 102, 117, 116, 101, 120, 95,
-        101, 120, 105, 116, 95, 109, 117, 116, 101, 120, 0, 102, 117, 116, 101, 120, 95, 115, 116,
-        97, 116, 101, 0, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 99, 116, 120, 112, 0,
-        112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 109, 117, 116, 101, 120, 0, 112, 101,
-        114, 102, 95, 101, 118, 101, 110, 116, 95, 108, 105, 115, 116, 0, 112, 114, 101, 101, 109,
-        112, 116, 95, 100, 105, 115, 97, 98, 108, 101, 95, 105, 112, 0, 109, 101, 109, 112, 111,
-        108, 105, 99, 121, 0, 105, 108, 95, 112, 114, 101, 118, 0, 112, 114, 101, 102, 95, 110,
-        111, 100, 101, 95, 102, 111, 114, 107, 0, 110, 117, 109, 97, 95, 115, 99, 97, 110, 95, 115,
-        101, 113, 0, 110, 117, 109, 97, 95, 115, 99, 97, 110, 95, 112, 101, 114, 105, 111, 100, 0,
-        110, 117, 109, 97, 95, 115, 99, 97, 110, 95, 112, 101, 114, 105, 111, 100, 95, 109, 97,
-        120, 0, 110, 117, 109, 97, 95, 112, 114, 101, 102, 101, 114, 114, 101, 100, 95, 110, 105,
-        100, 0, 110, 117, 109, 97, 95, 109, 105, 103, 114, 97, 116, 101, 95, 114, 101, 116, 114,
-        121, 0, 110, 111, 100, 101, 95, 115, 116, 97, 109, 112, 0, 108, 97, 115, 116, 95, 116, 97,
-        115, 107, 95, 110, 117, 109, 97, 95, 112, 108, 97, 99, 101, 109, 101, 110, 116, 0, 108, 97,
-        115, 116, 95, 115, 117, 109, 95, 101, 120, 101, 99, 95, 114, 117, 110, 116, 105, 109, 101,
-        0, 110, 117, 109, 97, 95, 119, 111, 114, 107, 0, 110, 117, 109, 97, 95, 103, 114, 111, 117,
-        112, 0, 110, 117, 109, 97, 95, 102, 97, 117, 108, 116, 115, 0, 116, 111, 116, 97, 108, 95,
-        110, 117, 109, 97, 95, 102, 97, 117, 108, 116, 115, 0, 110, 117, 109, 97, 95, 102, 97, 117,
-        108, 116, 115, 95, 108, 111, 99, 97, 108, 105, 116, 121, 0, 110, 117, 109, 97, 95, 112, 97,
-        103, 101, 115, 95, 109, 105, 103, 114, 97, 116, 101, 100, 0, 114, 115, 101, 113, 0, 114,
-        115, 101, 113, 95, 115, 105, 103, 0, 114, 115, 101, 113, 95, 101, 118, 101, 110, 116, 95,
-        109, 97, 115, 107, 0, 116, 108, 98, 95, 117, 98, 99, 0, 115, 112, 108, 105, 99, 101, 95,
-        112, 105, 112, 101, 0, 116, 97, 115, 107, 95, 102, 114, 97, 103, 0, 100, 101, 108, 97, 121,
-        115, 0, 110, 114, 95, 100, 105, 114, 116, 105, 101, 100, 0, 110, 114, 95, 100, 105, 114,
-        116, 105, 101, 100, 95, 112, 97, 117, 115, 101, 0, 100, 105, 114, 116, 121, 95, 112, 97,
-        117, 115, 101, 100, 95, 119, 104, 101, 110, 0, 108, 97, 116, 101, 110, 99, 121, 95, 114,
-        101, 99, 111, 114, 100, 95, 99, 111, 117, 110, 116, 0, 108, 97, 116, 101, 110, 99, 121, 95,
-        114, 101, 99, 111, 114, 100, 0, 116, 105, 109, 101, 114, 95, 115, 108, 97, 99, 107, 95,
-        110, 115, 0, 100, 101, 102, 97, 117, 108, 116, 95, 116, 105, 109, 101, 114, 95, 115, 108,
-        97, 99, 107, 95, 110, 115, 0, 107, 117, 110, 105, 116, 95, 116, 101, 115, 116, 0, 99, 117,
-        114, 114, 95, 114, 101, 116, 95, 115, 116, 97, 99, 107, 0, 99, 117, 114, 114, 95, 114, 101,
-        116, 95, 100, 101, 112, 116, 104, 0, 114, 101, 116, 95, 115, 116, 97, 99, 107, 0, 102, 116,
-        114, 97, 99, 101, 95, 116, 105, 109, 101, 115, 116, 97, 109, 112, 0, 116, 114, 97, 99, 101,
-        95, 111, 118, 101, 114, 114, 117, 110, 0, 116, 114, 97, 99, 105, 110, 103, 95, 103, 114,
-        97, 112, 104, 95, 112, 97, 117, 115, 101, 0, 116, 114, 97, 99, 101, 0, 116, 114, 97, 99,
-        101, 95, 114, 101, 99, 117, 114, 115, 105, 111, 110, 0, 109, 101, 109, 99, 103, 95, 105,
-        110, 95, 111, 111, 109, 0, 109, 101, 109, 99, 103, 95, 111, 111, 109, 95, 103, 102, 112,
-        95, 109, 97, 115, 107, 0, 109, 101, 109, 99, 103, 95, 111, 111, 109, 95, 111, 114, 100,
-        101, 114, 0, 109, 101, 109, 99, 103, 95, 110, 114, 95, 112, 97, 103, 101, 115, 95, 111,
-        118, 101, 114, 95, 104, 105, 103, 104, 0, 97, 99, 116, 105, 118, 101, 95, 109, 101, 109,
-        99, 103, 0, 116, 104, 114, 111, 116, 116, 108, 101, 95, 113, 117, 101, 117, 101, 0, 117,
-        116, 97, 115, 107, 0, 115, 101, 113, 117, 101, 110, 116, 105, 97, 108, 95, 105, 111, 0,
-        115, 101, 113, 117, 101, 110, 116, 105, 97, 108, 95, 105, 111, 95, 97, 118, 103, 0, 107,
-        109, 97, 112, 95, 99, 116, 114, 108, 0, 112, 97, 103, 101, 102, 97, 117, 108, 116, 95, 100,
-        105, 115, 97, 98, 108, 101, 100, 0, 111, 111, 109, 95, 114, 101, 97, 112, 101, 114, 95,
-        108, 105, 115, 116, 0, 111, 111, 109, 95, 114, 101, 97, 112, 101, 114, 95, 116, 105, 109,
-        101, 114, 0, 115, 116, 97, 99, 107, 95, 118, 109, 95, 97, 114, 101, 97, 0, 115, 116, 97,
-        99, 107, 95, 114, 101, 102, 99, 111, 117, 110, 116, 0, 112, 97, 116, 99, 104, 95, 115, 116,
-        97, 116, 101, 0, 115, 101, 99, 117, 114, 105, 116, 121, 0, 98, 112, 102, 95, 115, 116, 111,
-        114, 97, 103, 101, 0, 98, 112, 102, 95, 99, 116, 120, 0, 109, 99, 101, 95, 118, 97, 100,
-        100, 114, 0, 109, 99, 101, 95, 107, 102, 108, 97, 103, 115, 0, 109, 99, 101, 95, 97, 100,
-        100, 114, 0, 109, 99, 101, 95, 114, 105, 112, 118, 0, 109, 99, 101, 95, 119, 104, 111, 108,
-        101, 95, 112, 97, 103, 101, 0, 95, 95, 109, 99, 101, 95, 114, 101, 115, 101, 114, 118, 101,
-        100, 0, 109, 99, 101, 95, 107, 105, 108, 108, 95, 109, 101, 0, 109, 99, 101, 95, 99, 111,
-        117, 110, 116, 0, 107, 114, 101, 116, 112, 114, 111, 98, 101, 95, 105, 110, 115, 116, 97,
-        110, 99, 101, 115, 0, 114, 101, 116, 104, 111, 111, 107, 115, 0, 108, 49, 100, 95, 102,
-        108, 117, 115, 104, 95, 107, 105, 108, 108, 0, 116, 104, 114, 101, 97, 100, 0, 115, 121,
-        115, 99, 97, 108, 108, 95, 119, 111, 114, 107, 0, 115, 116, 97, 116, 117, 115, 0, 114, 101,
-        102, 99, 111, 117, 110, 116, 95, 116, 0, 114, 101, 102, 99, 111, 117, 110, 116, 95, 115,
-        116, 114, 117, 99, 116, 0, 114, 101, 102, 115, 0, 97, 116, 111, 109, 105, 99, 95, 116, 0,
-        99, 111, 117, 110, 116, 101, 114, 0, 95, 95, 99, 97, 108, 108, 95, 115, 105, 110, 103, 108,
-        101, 95, 110, 111, 100, 101, 0, 108, 108, 105, 115, 116, 0, 115, 114, 99, 0, 100, 115, 116,
-        0, 108, 108, 105, 115, 116, 95, 110, 111, 100, 101, 0, 117, 95, 102, 108, 97, 103, 115, 0,
-        97, 95, 102, 108, 97, 103, 115, 0, 117, 49, 54, 0, 95, 95, 117, 49, 54, 0, 117, 110, 115,
-        105, 103, 110, 101, 100, 32, 115, 104, 111, 114, 116, 0, 115, 99, 104, 101, 100, 95, 101,
-        110, 116, 105, 116, 121, 0, 108, 111, 97, 100, 0, 114, 117, 110, 95, 110, 111, 100, 101, 0,
-        103, 114, 111, 117, 112, 95, 110, 111, 100, 101, 0, 101, 120, 101, 99, 95, 115, 116, 97,
-        114, 116, 0, 115, 117, 109, 95, 101, 120, 101, 99, 95, 114, 117, 110, 116, 105, 109, 101,
-        0, 118, 114, 117, 110, 116, 105, 109, 101, 0, 112, 114, 101, 118, 95, 115, 117, 109, 95,
-        101, 120, 101, 99, 95, 114, 117, 110, 116, 105, 109, 101, 0, 110, 114, 95, 109, 105, 103,
-        114, 97, 116, 105, 111, 110, 115, 0, 100, 101, 112, 116, 104, 0, 99, 102, 115, 95, 114,
-        113, 0, 109, 121, 95, 113, 0, 114, 117, 110, 110, 97, 98, 108, 101, 95, 119, 101, 105, 103,
-        104, 116, 0, 97, 118, 103, 0, 108, 111, 97, 100, 95, 119, 101, 105, 103, 104, 116, 0, 119,
-        101, 105, 103, 104, 116, 0, 105, 110, 118, 95, 119, 101, 105, 103, 104, 116, 0, 114, 98,
-        95, 110, 111, 100, 101, 0, 95, 95, 114, 98, 95, 112, 97, 114, 101, 110, 116, 95, 99, 111,
-        108, 111, 114, 0, 114, 98, 95, 114, 105, 103, 104, 116, 0, 114, 98, 95, 108, 101, 102, 116,
-        0, 108, 105, 115, 116, 95, 104, 101, 97, 100, 0, 112, 114, 101, 118, 0, 115, 99, 104, 101,
-        100, 95, 97, 118, 103, 0, 108, 97, 115, 116, 95, 117, 112, 100, 97, 116, 101, 95, 116, 105,
-        109, 101, 0, 108, 111, 97, 100, 95, 115, 117, 109, 0, 114, 117, 110, 110, 97, 98, 108, 101,
-        95, 115, 117, 109, 0, 117, 116, 105, 108, 95, 115, 117, 109, 0, 112, 101, 114, 105, 111,
-        100, 95, 99, 111, 110, 116, 114, 105, 98, 0, 108, 111, 97, 100, 95, 97, 118, 103, 0, 114,
-        117, 110, 110, 97, 98, 108, 101, 95, 97, 118, 103, 0, 117, 116, 105, 108, 95, 97, 118, 103,
-        0, 117, 116, 105, 108, 95, 101, 115, 116, 0, 101, 110, 113, 117, 101, 117, 101, 100, 0,
-        101, 119, 109, 97, 0, 115, 99, 104, 101, 100, 95, 114, 116, 95, 101, 110, 116, 105, 116,
-        121, 0, 114, 117, 110, 95, 108, 105, 115, 116, 0, 116, 105, 109, 101, 111, 117, 116, 0,
-        119, 97, 116, 99, 104, 100, 111, 103, 95, 115, 116, 97, 109, 112, 0, 116, 105, 109, 101,
-        95, 115, 108, 105, 99, 101, 0, 111, 110, 95, 108, 105, 115, 116, 0, 98, 97, 99, 107, 0,
-        115, 99, 104, 101, 100, 95, 100, 108, 95, 101, 110, 116, 105, 116, 121, 0, 100, 108, 95,
-        114, 117, 110, 116, 105, 109, 101, 0, 100, 108, 95, 100, 101, 97, 100, 108, 105, 110, 101,
-        0, 100, 108, 95, 112, 101, 114, 105, 111, 100, 0, 100, 108, 95, 98, 119, 0, 100, 108, 95,
-        100, 101, 110, 115, 105, 116, 121, 0, 114, 117, 110, 116, 105, 109, 101, 0, 100, 101, 97,
-        100, 108, 105, 110, 101, 0, 100, 108, 95, 116, 104, 114, 111, 116, 116, 108, 101, 100, 0,
-        100, 108, 95, 121, 105, 101, 108, 100, 101, 100, 0, 100, 108, 95, 110, 111, 110, 95, 99,
-        111, 110, 116, 101, 110, 100, 105, 110, 103, 0, 100, 108, 95, 111, 118, 101, 114, 114, 117,
-        110, 0, 100, 108, 95, 116, 105, 109, 101, 114, 0, 105, 110, 97, 99, 116, 105, 118, 101, 95,
-        116, 105, 109, 101, 114, 0, 112, 105, 95, 115, 101, 0, 104, 114, 116, 105, 109, 101, 114,
-        0, 110, 111, 100, 101, 0, 95, 115, 111, 102, 116, 101, 120, 112, 105, 114, 101, 115, 0,
-        102, 117, 110, 99, 116, 105, 111, 110, 0, 98, 97, 115, 101, 0, 115, 116, 97, 116, 101, 0,
-        105, 115, 95, 114, 101, 108, 0, 105, 115, 95, 115, 111, 102, 116, 0, 105, 115, 95, 104, 97,
-        114, 100, 0, 116, 105, 109, 101, 114, 113, 117, 101, 117, 101, 95, 110, 111, 100, 101, 0,
-        101, 120, 112, 105, 114, 101, 115, 0, 107, 116, 105, 109, 101, 95, 116, 0, 104, 114, 116,
-        105, 109, 101, 114, 95, 114, 101, 115, 116, 97, 114, 116, 0, 72, 82, 84, 73, 77, 69, 82,
-        95, 78, 79, 82, 69, 83, 84, 65, 82, 84, 0, 72, 82, 84, 73, 77, 69, 82, 95, 82, 69, 83, 84,
-        65, 82, 84, 0, 115, 99, 104, 101, 100, 95, 115, 116, 97, 116, 105, 115, 116, 105, 99, 115,
-        0, 119, 97, 105, 116, 95, 115, 116, 97, 114, 116, 0, 119, 97, 105, 116, 95, 109, 97, 120,
-        0, 119, 97, 105, 116, 95, 99, 111, 117, 110, 116, 0, 119, 97, 105, 116, 95, 115, 117, 109,
-        0, 105, 111, 119, 97, 105, 116, 95, 99, 111, 117, 110, 116, 0, 105, 111, 119, 97, 105, 116,
-        95, 115, 117, 109, 0, 115, 108, 101, 101, 112, 95, 115, 116, 97, 114, 116, 0, 115, 108,
-        101, 101, 112, 95, 109, 97, 120, 0, 115, 117, 109, 95, 115, 108, 101, 101, 112, 95, 114,
-        117, 110, 116, 105, 109, 101, 0, 98, 108, 111, 99, 107, 95, 115, 116, 97, 114, 116, 0, 98,
-        108, 111, 99, 107, 95, 109, 97, 120, 0, 115, 117, 109, 95, 98, 108, 111, 99, 107, 95, 114,
-        117, 110, 116, 105, 109, 101, 0, 101, 120, 101, 99, 95, 109, 97, 120, 0, 115, 108, 105, 99,
-        101, 95, 109, 97, 120, 0, 110, 114, 95, 109, 105, 103, 114, 97, 116, 105, 111, 110, 115,
-        95, 99, 111, 108, 100, 0, 110, 114, 95, 102, 97, 105, 108, 101, 100, 95, 109, 105, 103,
-        114, 97, 116, 105, 111, 110, 115, 95, 97, 102, 102, 105, 110, 101, 0, 110, 114, 95, 102,
-        97, 105, 108, 101, 100, 95, 109, 105, 103, 114, 97, 116, 105, 111, 110, 115, 95, 114, 117,
-        110, 110, 105, 110, 103, 0, 110, 114, 95, 102, 97, 105, 108, 101, 100, 95, 109, 105, 103,
-        114, 97, 116, 105, 111, 110, 115, 95, 104, 111, 116, 0, 110, 114, 95, 102, 111, 114, 99,
-        101, 100, 95, 109, 105, 103, 114, 97, 116, 105, 111, 110, 115, 0, 110, 114, 95, 119, 97,
-        107, 101, 117, 112, 115, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 115, 121,
-        110, 99, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 109, 105, 103, 114, 97,
-        116, 101, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 108, 111, 99, 97, 108, 0,
-        110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 114, 101, 109, 111, 116, 101, 0, 110,
-        114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 97, 102, 102, 105, 110, 101, 0, 110, 114,
-        95, 119, 97, 107, 101, 117, 112, 115, 95, 97, 102, 102, 105, 110, 101, 95, 97, 116, 116,
-        101, 109, 112, 116, 115, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 112, 97,
-        115, 115, 105, 118, 101, 0, 110, 114, 95, 119, 97, 107, 101, 117, 112, 115, 95, 105, 100,
-        108, 101, 0, 99, 111, 114, 101, 95, 102, 111, 114, 99, 101, 105, 100, 108, 101, 95, 115,
-        117, 109, 0, 104, 108, 105, 115, 116, 95, 104, 101, 97, 100, 0, 102, 105, 114, 115, 116, 0,
-        99, 112, 117, 109, 97, 115, 107, 95, 116, 0, 99, 112, 117, 109, 97, 115, 107, 0, 98, 105,
-        116, 115, 0, 114, 99, 117, 95, 115, 112, 101, 99, 105, 97, 108, 0, 98, 0, 115, 0, 110, 101,
-        101, 100, 95, 113, 115, 0, 101, 120, 112, 95, 104, 105, 110, 116, 0, 110, 101, 101, 100,
-        95, 109, 98, 0, 112, 99, 111, 117, 110, 116, 0, 114, 117, 110, 95, 100, 101, 108, 97, 121,
-        0, 108, 97, 115, 116, 95, 97, 114, 114, 105, 118, 97, 108, 0, 108, 97, 115, 116, 95, 113,
-        117, 101, 117, 101, 100, 0, 112, 108, 105, 115, 116, 95, 110, 111, 100, 101, 0, 112, 114,
-        105, 111, 95, 108, 105, 115, 116, 0, 110, 111, 100, 101, 95, 108, 105, 115, 116, 0, 115,
-        101, 113, 110, 117, 109, 0, 118, 109, 97, 115, 0, 118, 109, 95, 97, 114, 101, 97, 95, 115,
-        116, 114, 117, 99, 116, 0, 118, 109, 95, 115, 116, 97, 114, 116, 0, 118, 109, 95, 101, 110,
-        100, 0, 118, 109, 95, 110, 101, 120, 116, 0, 118, 109, 95, 112, 114, 101, 118, 0, 118, 109,
-        95, 114, 98, 0, 114, 98, 95, 115, 117, 98, 116, 114, 101, 101, 95, 103, 97, 112, 0, 118,
-        109, 95, 109, 109, 0, 118, 109, 95, 112, 97, 103, 101, 95, 112, 114, 111, 116, 0, 118, 109,
-        95, 102, 108, 97, 103, 115, 0, 97, 110, 111, 110, 95, 118, 109, 97, 95, 99, 104, 97, 105,
-        110, 0, 97, 110, 111, 110, 95, 118, 109, 97, 0, 118, 109, 95, 111, 112, 115, 0, 118, 109,
-        95, 112, 103, 111, 102, 102, 0, 118, 109, 95, 102, 105, 108, 101, 0, 118, 109, 95, 112,
-        114, 105, 118, 97, 116, 101, 95, 100, 97, 116, 97, 0, 115, 119, 97, 112, 95, 114, 101, 97,
-        100, 97, 104, 101, 97, 100, 95, 105, 110, 102, 111, 0, 118, 109, 95, 112, 111, 108, 105,
-        99, 121, 0, 118, 109, 95, 117, 115, 101, 114, 102, 97, 117, 108, 116, 102, 100, 95, 99,
-        116, 120, 0, 112, 103, 112, 114, 111, 116, 95, 116, 0, 112, 103, 112, 114, 111, 116, 0,
-        112, 103, 112, 114, 111, 116, 118, 97, 108, 95, 116, 0, 115, 104, 97, 114, 101, 100, 0, 97,
-        110, 111, 110, 95, 110, 97, 109, 101, 0, 114, 98, 0, 114, 98, 95, 115, 117, 98, 116, 114,
-        101, 101, 95, 108, 97, 115, 116, 0, 97, 116, 111, 109, 105, 99, 95, 108, 111, 110, 103, 95,
-        116, 0, 97, 116, 111, 109, 105, 99, 54, 52, 95, 116, 0, 116, 97, 115, 107, 95, 114, 115,
-        115, 95, 115, 116, 97, 116, 0, 99, 111, 117, 110, 116, 0, 97, 114, 99, 104, 95, 100, 97,
-        116, 97, 0, 102, 110, 0, 108, 111, 110, 103, 0, 102, 117, 116, 101, 120, 0, 110, 97, 110,
-        111, 115, 108, 101, 101, 112, 0, 112, 111, 108, 108, 0, 117, 97, 100, 100, 114, 0, 118, 97,
-        108, 0, 98, 105, 116, 115, 101, 116, 0, 116, 105, 109, 101, 0, 117, 97, 100, 100, 114, 50,
-        0, 99, 108, 111, 99, 107, 105, 100, 0, 99, 108, 111, 99, 107, 105, 100, 95, 116, 0, 95, 95,
-        107, 101, 114, 110, 101, 108, 95, 99, 108, 111, 99, 107, 105, 100, 95, 116, 0, 116, 105,
-        109, 101, 115, 112, 101, 99, 95, 116, 121, 112, 101, 0, 84, 84, 95, 78, 79, 78, 69, 0, 84,
-        84, 95, 78, 65, 84, 73, 86, 69, 0, 84, 84, 95, 67, 79, 77, 80, 65, 84, 0, 114, 109, 116,
-        112, 0, 99, 111, 109, 112, 97, 116, 95, 114, 109, 116, 112, 0, 117, 102, 100, 115, 0, 110,
-        102, 100, 115, 0, 104, 97, 115, 95, 116, 105, 109, 101, 111, 117, 116, 0, 116, 118, 95,
-        115, 101, 99, 0, 116, 118, 95, 110, 115, 101, 99, 0, 104, 108, 105, 115, 116, 95, 110, 111,
-        100, 101, 0, 112, 112, 114, 101, 118, 0, 108, 111, 99, 107, 0, 114, 97, 119, 95, 115, 112,
-        105, 110, 108, 111, 99, 107, 95, 116, 0, 114, 97, 119, 95, 115, 112, 105, 110, 108, 111,
-        99, 107, 0, 114, 97, 119, 95, 108, 111, 99, 107, 0, 97, 114, 99, 104, 95, 115, 112, 105,
-        110, 108, 111, 99, 107, 95, 116, 0, 113, 115, 112, 105, 110, 108, 111, 99, 107, 0, 108,
-        111, 99, 107, 101, 100, 0, 108, 111, 99, 107, 101, 100, 95, 112, 101, 110, 100, 105, 110,
-        103, 0, 116, 97, 105, 108, 0, 115, 101, 113, 99, 111, 117, 110, 116, 0, 115, 116, 97, 114,
-        116, 116, 105, 109, 101, 0, 115, 101, 113, 99, 111, 117, 110, 116, 95, 116, 0, 115, 101,
-        113, 117, 101, 110, 99, 101, 0, 118, 116, 105, 109, 101, 95, 115, 116, 97, 116, 101, 0, 86,
-        84, 73, 77, 69, 95, 73, 78, 65, 67, 84, 73, 86, 69, 0, 86, 84, 73, 77, 69, 95, 73, 68, 76,
-        69, 0, 86, 84, 73, 77, 69, 95, 83, 89, 83, 0, 86, 84, 73, 77, 69, 95, 85, 83, 69, 82, 0,
-        86, 84, 73, 77, 69, 95, 71, 85, 69, 83, 84, 0, 98, 97, 115, 101, 115, 0, 116, 105, 109,
-        101, 114, 115, 95, 97, 99, 116, 105, 118, 101, 0, 101, 120, 112, 105, 114, 121, 95, 97, 99,
-        116, 105, 118, 101, 0, 112, 111, 115, 105, 120, 95, 99, 112, 117, 116, 105, 109, 101, 114,
-        95, 98, 97, 115, 101, 0, 110, 101, 120, 116, 101, 118, 116, 0, 116, 113, 104, 101, 97, 100,
-        0, 116, 105, 109, 101, 114, 113, 117, 101, 117, 101, 95, 104, 101, 97, 100, 0, 114, 98, 95,
-        114, 111, 111, 116, 0, 114, 98, 95, 114, 111, 111, 116, 95, 99, 97, 99, 104, 101, 100, 0,
-        114, 98, 95, 108, 101, 102, 116, 109, 111, 115, 116, 0, 119, 111, 114, 107, 0, 115, 99,
-        104, 101, 100, 117, 108, 101, 100, 0, 99, 97, 108, 108, 98, 97, 99, 107, 95, 104, 101, 97,
-        100, 0, 115, 121, 115, 118, 95, 115, 101, 109, 0, 117, 110, 100, 111, 95, 108, 105, 115,
-        116, 0, 115, 121, 115, 118, 95, 115, 104, 109, 0, 115, 104, 109, 95, 99, 108, 105, 115,
-        116, 0, 115, 105, 103, 115, 101, 116, 95, 116, 0, 115, 105, 103, 0, 115, 105, 103, 112,
-        101, 110, 100, 105, 110, 103, 0, 108, 105, 115, 116, 0, 115, 105, 122, 101, 95, 116, 0, 95,
-        95, 107, 101, 114, 110, 101, 108, 95, 115, 105, 122, 101, 95, 116, 0, 95, 95, 107, 101,
-        114, 110, 101, 108, 95, 117, 108, 111, 110, 103, 95, 116, 0, 107, 117, 105, 100, 95, 116,
-        0, 117, 105, 100, 95, 116, 0, 95, 95, 107, 101, 114, 110, 101, 108, 95, 117, 105, 100, 51,
-        50, 95, 116, 0, 109, 111, 100, 101, 0, 102, 105, 108, 116, 101, 114, 95, 99, 111, 117, 110,
-        116, 0, 102, 105, 108, 116, 101, 114, 0, 115, 121, 115, 99, 97, 108, 108, 95, 117, 115,
-        101, 114, 95, 100, 105, 115, 112, 97, 116, 99, 104, 0, 115, 101, 108, 101, 99, 116, 111,
-        114, 0, 111, 102, 102, 115, 101, 116, 0, 111, 110, 95, 100, 105, 115, 112, 97, 116, 99,
-        104, 0, 98, 111, 111, 108, 0, 95, 66, 111, 111, 108, 0, 115, 112, 105, 110, 108, 111, 99,
-        107, 95, 116, 0, 115, 112, 105, 110, 108, 111, 99, 107, 0, 114, 108, 111, 99, 107, 0, 119,
-        97, 107, 101, 95, 113, 95, 110, 111, 100, 101, 0, 107, 101, 114, 110, 101, 108, 95, 115,
-        105, 103, 105, 110, 102, 111, 95, 116, 0, 116, 97, 115, 107, 95, 105, 111, 95, 97, 99, 99,
-        111, 117, 110, 116, 105, 110, 103, 0, 114, 99, 104, 97, 114, 0, 119, 99, 104, 97, 114, 0,
-        115, 121, 115, 99, 114, 0, 115, 121, 115, 99, 119, 0, 114, 101, 97, 100, 95, 98, 121, 116,
-        101, 115, 0, 119, 114, 105, 116, 101, 95, 98, 121, 116, 101, 115, 0, 99, 97, 110, 99, 101,
-        108, 108, 101, 100, 95, 119, 114, 105, 116, 101, 95, 98, 121, 116, 101, 115, 0, 110, 111,
-        100, 101, 109, 97, 115, 107, 95, 116, 0, 115, 101, 113, 99, 111, 117, 110, 116, 95, 115,
-        112, 105, 110, 108, 111, 99, 107, 95, 116, 0, 115, 101, 113, 99, 111, 117, 110, 116, 95,
-        115, 112, 105, 110, 108, 111, 99, 107, 0, 109, 117, 116, 101, 120, 0, 111, 119, 110, 101,
-        114, 0, 119, 97, 105, 116, 95, 108, 111, 99, 107, 0, 111, 115, 113, 0, 119, 97, 105, 116,
-        95, 108, 105, 115, 116, 0, 111, 112, 116, 105, 109, 105, 115, 116, 105, 99, 95, 115, 112,
-        105, 110, 95, 113, 117, 101, 117, 101, 0, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116,
-        95, 99, 111, 110, 116, 101, 120, 116, 0, 112, 109, 117, 0, 97, 99, 116, 105, 118, 101, 95,
-        99, 116, 120, 95, 108, 105, 115, 116, 0, 112, 105, 110, 110, 101, 100, 95, 103, 114, 111,
-        117, 112, 115, 0, 102, 108, 101, 120, 105, 98, 108, 101, 95, 103, 114, 111, 117, 112, 115,
-        0, 101, 118, 101, 110, 116, 95, 108, 105, 115, 116, 0, 112, 105, 110, 110, 101, 100, 95,
-        97, 99, 116, 105, 118, 101, 0, 102, 108, 101, 120, 105, 98, 108, 101, 95, 97, 99, 116, 105,
-        118, 101, 0, 110, 114, 95, 101, 118, 101, 110, 116, 115, 0, 110, 114, 95, 97, 99, 116, 105,
-        118, 101, 0, 110, 114, 95, 117, 115, 101, 114, 0, 105, 115, 95, 97, 99, 116, 105, 118, 101,
-        0, 110, 114, 95, 115, 116, 97, 116, 0, 110, 114, 95, 102, 114, 101, 113, 0, 114, 111, 116,
-        97, 116, 101, 95, 100, 105, 115, 97, 98, 108, 101, 0, 114, 111, 116, 97, 116, 101, 95, 110,
-        101, 99, 101, 115, 115, 97, 114, 121, 0, 114, 101, 102, 99, 111, 117, 110, 116, 0, 116, 97,
-        115, 107, 0, 116, 105, 109, 101, 111, 102, 102, 115, 101, 116, 0, 112, 97, 114, 101, 110,
-        116, 95, 99, 116, 120, 0, 112, 97, 114, 101, 110, 116, 95, 103, 101, 110, 0, 103, 101, 110,
-        101, 114, 97, 116, 105, 111, 110, 0, 112, 105, 110, 95, 99, 111, 117, 110, 116, 0, 110,
-        114, 95, 99, 103, 114, 111, 117, 112, 115, 0, 116, 97, 115, 107, 95, 99, 116, 120, 95, 100,
-        97, 116, 97, 0, 110, 114, 95, 112, 101, 110, 100, 105, 110, 103, 0, 112, 101, 114, 102, 95,
-        101, 118, 101, 110, 116, 95, 103, 114, 111, 117, 112, 115, 0, 116, 114, 101, 101, 0, 105,
-        110, 100, 101, 120, 0, 108, 111, 99, 97, 108, 95, 116, 0, 97, 0, 116, 108, 98, 102, 108,
-        117, 115, 104, 95, 117, 110, 109, 97, 112, 95, 98, 97, 116, 99, 104, 0, 97, 114, 99, 104,
-        0, 102, 108, 117, 115, 104, 95, 114, 101, 113, 117, 105, 114, 101, 100, 0, 119, 114, 105,
-        116, 97, 98, 108, 101, 0, 97, 114, 99, 104, 95, 116, 108, 98, 102, 108, 117, 115, 104, 95,
-        117, 110, 109, 97, 112, 95, 98, 97, 116, 99, 104, 0, 114, 99, 117, 95, 117, 115, 101, 114,
-        115, 0, 114, 99, 117, 0, 112, 97, 103, 101, 95, 102, 114, 97, 103, 0, 112, 97, 103, 101, 0,
-        98, 97, 99, 107, 116, 114, 97, 99, 101, 0, 109, 97, 120, 0, 103, 102, 112, 95, 116, 0, 116,
-        105, 109, 101, 114, 95, 108, 105, 115, 116, 0, 101, 110, 116, 114, 121, 0, 108, 108, 105,
-        115, 116, 95, 104, 101, 97, 100, 0, 116, 104, 114, 101, 97, 100, 95, 115, 116, 114, 117,
-        99, 116, 0, 116, 108, 115, 95, 97, 114, 114, 97, 121, 0, 101, 115, 0, 100, 115, 0, 102,
-        115, 105, 110, 100, 101, 120, 0, 103, 115, 105, 110, 100, 101, 120, 0, 102, 115, 98, 97,
-        115, 101, 0, 103, 115, 98, 97, 115, 101, 0, 112, 116, 114, 97, 99, 101, 95, 98, 112, 115,
-        0, 118, 105, 114, 116, 117, 97, 108, 95, 100, 114, 54, 0, 112, 116, 114, 97, 99, 101, 95,
-        100, 114, 55, 0, 99, 114, 50, 0, 116, 114, 97, 112, 95, 110, 114, 0, 105, 111, 95, 98, 105,
-        116, 109, 97, 112, 0, 105, 111, 112, 108, 95, 101, 109, 117, 108, 0, 105, 111, 112, 108,
-        95, 119, 97, 114, 110, 0, 115, 105, 103, 95, 111, 110, 95, 117, 97, 99, 99, 101, 115, 115,
-        95, 101, 114, 114, 0, 112, 107, 114, 117, 0, 102, 112, 117, 0, 100, 101, 115, 99, 95, 115,
-        116, 114, 117, 99, 116, 0, 108, 105, 109, 105, 116, 48, 0, 98, 97, 115, 101, 48, 0, 98, 97,
-        115, 101, 49, 0, 100, 112, 108, 0, 112, 0, 108, 105, 109, 105, 116, 49, 0, 97, 118, 108, 0,
-        108, 0, 100, 0, 103, 0, 98, 97, 115, 101, 50, 0, 112, 101, 114, 102, 95, 101, 118, 101,
-        110, 116, 0, 101, 118, 101, 110, 116, 95, 101, 110, 116, 114, 121, 0, 115, 105, 98, 108,
-        105, 110, 103, 95, 108, 105, 115, 116, 0, 97, 99, 116, 105, 118, 101, 95, 108, 105, 115,
-        116, 0, 103, 114, 111, 117, 112, 95, 105, 110, 100, 101, 120, 0, 109, 105, 103, 114, 97,
-        116, 101, 95, 101, 110, 116, 114, 121, 0, 104, 108, 105, 115, 116, 95, 101, 110, 116, 114,
-        121, 0, 97, 99, 116, 105, 118, 101, 95, 101, 110, 116, 114, 121, 0, 110, 114, 95, 115, 105,
-        98, 108, 105, 110, 103, 115, 0, 101, 118, 101, 110, 116, 95, 99, 97, 112, 115, 0, 103, 114,
-        111, 117, 112, 95, 99, 97, 112, 115, 0, 112, 109, 117, 95, 112, 114, 105, 118, 97, 116,
-        101, 0, 97, 116, 116, 97, 99, 104, 95, 115, 116, 97, 116, 101, 0, 99, 104, 105, 108, 100,
-        95, 99, 111, 117, 110, 116, 0, 116, 111, 116, 97, 108, 95, 116, 105, 109, 101, 95, 101,
-        110, 97, 98, 108, 101, 100, 0, 116, 111, 116, 97, 108, 95, 116, 105, 109, 101, 95, 114,
-        117, 110, 110, 105, 110, 103, 0, 116, 115, 116, 97, 109, 112, 0, 97, 116, 116, 114, 0, 104,
-        101, 97, 100, 101, 114, 95, 115, 105, 122, 101, 0, 105, 100, 95, 104, 101, 97, 100, 101,
-        114, 95, 115, 105, 122, 101, 0, 114, 101, 97, 100, 95, 115, 105, 122, 101, 0, 104, 119, 0,
-        99, 104, 105, 108, 100, 95, 116, 111, 116, 97, 108, 95, 116, 105, 109, 101, 95, 101, 110,
-        97, 98, 108, 101, 100, 0, 99, 104, 105, 108, 100, 95, 116, 111, 116, 97, 108, 95, 116, 105,
-        109, 101, 95, 114, 117, 110, 110, 105, 110, 103, 0, 99, 104, 105, 108, 100, 95, 109, 117,
-        116, 101, 120, 0, 99, 104, 105, 108, 100, 95, 108, 105, 115, 116, 0, 111, 110, 99, 112,
-        117, 0, 111, 119, 110, 101, 114, 95, 101, 110, 116, 114, 121, 0, 109, 109, 97, 112, 95,
-        109, 117, 116, 101, 120, 0, 109, 109, 97, 112, 95, 99, 111, 117, 110, 116, 0, 114, 98, 95,
-        101, 110, 116, 114, 121, 0, 114, 99, 117, 95, 98, 97, 116, 99, 104, 101, 115, 0, 114, 99,
-        117, 95, 112, 101, 110, 100, 105, 110, 103, 0, 119, 97, 105, 116, 113, 0, 102, 97, 115,
-        121, 110, 99, 0, 112, 101, 110, 100, 105, 110, 103, 95, 119, 97, 107, 101, 117, 112, 0,
-        112, 101, 110, 100, 105, 110, 103, 95, 107, 105, 108, 108, 0, 112, 101, 110, 100, 105, 110,
-        103, 95, 100, 105, 115, 97, 98, 108, 101, 0, 112, 101, 110, 100, 105, 110, 103, 95, 115,
-        105, 103, 116, 114, 97, 112, 0, 112, 101, 110, 100, 105, 110, 103, 95, 97, 100, 100, 114,
-        0, 112, 101, 110, 100, 105, 110, 103, 95, 105, 114, 113, 0, 112, 101, 110, 100, 105, 110,
-        103, 95, 116, 97, 115, 107, 0, 112, 101, 110, 100, 105, 110, 103, 95, 119, 111, 114, 107,
-        0, 101, 118, 101, 110, 116, 95, 108, 105, 109, 105, 116, 0, 97, 100, 100, 114, 95, 102,
-        105, 108, 116, 101, 114, 115, 0, 97, 100, 100, 114, 95, 102, 105, 108, 116, 101, 114, 95,
-        114, 97, 110, 103, 101, 115, 0, 97, 100, 100, 114, 95, 102, 105, 108, 116, 101, 114, 115,
-        95, 103, 101, 110, 0, 97, 117, 120, 95, 101, 118, 101, 110, 116, 0, 100, 101, 115, 116,
-        114, 111, 121, 0, 110, 115, 0, 105, 100, 0, 108, 111, 115, 116, 95, 115, 97, 109, 112, 108,
-        101, 115, 0, 99, 108, 111, 99, 107, 0, 111, 118, 101, 114, 102, 108, 111, 119, 95, 104, 97,
-        110, 100, 108, 101, 114, 0, 111, 118, 101, 114, 102, 108, 111, 119, 95, 104, 97, 110, 100,
-        108, 101, 114, 95, 99, 111, 110, 116, 101, 120, 116, 0, 111, 114, 105, 103, 95, 111, 118,
-        101, 114, 102, 108, 111, 119, 95, 104, 97, 110, 100, 108, 101, 114, 0, 112, 114, 111, 103,
-        0, 98, 112, 102, 95, 99, 111, 111, 107, 105, 101, 0, 116, 112, 95, 101, 118, 101, 110, 116,
-        0, 102, 116, 114, 97, 99, 101, 95, 111, 112, 115, 0, 99, 103, 114, 112, 0, 115, 98, 95,
-        108, 105, 115, 116, 0, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 115, 116, 97,
-        116, 101, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84, 65, 84, 69, 95, 68, 69,
-        65, 68, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84, 65, 84, 69, 95, 69, 88, 73,
-        84, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84, 65, 84, 69, 95, 69, 82, 82, 79,
-        82, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84, 65, 84, 69, 95, 79, 70, 70, 0,
-        80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84, 65, 84, 69, 95, 73, 78, 65, 67, 84, 73,
-        86, 69, 0, 80, 69, 82, 70, 95, 69, 86, 69, 78, 84, 95, 83, 84, 65, 84, 69, 95, 65, 67, 84,
-        73, 86, 69, 0, 108, 111, 99, 97, 108, 54, 52, 95, 116, 0, 112, 101, 114, 102, 95, 101, 118,
-        101, 110, 116, 95, 97, 116, 116, 114, 0, 99, 111, 110, 102, 105, 103, 0, 115, 97, 109, 112,
-        108, 101, 95, 116, 121, 112, 101, 0, 114, 101, 97, 100, 95, 102, 111, 114, 109, 97, 116, 0,
-        100, 105, 115, 97, 98, 108, 101, 100, 0, 105, 110, 104, 101, 114, 105, 116, 0, 112, 105,
-        110, 110, 101, 100, 0, 101, 120, 99, 108, 117, 115, 105, 118, 101, 0, 101, 120, 99, 108,
-        117, 100, 101, 95, 117, 115, 101, 114, 0, 101, 120, 99, 108, 117, 100, 101, 95, 107, 101,
-        114, 110, 101, 108, 0, 101, 120, 99, 108, 117, 100, 101, 95, 104, 118, 0, 101, 120, 99,
-        108, 117, 100, 101, 95, 105, 100, 108, 101, 0, 109, 109, 97, 112, 0, 102, 114, 101, 113, 0,
-        105, 110, 104, 101, 114, 105, 116, 95, 115, 116, 97, 116, 0, 101, 110, 97, 98, 108, 101,
-        95, 111, 110, 95, 101, 120, 101, 99, 0, 119, 97, 116, 101, 114, 109, 97, 114, 107, 0, 112,
-        114, 101, 99, 105, 115, 101, 95, 105, 112, 0, 109, 109, 97, 112, 95, 100, 97, 116, 97, 0,
-        115, 97, 109, 112, 108, 101, 95, 105, 100, 95, 97, 108, 108, 0, 101, 120, 99, 108, 117,
-        100, 101, 95, 104, 111, 115, 116, 0, 101, 120, 99, 108, 117, 100, 101, 95, 103, 117, 101,
-        115, 116, 0, 101, 120, 99, 108, 117, 100, 101, 95, 99, 97, 108, 108, 99, 104, 97, 105, 110,
-        95, 107, 101, 114, 110, 101, 108, 0, 101, 120, 99, 108, 117, 100, 101, 95, 99, 97, 108,
-        108, 99, 104, 97, 105, 110, 95, 117, 115, 101, 114, 0, 109, 109, 97, 112, 50, 0, 99, 111,
-        109, 109, 95, 101, 120, 101, 99, 0, 117, 115, 101, 95, 99, 108, 111, 99, 107, 105, 100, 0,
-        99, 111, 110, 116, 101, 120, 116, 95, 115, 119, 105, 116, 99, 104, 0, 119, 114, 105, 116,
-        101, 95, 98, 97, 99, 107, 119, 97, 114, 100, 0, 110, 97, 109, 101, 115, 112, 97, 99, 101,
-        115, 0, 107, 115, 121, 109, 98, 111, 108, 0, 98, 112, 102, 95, 101, 118, 101, 110, 116, 0,
-        97, 117, 120, 95, 111, 117, 116, 112, 117, 116, 0, 99, 103, 114, 111, 117, 112, 0, 116,
-        101, 120, 116, 95, 112, 111, 107, 101, 0, 98, 117, 105, 108, 100, 95, 105, 100, 0, 105,
-        110, 104, 101, 114, 105, 116, 95, 116, 104, 114, 101, 97, 100, 0, 114, 101, 109, 111, 118,
-        101, 95, 111, 110, 95, 101, 120, 101, 99, 0, 115, 105, 103, 116, 114, 97, 112, 0, 95, 95,
-        114, 101, 115, 101, 114, 118, 101, 100, 95, 49, 0, 98, 112, 95, 116, 121, 112, 101, 0, 98,
-        114, 97, 110, 99, 104, 95, 115, 97, 109, 112, 108, 101, 95, 116, 121, 112, 101, 0, 115, 97,
-        109, 112, 108, 101, 95, 114, 101, 103, 115, 95, 117, 115, 101, 114, 0, 115, 97, 109, 112,
-        108, 101, 95, 115, 116, 97, 99, 107, 95, 117, 115, 101, 114, 0, 115, 97, 109, 112, 108,
-        101, 95, 114, 101, 103, 115, 95, 105, 110, 116, 114, 0, 97, 117, 120, 95, 119, 97, 116,
-        101, 114, 109, 97, 114, 107, 0, 115, 97, 109, 112, 108, 101, 95, 109, 97, 120, 95, 115,
-        116, 97, 99, 107, 0, 95, 95, 114, 101, 115, 101, 114, 118, 101, 100, 95, 50, 0, 97, 117,
-        120, 95, 115, 97, 109, 112, 108, 101, 95, 115, 105, 122, 101, 0, 95, 95, 114, 101, 115,
-        101, 114, 118, 101, 100, 95, 51, 0, 115, 105, 103, 95, 100, 97, 116, 97, 0, 115, 97, 109,
-        112, 108, 101, 95, 102, 114, 101, 113, 0, 119, 97, 107, 101, 117, 112, 95, 101, 118, 101,
-        110, 116, 115, 0, 119, 97, 107, 101, 117, 112, 95, 119, 97, 116, 101, 114, 109, 97, 114,
-        107, 0, 98, 112, 95, 97, 100, 100, 114, 0, 107, 112, 114, 111, 98, 101, 95, 102, 117, 110,
-        99, 0, 117, 112, 114, 111, 98, 101, 95, 112, 97, 116, 104, 0, 99, 111, 110, 102, 105, 103,
-        49, 0, 98, 112, 95, 108, 101, 110, 0, 107, 112, 114, 111, 98, 101, 95, 97, 100, 100, 114,
-        0, 112, 114, 111, 98, 101, 95, 111, 102, 102, 115, 101, 116, 0, 99, 111, 110, 102, 105,
-        103, 50, 0, 95, 95, 115, 51, 50, 0, 104, 119, 95, 112, 101, 114, 102, 95, 101, 118, 101,
-        110, 116, 0, 116, 97, 114, 103, 101, 116, 0, 112, 114, 101, 118, 95, 99, 111, 117, 110,
-        116, 0, 105, 110, 116, 101, 114, 114, 117, 112, 116, 115, 95, 115, 101, 113, 0, 105, 110,
-        116, 101, 114, 114, 117, 112, 116, 115, 0, 102, 114, 101, 113, 95, 116, 105, 109, 101, 95,
-        115, 116, 97, 109, 112, 0, 102, 114, 101, 113, 95, 99, 111, 117, 110, 116, 95, 115, 116,
-        97, 109, 112, 0, 108, 97, 115, 116, 95, 116, 97, 103, 0, 99, 111, 110, 102, 105, 103, 95,
-        98, 97, 115, 101, 0, 101, 118, 101, 110, 116, 95, 98, 97, 115, 101, 0, 101, 118, 101, 110,
-        116, 95, 98, 97, 115, 101, 95, 114, 100, 112, 109, 99, 0, 105, 100, 120, 0, 108, 97, 115,
-        116, 95, 99, 112, 117, 0, 101, 120, 116, 114, 97, 95, 114, 101, 103, 0, 98, 114, 97, 110,
-        99, 104, 95, 114, 101, 103, 0, 104, 119, 95, 112, 101, 114, 102, 95, 101, 118, 101, 110,
-        116, 95, 101, 120, 116, 114, 97, 0, 114, 101, 103, 0, 97, 108, 108, 111, 99, 0, 116, 112,
-        95, 108, 105, 115, 116, 0, 112, 119, 114, 95, 97, 99, 99, 0, 112, 116, 115, 99, 0, 105,
-        110, 102, 111, 0, 98, 112, 95, 108, 105, 115, 116, 0, 97, 114, 99, 104, 95, 104, 119, 95,
-        98, 114, 101, 97, 107, 112, 111, 105, 110, 116, 0, 97, 100, 100, 114, 101, 115, 115, 0,
-        109, 97, 115, 107, 0, 105, 111, 109, 109, 117, 95, 98, 97, 110, 107, 0, 105, 111, 109, 109,
-        117, 95, 99, 110, 116, 114, 0, 112, 97, 100, 100, 105, 110, 103, 0, 99, 111, 110, 102, 0,
-        99, 111, 110, 102, 49, 0, 108, 97, 115, 116, 95, 112, 101, 114, 105, 111, 100, 0, 112, 101,
-        114, 105, 111, 100, 95, 108, 101, 102, 116, 0, 115, 97, 118, 101, 100, 95, 109, 101, 116,
-        114, 105, 99, 0, 115, 97, 118, 101, 100, 95, 115, 108, 111, 116, 115, 0, 119, 97, 105, 116,
-        95, 113, 117, 101, 117, 101, 95, 104, 101, 97, 100, 95, 116, 0, 119, 97, 105, 116, 95, 113,
-        117, 101, 117, 101, 95, 104, 101, 97, 100, 0, 104, 101, 97, 100, 0, 105, 114, 113, 95, 119,
-        111, 114, 107, 0, 105, 114, 113, 119, 97, 105, 116, 0, 114, 99, 117, 119, 97, 105, 116, 0,
-        112, 101, 114, 102, 95, 97, 100, 100, 114, 95, 102, 105, 108, 116, 101, 114, 115, 95, 104,
-        101, 97, 100, 0, 110, 114, 95, 102, 105, 108, 101, 95, 102, 105, 108, 116, 101, 114, 115,
-        0, 112, 101, 114, 102, 95, 111, 118, 101, 114, 102, 108, 111, 119, 95, 104, 97, 110, 100,
-        108, 101, 114, 95, 116, 0, 112, 101, 114, 102, 95, 115, 97, 109, 112, 108, 101, 95, 100,
-        97, 116, 97, 0, 114, 97, 119, 0, 98, 114, 95, 115, 116, 97, 99, 107, 0, 112, 101, 114, 105,
-        111, 100, 0, 116, 120, 110, 0, 100, 97, 116, 97, 95, 115, 114, 99, 0, 116, 105, 100, 95,
-        101, 110, 116, 114, 121, 0, 115, 116, 114, 101, 97, 109, 95, 105, 100, 0, 99, 112, 117, 95,
-        101, 110, 116, 114, 121, 0, 99, 97, 108, 108, 99, 104, 97, 105, 110, 0, 97, 117, 120, 95,
-        115, 105, 122, 101, 0, 114, 101, 103, 115, 95, 117, 115, 101, 114, 0, 114, 101, 103, 115,
-        95, 105, 110, 116, 114, 0, 115, 116, 97, 99, 107, 95, 117, 115, 101, 114, 95, 115, 105,
-        122, 101, 0, 112, 104, 121, 115, 95, 97, 100, 100, 114, 0, 100, 97, 116, 97, 95, 112, 97,
-        103, 101, 95, 115, 105, 122, 101, 0, 99, 111, 100, 101, 95, 112, 97, 103, 101, 95, 115,
-        105, 122, 101, 0, 112, 101, 114, 102, 95, 115, 97, 109, 112, 108, 101, 95, 119, 101, 105,
-        103, 104, 116, 0, 102, 117, 108, 108, 0, 118, 97, 114, 49, 95, 100, 119, 0, 118, 97, 114,
-        50, 95, 119, 0, 118, 97, 114, 51, 95, 119, 0, 112, 101, 114, 102, 95, 109, 101, 109, 95,
-        100, 97, 116, 97, 95, 115, 114, 99, 0, 109, 101, 109, 95, 111, 112, 0, 109, 101, 109, 95,
-        108, 118, 108, 0, 109, 101, 109, 95, 115, 110, 111, 111, 112, 0, 109, 101, 109, 95, 108,
-        111, 99, 107, 0, 109, 101, 109, 95, 100, 116, 108, 98, 0, 109, 101, 109, 95, 108, 118, 108,
-        95, 110, 117, 109, 0, 109, 101, 109, 95, 114, 101, 109, 111, 116, 101, 0, 109, 101, 109,
-        95, 115, 110, 111, 111, 112, 120, 0, 109, 101, 109, 95, 98, 108, 107, 0, 109, 101, 109, 95,
-        104, 111, 112, 115, 0, 109, 101, 109, 95, 114, 115, 118, 100, 0, 114, 101, 115, 101, 114,
-        118, 101, 100, 0, 112, 101, 114, 102, 95, 114, 101, 103, 115, 0, 97, 98, 105, 0, 112, 114,
-        105, 118, 97, 116, 101, 0, 115, 97, 118, 101, 100, 95, 102, 117, 110, 99, 0, 108, 111, 99,
-        97, 108, 95, 104, 97, 115, 104, 0, 102, 117, 110, 99, 95, 104, 97, 115, 104, 0, 111, 108,
-        100, 95, 104, 97, 115, 104, 0, 116, 114, 97, 109, 112, 111, 108, 105, 110, 101, 0, 116,
-        114, 97, 109, 112, 111, 108, 105, 110, 101, 95, 115, 105, 122, 101, 0, 111, 112, 115, 95,
-        102, 117, 110, 99, 0, 102, 116, 114, 97, 99, 101, 95, 102, 117, 110, 99, 95, 116, 0, 102,
-        116, 114, 97, 99, 101, 95, 114, 101, 103, 115, 0, 102, 116, 114, 97, 99, 101, 95, 111, 112,
-        115, 95, 104, 97, 115, 104, 0, 110, 111, 116, 114, 97, 99, 101, 95, 104, 97, 115, 104, 0,
-        102, 105, 108, 116, 101, 114, 95, 104, 97, 115, 104, 0, 114, 101, 103, 101, 120, 95, 108,
-        111, 99, 107, 0, 102, 116, 114, 97, 99, 101, 95, 111, 112, 115, 95, 102, 117, 110, 99, 95,
-        116, 0, 102, 116, 114, 97, 99, 101, 95, 111, 112, 115, 95, 99, 109, 100, 0, 70, 84, 82, 65,
-        67, 69, 95, 79, 80, 83, 95, 67, 77, 68, 95, 69, 78, 65, 66, 76, 69, 95, 83, 72, 65, 82, 69,
-        95, 73, 80, 77, 79, 68, 73, 70, 89, 95, 83, 69, 76, 70, 0, 70, 84, 82, 65, 67, 69, 95, 79,
-        80, 83, 95, 67, 77, 68, 95, 69, 78, 65, 66, 76, 69, 95, 83, 72, 65, 82, 69, 95, 73, 80, 77,
-        79, 68, 73, 70, 89, 95, 80, 69, 69, 82, 0, 70, 84, 82, 65, 67, 69, 95, 79, 80, 83, 95, 67,
-        77, 68, 95, 68, 73, 83, 65, 66, 76, 69, 95, 83, 72, 65, 82, 69, 95, 73, 80, 77, 79, 68, 73,
-        70, 89, 95, 80, 69, 69, 82, 0, 97, 118, 120, 53, 49, 50, 95, 116, 105, 109, 101, 115, 116,
-        97, 109, 112, 0, 102, 112, 115, 116, 97, 116, 101, 0, 95, 95, 116, 97, 115, 107, 95, 102,
-        112, 115, 116, 97, 116, 101, 0, 112, 101, 114, 109, 0, 103, 117, 101, 115, 116, 95, 112,
-        101, 114, 109, 0, 95, 95, 102, 112, 115, 116, 97, 116, 101, 0, 102, 112, 117, 95, 115, 116,
-        97, 116, 101, 95, 112, 101, 114, 109, 0, 95, 95, 115, 116, 97, 116, 101, 95, 112, 101, 114,
-        109, 0, 95, 95, 115, 116, 97, 116, 101, 95, 115, 105, 122, 101, 0, 95, 95, 117, 115, 101,
-        114, 95, 115, 116, 97, 116, 101, 95, 115, 105, 122, 101, 0, 117, 115, 101, 114, 95, 115,
-        105, 122, 101, 0, 120, 102, 101, 97, 116, 117, 114, 101, 115, 0, 117, 115, 101, 114, 95,
-        120, 102, 101, 97, 116, 117, 114, 101, 115, 0, 120, 102, 100, 0, 105, 115, 95, 118, 97,
-        108, 108, 111, 99, 0, 105, 115, 95, 103, 117, 101, 115, 116, 0, 105, 115, 95, 99, 111, 110,
-        102, 105, 100, 101, 110, 116, 105, 97, 108, 0, 105, 110, 95, 117, 115, 101, 0, 102, 112,
-        114, 101, 103, 115, 95, 115, 116, 97, 116, 101, 0, 102, 115, 97, 118, 101, 0, 102, 120,
-        115, 97, 118, 101, 0, 115, 111, 102, 116, 0, 120, 115, 97, 118, 101, 0, 95, 95, 112, 97,
-        100, 100, 105, 110, 103, 0, 102, 114, 101, 103, 115, 95, 115, 116, 97, 116, 101, 0, 99,
-        119, 100, 0, 115, 119, 100, 0, 116, 119, 100, 0, 102, 105, 112, 0, 102, 99, 115, 0, 102,
-        111, 111, 0, 102, 111, 115, 0, 115, 116, 95, 115, 112, 97, 99, 101, 0, 102, 120, 114, 101,
-        103, 115, 95, 115, 116, 97, 116, 101, 0, 102, 111, 112, 0, 109, 120, 99, 115, 114, 0, 109,
-        120, 99, 115, 114, 95, 109, 97, 115, 107, 0, 120, 109, 109, 95, 115, 112, 97, 99, 101, 0,
-        114, 105, 112, 0, 114, 100, 112, 0, 112, 97, 100, 100, 105, 110, 103, 49, 0, 115, 119, 95,
-        114, 101, 115, 101, 114, 118, 101, 100, 0, 115, 119, 114, 101, 103, 115, 95, 115, 116, 97,
-        116, 101, 0, 102, 116, 111, 112, 0, 99, 104, 97, 110, 103, 101, 100, 0, 108, 111, 111, 107,
-        97, 104, 101, 97, 100, 0, 110, 111, 95, 117, 112, 100, 97, 116, 101, 0, 114, 109, 0, 97,
-        108, 105, 109, 105, 116, 0, 101, 110, 116, 114, 121, 95, 101, 105, 112, 0, 120, 114, 101,
-        103, 115, 95, 115, 116, 97, 116, 101, 0, 105, 51, 56, 55, 0, 104, 101, 97, 100, 101, 114,
-        0, 101, 120, 116, 101, 110, 100, 101, 100, 95, 115, 116, 97, 116, 101, 95, 97, 114, 101,
-        97, 0, 120, 115, 116, 97, 116, 101, 95, 104, 101, 97, 100, 101, 114, 0, 120, 99, 111, 109,
-        112, 95, 98, 118, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97,
-        99, 107, 0, 118, 101, 114, 98, 111, 115, 101, 0, 101, 118, 101, 110, 116, 0, 117, 105, 100,
-        0, 109, 115, 103, 0, 95, 101, 118, 101, 110, 116, 0, 111, 110, 95, 101, 118, 101, 110, 116,
-        46, 95, 95, 95, 95, 102, 109, 116, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95,
-        95, 95, 102, 109, 116, 46, 49, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95,
-        95, 102, 109, 116, 46, 50, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95,
-        102, 109, 116, 46, 51, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102,
-        109, 116, 46, 52, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109,
-        116, 46, 53, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116,
-        46, 54, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46,
-        55, 0, 111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 56, 0,
-        111, 110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 57, 0, 111,
-        110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 48, 0, 111,
-        110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 49, 0, 111,
-        110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 50, 0, 111,
-        110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 51, 0, 111,
-        110, 95, 101, 118, 101, 110, 116, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 52, 0, 119,
-        97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95,
-        95, 102, 109, 116, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116,
-        97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 53, 0, 119, 97, 108, 107, 95, 112,
-        121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46,
-        49, 54, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107,
-        46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 55, 0, 119, 97, 108, 107, 95, 112, 121, 116,
-        104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 49, 56, 0,
-        119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95,
-        95, 95, 102, 109, 116, 46, 49, 57, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110,
-        95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 48, 0, 119, 97, 108,
-        107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102,
-        109, 116, 46, 50, 49, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116,
-        97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 50, 0, 119, 97, 108, 107, 95, 112,
-        121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46,
-        50, 51, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107,
-        46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 52, 0, 119, 97, 108, 107, 95, 112, 121, 116,
-        104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 53, 0,
-        119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95,
-        95, 95, 102, 109, 116, 46, 50, 54, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110,
-        95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 55, 0, 119, 97, 108,
-        107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102,
-        109, 116, 46, 50, 56, 0, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116,
-        97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46, 50, 57, 0, 119, 97, 108, 107, 95, 112,
-        121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 46, 95, 95, 95, 95, 102, 109, 116, 46,
-        51, 48, 0, 98, 112, 102, 95, 109, 101, 116, 97, 100, 97, 116, 97, 95, 110, 97, 109, 101, 0,
-        86, 69, 82, 83, 73, 79, 78, 0, 76, 73, 67, 69, 78, 83, 69, 0, 98, 112, 102, 95, 109, 97,
-        112, 95, 108, 111, 111, 107, 117, 112, 95, 111, 114, 95, 116, 114, 121, 95, 105, 110, 105,
-        116, 46, 95, 95, 95, 95, 102, 109, 116, 0, 115, 117, 98, 109, 105, 116, 95, 115, 97, 109,
-        112, 108, 101, 46, 95, 95, 95, 95, 102, 109, 116, 0, 115, 117, 98, 109, 105, 116, 95, 115,
-        97, 109, 112, 108, 101, 46, 95, 95, 95, 95, 102, 109, 116, 46, 51, 49, 0, 103, 101, 116,
-        95, 115, 121, 109, 98, 111, 108, 95, 105, 100, 46, 95, 95, 95, 95, 102, 109, 116, 0, 95,
-        95, 107, 101, 114, 110, 101, 108, 95, 116, 105, 109, 101, 115, 112, 101, 99, 0, 97, 110,
-        111, 110, 95, 118, 109, 97, 95, 110, 97, 109, 101, 0, 98, 108, 107, 95, 112, 108, 117, 103,
-        0, 98, 112, 102, 95, 108, 111, 99, 97, 108, 95, 115, 116, 111, 114, 97, 103, 101, 0, 98,
-        112, 102, 95, 112, 114, 111, 103, 0, 98, 112, 102, 95, 114, 117, 110, 95, 99, 116, 120, 0,
-        99, 111, 109, 112, 97, 116, 95, 114, 111, 98, 117, 115, 116, 95, 108, 105, 115, 116, 95,
-        104, 101, 97, 100, 0, 99, 111, 109, 112, 108, 101, 116, 105, 111, 110, 0, 99, 115, 115, 95,
-        115, 101, 116, 0, 101, 118, 101, 110, 116, 95, 102, 105, 108, 116, 101, 114, 0, 102, 97,
-        115, 121, 110, 99, 95, 115, 116, 114, 117, 99, 116, 0, 102, 105, 108, 101, 115, 95, 115,
-        116, 114, 117, 99, 116, 0, 102, 115, 95, 115, 116, 114, 117, 99, 116, 0, 102, 116, 114, 97,
-        99, 101, 95, 104, 97, 115, 104, 0, 102, 116, 114, 97, 99, 101, 95, 114, 101, 116, 95, 115,
-        116, 97, 99, 107, 0, 102, 117, 116, 101, 120, 95, 112, 105, 95, 115, 116, 97, 116, 101, 0,
-        104, 114, 116, 105, 109, 101, 114, 95, 99, 108, 111, 99, 107, 95, 98, 97, 115, 101, 0, 105,
-        111, 95, 117, 114, 105, 110, 103, 95, 116, 97, 115, 107, 0, 107, 101, 114, 110, 101, 108,
-        95, 115, 105, 103, 105, 110, 102, 111, 0, 107, 117, 110, 105, 116, 0, 109, 97, 116, 104,
-        95, 101, 109, 117, 95, 105, 110, 102, 111, 0, 109, 101, 109, 95, 99, 103, 114, 111, 117,
-        112, 0, 109, 109, 95, 115, 116, 114, 117, 99, 116, 0, 111, 108, 100, 95, 116, 105, 109,
-        101, 115, 112, 101, 99, 51, 50, 0, 112, 101, 114, 102, 95, 97, 100, 100, 114, 95, 102, 105,
-        108, 116, 101, 114, 95, 114, 97, 110, 103, 101, 0, 112, 101, 114, 102, 95, 98, 114, 97,
-        110, 99, 104, 95, 115, 116, 97, 99, 107, 0, 112, 101, 114, 102, 95, 98, 117, 102, 102, 101,
-        114, 0, 112, 101, 114, 102, 95, 99, 97, 108, 108, 99, 104, 97, 105, 110, 95, 101, 110, 116,
-        114, 121, 0, 112, 101, 114, 102, 95, 99, 103, 114, 111, 117, 112, 0, 112, 101, 114, 102,
-        95, 114, 97, 119, 95, 114, 101, 99, 111, 114, 100, 0, 112, 105, 100, 95, 110, 97, 109, 101,
-        115, 112, 97, 99, 101, 0, 112, 105, 112, 101, 95, 105, 110, 111, 100, 101, 95, 105, 110,
-        102, 111, 0, 112, 111, 108, 108, 102, 100, 0, 114, 99, 117, 95, 110, 111, 100, 101, 0, 114,
-        101, 113, 117, 101, 115, 116, 95, 113, 117, 101, 117, 101, 0, 114, 111, 98, 117, 115, 116,
-        95, 108, 105, 115, 116, 95, 104, 101, 97, 100, 0, 114, 116, 95, 109, 117, 116, 101, 120,
-        95, 119, 97, 105, 116, 101, 114, 0, 115, 101, 99, 99, 111, 109, 112, 95, 102, 105, 108,
-        116, 101, 114, 0, 115, 101, 109, 95, 117, 110, 100, 111, 95, 108, 105, 115, 116, 0, 115,
-        105, 103, 104, 97, 110, 100, 95, 115, 116, 114, 117, 99, 116, 0, 115, 105, 103, 110, 97,
-        108, 95, 115, 116, 114, 117, 99, 116, 0, 116, 97, 115, 107, 95, 100, 101, 108, 97, 121, 95,
-        105, 110, 102, 111, 0, 116, 97, 115, 107, 95, 103, 114, 111, 117, 112, 0, 116, 114, 97, 99,
-        101, 95, 101, 118, 101, 110, 116, 95, 99, 97, 108, 108, 0, 117, 112, 114, 111, 98, 101, 95,
-        116, 97, 115, 107, 0, 117, 115, 101, 114, 102, 97, 117, 108, 116, 102, 100, 95, 99, 116,
-        120, 0, 118, 109, 95, 111, 112, 101, 114, 97, 116, 105, 111, 110, 115, 95, 115, 116, 114,
-        117, 99, 116, 0, 118, 109, 95, 115, 116, 114, 117, 99, 116, 0, 47, 104, 111, 109, 101, 47,
-        107, 97, 107, 107, 111, 121, 117, 110, 47, 87, 111, 114, 107, 115, 112, 97, 99, 101, 47,
-        80, 114, 111, 106, 101, 99, 116, 115, 47, 112, 121, 45, 112, 101, 114, 102, 47, 46, 47,
-        115, 114, 99, 47, 98, 112, 102, 47, 112, 121, 112, 101, 114, 102, 46, 98, 112, 102, 46, 99,
-        0, 105, 110, 116, 32, 111, 110, 95, 101, 118, 101, 110, 116, 40, 115, 116, 114, 117, 99,
-        116, 32, 98, 112, 102, 95, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 100, 97,
-        116, 97, 32, 42, 99, 116, 120, 41, 32, 123, 0, 32, 32, 32, 32, 117, 54, 52, 32, 112, 105,
-        100, 95, 116, 103, 105, 100, 32, 61, 32, 98, 112, 102, 95, 103, 101, 116, 95, 99, 117, 114,
-        114, 101, 110, 116, 95, 112, 105, 100, 95, 116, 103, 105, 100, 40, 41, 59, 0, 32, 32, 32,
-        32, 112, 105, 100, 95, 116, 32, 112, 105, 100, 32, 61, 32, 112, 105, 100, 95, 116, 103,
-        105, 100, 32, 62, 62, 32, 51, 50, 59, 0, 32, 32, 32, 32, 105, 102, 32, 40, 112, 105, 100,
-        32, 61, 61, 32, 48, 41, 32, 123, 0, 32, 32, 32, 32, 80, 114, 111, 99, 101, 115, 115, 73,
-        110, 102, 111, 32, 42, 112, 114, 111, 99, 101, 115, 115, 95, 105, 110, 102, 111, 32, 61,
-        32, 98, 112, 102, 95, 109, 97, 112, 95, 108, 111, 111, 107, 117, 112, 95, 101, 108, 101,
-        109, 40, 38, 112, 105, 100, 95, 116, 111, 95, 112, 114, 111, 99, 101, 115, 115, 95, 105,
-        110, 102, 111, 44, 32, 38, 112, 105, 100, 41, 59, 0, 32, 32, 32, 32, 105, 102, 32, 40, 33,
-        112, 114, 111, 99, 101, 115, 115, 95, 105, 110, 102, 111, 41, 32, 123, 0, 32, 32, 32, 32,
-        76, 79, 71, 40, 34, 91, 115, 116, 97, 114, 116, 93, 34, 41, 59, 0, 32, 32, 32, 32, 76, 79,
-        71, 40, 34, 91, 101, 118, 101, 110, 116, 93, 32, 112, 105, 100, 61, 37, 100, 32, 116, 105,
-        100, 61, 37, 100, 34, 44, 32, 112, 105, 100, 44, 32, 116, 105, 100, 41, 59, 0, 32, 32, 32,
-        32, 105, 102, 32, 40, 112, 114, 111, 99, 101, 115, 115, 95, 105, 110, 102, 111, 45, 62,
-        116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 95, 97, 100, 100, 114, 32, 61, 61,
-        32, 48, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 91, 101, 114,
-        114, 111, 114, 93, 32, 112, 114, 111, 99, 101, 115, 115, 95, 105, 110, 102, 111, 46, 116,
-        104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 95, 97, 100, 100, 114, 32, 119, 97,
-        115, 32, 78, 85, 76, 76, 34, 41, 59, 0, 32, 32, 32, 32, 71, 69, 84, 95, 83, 84, 65, 84, 69,
-        40, 41, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 112, 114, 111, 99, 101, 115,
-        115, 95, 105, 110, 102, 111, 32, 61, 32, 40, 80, 114, 111, 99, 101, 115, 115, 73, 110, 102,
-        111, 41, 123, 48, 125, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 112, 114,
-        111, 99, 101, 115, 115, 95, 105, 110, 102, 111, 32, 61, 32, 42, 112, 114, 111, 99, 101,
-        115, 115, 95, 105, 110, 102, 111, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62,
-        102, 114, 97, 109, 101, 95, 112, 116, 114, 32, 61, 32, 48, 59, 0, 32, 32, 32, 32, 115, 116,
-        97, 116, 101, 45, 62, 115, 97, 109, 112, 108, 101, 32, 61, 32, 40, 83, 97, 109, 112, 108,
-        101, 41, 123, 48, 125, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 115, 97, 109,
-        112, 108, 101, 46, 116, 105, 109, 101, 115, 116, 97, 109, 112, 32, 61, 32, 98, 112, 102,
-        95, 107, 116, 105, 109, 101, 95, 103, 101, 116, 95, 110, 115, 40, 41, 59, 0, 32, 32, 32,
-        32, 115, 116, 97, 116, 101, 45, 62, 115, 97, 109, 112, 108, 101, 46, 116, 105, 100, 32, 61,
-        32, 116, 105, 100, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 115, 97, 109,
-        112, 108, 101, 46, 112, 105, 100, 32, 61, 32, 112, 105, 100, 59, 0, 32, 32, 32, 32, 115,
-        116, 97, 116, 101, 45, 62, 115, 97, 109, 112, 108, 101, 46, 99, 112, 117, 32, 61, 32, 98,
-        112, 102, 95, 103, 101, 116, 95, 115, 109, 112, 95, 112, 114, 111, 99, 101, 115, 115, 111,
-        114, 95, 105, 100, 40, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 103, 101, 116, 95, 99,
-        117, 114, 114, 101, 110, 116, 95, 99, 111, 109, 109, 40, 38, 115, 116, 97, 116, 101, 45,
-        62, 115, 97, 109, 112, 108, 101, 46, 99, 111, 109, 109, 44, 32, 115, 105, 122, 101, 111,
-        102, 40, 115, 116, 97, 116, 101, 45, 62, 115, 97, 109, 112, 108, 101, 46, 99, 111, 109,
-        109, 41, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 46, 112, 105, 100, 32, 61, 32, 112,
-        105, 100, 44, 0, 32, 32, 32, 32, 32, 32, 32, 32, 46, 117, 115, 101, 114, 95, 115, 116, 97,
-        99, 107, 95, 105, 100, 32, 61, 32, 98, 112, 102, 95, 103, 101, 116, 95, 115, 116, 97, 99,
-        107, 105, 100, 40, 99, 116, 120, 44, 32, 38, 115, 116, 97, 99, 107, 95, 116, 114, 97, 99,
-        101, 115, 44, 32, 66, 80, 70, 95, 70, 95, 85, 83, 69, 82, 95, 83, 84, 65, 67, 75, 41, 44,
-        0, 32, 32, 32, 32, 32, 32, 32, 32, 46, 107, 101, 114, 110, 101, 108, 95, 115, 116, 97, 99,
-        107, 95, 105, 100, 32, 61, 32, 98, 112, 102, 95, 103, 101, 116, 95, 115, 116, 97, 99, 107,
-        105, 100, 40, 99, 116, 120, 44, 32, 38, 115, 116, 97, 99, 107, 95, 116, 114, 97, 99, 101,
-        115, 44, 32, 48, 41, 44, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 115, 97, 109,
-        112, 108, 101, 46, 115, 116, 97, 99, 107, 95, 115, 116, 97, 116, 117, 115, 32, 61, 32, 83,
-        84, 65, 67, 75, 95, 69, 82, 82, 79, 82, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45,
-        62, 115, 97, 109, 112, 108, 101, 46, 110, 97, 116, 105, 118, 101, 95, 115, 116, 97, 99,
-        107, 95, 99, 111, 117, 110, 116, 95, 107, 101, 121, 32, 61, 32, 40, 115, 116, 97, 99, 107,
-        95, 99, 111, 117, 110, 116, 95, 107, 101, 121, 95, 116, 41, 123, 0, 32, 32, 32, 32, 115,
-        116, 97, 116, 101, 45, 62, 115, 97, 109, 112, 108, 101, 46, 115, 116, 97, 99, 107, 46, 108,
-        101, 110, 32, 61, 32, 48, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 115, 97,
-        109, 112, 108, 101, 46, 101, 114, 114, 111, 114, 95, 99, 111, 100, 101, 32, 61, 32, 69, 82,
-        82, 79, 82, 95, 78, 79, 78, 69, 59, 0, 32, 32, 32, 32, 118, 97, 108, 32, 61, 32, 98, 112,
-        102, 95, 109, 97, 112, 95, 108, 111, 111, 107, 117, 112, 95, 101, 108, 101, 109, 40, 109,
-        97, 112, 44, 32, 107, 101, 121, 41, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62,
-        116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 32, 61, 32, 48, 59, 0, 32, 32, 32,
-        32, 105, 102, 32, 40, 118, 97, 108, 41, 32, 123, 0, 32, 32, 32, 32, 101, 114, 114, 32, 61,
-        32, 98, 112, 102, 95, 109, 97, 112, 95, 117, 112, 100, 97, 116, 101, 95, 101, 108, 101,
-        109, 40, 109, 97, 112, 44, 32, 107, 101, 121, 44, 32, 105, 110, 105, 116, 44, 32, 66, 80,
-        70, 95, 78, 79, 69, 88, 73, 83, 84, 41, 59, 0, 32, 32, 32, 32, 105, 102, 32, 40, 101, 114,
-        114, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 91, 101, 114, 114,
-        111, 114, 93, 32, 98, 112, 102, 95, 109, 97, 112, 95, 108, 111, 111, 107, 117, 112, 95,
-        111, 114, 95, 116, 114, 121, 95, 105, 110, 105, 116, 32, 119, 105, 116, 104, 32, 114, 101,
-        116, 58, 32, 37, 100, 34, 44, 32, 101, 114, 114, 41, 59, 0, 32, 32, 32, 32, 114, 101, 116,
-        117, 114, 110, 32, 98, 112, 102, 95, 109, 97, 112, 95, 108, 111, 111, 107, 117, 112, 95,
-        101, 108, 101, 109, 40, 109, 97, 112, 44, 32, 107, 101, 121, 41, 59, 0, 32, 32, 32, 32,
-        105, 102, 32, 40, 115, 99, 111, 117, 110, 116, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32,
-        32, 95, 95, 115, 121, 110, 99, 95, 102, 101, 116, 99, 104, 95, 97, 110, 100, 95, 97, 100,
-        100, 40, 115, 99, 111, 117, 110, 116, 44, 32, 49, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32,
-        32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 40, 118, 111, 105, 100,
-        32, 42, 41, 40, 108, 111, 110, 103, 41, 112, 114, 111, 99, 101, 115, 115, 95, 105, 110,
-        102, 111, 45, 62, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 95, 97, 100,
-        100, 114, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101, 95, 114,
-        101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 115, 116, 97, 116, 101, 45, 62, 116, 104,
-        114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 44, 32, 115, 105, 122, 101, 111, 102, 40,
-        115, 116, 97, 116, 101, 45, 62, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101,
-        41, 44, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34, 112, 114, 111, 99, 101, 115, 115, 95, 105,
-        110, 102, 111, 45, 62, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 95, 97,
-        100, 100, 114, 32, 48, 120, 37, 108, 108, 120, 34, 44, 32, 112, 114, 111, 99, 101, 115,
-        115, 95, 105, 110, 102, 111, 45, 62, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116,
-        101, 95, 97, 100, 100, 114, 41, 59, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34, 116, 104, 114,
-        101, 97, 100, 95, 115, 116, 97, 116, 101, 32, 48, 120, 37, 108, 108, 120, 34, 44, 32, 115,
-        116, 97, 116, 101, 45, 62, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 41, 59,
-        0, 32, 32, 32, 32, 115, 116, 114, 117, 99, 116, 32, 116, 97, 115, 107, 95, 115, 116, 114,
-        117, 99, 116, 32, 42, 116, 97, 115, 107, 32, 61, 32, 40, 115, 116, 114, 117, 99, 116, 32,
-        116, 97, 115, 107, 95, 115, 116, 114, 117, 99, 116, 32, 42, 41, 98, 112, 102, 95, 103, 101,
-        116, 95, 99, 117, 114, 114, 101, 110, 116, 95, 116, 97, 115, 107, 40, 41, 59, 0, 32, 32,
-        32, 32, 108, 111, 110, 103, 32, 117, 110, 115, 105, 103, 110, 101, 100, 32, 105, 110, 116,
-        32, 116, 108, 115, 95, 98, 97, 115, 101, 32, 61, 32, 66, 80, 70, 95, 67, 79, 82, 69, 95,
-        82, 69, 65, 68, 40, 116, 97, 115, 107, 44, 32, 116, 104, 114, 101, 97, 100, 46, 102, 115,
-        98, 97, 115, 101, 41, 59, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34, 116, 108, 115, 95, 98, 97,
-        115, 101, 32, 48, 120, 37, 108, 108, 120, 34, 44, 32, 40, 118, 111, 105, 100, 32, 42, 41,
-        116, 108, 115, 95, 98, 97, 115, 101, 41, 59, 0, 32, 32, 32, 32, 71, 69, 84, 95, 79, 70, 70,
-        83, 69, 84, 83, 40, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32,
-        32, 32, 32, 32, 32, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 116, 104, 114, 101, 97,
-        100, 95, 115, 116, 97, 116, 101, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62,
-        112, 121, 95, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 46, 116, 104, 114,
-        101, 97, 100, 95, 105, 100, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98,
-        101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 112, 116, 104, 114, 101, 97,
-        100, 95, 99, 114, 101, 97, 116, 101, 100, 44, 32, 115, 105, 122, 101, 111, 102, 40, 112,
-        116, 104, 114, 101, 97, 100, 95, 99, 114, 101, 97, 116, 101, 100, 41, 44, 0, 32, 32, 32,
-        32, 105, 102, 32, 40, 112, 116, 104, 114, 101, 97, 100, 95, 99, 114, 101, 97, 116, 101,
-        100, 32, 61, 61, 32, 48, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40,
-        34, 91, 101, 114, 114, 111, 114, 93, 32, 112, 116, 104, 114, 101, 97, 100, 95, 99, 114,
-        101, 97, 116, 101, 100, 32, 119, 97, 115, 32, 78, 85, 76, 76, 34, 41, 59, 0, 32, 32, 32,
-        32, 76, 79, 71, 40, 34, 112, 116, 104, 114, 101, 97, 100, 95, 99, 114, 101, 97, 116, 101,
-        100, 32, 48, 120, 37, 108, 108, 120, 34, 44, 32, 112, 116, 104, 114, 101, 97, 100, 95, 99,
-        114, 101, 97, 116, 101, 100, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111,
-        98, 101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 112, 116, 104, 114, 101,
-        97, 100, 95, 115, 101, 108, 102, 44, 32, 115, 105, 122, 101, 111, 102, 40, 112, 116, 104,
-        114, 101, 97, 100, 95, 115, 101, 108, 102, 41, 44, 32, 40, 118, 111, 105, 100, 32, 42, 41,
-        116, 108, 115, 95, 98, 97, 115, 101, 32, 43, 32, 48, 120, 49, 48, 41, 59, 0, 32, 32, 32,
-        32, 105, 102, 32, 40, 112, 116, 104, 114, 101, 97, 100, 95, 115, 101, 108, 102, 32, 61, 61,
-        32, 48, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 91, 101, 114,
-        114, 111, 114, 93, 32, 112, 116, 104, 114, 101, 97, 100, 95, 115, 101, 108, 102, 32, 119,
-        97, 115, 32, 78, 85, 76, 76, 34, 41, 59, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34, 112, 116,
-        104, 114, 101, 97, 100, 95, 115, 101, 108, 102, 32, 48, 120, 37, 108, 108, 120, 34, 44, 32,
-        112, 116, 104, 114, 101, 97, 100, 95, 115, 101, 108, 102, 41, 59, 0, 32, 32, 32, 32, 105,
-        102, 32, 40, 115, 116, 97, 116, 101, 45, 62, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97,
-        116, 101, 32, 61, 61, 32, 48, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71,
-        40, 34, 91, 101, 114, 114, 111, 114, 93, 32, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97,
-        116, 101, 32, 119, 97, 115, 32, 78, 85, 76, 76, 34, 41, 59, 0, 32, 32, 32, 32, 105, 102,
-        32, 40, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 116, 104, 114, 101, 97,
-        100, 95, 115, 116, 97, 116, 101, 46, 102, 114, 97, 109, 101, 32, 62, 32, 45, 49, 41, 32,
-        123, 0, 32, 32, 32, 32, 105, 102, 32, 40, 115, 116, 97, 116, 101, 45, 62, 102, 114, 97,
-        109, 101, 95, 112, 116, 114, 32, 61, 61, 32, 48, 41, 32, 123, 0, 32, 32, 32, 32, 76, 79,
-        71, 40, 34, 102, 114, 97, 109, 101, 95, 112, 116, 114, 32, 48, 120, 37, 108, 108, 120, 34,
-        44, 32, 115, 116, 97, 116, 101, 45, 62, 102, 114, 97, 109, 101, 95, 112, 116, 114, 41, 59,
-        0, 32, 32, 32, 32, 98, 112, 102, 95, 116, 97, 105, 108, 95, 99, 97, 108, 108, 40, 99, 116,
-        120, 44, 32, 38, 112, 114, 111, 103, 114, 97, 109, 115, 44, 32, 80, 89, 80, 69, 82, 70, 95,
-        83, 84, 65, 67, 75, 95, 87, 65, 76, 75, 73, 78, 71, 95, 80, 82, 79, 71, 82, 65, 77, 95, 73,
-        68, 88, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98,
-        101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 99, 102, 114, 97, 109, 101, 44,
-        32, 115, 105, 122, 101, 111, 102, 40, 99, 102, 114, 97, 109, 101, 41, 44, 32, 40, 118, 111,
-        105, 100, 32, 42, 41, 40, 115, 116, 97, 116, 101, 45, 62, 116, 104, 114, 101, 97, 100, 95,
-        115, 116, 97, 116, 101, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112, 121,
-        95, 116, 104, 114, 101, 97, 100, 95, 115, 116, 97, 116, 101, 46, 99, 102, 114, 97, 109,
-        101, 41, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 105, 102, 32, 40, 99, 102, 114, 97,
-        109, 101, 32, 61, 61, 32, 48, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32,
-        32, 76, 79, 71, 40, 34, 91, 101, 114, 114, 111, 114, 93, 32, 99, 102, 114, 97, 109, 101,
-        32, 119, 97, 115, 32, 78, 85, 76, 76, 34, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 32,
-        32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 115, 97, 109, 112, 108, 101, 46, 101, 114, 114,
-        111, 114, 95, 99, 111, 100, 101, 32, 61, 32, 69, 82, 82, 79, 82, 95, 84, 83, 84, 65, 84,
-        69, 95, 67, 70, 82, 65, 77, 69, 95, 73, 83, 95, 78, 85, 76, 76, 59, 0, 32, 32, 32, 32, 32,
-        32, 32, 32, 76, 79, 71, 40, 34, 91, 101, 114, 114, 111, 114, 93, 32, 102, 114, 97, 109,
-        101, 95, 112, 116, 114, 32, 119, 97, 115, 32, 78, 85, 76, 76, 34, 41, 59, 0, 32, 32, 32,
-        32, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 115, 97, 109, 112, 108, 101, 46, 101,
-        114, 114, 111, 114, 95, 99, 111, 100, 101, 32, 61, 32, 69, 82, 82, 79, 82, 95, 69, 77, 80,
-        84, 89, 95, 83, 84, 65, 67, 75, 59, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34, 91, 115, 116,
-        111, 112, 93, 34, 41, 59, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34, 34, 41, 59, 0, 32, 32, 32,
-        32, 98, 112, 102, 95, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95, 111, 117, 116,
-        112, 117, 116, 40, 99, 116, 120, 44, 32, 38, 101, 118, 101, 110, 116, 115, 44, 32, 66, 80,
-        70, 95, 70, 95, 67, 85, 82, 82, 69, 78, 84, 95, 67, 80, 85, 44, 32, 38, 115, 116, 97, 116,
-        101, 45, 62, 115, 97, 109, 112, 108, 101, 44, 32, 115, 105, 122, 101, 111, 102, 40, 115,
-        116, 97, 116, 101, 45, 62, 115, 97, 109, 112, 108, 101, 41, 41, 59, 0, 125, 0, 32, 32, 32,
-        32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 99, 102, 114, 97, 109, 101, 32, 48, 120, 37, 108,
-        108, 120, 34, 44, 32, 99, 102, 114, 97, 109, 101, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32,
-        32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 40,
-        118, 111, 105, 100, 32, 42, 41, 40, 99, 102, 114, 97, 109, 101, 32, 43, 32, 111, 102, 102,
-        115, 101, 116, 115, 45, 62, 112, 121, 95, 99, 102, 114, 97, 109, 101, 46, 99, 117, 114,
-        114, 101, 110, 116, 95, 102, 114, 97, 109, 101, 41, 41, 59, 0, 105, 110, 116, 32, 119, 97,
-        108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 40, 115, 116, 114,
-        117, 99, 116, 32, 98, 112, 102, 95, 112, 101, 114, 102, 95, 101, 118, 101, 110, 116, 95,
-        100, 97, 116, 97, 32, 42, 99, 116, 120, 41, 32, 123, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34,
-        91, 115, 116, 97, 114, 116, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110,
-        95, 115, 116, 97, 99, 107, 34, 41, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62,
-        115, 116, 97, 99, 107, 95, 119, 97, 108, 107, 101, 114, 95, 112, 114, 111, 103, 95, 99, 97,
-        108, 108, 95, 99, 111, 117, 110, 116, 43, 43, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 118,
-        111, 105, 100, 32, 42, 99, 117, 114, 95, 102, 114, 97, 109, 101, 32, 61, 32, 115, 116, 97,
-        116, 101, 45, 62, 102, 114, 97, 109, 101, 95, 112, 116, 114, 59, 0, 32, 32, 32, 32, 32, 32,
-        32, 32, 105, 102, 32, 40, 33, 99, 117, 114, 95, 102, 114, 97, 109, 101, 41, 32, 123, 0, 32,
-        32, 32, 32, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101, 95, 114, 101, 97,
-        100, 95, 117, 115, 101, 114, 40, 38, 99, 117, 114, 95, 99, 111, 100, 101, 95, 112, 116,
-        114, 44, 32, 115, 105, 122, 101, 111, 102, 40, 99, 117, 114, 95, 99, 111, 100, 101, 95,
-        112, 116, 114, 41, 44, 32, 115, 116, 97, 116, 101, 45, 62, 102, 114, 97, 109, 101, 95, 112,
-        116, 114, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 102, 114,
-        97, 109, 101, 95, 111, 98, 106, 101, 99, 116, 46, 102, 95, 99, 111, 100, 101, 41, 59, 0,
-        32, 32, 32, 32, 32, 32, 32, 32, 105, 102, 32, 40, 33, 99, 117, 114, 95, 99, 111, 100, 101,
-        95, 112, 116, 114, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79,
-        71, 40, 34, 91, 101, 114, 114, 111, 114, 93, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101,
-        95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 32, 102, 97, 105, 108, 101, 100, 34, 41, 59,
-        0, 32, 32, 32, 32, 76, 79, 71, 40, 34, 91, 105, 116, 101, 114, 97, 116, 105, 111, 110, 93,
-        32, 102, 114, 97, 109, 101, 95, 99, 111, 117, 110, 116, 32, 37, 100, 34, 44, 32, 102, 114,
-        97, 109, 101, 95, 99, 111, 117, 110, 116, 41, 59, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34,
-        115, 116, 97, 116, 101, 45, 62, 115, 116, 97, 99, 107, 95, 119, 97, 108, 107, 101, 114, 95,
-        112, 114, 111, 103, 95, 99, 97, 108, 108, 95, 99, 111, 117, 110, 116, 32, 37, 100, 34, 44,
-        32, 115, 116, 97, 116, 101, 45, 62, 115, 116, 97, 99, 107, 95, 119, 97, 108, 107, 101, 114,
-        95, 112, 114, 111, 103, 95, 99, 97, 108, 108, 95, 99, 111, 117, 110, 116, 41, 59, 0, 32,
-        32, 32, 32, 105, 102, 32, 40, 115, 116, 97, 116, 101, 45, 62, 115, 116, 97, 99, 107, 95,
-        119, 97, 108, 107, 101, 114, 95, 112, 114, 111, 103, 95, 99, 97, 108, 108, 95, 99, 111,
-        117, 110, 116, 32, 60, 32, 80, 89, 84, 72, 79, 78, 95, 83, 84, 65, 67, 75, 95, 80, 82, 79,
-        71, 95, 67, 78, 84, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 91,
-        99, 111, 110, 116, 105, 110, 117, 101, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104,
-        111, 110, 95, 115, 116, 97, 99, 107, 34, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 98,
-        112, 102, 95, 116, 97, 105, 108, 95, 99, 97, 108, 108, 40, 99, 116, 120, 44, 32, 38, 112,
-        114, 111, 103, 114, 97, 109, 115, 44, 32, 80, 89, 80, 69, 82, 70, 95, 83, 84, 65, 67, 75,
-        95, 87, 65, 76, 75, 73, 78, 71, 95, 80, 82, 79, 71, 82, 65, 77, 95, 73, 68, 88, 41, 59, 0,
-        32, 32, 32, 32, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 115, 97, 109, 112, 108,
-        101, 46, 101, 114, 114, 111, 114, 95, 99, 111, 100, 101, 32, 61, 32, 69, 82, 82, 79, 82,
-        95, 67, 65, 76, 76, 95, 70, 65, 73, 76, 69, 68, 59, 0, 32, 32, 32, 32, 76, 79, 71, 40, 34,
-        91, 101, 114, 114, 111, 114, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111, 110,
-        95, 115, 116, 97, 99, 107, 32, 84, 82, 85, 78, 67, 65, 84, 69, 68, 34, 41, 59, 0, 32, 32,
-        32, 32, 76, 79, 71, 40, 34, 91, 116, 114, 117, 110, 99, 97, 116, 101, 100, 93, 32, 119, 97,
-        108, 107, 95, 112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 44, 32, 115, 116,
-        97, 99, 107, 95, 108, 101, 110, 61, 37, 100, 34, 44, 32, 115, 97, 109, 112, 108, 101, 45,
-        62, 115, 116, 97, 99, 107, 46, 108, 101, 110, 41, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116,
-        101, 45, 62, 115, 97, 109, 112, 108, 101, 46, 115, 116, 97, 99, 107, 95, 115, 116, 97, 116,
-        117, 115, 32, 61, 32, 83, 84, 65, 67, 75, 95, 84, 82, 85, 78, 67, 65, 84, 69, 68, 59, 0,
-        32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 102, 114, 97, 109, 101, 32, 37, 100,
-        34, 44, 32, 102, 114, 97, 109, 101, 95, 99, 111, 117, 110, 116, 41, 59, 0, 32, 32, 32, 32,
-        32, 32, 32, 32, 76, 79, 71, 40, 34, 99, 117, 114, 95, 102, 114, 97, 109, 101, 95, 112, 116,
-        114, 32, 48, 120, 37, 108, 108, 120, 34, 44, 32, 99, 117, 114, 95, 102, 114, 97, 109, 101,
-        41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 99, 117, 114, 95, 99, 111,
-        100, 101, 95, 112, 116, 114, 32, 48, 120, 37, 108, 108, 120, 34, 44, 32, 99, 117, 114, 95,
-        99, 111, 100, 101, 95, 112, 116, 114, 41, 59, 0, 32, 32, 32, 32, 95, 95, 98, 117, 105, 108,
-        116, 105, 110, 95, 109, 101, 109, 115, 101, 116, 40, 40, 118, 111, 105, 100, 32, 42, 41,
-        115, 121, 109, 44, 32, 48, 44, 32, 115, 105, 122, 101, 111, 102, 40, 83, 121, 109, 98, 111,
-        108, 41, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 114, 101, 97, 100, 95, 115, 121, 109,
-        98, 111, 108, 40, 111, 102, 102, 115, 101, 116, 115, 44, 32, 99, 117, 114, 95, 102, 114,
-        97, 109, 101, 44, 32, 99, 117, 114, 95, 99, 111, 100, 101, 95, 112, 116, 114, 44, 32, 38,
-        115, 121, 109, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101, 95,
-        114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 97, 114, 103, 115, 95, 112, 116, 114,
-        44, 32, 115, 105, 122, 101, 111, 102, 40, 118, 111, 105, 100, 32, 42, 41, 44, 32, 99, 111,
-        100, 101, 95, 112, 116, 114, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112,
-        121, 95, 99, 111, 100, 101, 95, 111, 98, 106, 101, 99, 116, 46, 99, 111, 95, 118, 97, 114,
-        110, 97, 109, 101, 115, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98,
-        101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 97, 114, 103, 115, 95, 112,
-        116, 114, 44, 32, 115, 105, 122, 101, 111, 102, 40, 118, 111, 105, 100, 32, 42, 41, 44, 32,
-        97, 114, 103, 115, 95, 112, 116, 114, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45,
-        62, 112, 121, 95, 116, 117, 112, 108, 101, 95, 111, 98, 106, 101, 99, 116, 46, 111, 98, 95,
-        105, 116, 101, 109, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101,
-        95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 95, 115, 116, 114, 40, 38, 115, 121, 109,
-        98, 111, 108, 45, 62, 102, 117, 110, 99, 44, 32, 115, 105, 122, 101, 111, 102, 40, 115,
-        121, 109, 98, 111, 108, 45, 62, 102, 117, 110, 99, 41, 44, 32, 97, 114, 103, 115, 95, 112,
-        116, 114, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 115, 116,
-        114, 105, 110, 103, 46, 100, 97, 116, 97, 41, 59, 0, 32, 32, 32, 32, 98, 111, 111, 108, 32,
-        102, 105, 114, 115, 116, 95, 115, 101, 108, 102, 32, 61, 32, 42, 40, 115, 51, 50, 32, 42,
-        41, 115, 121, 109, 98, 111, 108, 45, 62, 102, 117, 110, 99, 32, 61, 61, 32, 42, 40, 115,
-        51, 50, 32, 42, 41, 115, 101, 108, 102, 95, 115, 116, 114, 59, 0, 32, 32, 32, 32, 105, 102,
-        32, 40, 102, 105, 114, 115, 116, 95, 115, 101, 108, 102, 32, 124, 124, 32, 102, 105, 114,
-        115, 116, 95, 99, 108, 115, 41, 32, 123, 0, 32, 32, 32, 32, 32, 32, 32, 32, 98, 112, 102,
-        95, 112, 114, 111, 98, 101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 112,
-        116, 114, 44, 32, 115, 105, 122, 101, 111, 102, 40, 118, 111, 105, 100, 32, 42, 41, 44, 32,
-        99, 117, 114, 95, 102, 114, 97, 109, 101, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115,
-        45, 62, 112, 121, 95, 102, 114, 97, 109, 101, 95, 111, 98, 106, 101, 99, 116, 46, 102, 95,
-        108, 111, 99, 97, 108, 115, 112, 108, 117, 115, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32,
-        105, 102, 32, 40, 102, 105, 114, 115, 116, 95, 115, 101, 108, 102, 41, 32, 123, 0, 32, 32,
-        32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101, 95, 114,
-        101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 112, 116, 114, 44, 32, 115, 105, 122, 101,
-        111, 102, 40, 118, 111, 105, 100, 32, 42, 41, 44, 32, 112, 116, 114, 32, 43, 32, 111, 102,
-        102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 111, 98, 106, 101, 99, 116, 46, 111, 98, 95,
-        116, 121, 112, 101, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114,
-        111, 98, 101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 112, 116, 114, 44, 32,
-        115, 105, 122, 101, 111, 102, 40, 118, 111, 105, 100, 32, 42, 41, 44, 32, 112, 116, 114,
-        32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 116, 121, 112, 101,
-        95, 111, 98, 106, 101, 99, 116, 46, 116, 112, 95, 110, 97, 109, 101, 41, 59, 0, 32, 32, 32,
-        32, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101, 95, 114, 101, 97, 100, 95,
-        117, 115, 101, 114, 95, 115, 116, 114, 40, 38, 115, 121, 109, 98, 111, 108, 45, 62, 99,
-        108, 97, 115, 115, 44, 32, 115, 105, 122, 101, 111, 102, 40, 115, 121, 109, 98, 111, 108,
-        45, 62, 99, 108, 97, 115, 115, 41, 44, 32, 112, 116, 114, 41, 59, 0, 32, 32, 32, 32, 98,
-        112, 102, 95, 112, 114, 111, 98, 101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40,
-        38, 112, 121, 115, 116, 114, 95, 112, 116, 114, 44, 32, 115, 105, 122, 101, 111, 102, 40,
-        118, 111, 105, 100, 32, 42, 41, 44, 32, 99, 111, 100, 101, 95, 112, 116, 114, 32, 43, 32,
-        111, 102, 102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 99, 111, 100, 101, 95, 111, 98,
-        106, 101, 99, 116, 46, 99, 111, 95, 102, 105, 108, 101, 110, 97, 109, 101, 41, 59, 0, 32,
-        32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101, 95, 114, 101, 97, 100, 95, 117, 115,
-        101, 114, 95, 115, 116, 114, 40, 38, 115, 121, 109, 98, 111, 108, 45, 62, 102, 105, 108,
-        101, 44, 32, 115, 105, 122, 101, 111, 102, 40, 115, 121, 109, 98, 111, 108, 45, 62, 102,
-        105, 108, 101, 41, 44, 32, 112, 121, 115, 116, 114, 95, 112, 116, 114, 32, 43, 32, 111,
-        102, 102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 115, 116, 114, 105, 110, 103, 46, 100,
-        97, 116, 97, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101, 95, 114,
-        101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 112, 121, 115, 116, 114, 95, 112, 116, 114,
-        44, 32, 115, 105, 122, 101, 111, 102, 40, 118, 111, 105, 100, 32, 42, 41, 44, 32, 99, 111,
-        100, 101, 95, 112, 116, 114, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112,
-        121, 95, 99, 111, 100, 101, 95, 111, 98, 106, 101, 99, 116, 46, 99, 111, 95, 110, 97, 109,
-        101, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101, 95, 114, 101, 97,
-        100, 95, 117, 115, 101, 114, 95, 115, 116, 114, 40, 38, 115, 121, 109, 98, 111, 108, 45,
-        62, 102, 117, 110, 99, 44, 32, 115, 105, 122, 101, 111, 102, 40, 115, 121, 109, 98, 111,
-        108, 45, 62, 102, 117, 110, 99, 41, 44, 32, 112, 121, 115, 116, 114, 95, 112, 116, 114, 32,
-        43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 115, 116, 114, 105, 110,
-        103, 46, 100, 97, 116, 97, 41, 59, 0, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98,
-        101, 95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 115, 121, 109, 98, 111, 108,
-        45, 62, 108, 105, 110, 101, 44, 32, 115, 105, 122, 101, 111, 102, 40, 115, 121, 109, 98,
-        111, 108, 45, 62, 108, 105, 110, 101, 41, 44, 32, 99, 111, 100, 101, 95, 112, 116, 114, 32,
-        43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112, 121, 95, 99, 111, 100, 101, 95,
-        111, 98, 106, 101, 99, 116, 46, 99, 111, 95, 102, 105, 114, 115, 116, 108, 105, 110, 101,
-        110, 111, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 115, 121, 109, 46,
-        102, 105, 108, 101, 32, 37, 115, 34, 44, 32, 115, 121, 109, 46, 102, 105, 108, 101, 41, 59,
-        0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 115, 121, 109, 46, 99, 108, 97, 115,
-        115, 32, 37, 115, 34, 44, 32, 115, 121, 109, 46, 99, 108, 97, 115, 115, 41, 59, 0, 32, 32,
-        32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 115, 121, 109, 46, 102, 110, 32, 37, 115, 34,
-        44, 32, 115, 121, 109, 46, 102, 117, 110, 99, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32,
-        76, 79, 71, 40, 34, 115, 121, 109, 46, 108, 105, 110, 101, 32, 37, 100, 34, 44, 32, 115,
-        121, 109, 46, 108, 105, 110, 101, 41, 59, 0, 32, 32, 32, 32, 105, 110, 116, 32, 42, 115,
-        121, 109, 98, 111, 108, 95, 105, 100, 95, 112, 116, 114, 32, 61, 32, 98, 112, 102, 95, 109,
-        97, 112, 95, 108, 111, 111, 107, 117, 112, 95, 101, 108, 101, 109, 40, 38, 115, 121, 109,
-        98, 111, 108, 115, 44, 32, 115, 121, 109, 41, 59, 0, 32, 32, 32, 32, 105, 102, 32, 40, 115,
-        121, 109, 98, 111, 108, 95, 105, 100, 95, 112, 116, 114, 41, 32, 123, 0, 32, 32, 32, 32,
-        32, 32, 32, 32, 114, 101, 116, 117, 114, 110, 32, 42, 115, 121, 109, 98, 111, 108, 95, 105,
-        100, 95, 112, 116, 114, 59, 0, 32, 32, 32, 32, 117, 51, 50, 32, 122, 101, 114, 111, 32, 61,
-        32, 48, 59, 0, 32, 32, 32, 32, 117, 54, 52, 32, 42, 115, 121, 109, 95, 105, 100, 120, 32,
-        61, 32, 98, 112, 102, 95, 109, 97, 112, 95, 108, 111, 111, 107, 117, 112, 95, 101, 108,
-        101, 109, 40, 38, 115, 121, 109, 98, 111, 108, 95, 105, 110, 100, 101, 120, 44, 32, 38,
-        122, 101, 114, 111, 41, 59, 0, 32, 32, 32, 32, 105, 102, 32, 40, 115, 121, 109, 95, 105,
-        100, 120, 32, 61, 61, 32, 78, 85, 76, 76, 41, 32, 123, 0, 32, 32, 32, 32, 117, 54, 52, 32,
-        105, 100, 120, 32, 61, 32, 95, 95, 115, 121, 110, 99, 95, 102, 101, 116, 99, 104, 95, 97,
-        110, 100, 95, 97, 100, 100, 40, 115, 121, 109, 95, 105, 100, 120, 44, 32, 49, 41, 59, 0,
-        32, 32, 32, 32, 101, 114, 114, 32, 61, 32, 98, 112, 102, 95, 109, 97, 112, 95, 117, 112,
-        100, 97, 116, 101, 95, 101, 108, 101, 109, 40, 38, 115, 121, 109, 98, 111, 108, 115, 44,
-        32, 115, 121, 109, 44, 32, 38, 105, 100, 120, 44, 32, 66, 80, 70, 95, 65, 78, 89, 41, 59,
-        0, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 91, 101, 114, 114, 111, 114, 93, 32,
-        115, 121, 109, 98, 111, 108, 115, 32, 102, 97, 105, 108, 101, 100, 32, 119, 105, 116, 104,
-        32, 37, 100, 34, 44, 32, 101, 114, 114, 41, 59, 0, 32, 32, 32, 32, 114, 101, 116, 117, 114,
-        110, 32, 105, 100, 120, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 115, 54, 52, 32, 99, 117,
-        114, 95, 108, 101, 110, 32, 61, 32, 115, 97, 109, 112, 108, 101, 45, 62, 115, 116, 97, 99,
-        107, 46, 108, 101, 110, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 105, 102, 32, 40, 99, 117,
-        114, 95, 108, 101, 110, 32, 62, 61, 32, 48, 32, 38, 38, 32, 99, 117, 114, 95, 108, 101,
-        110, 32, 60, 32, 83, 84, 65, 67, 75, 95, 77, 65, 88, 95, 76, 69, 78, 41, 32, 123, 0, 32,
-        32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 76, 79, 71, 40, 34, 115, 116, 97, 99, 107, 45,
-        62, 102, 114, 97, 109, 101, 115, 91, 37, 100, 93, 32, 61, 32, 37, 100, 34, 44, 32, 99, 117,
-        114, 95, 108, 101, 110, 44, 32, 115, 121, 109, 98, 111, 108, 95, 105, 100, 41, 59, 0, 32,
-        32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 115, 97, 109, 112, 108, 101, 45, 62, 115, 116,
-        97, 99, 107, 46, 108, 101, 110, 43, 43, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32,
-        32, 115, 97, 109, 112, 108, 101, 45, 62, 115, 116, 97, 99, 107, 46, 102, 114, 97, 109, 101,
-        115, 91, 99, 117, 114, 95, 108, 101, 110, 93, 32, 61, 32, 115, 121, 109, 98, 111, 108, 95,
-        105, 100, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 98, 112, 102, 95, 112, 114, 111, 98, 101,
-        95, 114, 101, 97, 100, 95, 117, 115, 101, 114, 40, 38, 115, 116, 97, 116, 101, 45, 62, 102,
-        114, 97, 109, 101, 95, 112, 116, 114, 44, 32, 115, 105, 122, 101, 111, 102, 40, 115, 116,
-        97, 116, 101, 45, 62, 102, 114, 97, 109, 101, 95, 112, 116, 114, 41, 44, 32, 99, 117, 114,
-        95, 102, 114, 97, 109, 101, 32, 43, 32, 111, 102, 102, 115, 101, 116, 115, 45, 62, 112,
-        121, 95, 102, 114, 97, 109, 101, 95, 111, 98, 106, 101, 99, 116, 46, 102, 95, 98, 97, 99,
-        107, 41, 59, 0, 32, 32, 32, 32, 32, 32, 32, 32, 105, 102, 32, 40, 33, 115, 116, 97, 116,
-        101, 45, 62, 102, 114, 97, 109, 101, 95, 112, 116, 114, 41, 32, 123, 0, 32, 32, 32, 32, 76,
-        79, 71, 40, 34, 91, 99, 111, 109, 112, 108, 101, 116, 101, 93, 32, 119, 97, 108, 107, 95,
-        112, 121, 116, 104, 111, 110, 95, 115, 116, 97, 99, 107, 44, 32, 115, 116, 97, 99, 107, 95,
-        108, 101, 110, 61, 37, 100, 34, 44, 32, 115, 97, 109, 112, 108, 101, 45, 62, 115, 116, 97,
-        99, 107, 46, 108, 101, 110, 41, 59, 0, 32, 32, 32, 32, 115, 116, 97, 116, 101, 45, 62, 115,
-        97, 109, 112, 108, 101, 46, 115, 116, 97, 99, 107, 95, 115, 116, 97, 116, 117, 115, 32, 61,
-        32, 83, 84, 65, 67, 75, 95, 67, 79, 77, 80, 76, 69, 84, 69, 59, 0, 32, 32, 32, 32, 76, 79,
-        71, 40, 34, 91, 115, 116, 111, 112, 93, 32, 119, 97, 108, 107, 95, 112, 121, 116, 104, 111,
-        110, 95, 115, 116, 97, 99, 107, 34, 41, 59, 0, 48, 58, 50, 53, 53, 58, 54, 0, 46, 114, 111,
-        100, 97, 116, 97, 0, 46, 98, 115, 115, 0, 46, 109, 97, 112, 115, 0, 118, 101, 114, 115,
-        105, 111, 110, 0, 108, 105, 99, 101, 110, 115, 101, 0, 0, 0, 0, 0, 159, 235, 1, 0, 32, 0,
-        0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 28, 0, 0, 0, 124, 122, 0, 0, 152, 122, 0, 0, 28, 0, 0, 0, 8,
-        0, 0, 0, 133, 33, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 24, 14, 0, 0, 184, 1, 0, 0,
-        16, 0, 0, 0, 133, 33, 0, 0, 167, 7, 0, 0, 0, 0, 0, 0, 4, 53, 0, 0, 69, 53, 0, 0, 0, 132, 3,
-        0, 8, 0, 0, 0, 4, 53, 0, 0, 117, 53, 0, 0, 20, 136, 3, 0, 24, 0, 0, 0, 4, 53, 0, 0, 164,
-        53, 0, 0, 26, 140, 3, 0, 40, 0, 0, 0, 4, 53, 0, 0, 164, 53, 0, 0, 11, 140, 3, 0, 48, 0, 0,
-        0, 4, 53, 0, 0, 196, 53, 0, 0, 9, 152, 3, 0, 64, 0, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 72, 0, 0, 0, 4, 53, 0, 0, 216, 53, 0, 0, 33, 168, 3, 0, 104, 0, 0, 0, 4, 53, 0, 0, 41,
-        54, 0, 0, 9, 172, 3, 0, 112, 0, 0, 0, 4, 53, 0, 0, 66, 54, 0, 0, 5, 188, 3, 0, 136, 0, 0,
-        0, 4, 53, 0, 0, 66, 54, 0, 0, 5, 188, 3, 0, 144, 0, 0, 0, 4, 53, 0, 0, 66, 54, 0, 0, 5,
-        188, 3, 0, 176, 0, 0, 0, 4, 53, 0, 0, 86, 54, 0, 0, 5, 192, 3, 0, 184, 0, 0, 0, 4, 53, 0,
-        0, 86, 54, 0, 0, 5, 192, 3, 0, 192, 0, 0, 0, 4, 53, 0, 0, 86, 54, 0, 0, 5, 192, 3, 0, 240,
-        0, 0, 0, 4, 53, 0, 0, 130, 54, 0, 0, 23, 200, 3, 0, 248, 0, 0, 0, 4, 53, 0, 0, 130, 54, 0,
-        0, 9, 200, 3, 0, 0, 1, 0, 0, 4, 53, 0, 0, 178, 54, 0, 0, 9, 204, 3, 0, 24, 1, 0, 0, 4, 53,
-        0, 0, 178, 54, 0, 0, 9, 204, 3, 0, 32, 1, 0, 0, 4, 53, 0, 0, 178, 54, 0, 0, 9, 204, 3, 0,
-        96, 1, 0, 0, 4, 53, 0, 0, 242, 54, 0, 0, 5, 232, 3, 0, 112, 1, 0, 0, 4, 53, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 120, 1, 0, 0, 4, 53, 0, 0, 242, 54, 0, 0, 5, 232, 3, 0, 160, 1, 0, 0, 4, 53,
-        0, 0, 3, 55, 0, 0, 27, 240, 3, 0, 192, 1, 0, 0, 4, 53, 0, 0, 47, 55, 0, 0, 27, 244, 3, 0,
-        240, 1, 0, 0, 4, 53, 0, 0, 88, 55, 0, 0, 22, 12, 4, 0, 8, 2, 0, 0, 4, 53, 0, 0, 114, 55, 0,
-        0, 21, 24, 4, 0, 136, 3, 0, 0, 4, 53, 0, 0, 147, 55, 0, 0, 31, 28, 4, 0, 144, 3, 0, 0, 4,
-        53, 0, 0, 147, 55, 0, 0, 29, 28, 4, 0, 152, 3, 0, 0, 4, 53, 0, 0, 197, 55, 0, 0, 23, 32, 4,
-        0, 160, 3, 0, 0, 4, 53, 0, 0, 226, 55, 0, 0, 25, 36, 4, 0, 168, 3, 0, 0, 4, 53, 0, 0, 226,
-        55, 0, 0, 23, 36, 4, 0, 176, 3, 0, 0, 4, 53, 0, 0, 255, 55, 0, 0, 25, 40, 4, 0, 184, 3, 0,
-        0, 4, 53, 0, 0, 255, 55, 0, 0, 23, 40, 4, 0, 192, 3, 0, 0, 4, 53, 0, 0, 51, 56, 0, 0, 41,
-        44, 4, 0, 208, 3, 0, 0, 4, 53, 0, 0, 51, 56, 0, 0, 5, 44, 4, 0, 224, 3, 0, 0, 4, 53, 0, 0,
-        126, 56, 0, 0, 16, 52, 4, 0, 232, 3, 0, 0, 4, 53, 0, 0, 146, 56, 0, 0, 26, 64, 4, 0, 40, 4,
-        0, 0, 4, 53, 0, 0, 226, 56, 0, 0, 28, 60, 4, 0, 88, 4, 0, 0, 4, 53, 0, 0, 37, 57, 0, 0, 32,
-        72, 4, 0, 96, 4, 0, 0, 4, 53, 0, 0, 83, 57, 0, 0, 44, 48, 4, 0, 144, 4, 0, 0, 4, 53, 0, 0,
-        147, 57, 0, 0, 29, 88, 4, 0, 8, 7, 0, 0, 4, 53, 0, 0, 180, 57, 0, 0, 30, 76, 4, 0, 16, 7,
-        0, 0, 4, 53, 0, 0, 147, 57, 0, 0, 29, 88, 4, 0, 24, 7, 0, 0, 4, 53, 0, 0, 83, 57, 0, 0, 44,
-        48, 4, 0, 40, 7, 0, 0, 4, 53, 0, 0, 223, 57, 0, 0, 11, 176, 2, 0, 72, 7, 0, 0, 4, 53, 0, 0,
-        8, 58, 0, 0, 12, 252, 3, 0, 88, 7, 0, 0, 4, 53, 0, 0, 37, 58, 0, 0, 9, 180, 2, 0, 104, 7,
-        0, 0, 4, 53, 0, 0, 52, 58, 0, 0, 11, 196, 2, 0, 152, 7, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0,
-        9, 200, 2, 0, 160, 7, 0, 0, 4, 53, 0, 0, 127, 58, 0, 0, 9, 204, 2, 0, 184, 7, 0, 0, 4, 53,
-        0, 0, 127, 58, 0, 0, 9, 204, 2, 0, 192, 7, 0, 0, 4, 53, 0, 0, 127, 58, 0, 0, 9, 204, 2, 0,
-        240, 7, 0, 0, 4, 53, 0, 0, 196, 58, 0, 0, 12, 220, 2, 0, 16, 8, 0, 0, 4, 53, 0, 0, 238, 58,
-        0, 0, 9, 104, 4, 0, 32, 8, 0, 0, 4, 53, 0, 0, 0, 59, 0, 0, 9, 108, 4, 0, 48, 8, 0, 0, 4,
-        53, 0, 0, 41, 59, 0, 0, 53, 168, 4, 0, 56, 8, 0, 0, 4, 53, 0, 0, 113, 59, 0, 0, 5, 164, 4,
-        0, 80, 8, 0, 0, 4, 53, 0, 0, 188, 59, 0, 0, 5, 172, 4, 0, 104, 8, 0, 0, 4, 53, 0, 0, 188,
-        59, 0, 0, 5, 172, 4, 0, 112, 8, 0, 0, 4, 53, 0, 0, 188, 59, 0, 0, 5, 172, 4, 0, 152, 8, 0,
-        0, 4, 53, 0, 0, 16, 60, 0, 0, 5, 176, 4, 0, 160, 8, 0, 0, 4, 53, 0, 0, 16, 60, 0, 0, 5,
-        176, 4, 0, 168, 8, 0, 0, 4, 53, 0, 0, 16, 60, 0, 0, 5, 176, 4, 0, 208, 8, 0, 0, 4, 53, 0,
-        0, 69, 60, 0, 0, 54, 248, 4, 0, 248, 8, 0, 0, 4, 53, 0, 0, 146, 60, 0, 0, 34, 4, 5, 0, 16,
-        9, 0, 0, 4, 53, 0, 0, 146, 60, 0, 0, 34, 4, 5, 0, 24, 9, 0, 0, 4, 53, 0, 0, 215, 60, 0, 0,
-        5, 8, 5, 0, 48, 9, 0, 0, 4, 53, 0, 0, 215, 60, 0, 0, 5, 8, 5, 0, 56, 9, 0, 0, 4, 53, 0, 0,
-        215, 60, 0, 0, 5, 8, 5, 0, 96, 9, 0, 0, 4, 53, 0, 0, 5, 61, 0, 0, 5, 16, 5, 0, 152, 9, 0,
-        0, 4, 53, 0, 0, 24, 61, 0, 0, 72, 36, 5, 0, 160, 9, 0, 0, 4, 53, 0, 0, 24, 61, 0, 0, 32,
-        36, 5, 0, 168, 9, 0, 0, 4, 53, 0, 0, 24, 61, 0, 0, 45, 36, 5, 0, 184, 9, 0, 0, 4, 53, 0, 0,
-        24, 61, 0, 0, 72, 36, 5, 0, 192, 9, 0, 0, 4, 53, 0, 0, 107, 61, 0, 0, 5, 32, 5, 0, 208, 9,
-        0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 9, 0, 0, 4, 53, 0, 0, 174, 61, 0, 0, 9, 40,
-        5, 0, 240, 9, 0, 0, 4, 53, 0, 0, 174, 61, 0, 0, 9, 40, 5, 0, 0, 10, 0, 0, 4, 53, 0, 0, 206,
-        61, 0, 0, 9, 44, 5, 0, 8, 10, 0, 0, 4, 53, 0, 0, 206, 61, 0, 0, 9, 44, 5, 0, 48, 10, 0, 0,
-        4, 53, 0, 0, 255, 61, 0, 0, 5, 56, 5, 0, 56, 10, 0, 0, 4, 53, 0, 0, 255, 61, 0, 0, 5, 56,
-        5, 0, 88, 10, 0, 0, 4, 53, 0, 0, 51, 62, 0, 0, 79, 72, 5, 0, 104, 10, 0, 0, 4, 53, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 112, 10, 0, 0, 4, 53, 0, 0, 51, 62, 0, 0, 5, 72, 5, 0, 136, 10, 0, 0,
-        4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 10, 0, 0, 4, 53, 0, 0, 138, 62, 0, 0, 9, 76, 5,
-        0, 168, 10, 0, 0, 4, 53, 0, 0, 138, 62, 0, 0, 9, 76, 5, 0, 184, 10, 0, 0, 4, 53, 0, 0, 167,
-        62, 0, 0, 9, 80, 5, 0, 192, 10, 0, 0, 4, 53, 0, 0, 167, 62, 0, 0, 9, 80, 5, 0, 216, 10, 0,
-        0, 4, 53, 0, 0, 213, 62, 0, 0, 5, 92, 5, 0, 224, 10, 0, 0, 4, 53, 0, 0, 213, 62, 0, 0, 5,
-        92, 5, 0, 0, 11, 0, 0, 4, 53, 0, 0, 3, 63, 0, 0, 16, 152, 5, 0, 8, 11, 0, 0, 4, 53, 0, 0,
-        3, 63, 0, 0, 9, 152, 5, 0, 16, 11, 0, 0, 4, 53, 0, 0, 39, 63, 0, 0, 9, 156, 5, 0, 48, 11,
-        0, 0, 4, 53, 0, 0, 39, 63, 0, 0, 9, 156, 5, 0, 56, 11, 0, 0, 4, 53, 0, 0, 39, 63, 0, 0, 9,
-        156, 5, 0, 72, 11, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 11, 0, 0, 4, 53, 0, 0,
-        85, 63, 0, 0, 34, 180, 5, 0, 128, 11, 0, 0, 4, 53, 0, 0, 85, 63, 0, 0, 9, 180, 5, 0, 136,
-        11, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 11, 0, 0, 4, 53, 0, 0, 132, 63, 0, 0,
-        16, 244, 5, 0, 184, 11, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 208, 11, 0, 0, 4, 53, 0,
-        0, 132, 63, 0, 0, 9, 244, 5, 0, 224, 11, 0, 0, 4, 53, 0, 0, 165, 63, 0, 0, 5, 12, 6, 0,
-        232, 11, 0, 0, 4, 53, 0, 0, 165, 63, 0, 0, 5, 12, 6, 0, 8, 12, 0, 0, 4, 53, 0, 0, 212, 63,
-        0, 0, 5, 16, 6, 0, 56, 12, 0, 0, 4, 53, 0, 0, 25, 64, 0, 0, 110, 200, 5, 0, 64, 12, 0, 0,
-        4, 53, 0, 0, 25, 64, 0, 0, 83, 200, 5, 0, 80, 12, 0, 0, 4, 53, 0, 0, 25, 64, 0, 0, 110,
-        200, 5, 0, 88, 12, 0, 0, 4, 53, 0, 0, 25, 64, 0, 0, 9, 200, 5, 0, 104, 12, 0, 0, 4, 53, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 12, 0, 0, 4, 53, 0, 0, 144, 64, 0, 0, 13, 204, 5, 0, 136,
-        12, 0, 0, 4, 53, 0, 0, 144, 64, 0, 0, 13, 204, 5, 0, 144, 12, 0, 0, 4, 53, 0, 0, 171, 64,
-        0, 0, 13, 208, 5, 0, 152, 12, 0, 0, 4, 53, 0, 0, 171, 64, 0, 0, 13, 208, 5, 0, 192, 12, 0,
-        0, 4, 53, 0, 0, 215, 64, 0, 0, 38, 212, 5, 0, 216, 12, 0, 0, 4, 53, 0, 0, 27, 65, 0, 0, 9,
-        248, 5, 0, 224, 12, 0, 0, 4, 53, 0, 0, 27, 65, 0, 0, 9, 248, 5, 0, 8, 13, 0, 0, 4, 53, 0,
-        0, 70, 65, 0, 0, 34, 252, 5, 0, 16, 13, 0, 0, 4, 53, 0, 0, 124, 65, 0, 0, 5, 236, 2, 0, 40,
-        13, 0, 0, 4, 53, 0, 0, 124, 65, 0, 0, 5, 236, 2, 0, 48, 13, 0, 0, 4, 53, 0, 0, 124, 65, 0,
-        0, 5, 236, 2, 0, 80, 13, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 13, 0, 0, 4, 53, 0,
-        0, 143, 65, 0, 0, 5, 240, 2, 0, 96, 13, 0, 0, 4, 53, 0, 0, 143, 65, 0, 0, 5, 240, 2, 0,
-        104, 13, 0, 0, 4, 53, 0, 0, 143, 65, 0, 0, 5, 240, 2, 0, 136, 13, 0, 0, 4, 53, 0, 0, 156,
-        65, 0, 0, 5, 248, 2, 0, 200, 13, 0, 0, 4, 53, 0, 0, 255, 65, 0, 0, 1, 48, 6, 0, 216, 13, 0,
-        0, 4, 53, 0, 0, 1, 66, 0, 0, 9, 224, 5, 0, 224, 13, 0, 0, 4, 53, 0, 0, 1, 66, 0, 0, 9, 224,
-        5, 0, 0, 14, 0, 0, 4, 53, 0, 0, 39, 66, 0, 0, 38, 236, 5, 0, 8, 14, 0, 0, 4, 53, 0, 0, 39,
-        66, 0, 0, 66, 236, 5, 0, 24, 14, 0, 0, 4, 53, 0, 0, 121, 66, 0, 0, 0, 204, 7, 0, 40, 14, 0,
-        0, 4, 53, 0, 0, 242, 54, 0, 0, 5, 208, 7, 0, 56, 14, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 64, 14, 0, 0, 4, 53, 0, 0, 242, 54, 0, 0, 5, 208, 7, 0, 104, 14, 0, 0, 4, 53, 0, 0,
-        5, 61, 0, 0, 5, 212, 7, 0, 168, 14, 0, 0, 4, 53, 0, 0, 178, 66, 0, 0, 5, 220, 7, 0, 192,
-        14, 0, 0, 4, 53, 0, 0, 178, 66, 0, 0, 5, 220, 7, 0, 200, 14, 0, 0, 4, 53, 0, 0, 178, 66, 0,
-        0, 5, 220, 7, 0, 232, 14, 0, 0, 4, 53, 0, 0, 216, 66, 0, 0, 40, 224, 7, 0, 40, 15, 0, 0, 4,
-        53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 48, 15, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0,
-        56, 15, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 64, 15, 0, 0, 4, 53, 0, 0, 73, 67,
-        0, 0, 83, 24, 8, 0, 88, 15, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 96, 15, 0, 0,
-        4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0, 112, 15, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        168, 15, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 176, 15, 0, 0, 4, 53, 0, 0, 191,
-        67, 0, 0, 13, 28, 8, 0, 184, 15, 0, 0, 4, 53, 0, 0, 220, 67, 0, 0, 13, 32, 8, 0, 200, 15,
-        0, 0, 4, 53, 0, 0, 220, 67, 0, 0, 13, 32, 8, 0, 232, 15, 0, 0, 4, 53, 0, 0, 19, 68, 0, 0,
-        5, 168, 8, 0, 0, 16, 0, 0, 4, 53, 0, 0, 19, 68, 0, 0, 5, 168, 8, 0, 8, 16, 0, 0, 4, 53, 0,
-        0, 19, 68, 0, 0, 5, 168, 8, 0, 48, 16, 0, 0, 4, 53, 0, 0, 71, 68, 0, 0, 5, 176, 8, 0, 72,
-        16, 0, 0, 4, 53, 0, 0, 71, 68, 0, 0, 5, 176, 8, 0, 80, 16, 0, 0, 4, 53, 0, 0, 71, 68, 0, 0,
-        5, 176, 8, 0, 120, 16, 0, 0, 4, 53, 0, 0, 159, 68, 0, 0, 16, 180, 8, 0, 128, 16, 0, 0, 4,
-        53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 16, 0, 0, 4, 53, 0, 0, 159, 68, 0, 0, 16, 180, 8, 0,
-        168, 16, 0, 0, 4, 53, 0, 0, 159, 68, 0, 0, 9, 180, 8, 0, 176, 16, 0, 0, 4, 53, 0, 0, 230,
-        68, 0, 0, 9, 184, 8, 0, 184, 16, 0, 0, 4, 53, 0, 0, 230, 68, 0, 0, 9, 184, 8, 0, 216, 16,
-        0, 0, 4, 53, 0, 0, 19, 69, 0, 0, 9, 188, 8, 0, 8, 17, 0, 0, 4, 53, 0, 0, 92, 69, 0, 0, 34,
-        192, 8, 0, 24, 17, 0, 0, 4, 53, 0, 0, 146, 69, 0, 0, 5, 208, 8, 0, 32, 17, 0, 0, 4, 53, 0,
-        0, 146, 69, 0, 0, 5, 208, 8, 0, 64, 17, 0, 0, 4, 53, 0, 0, 194, 69, 0, 0, 5, 212, 8, 0, 88,
-        17, 0, 0, 4, 53, 0, 0, 194, 69, 0, 0, 5, 212, 8, 0, 96, 17, 0, 0, 4, 53, 0, 0, 194, 69, 0,
-        0, 5, 212, 8, 0, 168, 17, 0, 0, 4, 53, 0, 0, 13, 70, 0, 0, 32, 220, 8, 0, 184, 17, 0, 0, 4,
-        53, 0, 0, 180, 57, 0, 0, 30, 216, 8, 0, 200, 17, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8,
-        0, 208, 17, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 248, 17, 0, 0, 4, 53, 0, 0, 101,
-        70, 0, 0, 9, 52, 8, 0, 40, 18, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 48, 18, 0, 0,
-        4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 88, 18, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56,
-        8, 0, 96, 18, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 104, 18, 0, 0, 4, 53, 0, 0,
-        149, 70, 0, 0, 9, 56, 8, 0, 144, 18, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 18, 0,
-        0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 176, 19, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41,
-        80, 8, 0, 184, 19, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 192, 19, 0, 0, 4, 53, 0,
-        0, 58, 71, 0, 0, 61, 208, 6, 0, 224, 19, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232,
-        19, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 0, 20, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0,
-        88, 212, 6, 0, 8, 20, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 16, 20, 0, 0, 4, 53,
-        0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 24, 20, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0,
-        48, 20, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 56, 20, 0, 0, 4, 53, 0, 0, 255,
-        71, 0, 0, 66, 216, 6, 0, 64, 20, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216, 6, 0, 72, 20,
-        0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 96, 20, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0,
-        23, 236, 6, 0, 104, 20, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0, 120, 20, 0, 0, 4,
-        53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 128, 20, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7,
-        0, 152, 20, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 20, 0, 0, 4, 53, 0, 0, 200, 72,
-        0, 0, 9, 4, 7, 0, 176, 20, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 184, 20, 0, 0, 4,
-        53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 192, 20, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7,
-        0, 200, 20, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 216, 20, 0, 0, 4, 53, 0, 0, 72,
-        73, 0, 0, 80, 16, 7, 0, 224, 20, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 240, 20, 0,
-        0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 248, 20, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51,
-        24, 7, 0, 0, 21, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 16, 21, 0, 0, 4, 53, 0, 0,
-        161, 73, 0, 0, 81, 24, 7, 0, 24, 21, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 40, 21,
-        0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 48, 21, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0,
-        9, 28, 7, 0, 72, 21, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 80, 21, 0, 0, 4, 53, 0,
-        0, 72, 74, 0, 0, 62, 52, 7, 0, 104, 21, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 112,
-        21, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 136, 21, 0, 0, 4, 53, 0, 0, 173, 74, 0,
-        0, 97, 56, 7, 0, 144, 21, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 152, 21, 0, 0, 4,
-        53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 168, 21, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7,
-        0, 176, 21, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 192, 21, 0, 0, 4, 53, 0, 0, 20,
-        75, 0, 0, 88, 68, 7, 0, 200, 21, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 216, 21, 0,
-        0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 240, 21, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97,
-        72, 7, 0, 248, 21, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 0, 22, 0, 0, 4, 53, 0,
-        0, 117, 75, 0, 0, 76, 72, 7, 0, 8, 22, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 32,
-        22, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 40, 22, 0, 0, 4, 53, 0, 0, 220, 75, 0,
-        0, 71, 84, 7, 0, 48, 22, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 80, 22, 0, 0, 4,
-        53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 104, 22, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0,
-        120, 22, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 160, 22, 0, 0, 4, 53, 0, 0, 115, 76,
-        0, 0, 9, 92, 8, 0, 168, 22, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 176, 22, 0, 0,
-        4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 216, 22, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96,
-        8, 0, 240, 22, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 248, 22, 0, 0, 4, 53, 0, 0,
-        155, 76, 0, 0, 9, 96, 8, 0, 32, 23, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 40, 23,
-        0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 48, 23, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0,
-        9, 100, 8, 0, 96, 23, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 23, 0, 0, 4, 53, 0,
-        0, 229, 76, 0, 0, 26, 60, 6, 0, 136, 23, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 144,
-        23, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 176, 23, 0, 0, 4, 53, 0, 0, 90, 77, 0,
-        0, 9, 80, 6, 0, 192, 23, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 23, 0, 0, 4, 53,
-        0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 232, 23, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0,
-        248, 23, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 0, 24, 0, 0, 4, 53, 0, 0, 197,
-        77, 0, 0, 9, 108, 6, 0, 16, 24, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 24, 0, 0, 4,
-        53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0, 96, 24, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120,
-        6, 0, 104, 24, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 128, 24, 0, 0, 4, 53, 0, 0,
-        50, 78, 0, 0, 9, 124, 6, 0, 136, 24, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 176,
-        24, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12, 132, 6, 0, 232, 24, 0, 0, 4, 53, 0, 0, 118, 78,
-        0, 0, 37, 112, 8, 0, 8, 25, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 16, 25, 0, 0,
-        4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 56, 25, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13,
-        120, 8, 0, 64, 25, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 112, 25, 0, 0, 4, 53,
-        0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 120, 25, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0,
-        144, 25, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43, 124, 8, 0, 152, 25, 0, 0, 4, 53, 0, 0, 21,
-        79, 0, 0, 30, 128, 8, 0, 184, 25, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 192,
-        25, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144, 8, 0, 208, 25, 0, 0, 4, 53, 0, 0, 109, 79,
-        0, 0, 9, 144, 8, 0, 240, 25, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 8, 26, 0, 0,
-        4, 53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 16, 26, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8,
-        0, 24, 26, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 32, 26, 0, 0, 4, 53, 0, 0, 73,
-        67, 0, 0, 83, 24, 8, 0, 56, 26, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 64, 26, 0,
-        0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0, 80, 26, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 136, 26, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 144, 26, 0, 0, 4, 53, 0, 0,
-        191, 67, 0, 0, 13, 28, 8, 0, 152, 26, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 160,
-        26, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 200, 26, 0, 0, 4, 53, 0, 0, 101, 70, 0,
-        0, 9, 52, 8, 0, 224, 26, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 232, 26, 0, 0, 4,
-        53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 16, 27, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8,
-        0, 24, 27, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 32, 27, 0, 0, 4, 53, 0, 0, 149,
-        70, 0, 0, 9, 56, 8, 0, 80, 27, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 56, 28, 0,
-        0, 4, 53, 0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 64, 28, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87,
-        208, 6, 0, 72, 28, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 96, 28, 0, 0, 4, 53, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 28, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 128, 28,
-        0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0, 136, 28, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0,
-        52, 212, 6, 0, 144, 28, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 152, 28, 0, 0, 4,
-        53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 176, 28, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216,
-        6, 0, 184, 28, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 192, 28, 0, 0, 4, 53, 0, 0,
-        255, 71, 0, 0, 75, 216, 6, 0, 200, 28, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 224,
-        28, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0, 232, 28, 0, 0, 4, 53, 0, 0, 165, 72,
-        0, 0, 20, 252, 6, 0, 248, 28, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 0, 29, 0, 0,
-        4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 24, 29, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        32, 29, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 48, 29, 0, 0, 4, 53, 0, 0, 46, 73, 0,
-        0, 13, 8, 7, 0, 56, 29, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 64, 29, 0, 0, 4, 53,
-        0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 72, 29, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0,
-        88, 29, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 96, 29, 0, 0, 4, 53, 0, 0, 72, 73,
-        0, 0, 13, 16, 7, 0, 112, 29, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 120, 29, 0, 0,
-        4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 128, 29, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55,
-        24, 7, 0, 144, 29, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 152, 29, 0, 0, 4, 53, 0,
-        0, 161, 73, 0, 0, 9, 24, 7, 0, 168, 29, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0,
-        176, 29, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0, 200, 29, 0, 0, 4, 53, 0, 0, 72, 74,
-        0, 0, 88, 52, 7, 0, 208, 29, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 232, 29, 0, 0,
-        4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 240, 29, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52,
-        7, 0, 8, 30, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7, 0, 16, 30, 0, 0, 4, 53, 0, 0,
-        173, 74, 0, 0, 66, 56, 7, 0, 24, 30, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 40,
-        30, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 48, 30, 0, 0, 4, 53, 0, 0, 173, 74, 0,
-        0, 5, 56, 7, 0, 64, 30, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 72, 30, 0, 0, 4, 53,
-        0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 88, 30, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0,
-        112, 30, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 120, 30, 0, 0, 4, 53, 0, 0, 117,
-        75, 0, 0, 66, 72, 7, 0, 128, 30, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 136, 30,
-        0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 160, 30, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0,
-        97, 84, 7, 0, 168, 30, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 176, 30, 0, 0, 4,
-        53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 208, 30, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8,
-        0, 232, 30, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 248, 30, 0, 0, 4, 53, 0, 0, 77,
-        76, 0, 0, 9, 88, 8, 0, 32, 31, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 48, 31, 0, 0,
-        4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 56, 31, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92,
-        8, 0, 96, 31, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 120, 31, 0, 0, 4, 53, 0, 0,
-        155, 76, 0, 0, 9, 96, 8, 0, 128, 31, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 168,
-        31, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 176, 31, 0, 0, 4, 53, 0, 0, 191, 76, 0,
-        0, 9, 100, 8, 0, 184, 31, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 232, 31, 0, 0, 4,
-        53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 31, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0,
-        8, 32, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 16, 32, 0, 0, 4, 53, 0, 0, 59, 77, 0,
-        0, 16, 68, 6, 0, 40, 32, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 56, 32, 0, 0, 4, 53,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 32, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 88,
-        32, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 104, 32, 0, 0, 4, 53, 0, 0, 197, 77, 0,
-        0, 15, 108, 6, 0, 112, 32, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 128, 32, 0, 0,
-        4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 32, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6,
-        0, 208, 32, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 216, 32, 0, 0, 4, 53, 0, 0, 50,
-        78, 0, 0, 9, 124, 6, 0, 240, 32, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 248, 32, 0,
-        0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 32, 33, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12,
-        132, 6, 0, 40, 33, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 72, 33, 0, 0, 4, 53, 0,
-        0, 159, 78, 0, 0, 26, 116, 8, 0, 80, 33, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0,
-        120, 33, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 128, 33, 0, 0, 4, 53, 0, 0, 214,
-        78, 0, 0, 13, 120, 8, 0, 176, 33, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 184, 33,
-        0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 208, 33, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0,
-        43, 124, 8, 0, 216, 33, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 248, 33, 0, 0, 4,
-        53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 0, 34, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144,
-        8, 0, 16, 34, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 48, 34, 0, 0, 4, 53, 0, 0,
-        228, 79, 0, 0, 21, 148, 8, 0, 72, 34, 0, 0, 4, 53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 80,
-        34, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 88, 34, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0,
-        110, 24, 8, 0, 96, 34, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 120, 34, 0, 0, 4, 53,
-        0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 128, 34, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0,
-        144, 34, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 34, 0, 0, 4, 53, 0, 0, 191, 67, 0,
-        0, 14, 28, 8, 0, 208, 34, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 216, 34, 0, 0, 4,
-        53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 224, 34, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0,
-        8, 35, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 32, 35, 0, 0, 4, 53, 0, 0, 101, 70,
-        0, 0, 9, 52, 8, 0, 40, 35, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 80, 35, 0, 0, 4,
-        53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 88, 35, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8,
-        0, 96, 35, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 144, 35, 0, 0, 4, 53, 0, 0, 199,
-        70, 0, 0, 5, 132, 7, 0, 120, 36, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 128, 36,
-        0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 136, 36, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0,
-        61, 208, 6, 0, 160, 36, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 36, 0, 0, 4, 53, 0,
-        0, 58, 71, 0, 0, 5, 208, 6, 0, 192, 36, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0,
-        200, 36, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 208, 36, 0, 0, 4, 53, 0, 0, 158,
-        71, 0, 0, 61, 212, 6, 0, 216, 36, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 240, 36,
-        0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 248, 36, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0,
-        66, 216, 6, 0, 0, 37, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216, 6, 0, 8, 37, 0, 0, 4, 53,
-        0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 32, 37, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0,
-        40, 37, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0, 56, 37, 0, 0, 4, 53, 0, 0, 200,
-        72, 0, 0, 88, 4, 7, 0, 64, 37, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 88, 37, 0, 0,
-        4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 37, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0,
-        112, 37, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 120, 37, 0, 0, 4, 53, 0, 0, 72, 73,
-        0, 0, 80, 16, 7, 0, 128, 37, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 136, 37, 0, 0,
-        4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 152, 37, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16,
-        7, 0, 160, 37, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 176, 37, 0, 0, 4, 53, 0, 0,
-        161, 73, 0, 0, 81, 24, 7, 0, 184, 37, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 192,
-        37, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 208, 37, 0, 0, 4, 53, 0, 0, 161, 73, 0,
-        0, 81, 24, 7, 0, 216, 37, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 232, 37, 0, 0, 4,
-        53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 240, 37, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7,
-        0, 8, 38, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 16, 38, 0, 0, 4, 53, 0, 0, 72, 74,
-        0, 0, 62, 52, 7, 0, 40, 38, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 48, 38, 0, 0, 4,
-        53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 72, 38, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7,
-        0, 80, 38, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 88, 38, 0, 0, 4, 53, 0, 0, 173,
-        74, 0, 0, 76, 56, 7, 0, 104, 38, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 112, 38, 0,
-        0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 128, 38, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88,
-        68, 7, 0, 136, 38, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 152, 38, 0, 0, 4, 53, 0,
-        0, 20, 75, 0, 0, 5, 68, 7, 0, 176, 38, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 184,
-        38, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 192, 38, 0, 0, 4, 53, 0, 0, 117, 75, 0,
-        0, 76, 72, 7, 0, 200, 38, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 224, 38, 0, 0, 4,
-        53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 232, 38, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84,
-        7, 0, 240, 38, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 16, 39, 0, 0, 4, 53, 0, 0,
-        77, 76, 0, 0, 9, 88, 8, 0, 40, 39, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 56, 39, 0,
-        0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 96, 39, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92,
-        8, 0, 112, 39, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 120, 39, 0, 0, 4, 53, 0, 0,
-        115, 76, 0, 0, 9, 92, 8, 0, 160, 39, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 184,
-        39, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 192, 39, 0, 0, 4, 53, 0, 0, 155, 76, 0,
-        0, 9, 96, 8, 0, 232, 39, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 240, 39, 0, 0, 4,
-        53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 248, 39, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100,
-        8, 0, 40, 40, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 40, 0, 0, 4, 53, 0, 0, 229,
-        76, 0, 0, 26, 60, 6, 0, 72, 40, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 80, 40, 0, 0,
-        4, 53, 0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 104, 40, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80,
-        6, 0, 120, 40, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 40, 0, 0, 4, 53, 0, 0, 108,
-        77, 0, 0, 20, 84, 6, 0, 152, 40, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 168, 40, 0,
-        0, 4, 53, 0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 176, 40, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9,
-        108, 6, 0, 192, 40, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 40, 0, 0, 4, 53, 0, 0,
-        245, 77, 0, 0, 11, 116, 6, 0, 16, 41, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 24,
-        41, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 48, 41, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0,
-        9, 124, 6, 0, 56, 41, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 96, 41, 0, 0, 4, 53,
-        0, 0, 102, 78, 0, 0, 12, 132, 6, 0, 104, 41, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8,
-        0, 136, 41, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 144, 41, 0, 0, 4, 53, 0, 0,
-        214, 78, 0, 0, 13, 120, 8, 0, 184, 41, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0,
-        192, 41, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 240, 41, 0, 0, 4, 53, 0, 0, 21,
-        79, 0, 0, 30, 128, 8, 0, 248, 41, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 16, 42,
-        0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43, 124, 8, 0, 24, 42, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0,
-        30, 128, 8, 0, 56, 42, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 64, 42, 0, 0, 4,
-        53, 0, 0, 109, 79, 0, 0, 84, 144, 8, 0, 80, 42, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144,
-        8, 0, 112, 42, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 136, 42, 0, 0, 4, 53, 0, 0,
-        3, 67, 0, 0, 34, 252, 7, 0, 144, 42, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 152, 42,
-        0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 160, 42, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0,
-        83, 24, 8, 0, 184, 42, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 192, 42, 0, 0, 4,
-        53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0, 208, 42, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8,
-        43, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 16, 43, 0, 0, 4, 53, 0, 0, 191, 67, 0,
-        0, 13, 28, 8, 0, 24, 43, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 32, 43, 0, 0, 4, 53,
-        0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 72, 43, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 96,
-        43, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 104, 43, 0, 0, 4, 53, 0, 0, 101, 70, 0,
-        0, 9, 52, 8, 0, 144, 43, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 152, 43, 0, 0, 4,
-        53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 160, 43, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8,
-        0, 208, 43, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 184, 44, 0, 0, 4, 53, 0, 0,
-        253, 70, 0, 0, 41, 80, 8, 0, 192, 44, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 200,
-        44, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 224, 44, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 232, 44, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 0, 45, 0, 0, 4, 53, 0,
-        0, 158, 71, 0, 0, 88, 212, 6, 0, 8, 45, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0,
-        16, 45, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 24, 45, 0, 0, 4, 53, 0, 0, 158,
-        71, 0, 0, 5, 212, 6, 0, 48, 45, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 56, 45, 0,
-        0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 64, 45, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75,
-        216, 6, 0, 72, 45, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 96, 45, 0, 0, 4, 53, 0,
-        0, 101, 72, 0, 0, 23, 236, 6, 0, 104, 45, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0,
-        120, 45, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 128, 45, 0, 0, 4, 53, 0, 0, 200,
-        72, 0, 0, 61, 4, 7, 0, 152, 45, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 45, 0, 0,
-        4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 176, 45, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7,
-        0, 184, 45, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 192, 45, 0, 0, 4, 53, 0, 0, 72,
-        73, 0, 0, 55, 16, 7, 0, 200, 45, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 216, 45, 0,
-        0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 224, 45, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13,
-        16, 7, 0, 240, 45, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 248, 45, 0, 0, 4, 53, 0,
-        0, 161, 73, 0, 0, 51, 24, 7, 0, 0, 46, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 16,
-        46, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 24, 46, 0, 0, 4, 53, 0, 0, 161, 73, 0,
-        0, 9, 24, 7, 0, 40, 46, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 48, 46, 0, 0, 4,
-        53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0, 72, 46, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7,
-        0, 80, 46, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 104, 46, 0, 0, 4, 53, 0, 0, 72,
-        74, 0, 0, 88, 52, 7, 0, 112, 46, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 136, 46, 0,
-        0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7, 0, 144, 46, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66,
-        56, 7, 0, 152, 46, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 168, 46, 0, 0, 4, 53, 0,
-        0, 72, 74, 0, 0, 88, 52, 7, 0, 176, 46, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 192,
-        46, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 200, 46, 0, 0, 4, 53, 0, 0, 20, 75, 0,
-        0, 62, 68, 7, 0, 216, 46, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 240, 46, 0, 0, 4,
-        53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 248, 46, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72,
-        7, 0, 0, 47, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 8, 47, 0, 0, 4, 53, 0, 0, 117,
-        75, 0, 0, 5, 72, 7, 0, 32, 47, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 40, 47, 0,
-        0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 48, 47, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5,
-        84, 7, 0, 80, 47, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 104, 47, 0, 0, 4, 53, 0, 0,
-        77, 76, 0, 0, 9, 88, 8, 0, 120, 47, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 160, 47,
-        0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 176, 47, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0,
-        9, 92, 8, 0, 184, 47, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 224, 47, 0, 0, 4, 53,
-        0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 248, 47, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0,
-        0, 48, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 40, 48, 0, 0, 4, 53, 0, 0, 191, 76,
-        0, 0, 9, 100, 8, 0, 48, 48, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 56, 48, 0, 0,
-        4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 104, 48, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 112, 48, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 136, 48, 0, 0, 4, 53, 0, 0, 34,
-        77, 0, 0, 9, 64, 6, 0, 144, 48, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 168, 48, 0,
-        0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 184, 48, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 192, 48, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 216, 48, 0, 0, 4, 53, 0, 0,
-        170, 77, 0, 0, 9, 88, 6, 0, 232, 48, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 240,
-        48, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 0, 49, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 24, 49, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0, 80, 49, 0, 0, 4, 53,
-        0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 88, 49, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0,
-        112, 49, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 120, 49, 0, 0, 4, 53, 0, 0, 50, 78,
-        0, 0, 9, 124, 6, 0, 160, 49, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12, 132, 6, 0, 168, 49, 0,
-        0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 200, 49, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0,
-        26, 116, 8, 0, 208, 49, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 248, 49, 0, 0, 4,
-        53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 0, 50, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120,
-        8, 0, 48, 50, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 56, 50, 0, 0, 4, 53, 0, 0,
-        54, 79, 0, 0, 13, 124, 8, 0, 80, 50, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43, 124, 8, 0, 88,
-        50, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 120, 50, 0, 0, 4, 53, 0, 0, 109, 79, 0,
-        0, 111, 144, 8, 0, 128, 50, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144, 8, 0, 144, 50, 0, 0,
-        4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 176, 50, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21,
-        148, 8, 0, 200, 50, 0, 0, 4, 53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 208, 50, 0, 0, 4, 53, 0,
-        0, 47, 67, 0, 0, 13, 0, 8, 0, 216, 50, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 224,
-        50, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 248, 50, 0, 0, 4, 53, 0, 0, 73, 67, 0,
-        0, 110, 24, 8, 0, 0, 51, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0, 16, 51, 0, 0, 4, 53,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 51, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 80,
-        51, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 88, 51, 0, 0, 4, 53, 0, 0, 63, 70, 0,
-        0, 9, 48, 8, 0, 96, 51, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 136, 51, 0, 0, 4, 53,
-        0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 160, 51, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0,
-        168, 51, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 208, 51, 0, 0, 4, 53, 0, 0, 149,
-        70, 0, 0, 9, 56, 8, 0, 216, 51, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 224, 51, 0,
-        0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 16, 52, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0, 5,
-        132, 7, 0, 248, 52, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 0, 53, 0, 0, 4, 53, 0,
-        0, 58, 71, 0, 0, 87, 208, 6, 0, 8, 53, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 32,
-        53, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 53, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5,
-        208, 6, 0, 64, 53, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0, 72, 53, 0, 0, 4, 53, 0,
-        0, 158, 71, 0, 0, 52, 212, 6, 0, 80, 53, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0,
-        88, 53, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 112, 53, 0, 0, 4, 53, 0, 0, 255,
-        71, 0, 0, 96, 216, 6, 0, 120, 53, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 128, 53,
-        0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216, 6, 0, 136, 53, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0,
-        5, 216, 6, 0, 160, 53, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0, 168, 53, 0, 0, 4,
-        53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0, 184, 53, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4,
-        7, 0, 192, 53, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 216, 53, 0, 0, 4, 53, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 224, 53, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 240, 53, 0,
-        0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 248, 53, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80,
-        16, 7, 0, 0, 54, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 8, 54, 0, 0, 4, 53, 0, 0,
-        72, 73, 0, 0, 59, 16, 7, 0, 24, 54, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 32, 54,
-        0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 48, 54, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0,
-        81, 24, 7, 0, 56, 54, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 64, 54, 0, 0, 4, 53,
-        0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 80, 54, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0,
-        88, 54, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 104, 54, 0, 0, 4, 53, 0, 0, 251, 73,
-        0, 0, 72, 28, 7, 0, 112, 54, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0, 136, 54, 0, 0,
-        4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 144, 54, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52,
-        7, 0, 168, 54, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 176, 54, 0, 0, 4, 53, 0, 0,
-        72, 74, 0, 0, 5, 52, 7, 0, 200, 54, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7, 0, 208,
-        54, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 216, 54, 0, 0, 4, 53, 0, 0, 173, 74, 0,
-        0, 76, 56, 7, 0, 232, 54, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 240, 54, 0, 0, 4,
-        53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 0, 55, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0,
-        8, 55, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 24, 55, 0, 0, 4, 53, 0, 0, 20, 75, 0,
-        0, 5, 68, 7, 0, 48, 55, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 56, 55, 0, 0, 4,
-        53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 64, 55, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7,
-        0, 72, 55, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 96, 55, 0, 0, 4, 53, 0, 0, 220,
-        75, 0, 0, 97, 84, 7, 0, 104, 55, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 112, 55,
-        0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 144, 55, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9,
-        88, 8, 0, 168, 55, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 184, 55, 0, 0, 4, 53, 0,
-        0, 77, 76, 0, 0, 9, 88, 8, 0, 224, 55, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 232,
-        55, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 240, 55, 0, 0, 4, 53, 0, 0, 115, 76, 0,
-        0, 9, 92, 8, 0, 24, 56, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 48, 56, 0, 0, 4, 53,
-        0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 56, 56, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0,
-        96, 56, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 104, 56, 0, 0, 4, 53, 0, 0, 191,
-        76, 0, 0, 9, 100, 8, 0, 112, 56, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 160, 56,
-        0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 56, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26,
-        60, 6, 0, 192, 56, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 200, 56, 0, 0, 4, 53, 0,
-        0, 59, 77, 0, 0, 16, 68, 6, 0, 224, 56, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 240,
-        56, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 56, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0,
-        20, 84, 6, 0, 16, 57, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 32, 57, 0, 0, 4, 53,
-        0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 40, 57, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0,
-        56, 57, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 57, 0, 0, 4, 53, 0, 0, 245, 77, 0,
-        0, 11, 116, 6, 0, 136, 57, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 144, 57, 0, 0,
-        4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 168, 57, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124,
-        6, 0, 176, 57, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 216, 57, 0, 0, 4, 53, 0, 0,
-        102, 78, 0, 0, 12, 132, 6, 0, 224, 57, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 0,
-        58, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 8, 58, 0, 0, 4, 53, 0, 0, 214, 78, 0,
-        0, 13, 120, 8, 0, 40, 58, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 48, 58, 0, 0, 4,
-        53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 96, 58, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128,
-        8, 0, 112, 58, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 136, 58, 0, 0, 4, 53, 0, 0,
-        54, 79, 0, 0, 43, 124, 8, 0, 144, 58, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 176,
-        58, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 184, 58, 0, 0, 4, 53, 0, 0, 109, 79,
-        0, 0, 84, 144, 8, 0, 200, 58, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 232, 58, 0,
-        0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 0, 59, 0, 0, 4, 53, 0, 0, 3, 67, 0, 0, 34,
-        252, 7, 0, 8, 59, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 16, 59, 0, 0, 4, 53, 0, 0,
-        73, 67, 0, 0, 110, 24, 8, 0, 24, 59, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 48, 59,
-        0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 56, 59, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9,
-        24, 8, 0, 72, 59, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 59, 0, 0, 4, 53, 0, 0,
-        191, 67, 0, 0, 14, 28, 8, 0, 136, 59, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 144,
-        59, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 152, 59, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0,
-        9, 48, 8, 0, 192, 59, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 216, 59, 0, 0, 4, 53,
-        0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 224, 59, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0,
-        8, 60, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 16, 60, 0, 0, 4, 53, 0, 0, 149, 70,
-        0, 0, 9, 56, 8, 0, 24, 60, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 72, 60, 0, 0, 4,
-        53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 48, 61, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41, 80, 8,
-        0, 56, 61, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 64, 61, 0, 0, 4, 53, 0, 0, 58,
-        71, 0, 0, 61, 208, 6, 0, 88, 61, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 61, 0, 0,
-        4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 120, 61, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 88,
-        212, 6, 0, 128, 61, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 136, 61, 0, 0, 4, 53,
-        0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 144, 61, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212, 6,
-        0, 168, 61, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 176, 61, 0, 0, 4, 53, 0, 0,
-        255, 71, 0, 0, 66, 216, 6, 0, 184, 61, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216, 6, 0,
-        192, 61, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 216, 61, 0, 0, 4, 53, 0, 0, 101,
-        72, 0, 0, 23, 236, 6, 0, 224, 61, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0, 248, 61,
-        0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 0, 62, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61,
-        4, 7, 0, 24, 62, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 62, 0, 0, 4, 53, 0, 0, 200,
-        72, 0, 0, 9, 4, 7, 0, 48, 62, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 56, 62, 0, 0,
-        4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 64, 62, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16,
-        7, 0, 72, 62, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 88, 62, 0, 0, 4, 53, 0, 0, 72,
-        73, 0, 0, 80, 16, 7, 0, 96, 62, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 112, 62, 0,
-        0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 120, 62, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51,
-        24, 7, 0, 128, 62, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 144, 62, 0, 0, 4, 53, 0,
-        0, 161, 73, 0, 0, 81, 24, 7, 0, 152, 62, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0,
-        168, 62, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 176, 62, 0, 0, 4, 53, 0, 0, 251,
-        73, 0, 0, 9, 28, 7, 0, 208, 62, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 216, 62, 0,
-        0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 240, 62, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88,
-        52, 7, 0, 248, 62, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 16, 63, 0, 0, 4, 53, 0, 0,
-        173, 74, 0, 0, 97, 56, 7, 0, 24, 63, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 32,
-        63, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 48, 63, 0, 0, 4, 53, 0, 0, 72, 74, 0,
-        0, 88, 52, 7, 0, 56, 63, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 72, 63, 0, 0, 4,
-        53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 80, 63, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7,
-        0, 96, 63, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 120, 63, 0, 0, 4, 53, 0, 0, 117,
-        75, 0, 0, 97, 72, 7, 0, 128, 63, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 136, 63,
-        0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 144, 63, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0,
-        5, 72, 7, 0, 168, 63, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 176, 63, 0, 0, 4, 53,
-        0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 184, 63, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0,
-        216, 63, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 240, 63, 0, 0, 4, 53, 0, 0, 77, 76,
-        0, 0, 9, 88, 8, 0, 0, 64, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 40, 64, 0, 0, 4,
-        53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 48, 64, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8,
-        0, 56, 64, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 96, 64, 0, 0, 4, 53, 0, 0, 155,
-        76, 0, 0, 9, 96, 8, 0, 120, 64, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 128, 64, 0,
-        0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 168, 64, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9,
-        100, 8, 0, 176, 64, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 184, 64, 0, 0, 4, 53,
-        0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 232, 64, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240,
-        64, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 8, 65, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0,
-        9, 64, 6, 0, 16, 65, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 48, 65, 0, 0, 4, 53, 0,
-        0, 90, 77, 0, 0, 9, 80, 6, 0, 64, 65, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 65, 0,
-        0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 96, 65, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9,
-        88, 6, 0, 112, 65, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 120, 65, 0, 0, 4, 53,
-        0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 136, 65, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160,
-        65, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0, 216, 65, 0, 0, 4, 53, 0, 0, 112, 58,
-        0, 0, 9, 120, 6, 0, 224, 65, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 248, 65, 0, 0,
-        4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 0, 66, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6,
-        0, 40, 66, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12, 132, 6, 0, 56, 66, 0, 0, 4, 53, 0, 0, 118,
-        78, 0, 0, 37, 112, 8, 0, 80, 66, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 88, 66,
-        0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 120, 66, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0,
-        13, 120, 8, 0, 128, 66, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 176, 66, 0, 0, 4,
-        53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 192, 66, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124,
-        8, 0, 216, 66, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43, 124, 8, 0, 224, 66, 0, 0, 4, 53, 0, 0,
-        21, 79, 0, 0, 30, 128, 8, 0, 0, 67, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 8,
-        67, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144, 8, 0, 24, 67, 0, 0, 4, 53, 0, 0, 109, 79, 0,
-        0, 9, 144, 8, 0, 56, 67, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 80, 67, 0, 0, 4,
-        53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 88, 67, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0,
-        104, 67, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 112, 67, 0, 0, 4, 53, 0, 0, 73,
-        67, 0, 0, 83, 24, 8, 0, 136, 67, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 144, 67,
-        0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0, 160, 67, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 216, 67, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 232, 67, 0, 0, 4, 53, 0, 0,
-        191, 67, 0, 0, 13, 28, 8, 0, 240, 67, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 248,
-        67, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 32, 68, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0,
-        9, 52, 8, 0, 56, 68, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 64, 68, 0, 0, 4, 53, 0,
-        0, 101, 70, 0, 0, 9, 52, 8, 0, 104, 68, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 112,
-        68, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 120, 68, 0, 0, 4, 53, 0, 0, 149, 70, 0,
-        0, 9, 56, 8, 0, 168, 68, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 144, 69, 0, 0, 4,
-        53, 0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 160, 69, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208,
-        6, 0, 168, 69, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 192, 69, 0, 0, 4, 53, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 200, 69, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 224, 69, 0,
-        0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0, 232, 69, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0,
-        52, 212, 6, 0, 240, 69, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 248, 69, 0, 0, 4,
-        53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 16, 70, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216,
-        6, 0, 24, 70, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 32, 70, 0, 0, 4, 53, 0, 0,
-        255, 71, 0, 0, 75, 216, 6, 0, 40, 70, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 64,
-        70, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0, 72, 70, 0, 0, 4, 53, 0, 0, 165, 72, 0,
-        0, 20, 252, 6, 0, 88, 70, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 104, 70, 0, 0, 4,
-        53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 128, 70, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        136, 70, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 152, 70, 0, 0, 4, 53, 0, 0, 46, 73,
-        0, 0, 13, 8, 7, 0, 160, 70, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 176, 70, 0, 0,
-        4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 184, 70, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16,
-        7, 0, 200, 70, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 208, 70, 0, 0, 4, 53, 0, 0,
-        72, 73, 0, 0, 13, 16, 7, 0, 224, 70, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 240,
-        70, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 248, 70, 0, 0, 4, 53, 0, 0, 161, 73, 0,
-        0, 55, 24, 7, 0, 8, 71, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 16, 71, 0, 0, 4,
-        53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 32, 71, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7,
-        0, 40, 71, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0, 72, 71, 0, 0, 4, 53, 0, 0, 72,
-        74, 0, 0, 88, 52, 7, 0, 80, 71, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 104, 71, 0,
-        0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 112, 71, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5,
-        52, 7, 0, 136, 71, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7, 0, 144, 71, 0, 0, 4, 53, 0,
-        0, 173, 74, 0, 0, 66, 56, 7, 0, 152, 71, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0,
-        168, 71, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 176, 71, 0, 0, 4, 53, 0, 0, 173,
-        74, 0, 0, 5, 56, 7, 0, 192, 71, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 200, 71, 0,
-        0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 216, 71, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5,
-        68, 7, 0, 240, 71, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 248, 71, 0, 0, 4, 53, 0,
-        0, 117, 75, 0, 0, 66, 72, 7, 0, 0, 72, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 8,
-        72, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 32, 72, 0, 0, 4, 53, 0, 0, 220, 75, 0,
-        0, 97, 84, 7, 0, 40, 72, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 48, 72, 0, 0, 4,
-        53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 80, 72, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0,
-        104, 72, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 120, 72, 0, 0, 4, 53, 0, 0, 77, 76,
-        0, 0, 9, 88, 8, 0, 160, 72, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 168, 72, 0, 0,
-        4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 176, 72, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92,
-        8, 0, 216, 72, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 240, 72, 0, 0, 4, 53, 0, 0,
-        155, 76, 0, 0, 9, 96, 8, 0, 248, 72, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 32, 73,
-        0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 40, 73, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0,
-        9, 100, 8, 0, 48, 73, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 96, 73, 0, 0, 4, 53,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 73, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 128,
-        73, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 136, 73, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0,
-        16, 68, 6, 0, 160, 73, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 176, 73, 0, 0, 4, 53,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 73, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 208,
-        73, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 224, 73, 0, 0, 4, 53, 0, 0, 197, 77, 0,
-        0, 15, 108, 6, 0, 232, 73, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 248, 73, 0, 0,
-        4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 74, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6,
-        0, 72, 74, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 80, 74, 0, 0, 4, 53, 0, 0, 50,
-        78, 0, 0, 9, 124, 6, 0, 104, 74, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 112, 74, 0,
-        0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 152, 74, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12,
-        132, 6, 0, 160, 74, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 192, 74, 0, 0, 4, 53,
-        0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 200, 74, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8,
-        0, 232, 74, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 240, 74, 0, 0, 4, 53, 0, 0,
-        214, 78, 0, 0, 13, 120, 8, 0, 32, 75, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 48,
-        75, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 72, 75, 0, 0, 4, 53, 0, 0, 54, 79, 0,
-        0, 43, 124, 8, 0, 80, 75, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 112, 75, 0, 0, 4,
-        53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 120, 75, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84,
-        144, 8, 0, 136, 75, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 168, 75, 0, 0, 4, 53,
-        0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 200, 75, 0, 0, 4, 53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0,
-        208, 75, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 216, 75, 0, 0, 4, 53, 0, 0, 73, 67,
-        0, 0, 110, 24, 8, 0, 224, 75, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 248, 75, 0, 0,
-        4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 0, 76, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8,
-        0, 16, 76, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 76, 0, 0, 4, 53, 0, 0, 191, 67,
-        0, 0, 14, 28, 8, 0, 88, 76, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 96, 76, 0, 0,
-        4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 104, 76, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8,
-        0, 144, 76, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 168, 76, 0, 0, 4, 53, 0, 0, 101,
-        70, 0, 0, 9, 52, 8, 0, 176, 76, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 216, 76, 0,
-        0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 224, 76, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9,
-        56, 8, 0, 232, 76, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 24, 77, 0, 0, 4, 53, 0,
-        0, 199, 70, 0, 0, 5, 132, 7, 0, 0, 78, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 16,
-        78, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 24, 78, 0, 0, 4, 53, 0, 0, 58, 71, 0,
-        0, 61, 208, 6, 0, 48, 78, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 78, 0, 0, 4, 53,
-        0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 80, 78, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0,
-        88, 78, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 96, 78, 0, 0, 4, 53, 0, 0, 158,
-        71, 0, 0, 61, 212, 6, 0, 104, 78, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 128, 78,
-        0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 136, 78, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0,
-        66, 216, 6, 0, 144, 78, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216, 6, 0, 152, 78, 0, 0, 4,
-        53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 176, 78, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236,
-        6, 0, 184, 78, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0, 200, 78, 0, 0, 4, 53, 0, 0,
-        200, 72, 0, 0, 88, 4, 7, 0, 216, 78, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 240,
-        78, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 78, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0,
-        9, 4, 7, 0, 8, 79, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 16, 79, 0, 0, 4, 53, 0, 0,
-        72, 73, 0, 0, 80, 16, 7, 0, 32, 79, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 40, 79,
-        0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 56, 79, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80,
-        16, 7, 0, 64, 79, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 80, 79, 0, 0, 4, 53, 0, 0,
-        161, 73, 0, 0, 81, 24, 7, 0, 96, 79, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 104,
-        79, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 120, 79, 0, 0, 4, 53, 0, 0, 161, 73, 0,
-        0, 81, 24, 7, 0, 128, 79, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 144, 79, 0, 0, 4,
-        53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 152, 79, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7,
-        0, 184, 79, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 192, 79, 0, 0, 4, 53, 0, 0, 72,
-        74, 0, 0, 62, 52, 7, 0, 216, 79, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 224, 79, 0,
-        0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 248, 79, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97,
-        56, 7, 0, 0, 80, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 8, 80, 0, 0, 4, 53, 0, 0,
-        173, 74, 0, 0, 76, 56, 7, 0, 24, 80, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 32, 80,
-        0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 48, 80, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88,
-        68, 7, 0, 56, 80, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 72, 80, 0, 0, 4, 53, 0, 0,
-        20, 75, 0, 0, 5, 68, 7, 0, 96, 80, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 104, 80,
-        0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 112, 80, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0,
-        76, 72, 7, 0, 120, 80, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 144, 80, 0, 0, 4, 53,
-        0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 152, 80, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0,
-        160, 80, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 192, 80, 0, 0, 4, 53, 0, 0, 77, 76,
-        0, 0, 9, 88, 8, 0, 216, 80, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 232, 80, 0, 0, 4,
-        53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 16, 81, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0,
-        24, 81, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 32, 81, 0, 0, 4, 53, 0, 0, 115, 76,
-        0, 0, 9, 92, 8, 0, 72, 81, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 96, 81, 0, 0, 4,
-        53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 104, 81, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8,
-        0, 144, 81, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 152, 81, 0, 0, 4, 53, 0, 0,
-        191, 76, 0, 0, 9, 100, 8, 0, 160, 81, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 208,
-        81, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 81, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0,
-        26, 60, 6, 0, 240, 81, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 248, 81, 0, 0, 4, 53,
-        0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 16, 82, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 32,
-        82, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 82, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0,
-        20, 84, 6, 0, 64, 82, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 80, 82, 0, 0, 4, 53,
-        0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 88, 82, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0,
-        104, 82, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 82, 0, 0, 4, 53, 0, 0, 245, 77, 0,
-        0, 11, 116, 6, 0, 184, 82, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 192, 82, 0, 0,
-        4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 216, 82, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124,
-        6, 0, 224, 82, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 8, 83, 0, 0, 4, 53, 0, 0,
-        102, 78, 0, 0, 12, 132, 6, 0, 16, 83, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 48,
-        83, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 56, 83, 0, 0, 4, 53, 0, 0, 214, 78, 0,
-        0, 13, 120, 8, 0, 88, 83, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 96, 83, 0, 0, 4,
-        53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 144, 83, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128,
-        8, 0, 160, 83, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 184, 83, 0, 0, 4, 53, 0, 0,
-        54, 79, 0, 0, 43, 124, 8, 0, 192, 83, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 224,
-        83, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 232, 83, 0, 0, 4, 53, 0, 0, 109, 79,
-        0, 0, 84, 144, 8, 0, 0, 84, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 32, 84, 0, 0,
-        4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 56, 84, 0, 0, 4, 53, 0, 0, 3, 67, 0, 0, 34, 252,
-        7, 0, 64, 84, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 72, 84, 0, 0, 4, 53, 0, 0, 73,
-        67, 0, 0, 110, 24, 8, 0, 80, 84, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 104, 84, 0,
-        0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 120, 84, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9,
-        24, 8, 0, 136, 84, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 84, 0, 0, 4, 53, 0, 0,
-        191, 67, 0, 0, 14, 28, 8, 0, 200, 84, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 208,
-        84, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 216, 84, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0,
-        9, 48, 8, 0, 0, 85, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 24, 85, 0, 0, 4, 53, 0,
-        0, 101, 70, 0, 0, 9, 52, 8, 0, 32, 85, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 72,
-        85, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 80, 85, 0, 0, 4, 53, 0, 0, 149, 70, 0,
-        0, 9, 56, 8, 0, 88, 85, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 136, 85, 0, 0, 4,
-        53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 112, 86, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41, 80,
-        8, 0, 128, 86, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 136, 86, 0, 0, 4, 53, 0, 0,
-        58, 71, 0, 0, 61, 208, 6, 0, 160, 86, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 86,
-        0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 192, 86, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0,
-        88, 212, 6, 0, 200, 86, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 208, 86, 0, 0, 4,
-        53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 216, 86, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212,
-        6, 0, 240, 86, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 248, 86, 0, 0, 4, 53, 0, 0,
-        255, 71, 0, 0, 66, 216, 6, 0, 0, 87, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216, 6, 0, 8,
-        87, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 32, 87, 0, 0, 4, 53, 0, 0, 101, 72, 0,
-        0, 23, 236, 6, 0, 40, 87, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0, 56, 87, 0, 0, 4,
-        53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 72, 87, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7,
-        0, 96, 87, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 87, 0, 0, 4, 53, 0, 0, 200, 72,
-        0, 0, 9, 4, 7, 0, 120, 87, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 128, 87, 0, 0, 4,
-        53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 144, 87, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7,
-        0, 152, 87, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 168, 87, 0, 0, 4, 53, 0, 0, 72,
-        73, 0, 0, 80, 16, 7, 0, 176, 87, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 192, 87, 0,
-        0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 208, 87, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51,
-        24, 7, 0, 216, 87, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 232, 87, 0, 0, 4, 53, 0,
-        0, 161, 73, 0, 0, 81, 24, 7, 0, 240, 87, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 0,
-        88, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 8, 88, 0, 0, 4, 53, 0, 0, 251, 73, 0,
-        0, 9, 28, 7, 0, 40, 88, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 48, 88, 0, 0, 4, 53,
-        0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 72, 88, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0,
-        80, 88, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 104, 88, 0, 0, 4, 53, 0, 0, 173, 74,
-        0, 0, 97, 56, 7, 0, 112, 88, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 120, 88, 0, 0,
-        4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 136, 88, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52,
-        7, 0, 144, 88, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 160, 88, 0, 0, 4, 53, 0, 0,
-        20, 75, 0, 0, 88, 68, 7, 0, 168, 88, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 184,
-        88, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 208, 88, 0, 0, 4, 53, 0, 0, 117, 75, 0,
-        0, 97, 72, 7, 0, 216, 88, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 224, 88, 0, 0, 4,
-        53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 232, 88, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7,
-        0, 0, 89, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 8, 89, 0, 0, 4, 53, 0, 0, 220,
-        75, 0, 0, 71, 84, 7, 0, 16, 89, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 48, 89, 0,
-        0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 72, 89, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88,
-        8, 0, 88, 89, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 128, 89, 0, 0, 4, 53, 0, 0,
-        115, 76, 0, 0, 9, 92, 8, 0, 136, 89, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 144,
-        89, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 184, 89, 0, 0, 4, 53, 0, 0, 155, 76, 0,
-        0, 9, 96, 8, 0, 208, 89, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 216, 89, 0, 0, 4,
-        53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 0, 90, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8,
-        0, 8, 90, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 16, 90, 0, 0, 4, 53, 0, 0, 191,
-        76, 0, 0, 9, 100, 8, 0, 64, 90, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 90, 0, 0, 4,
-        53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 96, 90, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6,
-        0, 104, 90, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 128, 90, 0, 0, 4, 53, 0, 0, 90,
-        77, 0, 0, 9, 80, 6, 0, 144, 90, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 90, 0, 0,
-        4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 176, 90, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88,
-        6, 0, 192, 90, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 200, 90, 0, 0, 4, 53, 0, 0,
-        197, 77, 0, 0, 9, 108, 6, 0, 216, 90, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 90,
-        0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0, 40, 91, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0,
-        9, 120, 6, 0, 48, 91, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 72, 91, 0, 0, 4, 53,
-        0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 80, 91, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0,
-        120, 91, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12, 132, 6, 0, 128, 91, 0, 0, 4, 53, 0, 0, 118,
-        78, 0, 0, 37, 112, 8, 0, 160, 91, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 168, 91,
-        0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 200, 91, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0,
-        13, 120, 8, 0, 208, 91, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 0, 92, 0, 0, 4,
-        53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 16, 92, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8,
-        0, 40, 92, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43, 124, 8, 0, 48, 92, 0, 0, 4, 53, 0, 0, 21,
-        79, 0, 0, 30, 128, 8, 0, 80, 92, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 88, 92,
-        0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144, 8, 0, 104, 92, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0,
-        9, 144, 8, 0, 136, 92, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 168, 92, 0, 0, 4,
-        53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 176, 92, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8,
-        0, 184, 92, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 192, 92, 0, 0, 4, 53, 0, 0, 73,
-        67, 0, 0, 83, 24, 8, 0, 216, 92, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 224, 92,
-        0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0, 240, 92, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 40, 93, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 56, 93, 0, 0, 4, 53, 0, 0,
-        191, 67, 0, 0, 13, 28, 8, 0, 64, 93, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 72, 93,
-        0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 112, 93, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9,
-        52, 8, 0, 136, 93, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 144, 93, 0, 0, 4, 53, 0,
-        0, 101, 70, 0, 0, 9, 52, 8, 0, 184, 93, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 192,
-        93, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 200, 93, 0, 0, 4, 53, 0, 0, 149, 70, 0,
-        0, 9, 56, 8, 0, 248, 93, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 224, 94, 0, 0, 4,
-        53, 0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 240, 94, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208,
-        6, 0, 248, 94, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 16, 95, 0, 0, 4, 53, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 24, 95, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 48, 95, 0,
-        0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0, 56, 95, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52,
-        212, 6, 0, 64, 95, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 72, 95, 0, 0, 4, 53, 0,
-        0, 158, 71, 0, 0, 5, 212, 6, 0, 96, 95, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0,
-        104, 95, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 112, 95, 0, 0, 4, 53, 0, 0, 255,
-        71, 0, 0, 75, 216, 6, 0, 120, 95, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 144, 95,
-        0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0, 152, 95, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0,
-        20, 252, 6, 0, 168, 95, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 184, 95, 0, 0, 4,
-        53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 208, 95, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        216, 95, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 232, 95, 0, 0, 4, 53, 0, 0, 46, 73,
-        0, 0, 13, 8, 7, 0, 240, 95, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 0, 96, 0, 0, 4,
-        53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 8, 96, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0,
-        24, 96, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 32, 96, 0, 0, 4, 53, 0, 0, 72, 73,
-        0, 0, 13, 16, 7, 0, 48, 96, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 64, 96, 0, 0,
-        4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 72, 96, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24,
-        7, 0, 88, 96, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 96, 96, 0, 0, 4, 53, 0, 0,
-        161, 73, 0, 0, 9, 24, 7, 0, 112, 96, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 120,
-        96, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0, 152, 96, 0, 0, 4, 53, 0, 0, 72, 74, 0,
-        0, 88, 52, 7, 0, 160, 96, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 184, 96, 0, 0, 4,
-        53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 192, 96, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7,
-        0, 216, 96, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7, 0, 224, 96, 0, 0, 4, 53, 0, 0,
-        173, 74, 0, 0, 66, 56, 7, 0, 232, 96, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 248,
-        96, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 0, 97, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0,
-        5, 56, 7, 0, 16, 97, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 24, 97, 0, 0, 4, 53, 0,
-        0, 20, 75, 0, 0, 62, 68, 7, 0, 40, 97, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 64,
-        97, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 72, 97, 0, 0, 4, 53, 0, 0, 117, 75, 0,
-        0, 66, 72, 7, 0, 80, 97, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 88, 97, 0, 0, 4,
-        53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 112, 97, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7,
-        0, 120, 97, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 128, 97, 0, 0, 4, 53, 0, 0,
-        220, 75, 0, 0, 5, 84, 7, 0, 160, 97, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 184, 97,
-        0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 200, 97, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9,
-        88, 8, 0, 240, 97, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 248, 97, 0, 0, 4, 53, 0,
-        0, 115, 76, 0, 0, 9, 92, 8, 0, 0, 98, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 40,
-        98, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 64, 98, 0, 0, 4, 53, 0, 0, 155, 76, 0,
-        0, 9, 96, 8, 0, 72, 98, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 112, 98, 0, 0, 4,
-        53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 120, 98, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100,
-        8, 0, 128, 98, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 176, 98, 0, 0, 4, 53, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 184, 98, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 208, 98,
-        0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 216, 98, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16,
-        68, 6, 0, 240, 98, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 0, 99, 0, 0, 4, 53, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 8, 99, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 32, 99, 0,
-        0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 48, 99, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15,
-        108, 6, 0, 56, 99, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 72, 99, 0, 0, 4, 53, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 99, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0, 152,
-        99, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 160, 99, 0, 0, 4, 53, 0, 0, 50, 78, 0,
-        0, 9, 124, 6, 0, 184, 99, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 192, 99, 0, 0, 4,
-        53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 232, 99, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12, 132,
-        6, 0, 240, 99, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 16, 100, 0, 0, 4, 53, 0, 0,
-        159, 78, 0, 0, 26, 116, 8, 0, 24, 100, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 56,
-        100, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 64, 100, 0, 0, 4, 53, 0, 0, 214, 78,
-        0, 0, 13, 120, 8, 0, 112, 100, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 128, 100, 0,
-        0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 152, 100, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43,
-        124, 8, 0, 160, 100, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 192, 100, 0, 0, 4, 53,
-        0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 200, 100, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144,
-        8, 0, 216, 100, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 248, 100, 0, 0, 4, 53, 0,
-        0, 228, 79, 0, 0, 21, 148, 8, 0, 24, 101, 0, 0, 4, 53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0,
-        32, 101, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 40, 101, 0, 0, 4, 53, 0, 0, 73, 67,
-        0, 0, 110, 24, 8, 0, 48, 101, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 72, 101, 0, 0,
-        4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 80, 101, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24,
-        8, 0, 96, 101, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 101, 0, 0, 4, 53, 0, 0, 191,
-        67, 0, 0, 14, 28, 8, 0, 168, 101, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 176, 101,
-        0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 184, 101, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9,
-        48, 8, 0, 224, 101, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 248, 101, 0, 0, 4, 53,
-        0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 0, 102, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0,
-        40, 102, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 48, 102, 0, 0, 4, 53, 0, 0, 149,
-        70, 0, 0, 9, 56, 8, 0, 56, 102, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 104, 102, 0,
-        0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 80, 103, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41,
-        80, 8, 0, 96, 103, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 104, 103, 0, 0, 4, 53,
-        0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 128, 103, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        136, 103, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 160, 103, 0, 0, 4, 53, 0, 0, 158,
-        71, 0, 0, 88, 212, 6, 0, 168, 103, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 176,
-        103, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 184, 103, 0, 0, 4, 53, 0, 0, 158, 71,
-        0, 0, 5, 212, 6, 0, 208, 103, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 216, 103, 0,
-        0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 224, 103, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0,
-        75, 216, 6, 0, 232, 103, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 0, 104, 0, 0, 4,
-        53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0, 8, 104, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252,
-        6, 0, 24, 104, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 40, 104, 0, 0, 4, 53, 0, 0,
-        200, 72, 0, 0, 61, 4, 7, 0, 64, 104, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 104, 0,
-        0, 4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 88, 104, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8,
-        7, 0, 96, 104, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 112, 104, 0, 0, 4, 53, 0, 0,
-        72, 73, 0, 0, 55, 16, 7, 0, 120, 104, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 136,
-        104, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 144, 104, 0, 0, 4, 53, 0, 0, 72, 73, 0,
-        0, 13, 16, 7, 0, 160, 104, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 176, 104, 0, 0,
-        4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 184, 104, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55,
-        24, 7, 0, 200, 104, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 208, 104, 0, 0, 4, 53,
-        0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 224, 104, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0,
-        232, 104, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0, 8, 105, 0, 0, 4, 53, 0, 0, 72, 74,
-        0, 0, 88, 52, 7, 0, 16, 105, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 40, 105, 0, 0,
-        4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 48, 105, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52,
-        7, 0, 72, 105, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7, 0, 80, 105, 0, 0, 4, 53, 0, 0,
-        173, 74, 0, 0, 66, 56, 7, 0, 88, 105, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 104,
-        105, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 112, 105, 0, 0, 4, 53, 0, 0, 173, 74,
-        0, 0, 5, 56, 7, 0, 128, 105, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 136, 105, 0, 0,
-        4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 152, 105, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68,
-        7, 0, 176, 105, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 184, 105, 0, 0, 4, 53, 0,
-        0, 117, 75, 0, 0, 66, 72, 7, 0, 192, 105, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0,
-        200, 105, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 224, 105, 0, 0, 4, 53, 0, 0, 220,
-        75, 0, 0, 97, 84, 7, 0, 232, 105, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 240, 105,
-        0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 16, 106, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9,
-        88, 8, 0, 40, 106, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 56, 106, 0, 0, 4, 53, 0,
-        0, 77, 76, 0, 0, 9, 88, 8, 0, 96, 106, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 104,
-        106, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 112, 106, 0, 0, 4, 53, 0, 0, 115, 76,
-        0, 0, 9, 92, 8, 0, 152, 106, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 176, 106, 0, 0,
-        4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 184, 106, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96,
-        8, 0, 224, 106, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 232, 106, 0, 0, 4, 53, 0,
-        0, 191, 76, 0, 0, 9, 100, 8, 0, 240, 106, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0,
-        32, 107, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 107, 0, 0, 4, 53, 0, 0, 229, 76, 0,
-        0, 26, 60, 6, 0, 64, 107, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 72, 107, 0, 0, 4,
-        53, 0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 96, 107, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6,
-        0, 112, 107, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 107, 0, 0, 4, 53, 0, 0, 108,
-        77, 0, 0, 20, 84, 6, 0, 144, 107, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 160, 107,
-        0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 168, 107, 0, 0, 4, 53, 0, 0, 197, 77, 0,
-        0, 9, 108, 6, 0, 184, 107, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 208, 107, 0, 0, 4,
-        53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0, 8, 108, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120,
-        6, 0, 16, 108, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 40, 108, 0, 0, 4, 53, 0, 0,
-        50, 78, 0, 0, 9, 124, 6, 0, 48, 108, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 88,
-        108, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12, 132, 6, 0, 96, 108, 0, 0, 4, 53, 0, 0, 118, 78,
-        0, 0, 37, 112, 8, 0, 128, 108, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 136, 108,
-        0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 168, 108, 0, 0, 4, 53, 0, 0, 214, 78, 0,
-        0, 13, 120, 8, 0, 176, 108, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 224, 108, 0,
-        0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 240, 108, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13,
-        124, 8, 0, 8, 109, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43, 124, 8, 0, 16, 109, 0, 0, 4, 53, 0,
-        0, 21, 79, 0, 0, 30, 128, 8, 0, 48, 109, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0,
-        56, 109, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144, 8, 0, 72, 109, 0, 0, 4, 53, 0, 0, 109,
-        79, 0, 0, 9, 144, 8, 0, 104, 109, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 136,
-        109, 0, 0, 4, 53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 144, 109, 0, 0, 4, 53, 0, 0, 47, 67, 0,
-        0, 13, 0, 8, 0, 152, 109, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 160, 109, 0, 0,
-        4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 184, 109, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110,
-        24, 8, 0, 192, 109, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0, 208, 109, 0, 0, 4, 53, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 110, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 24, 110,
-        0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 32, 110, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0,
-        9, 48, 8, 0, 40, 110, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 80, 110, 0, 0, 4, 53,
-        0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 104, 110, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0,
-        112, 110, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 152, 110, 0, 0, 4, 53, 0, 0, 149,
-        70, 0, 0, 9, 56, 8, 0, 160, 110, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 168, 110,
-        0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 216, 110, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0,
-        5, 132, 7, 0, 192, 111, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 208, 111, 0, 0, 4,
-        53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 216, 111, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208,
-        6, 0, 240, 111, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 111, 0, 0, 4, 53, 0, 0, 58,
-        71, 0, 0, 5, 208, 6, 0, 16, 112, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0, 24, 112,
-        0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 32, 112, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0,
-        61, 212, 6, 0, 40, 112, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 64, 112, 0, 0, 4,
-        53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 72, 112, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216,
-        6, 0, 80, 112, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216, 6, 0, 88, 112, 0, 0, 4, 53, 0, 0,
-        255, 71, 0, 0, 5, 216, 6, 0, 112, 112, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0,
-        120, 112, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0, 136, 112, 0, 0, 4, 53, 0, 0,
-        200, 72, 0, 0, 88, 4, 7, 0, 152, 112, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 176,
-        112, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 112, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0,
-        9, 4, 7, 0, 200, 112, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 208, 112, 0, 0, 4, 53,
-        0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 224, 112, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0,
-        232, 112, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 248, 112, 0, 0, 4, 53, 0, 0, 72,
-        73, 0, 0, 80, 16, 7, 0, 0, 113, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 16, 113, 0,
-        0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 32, 113, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51,
-        24, 7, 0, 40, 113, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 56, 113, 0, 0, 4, 53, 0,
-        0, 161, 73, 0, 0, 81, 24, 7, 0, 64, 113, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 80,
-        113, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 88, 113, 0, 0, 4, 53, 0, 0, 251, 73,
-        0, 0, 9, 28, 7, 0, 120, 113, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 128, 113, 0, 0,
-        4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 152, 113, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52,
-        7, 0, 160, 113, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 184, 113, 0, 0, 4, 53, 0, 0,
-        173, 74, 0, 0, 97, 56, 7, 0, 192, 113, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 200,
-        113, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 216, 113, 0, 0, 4, 53, 0, 0, 72, 74,
-        0, 0, 88, 52, 7, 0, 224, 113, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 240, 113, 0,
-        0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 248, 113, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62,
-        68, 7, 0, 8, 114, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 32, 114, 0, 0, 4, 53, 0, 0,
-        117, 75, 0, 0, 97, 72, 7, 0, 40, 114, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 48,
-        114, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 56, 114, 0, 0, 4, 53, 0, 0, 117, 75,
-        0, 0, 5, 72, 7, 0, 80, 114, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 88, 114, 0, 0,
-        4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 96, 114, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84,
-        7, 0, 128, 114, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 152, 114, 0, 0, 4, 53, 0, 0,
-        77, 76, 0, 0, 9, 88, 8, 0, 168, 114, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 208,
-        114, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 216, 114, 0, 0, 4, 53, 0, 0, 115, 76,
-        0, 0, 9, 92, 8, 0, 224, 114, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 8, 115, 0, 0,
-        4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 32, 115, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96,
-        8, 0, 40, 115, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 80, 115, 0, 0, 4, 53, 0, 0,
-        191, 76, 0, 0, 9, 100, 8, 0, 88, 115, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 96,
-        115, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 144, 115, 0, 0, 4, 53, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 152, 115, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 176, 115, 0, 0, 4,
-        53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 184, 115, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16, 68, 6,
-        0, 208, 115, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 224, 115, 0, 0, 4, 53, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 232, 115, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 0, 116, 0,
-        0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 16, 116, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15,
-        108, 6, 0, 24, 116, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 40, 116, 0, 0, 4, 53,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 116, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0,
-        120, 116, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 128, 116, 0, 0, 4, 53, 0, 0, 50,
-        78, 0, 0, 9, 124, 6, 0, 152, 116, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 160, 116,
-        0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 200, 116, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0,
-        12, 132, 6, 0, 208, 116, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 240, 116, 0, 0,
-        4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 248, 116, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13,
-        120, 8, 0, 24, 117, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 32, 117, 0, 0, 4, 53,
-        0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 80, 117, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8,
-        0, 96, 117, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 120, 117, 0, 0, 4, 53, 0, 0,
-        54, 79, 0, 0, 43, 124, 8, 0, 128, 117, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 160,
-        117, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 168, 117, 0, 0, 4, 53, 0, 0, 109,
-        79, 0, 0, 84, 144, 8, 0, 184, 117, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 216,
-        117, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 248, 117, 0, 0, 4, 53, 0, 0, 3, 67,
-        0, 0, 34, 252, 7, 0, 0, 118, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 8, 118, 0, 0, 4,
-        53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 16, 118, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8,
-        0, 40, 118, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 48, 118, 0, 0, 4, 53, 0, 0, 73,
-        67, 0, 0, 9, 24, 8, 0, 64, 118, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 118, 0, 0,
-        4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 136, 118, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13,
-        28, 8, 0, 144, 118, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 152, 118, 0, 0, 4, 53, 0,
-        0, 63, 70, 0, 0, 9, 48, 8, 0, 192, 118, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 216,
-        118, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 224, 118, 0, 0, 4, 53, 0, 0, 101, 70,
-        0, 0, 9, 52, 8, 0, 8, 119, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 16, 119, 0, 0, 4,
-        53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 24, 119, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8,
-        0, 72, 119, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 48, 120, 0, 0, 4, 53, 0, 0,
-        253, 70, 0, 0, 41, 80, 8, 0, 64, 120, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 72,
-        120, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 96, 120, 0, 0, 4, 53, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 104, 120, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 128, 120, 0, 0, 4,
-        53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0, 136, 120, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52,
-        212, 6, 0, 144, 120, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 152, 120, 0, 0, 4,
-        53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 176, 120, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216,
-        6, 0, 184, 120, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 192, 120, 0, 0, 4, 53, 0,
-        0, 255, 71, 0, 0, 75, 216, 6, 0, 200, 120, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0,
-        224, 120, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0, 232, 120, 0, 0, 4, 53, 0, 0,
-        165, 72, 0, 0, 20, 252, 6, 0, 248, 120, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 8,
-        121, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 32, 121, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 40, 121, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 56, 121, 0, 0, 4, 53, 0,
-        0, 46, 73, 0, 0, 13, 8, 7, 0, 64, 121, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 80,
-        121, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 88, 121, 0, 0, 4, 53, 0, 0, 72, 73, 0,
-        0, 59, 16, 7, 0, 104, 121, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 112, 121, 0, 0,
-        4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 128, 121, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81,
-        24, 7, 0, 144, 121, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 152, 121, 0, 0, 4, 53,
-        0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 168, 121, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7,
-        0, 176, 121, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 192, 121, 0, 0, 4, 53, 0, 0,
-        251, 73, 0, 0, 72, 28, 7, 0, 200, 121, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0, 232,
-        121, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 240, 121, 0, 0, 4, 53, 0, 0, 72, 74, 0,
-        0, 62, 52, 7, 0, 8, 122, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 16, 122, 0, 0, 4,
-        53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 40, 122, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7,
-        0, 48, 122, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 56, 122, 0, 0, 4, 53, 0, 0,
-        173, 74, 0, 0, 76, 56, 7, 0, 72, 122, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 80,
-        122, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 96, 122, 0, 0, 4, 53, 0, 0, 20, 75, 0,
-        0, 88, 68, 7, 0, 104, 122, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 120, 122, 0, 0,
-        4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 144, 122, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72,
-        7, 0, 152, 122, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 160, 122, 0, 0, 4, 53, 0,
-        0, 117, 75, 0, 0, 76, 72, 7, 0, 168, 122, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0,
-        192, 122, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 200, 122, 0, 0, 4, 53, 0, 0, 220,
-        75, 0, 0, 71, 84, 7, 0, 208, 122, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 240, 122,
-        0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 8, 123, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9,
-        88, 8, 0, 24, 123, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 64, 123, 0, 0, 4, 53, 0,
-        0, 115, 76, 0, 0, 9, 92, 8, 0, 72, 123, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 80,
-        123, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 120, 123, 0, 0, 4, 53, 0, 0, 155, 76,
-        0, 0, 9, 96, 8, 0, 144, 123, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 152, 123, 0, 0,
-        4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 192, 123, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9,
-        100, 8, 0, 200, 123, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 208, 123, 0, 0, 4, 53,
-        0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 0, 124, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8,
-        124, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 32, 124, 0, 0, 4, 53, 0, 0, 34, 77, 0,
-        0, 9, 64, 6, 0, 40, 124, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 64, 124, 0, 0, 4,
-        53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 80, 124, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        88, 124, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 112, 124, 0, 0, 4, 53, 0, 0, 170,
-        77, 0, 0, 9, 88, 6, 0, 128, 124, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 136, 124,
-        0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 152, 124, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 176, 124, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0, 232, 124, 0, 0, 4, 53,
-        0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 240, 124, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0,
-        8, 125, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 16, 125, 0, 0, 4, 53, 0, 0, 50, 78,
-        0, 0, 9, 124, 6, 0, 56, 125, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12, 132, 6, 0, 64, 125, 0,
-        0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 96, 125, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0,
-        26, 116, 8, 0, 104, 125, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 136, 125, 0, 0,
-        4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 144, 125, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13,
-        120, 8, 0, 192, 125, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 208, 125, 0, 0, 4, 53,
-        0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 232, 125, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43, 124, 8,
-        0, 240, 125, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 16, 126, 0, 0, 4, 53, 0, 0,
-        109, 79, 0, 0, 111, 144, 8, 0, 24, 126, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144, 8, 0,
-        40, 126, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 72, 126, 0, 0, 4, 53, 0, 0, 228,
-        79, 0, 0, 21, 148, 8, 0, 104, 126, 0, 0, 4, 53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 112, 126,
-        0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 120, 126, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0,
-        110, 24, 8, 0, 128, 126, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 152, 126, 0, 0, 4,
-        53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 160, 126, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8,
-        0, 176, 126, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 126, 0, 0, 4, 53, 0, 0, 191,
-        67, 0, 0, 14, 28, 8, 0, 248, 126, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 0, 127,
-        0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 8, 127, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9,
-        48, 8, 0, 48, 127, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 72, 127, 0, 0, 4, 53, 0,
-        0, 101, 70, 0, 0, 9, 52, 8, 0, 80, 127, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 120,
-        127, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 128, 127, 0, 0, 4, 53, 0, 0, 149, 70,
-        0, 0, 9, 56, 8, 0, 136, 127, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 184, 127, 0, 0,
-        4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 160, 128, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41,
-        80, 8, 0, 176, 128, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6, 0, 184, 128, 0, 0, 4, 53,
-        0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 208, 128, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        216, 128, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 240, 128, 0, 0, 4, 53, 0, 0, 158,
-        71, 0, 0, 88, 212, 6, 0, 248, 128, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 0, 129,
-        0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 8, 129, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0,
-        5, 212, 6, 0, 32, 129, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 40, 129, 0, 0, 4,
-        53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 48, 129, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216,
-        6, 0, 56, 129, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6, 0, 80, 129, 0, 0, 4, 53, 0, 0,
-        101, 72, 0, 0, 23, 236, 6, 0, 88, 129, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0,
-        104, 129, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 120, 129, 0, 0, 4, 53, 0, 0, 200,
-        72, 0, 0, 61, 4, 7, 0, 144, 129, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 129, 0, 0,
-        4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 168, 129, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8,
-        7, 0, 176, 129, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 192, 129, 0, 0, 4, 53, 0, 0,
-        72, 73, 0, 0, 55, 16, 7, 0, 200, 129, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 216,
-        129, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 224, 129, 0, 0, 4, 53, 0, 0, 72, 73, 0,
-        0, 13, 16, 7, 0, 240, 129, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 0, 130, 0, 0, 4,
-        53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 8, 130, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7,
-        0, 24, 130, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 32, 130, 0, 0, 4, 53, 0, 0,
-        161, 73, 0, 0, 9, 24, 7, 0, 48, 130, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 56,
-        130, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0, 88, 130, 0, 0, 4, 53, 0, 0, 72, 74, 0,
-        0, 88, 52, 7, 0, 96, 130, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 120, 130, 0, 0, 4,
-        53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 128, 130, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7,
-        0, 152, 130, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56, 7, 0, 160, 130, 0, 0, 4, 53, 0, 0,
-        173, 74, 0, 0, 66, 56, 7, 0, 168, 130, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 184,
-        130, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 192, 130, 0, 0, 4, 53, 0, 0, 173, 74,
-        0, 0, 5, 56, 7, 0, 208, 130, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 216, 130, 0, 0,
-        4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 232, 130, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68,
-        7, 0, 0, 131, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 97, 72, 7, 0, 8, 131, 0, 0, 4, 53, 0, 0,
-        117, 75, 0, 0, 66, 72, 7, 0, 16, 131, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 24,
-        131, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72, 7, 0, 48, 131, 0, 0, 4, 53, 0, 0, 220, 75, 0,
-        0, 97, 84, 7, 0, 56, 131, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 64, 131, 0, 0, 4,
-        53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 96, 131, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8,
-        0, 120, 131, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 136, 131, 0, 0, 4, 53, 0, 0, 77,
-        76, 0, 0, 9, 88, 8, 0, 176, 131, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 184, 131,
-        0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 192, 131, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0,
-        9, 92, 8, 0, 232, 131, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 0, 132, 0, 0, 4, 53,
-        0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 8, 132, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0,
-        48, 132, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 56, 132, 0, 0, 4, 53, 0, 0, 191,
-        76, 0, 0, 9, 100, 8, 0, 64, 132, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 112, 132,
-        0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 132, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26,
-        60, 6, 0, 144, 132, 0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 152, 132, 0, 0, 4, 53, 0,
-        0, 59, 77, 0, 0, 16, 68, 6, 0, 176, 132, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 192,
-        132, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 132, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0,
-        20, 84, 6, 0, 224, 132, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 240, 132, 0, 0, 4,
-        53, 0, 0, 197, 77, 0, 0, 15, 108, 6, 0, 248, 132, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108,
-        6, 0, 8, 133, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 133, 0, 0, 4, 53, 0, 0, 245,
-        77, 0, 0, 11, 116, 6, 0, 88, 133, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 96, 133,
-        0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 120, 133, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0,
-        9, 124, 6, 0, 128, 133, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 168, 133, 0, 0, 4,
-        53, 0, 0, 102, 78, 0, 0, 12, 132, 6, 0, 176, 133, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37,
-        112, 8, 0, 208, 133, 0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 216, 133, 0, 0, 4,
-        53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 248, 133, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13,
-        120, 8, 0, 0, 134, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 48, 134, 0, 0, 4, 53,
-        0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 64, 134, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0,
-        88, 134, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 43, 124, 8, 0, 96, 134, 0, 0, 4, 53, 0, 0, 21,
-        79, 0, 0, 30, 128, 8, 0, 128, 134, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 136,
-        134, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84, 144, 8, 0, 152, 134, 0, 0, 4, 53, 0, 0, 109, 79,
-        0, 0, 9, 144, 8, 0, 184, 134, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 216, 134, 0,
-        0, 4, 53, 0, 0, 3, 67, 0, 0, 34, 252, 7, 0, 224, 134, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13,
-        0, 8, 0, 232, 134, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 240, 134, 0, 0, 4, 53,
-        0, 0, 73, 67, 0, 0, 83, 24, 8, 0, 8, 135, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0,
-        16, 135, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 9, 24, 8, 0, 32, 135, 0, 0, 4, 53, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 88, 135, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 104, 135, 0, 0, 4,
-        53, 0, 0, 191, 67, 0, 0, 13, 28, 8, 0, 112, 135, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8,
-        0, 120, 135, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 160, 135, 0, 0, 4, 53, 0, 0,
-        101, 70, 0, 0, 9, 52, 8, 0, 184, 135, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 192,
-        135, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 232, 135, 0, 0, 4, 53, 0, 0, 149, 70,
-        0, 0, 9, 56, 8, 0, 240, 135, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 248, 135, 0, 0,
-        4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 40, 136, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132,
-        7, 0, 16, 137, 0, 0, 4, 53, 0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 32, 137, 0, 0, 4, 53, 0, 0,
-        58, 71, 0, 0, 87, 208, 6, 0, 40, 137, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 64,
-        137, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 137, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0,
-        5, 208, 6, 0, 96, 137, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0, 104, 137, 0, 0, 4,
-        53, 0, 0, 158, 71, 0, 0, 52, 212, 6, 0, 112, 137, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61,
-        212, 6, 0, 120, 137, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 144, 137, 0, 0, 4, 53,
-        0, 0, 255, 71, 0, 0, 96, 216, 6, 0, 152, 137, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6,
-        0, 160, 137, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 75, 216, 6, 0, 168, 137, 0, 0, 4, 53, 0, 0,
-        255, 71, 0, 0, 5, 216, 6, 0, 192, 137, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0,
-        200, 137, 0, 0, 4, 53, 0, 0, 165, 72, 0, 0, 20, 252, 6, 0, 216, 137, 0, 0, 4, 53, 0, 0,
-        200, 72, 0, 0, 88, 4, 7, 0, 232, 137, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 0,
-        138, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 138, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0,
-        9, 4, 7, 0, 24, 138, 0, 0, 4, 53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 32, 138, 0, 0, 4, 53, 0,
-        0, 72, 73, 0, 0, 80, 16, 7, 0, 48, 138, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 56,
-        138, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 59, 16, 7, 0, 72, 138, 0, 0, 4, 53, 0, 0, 72, 73, 0,
-        0, 80, 16, 7, 0, 80, 138, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 96, 138, 0, 0, 4,
-        53, 0, 0, 161, 73, 0, 0, 81, 24, 7, 0, 112, 138, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51, 24,
-        7, 0, 120, 138, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 136, 138, 0, 0, 4, 53, 0,
-        0, 161, 73, 0, 0, 81, 24, 7, 0, 144, 138, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0,
-        160, 138, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 168, 138, 0, 0, 4, 53, 0, 0, 251,
-        73, 0, 0, 9, 28, 7, 0, 200, 138, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 208, 138,
-        0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 62, 52, 7, 0, 232, 138, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0,
-        88, 52, 7, 0, 240, 138, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 8, 139, 0, 0, 4, 53,
-        0, 0, 173, 74, 0, 0, 97, 56, 7, 0, 16, 139, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0,
-        24, 139, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 76, 56, 7, 0, 40, 139, 0, 0, 4, 53, 0, 0, 72,
-        74, 0, 0, 88, 52, 7, 0, 48, 139, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 64, 139, 0,
-        0, 4, 53, 0, 0, 20, 75, 0, 0, 88, 68, 7, 0, 72, 139, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62,
-        68, 7, 0, 88, 139, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 112, 139, 0, 0, 4, 53, 0,
-        0, 117, 75, 0, 0, 97, 72, 7, 0, 120, 139, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0,
-        128, 139, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 136, 139, 0, 0, 4, 53, 0, 0, 117,
-        75, 0, 0, 5, 72, 7, 0, 160, 139, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 168, 139,
-        0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 71, 84, 7, 0, 176, 139, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0,
-        5, 84, 7, 0, 208, 139, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 232, 139, 0, 0, 4, 53,
-        0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 248, 139, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0,
-        32, 140, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 40, 140, 0, 0, 4, 53, 0, 0, 115,
-        76, 0, 0, 9, 92, 8, 0, 48, 140, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 88, 140, 0,
-        0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 112, 140, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9,
-        96, 8, 0, 120, 140, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 160, 140, 0, 0, 4, 53,
-        0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 168, 140, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8,
-        0, 176, 140, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 224, 140, 0, 0, 4, 53, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 232, 140, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 0, 141,
-        0, 0, 4, 53, 0, 0, 34, 77, 0, 0, 9, 64, 6, 0, 8, 141, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16,
-        68, 6, 0, 32, 141, 0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 48, 141, 0, 0, 4, 53, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 141, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 80,
-        141, 0, 0, 4, 53, 0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 96, 141, 0, 0, 4, 53, 0, 0, 197, 77, 0,
-        0, 15, 108, 6, 0, 104, 141, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 120, 141, 0, 0,
-        4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 141, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116,
-        6, 0, 200, 141, 0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 208, 141, 0, 0, 4, 53, 0,
-        0, 50, 78, 0, 0, 9, 124, 6, 0, 232, 141, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0,
-        240, 141, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 24, 142, 0, 0, 4, 53, 0, 0, 102,
-        78, 0, 0, 12, 132, 6, 0, 32, 142, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 64, 142,
-        0, 0, 4, 53, 0, 0, 159, 78, 0, 0, 26, 116, 8, 0, 72, 142, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0,
-        13, 120, 8, 0, 104, 142, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 112, 142, 0, 0,
-        4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 160, 142, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30,
-        128, 8, 0, 176, 142, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 200, 142, 0, 0, 4, 53,
-        0, 0, 54, 79, 0, 0, 43, 124, 8, 0, 208, 142, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8,
-        0, 240, 142, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 248, 142, 0, 0, 4, 53, 0, 0,
-        109, 79, 0, 0, 84, 144, 8, 0, 8, 143, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 40,
-        143, 0, 0, 4, 53, 0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 72, 143, 0, 0, 4, 53, 0, 0, 3, 67, 0,
-        0, 34, 252, 7, 0, 80, 143, 0, 0, 4, 53, 0, 0, 47, 67, 0, 0, 13, 0, 8, 0, 88, 143, 0, 0, 4,
-        53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 96, 143, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 83, 24, 8,
-        0, 120, 143, 0, 0, 4, 53, 0, 0, 73, 67, 0, 0, 110, 24, 8, 0, 128, 143, 0, 0, 4, 53, 0, 0,
-        73, 67, 0, 0, 9, 24, 8, 0, 144, 143, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 143,
-        0, 0, 4, 53, 0, 0, 191, 67, 0, 0, 14, 28, 8, 0, 216, 143, 0, 0, 4, 53, 0, 0, 191, 67, 0, 0,
-        13, 28, 8, 0, 224, 143, 0, 0, 4, 53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 232, 143, 0, 0, 4,
-        53, 0, 0, 63, 70, 0, 0, 9, 48, 8, 0, 16, 144, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8,
-        0, 40, 144, 0, 0, 4, 53, 0, 0, 101, 70, 0, 0, 9, 52, 8, 0, 48, 144, 0, 0, 4, 53, 0, 0, 101,
-        70, 0, 0, 9, 52, 8, 0, 88, 144, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 96, 144, 0,
-        0, 4, 53, 0, 0, 149, 70, 0, 0, 9, 56, 8, 0, 104, 144, 0, 0, 4, 53, 0, 0, 149, 70, 0, 0, 9,
-        56, 8, 0, 152, 144, 0, 0, 4, 53, 0, 0, 199, 70, 0, 0, 5, 132, 7, 0, 128, 145, 0, 0, 4, 53,
-        0, 0, 253, 70, 0, 0, 41, 80, 8, 0, 144, 145, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 87, 208, 6,
-        0, 152, 145, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 61, 208, 6, 0, 176, 145, 0, 0, 4, 53, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 184, 145, 0, 0, 4, 53, 0, 0, 58, 71, 0, 0, 5, 208, 6, 0, 208, 145,
-        0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 88, 212, 6, 0, 216, 145, 0, 0, 4, 53, 0, 0, 158, 71, 0,
-        0, 52, 212, 6, 0, 224, 145, 0, 0, 4, 53, 0, 0, 158, 71, 0, 0, 61, 212, 6, 0, 232, 145, 0,
-        0, 4, 53, 0, 0, 158, 71, 0, 0, 5, 212, 6, 0, 0, 146, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 96,
-        216, 6, 0, 8, 146, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 66, 216, 6, 0, 16, 146, 0, 0, 4, 53,
-        0, 0, 255, 71, 0, 0, 75, 216, 6, 0, 24, 146, 0, 0, 4, 53, 0, 0, 255, 71, 0, 0, 5, 216, 6,
-        0, 48, 146, 0, 0, 4, 53, 0, 0, 101, 72, 0, 0, 23, 236, 6, 0, 56, 146, 0, 0, 4, 53, 0, 0,
-        165, 72, 0, 0, 20, 252, 6, 0, 72, 146, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 88, 4, 7, 0, 88,
-        146, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 61, 4, 7, 0, 112, 146, 0, 0, 4, 53, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 120, 146, 0, 0, 4, 53, 0, 0, 200, 72, 0, 0, 9, 4, 7, 0, 136, 146, 0, 0, 4,
-        53, 0, 0, 46, 73, 0, 0, 13, 8, 7, 0, 144, 146, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7,
-        0, 160, 146, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 55, 16, 7, 0, 168, 146, 0, 0, 4, 53, 0, 0,
-        72, 73, 0, 0, 59, 16, 7, 0, 184, 146, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 80, 16, 7, 0, 192,
-        146, 0, 0, 4, 53, 0, 0, 72, 73, 0, 0, 13, 16, 7, 0, 208, 146, 0, 0, 4, 53, 0, 0, 161, 73,
-        0, 0, 81, 24, 7, 0, 224, 146, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 51, 24, 7, 0, 232, 146, 0,
-        0, 4, 53, 0, 0, 161, 73, 0, 0, 55, 24, 7, 0, 248, 146, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0,
-        81, 24, 7, 0, 0, 147, 0, 0, 4, 53, 0, 0, 161, 73, 0, 0, 9, 24, 7, 0, 16, 147, 0, 0, 4, 53,
-        0, 0, 251, 73, 0, 0, 72, 28, 7, 0, 24, 147, 0, 0, 4, 53, 0, 0, 251, 73, 0, 0, 9, 28, 7, 0,
-        56, 147, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 64, 147, 0, 0, 4, 53, 0, 0, 72, 74,
-        0, 0, 62, 52, 7, 0, 88, 147, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0, 96, 147, 0, 0,
-        4, 53, 0, 0, 72, 74, 0, 0, 5, 52, 7, 0, 120, 147, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 97, 56,
-        7, 0, 128, 147, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 66, 56, 7, 0, 136, 147, 0, 0, 4, 53, 0,
-        0, 173, 74, 0, 0, 76, 56, 7, 0, 152, 147, 0, 0, 4, 53, 0, 0, 72, 74, 0, 0, 88, 52, 7, 0,
-        160, 147, 0, 0, 4, 53, 0, 0, 173, 74, 0, 0, 5, 56, 7, 0, 176, 147, 0, 0, 4, 53, 0, 0, 20,
-        75, 0, 0, 88, 68, 7, 0, 184, 147, 0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 62, 68, 7, 0, 200, 147,
-        0, 0, 4, 53, 0, 0, 20, 75, 0, 0, 5, 68, 7, 0, 224, 147, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0,
-        97, 72, 7, 0, 232, 147, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 66, 72, 7, 0, 240, 147, 0, 0, 4,
-        53, 0, 0, 117, 75, 0, 0, 76, 72, 7, 0, 248, 147, 0, 0, 4, 53, 0, 0, 117, 75, 0, 0, 5, 72,
-        7, 0, 16, 148, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 97, 84, 7, 0, 24, 148, 0, 0, 4, 53, 0, 0,
-        220, 75, 0, 0, 71, 84, 7, 0, 32, 148, 0, 0, 4, 53, 0, 0, 220, 75, 0, 0, 5, 84, 7, 0, 64,
-        148, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 88, 148, 0, 0, 4, 53, 0, 0, 77, 76, 0,
-        0, 9, 88, 8, 0, 104, 148, 0, 0, 4, 53, 0, 0, 77, 76, 0, 0, 9, 88, 8, 0, 144, 148, 0, 0, 4,
-        53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 152, 148, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8,
-        0, 160, 148, 0, 0, 4, 53, 0, 0, 115, 76, 0, 0, 9, 92, 8, 0, 200, 148, 0, 0, 4, 53, 0, 0,
-        155, 76, 0, 0, 9, 96, 8, 0, 224, 148, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 232,
-        148, 0, 0, 4, 53, 0, 0, 155, 76, 0, 0, 9, 96, 8, 0, 16, 149, 0, 0, 4, 53, 0, 0, 191, 76, 0,
-        0, 9, 100, 8, 0, 24, 149, 0, 0, 4, 53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 32, 149, 0, 0, 4,
-        53, 0, 0, 191, 76, 0, 0, 9, 100, 8, 0, 80, 149, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        88, 149, 0, 0, 4, 53, 0, 0, 229, 76, 0, 0, 26, 60, 6, 0, 112, 149, 0, 0, 4, 53, 0, 0, 34,
-        77, 0, 0, 9, 64, 6, 0, 120, 149, 0, 0, 4, 53, 0, 0, 59, 77, 0, 0, 16, 68, 6, 0, 144, 149,
-        0, 0, 4, 53, 0, 0, 90, 77, 0, 0, 9, 80, 6, 0, 160, 149, 0, 0, 4, 53, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 168, 149, 0, 0, 4, 53, 0, 0, 108, 77, 0, 0, 20, 84, 6, 0, 192, 149, 0, 0, 4, 53,
-        0, 0, 170, 77, 0, 0, 9, 88, 6, 0, 208, 149, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 15, 108, 6,
-        0, 216, 149, 0, 0, 4, 53, 0, 0, 197, 77, 0, 0, 9, 108, 6, 0, 232, 149, 0, 0, 4, 53, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 4, 53, 0, 0, 245, 77, 0, 0, 11, 116, 6, 0, 56, 150,
-        0, 0, 4, 53, 0, 0, 112, 58, 0, 0, 9, 120, 6, 0, 64, 150, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0,
-        9, 124, 6, 0, 88, 150, 0, 0, 4, 53, 0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 96, 150, 0, 0, 4, 53,
-        0, 0, 50, 78, 0, 0, 9, 124, 6, 0, 136, 150, 0, 0, 4, 53, 0, 0, 102, 78, 0, 0, 12, 132, 6,
-        0, 144, 150, 0, 0, 4, 53, 0, 0, 118, 78, 0, 0, 37, 112, 8, 0, 176, 150, 0, 0, 4, 53, 0, 0,
-        159, 78, 0, 0, 26, 116, 8, 0, 184, 150, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0,
-        216, 150, 0, 0, 4, 53, 0, 0, 214, 78, 0, 0, 13, 120, 8, 0, 224, 150, 0, 0, 4, 53, 0, 0,
-        214, 78, 0, 0, 13, 120, 8, 0, 16, 151, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 32,
-        151, 0, 0, 4, 53, 0, 0, 54, 79, 0, 0, 13, 124, 8, 0, 56, 151, 0, 0, 4, 53, 0, 0, 54, 79, 0,
-        0, 43, 124, 8, 0, 64, 151, 0, 0, 4, 53, 0, 0, 21, 79, 0, 0, 30, 128, 8, 0, 88, 151, 0, 0,
-        4, 53, 0, 0, 109, 79, 0, 0, 111, 144, 8, 0, 104, 151, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 84,
-        144, 8, 0, 120, 151, 0, 0, 4, 53, 0, 0, 109, 79, 0, 0, 9, 144, 8, 0, 152, 151, 0, 0, 4, 53,
-        0, 0, 228, 79, 0, 0, 21, 148, 8, 0, 192, 151, 0, 0, 4, 53, 0, 0, 5, 80, 0, 0, 5, 236, 8, 0,
-        216, 151, 0, 0, 4, 53, 0, 0, 5, 80, 0, 0, 5, 236, 8, 0, 224, 151, 0, 0, 4, 53, 0, 0, 5, 80,
-        0, 0, 5, 236, 8, 0, 48, 152, 0, 0, 4, 53, 0, 0, 79, 80, 0, 0, 32, 244, 8, 0, 56, 152, 0, 0,
-        4, 53, 0, 0, 180, 57, 0, 0, 30, 240, 8, 0, 72, 152, 0, 0, 4, 53, 0, 0, 128, 80, 0, 0, 5,
-        252, 8, 0, 96, 152, 0, 0, 4, 53, 0, 0, 128, 80, 0, 0, 5, 252, 8, 0, 104, 152, 0, 0, 4, 53,
-        0, 0, 128, 80, 0, 0, 5, 252, 8, 0, 136, 152, 0, 0, 4, 53, 0, 0, 124, 65, 0, 0, 5, 236, 2,
-        0, 144, 152, 0, 0, 4, 53, 0, 0, 124, 65, 0, 0, 5, 236, 2, 0, 152, 152, 0, 0, 4, 53, 0, 0,
-        124, 65, 0, 0, 5, 236, 2, 0, 184, 152, 0, 0, 4, 53, 0, 0, 143, 65, 0, 0, 5, 240, 2, 0, 208,
-        152, 0, 0, 4, 53, 0, 0, 143, 65, 0, 0, 5, 240, 2, 0, 216, 152, 0, 0, 4, 53, 0, 0, 143, 65,
-        0, 0, 5, 240, 2, 0, 248, 152, 0, 0, 4, 53, 0, 0, 156, 65, 0, 0, 68, 248, 2, 0, 0, 153, 0,
-        0, 4, 53, 0, 0, 156, 65, 0, 0, 5, 248, 2, 0, 64, 153, 0, 0, 4, 53, 0, 0, 255, 65, 0, 0, 1,
-        8, 9, 0, 16, 0, 0, 0, 133, 33, 0, 0, 1, 0, 0, 0, 216, 8, 0, 0, 126, 0, 0, 0, 165, 80, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 238, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 15, 0, 0, 0, 0, 0, 0, 144, 33, 0, 0, 0, 0, 0, 0, 1, 0,
-        0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 1, 0, 0, 0,
-        6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 48, 0, 0, 0, 0, 0, 0, 80, 153, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0,
-        0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 202, 0, 0, 0, 0, 0,
-        0, 161, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 36, 0, 0, 0, 8, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 205,
-        0, 0, 0, 0, 0, 0, 36, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 184, 205, 0, 0, 0, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 248, 206, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 252, 206, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 209, 14, 0, 0, 9, 0,
-        0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 207, 0, 0, 0, 0, 0, 0, 208, 21,
-        0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0,
-        224, 14, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 208, 228, 0, 0,
-        0, 0, 0, 0, 172, 166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 229, 14, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 128, 139, 1, 0, 0, 0, 0, 0, 212, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use nix::sys::time::TimeSpec;
+use nix::time::{clock_gettime, ClockId};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// Maps `CLOCK_MONOTONIC` timestamps, as returned by `bpf_ktime_get_ns`, to wall-clock time.
+#[derive(Clone, Copy, Debug)]
+pub struct MonotonicClock {
+    /// `CLOCK_REALTIME - CLOCK_MONOTONIC` in nanoseconds, at the time the clock was created.
+    offset_ns: i128,
+}
+
+impl MonotonicClock {
+    /// Samples both clocks to compute the offset between them.
+    /// The monotonic clock is read before and after the real-time clock, and the midpoint is used
+    /// to compensate for the time spent in between.
+    ///
+    /// # Errors
+    /// This function will return an error if any of the clocks can't be read.
+    pub fn new() -> Result<Self> {
+        let before = nanos(clock_gettime(ClockId::CLOCK_MONOTONIC)?);
+        let realtime = nanos(clock_gettime(ClockId::CLOCK_REALTIME)?);
+        let after = nanos(clock_gettime(ClockId::CLOCK_MONOTONIC)?);
+
+        Ok(Self {
+            offset_ns: realtime - (before + after) / 2,
+        })
+    }
+
+    /// Converts a monotonic timestamp to nanoseconds since the UNIX epoch.
+    #[must_use]
+    pub fn to_unix_nanos(self, monotonic_ns: u64) -> u64 {
+        u64::try_from(i128::from(monotonic_ns) + self.offset_ns).unwrap_or_default()
+    }
+}
+
+fn nanos(ts: TimeSpec) -> i128 {
+    i128::from(ts.tv_sec()) * NANOS_PER_SEC + i128::from(ts.tv_nsec())
+}
@@ -0,0 +1,239 @@
+// A long-running agent recording every CPU, controlled over a Unix socket. Clients send one JSON
+// request per line, e.g. `{"command": "attach", "pid": 42}`, and get one JSON response per line
+// back, with a `status` of either `ok` or `error`. See `py-perf ctl` for the client.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, Permissions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use crossbeam::channel::{bounded, unbounded, Receiver, Sender};
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::process_info::is_running;
+use crate::profile::{ProcessMetadata, Report};
+use crate::py_perf::{Control, PyPerf, Stats};
+
+pub const DEFAULT_SOCKET: &str = "/run/py-perf.sock";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum Request {
+    /// Start recording a Python process.
+    Attach { pid: i32 },
+    /// Stop recording a Python process.
+    Detach { pid: i32 },
+    /// List the recorded processes.
+    List,
+    /// Return the statistics of the profiler since it started.
+    Stats,
+    /// Write the samples recorded since the previous snapshot, within the window of the daemon,
+    /// to `path` as a pprof profile.
+    Snapshot { path: PathBuf },
+}
+
+/// What the connections share with the daemon.
+struct State {
+    controls: Sender<Control>,
+    stats: Arc<RwLock<Stats>>,
+    targets: Mutex<Vec<ProcessMetadata>>,
+    /// The intervals recorded since the previous snapshot, oldest first.
+    reports: Mutex<VecDeque<Report>>,
+}
+
+/// Records every CPU until a message is received on `stop`, and listens for requests on
+/// `socket`. Samples are only kept for the attached processes, and collected every `interval`.
+/// The intervals which started more than `window` ago are dropped, so that the memory stays
+/// bounded when no snapshot is taken.
+///
+/// # Errors
+/// This function will return an error if another daemon is listening on `socket`, if the
+/// socket can't be created or if the profiler fails.
+pub fn run(
+    py_perf: &mut PyPerf,
+    socket: &Path,
+    interval: Duration,
+    window: Duration,
+    stop: &Receiver<()>,
+) -> Result<()> {
+    if socket.exists() {
+        if UnixStream::connect(socket).is_ok() {
+            bail!("another daemon is listening on {}", socket.display());
+        }
+        debug!("removing stale socket {}", socket.display());
+        fs::remove_file(socket)?;
+    }
+    let listener = UnixListener::bind(socket)
+        .with_context(|| format!("failed to listen on {}", socket.display()))?;
+    // The requests can read the memory of any process and write files as root.
+    fs::set_permissions(socket, Permissions::from_mode(0o600))?;
+
+    let (snapshots_sender, snapshots) = unbounded();
+    let (controls, controls_receiver) = unbounded();
+    py_perf.set_system_wide();
+    py_perf.set_interval(interval, snapshots_sender);
+    py_perf.set_control(controls_receiver);
+
+    let state = Arc::new(State {
+        controls,
+        stats: py_perf.stats.clone(),
+        targets: Mutex::new(Vec::new()),
+        reports: Mutex::new(VecDeque::new()),
+    });
+
+    let collector_state = state.clone();
+    thread::spawn(move || {
+        for snapshot in snapshots {
+            // The profiler detaches the exited processes at the end of every interval too.
+            collector_state
+                .targets
+                .lock()
+                .unwrap()
+                .retain(|target| is_running(target.pid));
+
+            let timing = &snapshot.report.timing;
+            let end = timing.start_time + timing.duration;
+            let mut reports = collector_state.reports.lock().unwrap();
+            reports.push_back(snapshot.report);
+            if let Some(oldest) = end.checked_sub(window) {
+                while reports
+                    .front()
+                    .is_some_and(|report| report.timing.start_time < oldest)
+                {
+                    reports.pop_front();
+                    debug!("dropped an interval older than {:?}", window);
+                }
+            }
+        }
+    });
+
+    // Blocks on `accept` for as long as the process lives.
+    let listener_state = state.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    let state = listener_state.clone();
+                    thread::spawn(move || {
+                        if let Err(err) = serve(&state, &stream) {
+                            warn!("control connection failed: {:?}", err);
+                        }
+                    });
+                }
+                Err(err) => error!("failed to accept a control connection: {}", err),
+            }
+        }
+    });
+
+    info!("listening for requests on {}", socket.display());
+    let result = py_perf.start(stop);
+    if let Err(err) = fs::remove_file(socket) {
+        warn!("failed to remove {}: {}", socket.display(), err);
+    }
+    result.map(|_| ())
+}
+
+/// Answers the requests of a connection until it's closed.
+fn serve(state: &State, stream: &UnixStream) -> Result<()> {
+    let mut writer = BufWriter::new(stream);
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => {
+                info!("control request: {:?}", request);
+                handle(state, request).unwrap_or_else(|err| error_response(&format!("{err:#}")))
+            }
+            Err(err) => error_response(&format!("invalid request: {err}")),
+        };
+        serde_json::to_writer(&mut writer, &response)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+fn handle(state: &State, request: Request) -> Result<Value> {
+    let response = match request {
+        Request::Attach { pid } => {
+            let (reply, outcome) = bounded(1);
+            state
+                .controls
+                .send(Control::Attach { pid, reply })
+                .map_err(|_| anyhow!("the profiler is stopped"))?;
+            let process = outcome.recv()??;
+            state.targets.lock().unwrap().push(process.clone());
+            json!({"status": "ok", "target": process})
+        }
+        Request::Detach { pid } => {
+            let (reply, outcome) = bounded(1);
+            state
+                .controls
+                .send(Control::Detach { pid, reply })
+                .map_err(|_| anyhow!("the profiler is stopped"))?;
+            outcome.recv()??;
+            state
+                .targets
+                .lock()
+                .unwrap()
+                .retain(|target| target.pid != pid);
+            json!({"status": "ok"})
+        }
+        Request::List => {
+            let targets = state.targets.lock().unwrap();
+            json!({"status": "ok", "targets": *targets})
+        }
+        Request::Stats => {
+            let stats = state.stats.read().unwrap();
+            json!({"status": "ok", "stats": *stats})
+        }
+        Request::Snapshot { path } => {
+            let reports: Vec<Report> = state.reports.lock().unwrap().drain(..).collect();
+            if reports.is_empty() {
+                bail!("no interval has been recorded since the previous snapshot");
+            }
+            let report = Report::merge(reports);
+            let file = File::create(&path)
+                .with_context(|| format!("failed to create {}", path.display()))?;
+            let mut writer = BufWriter::new(file);
+            report.pprof(&mut writer)?;
+            writer.flush()?;
+            json!({"status": "ok", "path": path, "stacks": report.stacks.len()})
+        }
+    };
+    Ok(response)
+}
+
+fn error_response(message: &str) -> Value {
+    json!({"status": "error", "message": message})
+}
+
+/// Sends a request to the daemon listening on `socket` and returns its response.
+///
+/// # Errors
+/// This function will return an error if the daemon can't be reached or the response is
+/// malformed.
+pub fn request(socket: &Path, request: &Request) -> Result<Value> {
+    let stream = UnixStream::connect(socket)
+        .with_context(|| format!("failed to connect to the daemon on {}", socket.display()))?;
+    let mut writer = &stream;
+    serde_json::to_writer(&mut writer, request)?;
+    writer.write_all(b"\n")?;
+
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    if line.is_empty() {
+        return Err(anyhow!("the daemon closed the connection"));
+    }
+    Ok(serde_json::from_str(&line)?)
+}
@@ -0,0 +1,306 @@
+// Compares two reports, e.g. before and after a change: a differential flamegraph of the stacks
+// and the functions whose share of the samples changed the most.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use inferno::{differential, flamegraph};
+
+use crate::profile::{Frame, Report};
+use crate::text::{percent, weights_by};
+
+/// How the share of the samples of a function changed between two reports.
+/// Shares are in percent of the total samples of each report, so that reports of different
+/// durations or frequencies can be compared.
+#[derive(Clone, Debug)]
+pub struct FunctionDelta {
+    pub name: String,
+    /// Share of the samples the function was running in, i.e. it was the leaf frame.
+    pub base_self: f64,
+    pub new_self: f64,
+    /// Share of the samples the function was on the stack of.
+    pub base_total: f64,
+    pub new_total: f64,
+}
+
+impl FunctionDelta {
+    #[must_use]
+    pub fn self_delta(&self) -> f64 {
+        self.new_self - self.base_self
+    }
+
+    #[must_use]
+    pub fn total_delta(&self) -> f64 {
+        self.new_total - self.base_total
+    }
+}
+
+pub struct Diff {
+    /// The functions of both reports, ordered by the largest change in self share first.
+    pub functions: Vec<FunctionDelta>,
+}
+
+impl Diff {
+    #[must_use]
+    pub fn new(base: &Report, new: &Report) -> Self {
+        let base_shares = shares(base);
+        let new_shares = shares(new);
+
+        let names: HashSet<&String> = base_shares.keys().chain(new_shares.keys()).collect();
+        let mut functions: Vec<FunctionDelta> = names
+            .into_iter()
+            .map(|name| {
+                let (base_self, base_total) = base_shares.get(name).copied().unwrap_or_default();
+                let (new_self, new_total) = new_shares.get(name).copied().unwrap_or_default();
+                FunctionDelta {
+                    name: name.clone(),
+                    base_self,
+                    new_self,
+                    base_total,
+                    new_total,
+                }
+            })
+            .collect();
+        functions.sort_by(|a, b| {
+            b.self_delta()
+                .abs()
+                .total_cmp(&a.self_delta().abs())
+                .then_with(|| b.total_delta().abs().total_cmp(&a.total_delta().abs()))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        Self { functions }
+    }
+
+    /// Returns the functions whose self or total share grew by more than the given number of
+    /// percentage points.
+    #[must_use]
+    pub fn regressions(
+        &self,
+        max_self_increase: Option<f64>,
+        max_total_increase: Option<f64>,
+    ) -> Vec<&FunctionDelta> {
+        self.functions
+            .iter()
+            .filter(|function| {
+                matches!(max_self_increase, Some(max) if function.self_delta() > max)
+                    || matches!(max_total_increase, Some(max) if function.total_delta() > max)
+            })
+            .collect()
+    }
+
+    /// Writes a table of the `top` functions whose self share changed the most.
+    ///
+    /// # Errors
+    /// This function will return an error if the table can't be written.
+    pub fn write_table<W>(&self, mut writer: W, top: usize) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        let mut table = format!(
+            "{:>9} {:>9} {:>17} {:>17}  FUNCTION\n",
+            "SELF Δ", "TOTAL Δ", "SELF", "TOTAL"
+        );
+        for function in self.functions.iter().take(top) {
+            writeln!(
+                table,
+                "{:>+8.2}% {:>+8.2}% {:>7.2}% → {:>6.2}% {:>7.2}% → {:>6.2}%  {}",
+                function.self_delta(),
+                function.total_delta(),
+                function.base_self,
+                function.new_self,
+                function.base_total,
+                function.new_total,
+                function.name
+            )?;
+        }
+        writer.write_all(table.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Writes a differential flamegraph of the stacks of `new`, colored by how their share of the
+/// samples changed since `base`. The samples of `base` are scaled to the total of `new`.
+///
+/// # Errors
+/// This function will return an error if the flamegraph can't be rendered or written.
+pub fn flamegraph<W>(base: &Report, new: &Report, writer: W) -> Result<()>
+where
+    W: std::io::Write,
+{
+    let mut folded = Vec::new();
+    differential::from_readers(
+        differential::Options {
+            normalize: true,
+            ..differential::Options::default()
+        },
+        folded_stacks(base).as_bytes(),
+        folded_stacks(new).as_bytes(),
+        &mut folded,
+    )?;
+
+    let mut options = flamegraph::Options::default();
+    options.title = "py-perf diff".to_string();
+    flamegraph::from_reader(&mut options, &folded[..], writer)?;
+    Ok(())
+}
+
+/// Returns the (self, total) share of every function, in percent of the samples of the report.
+/// Functions are only identified by their name, since their lines move between versions.
+fn shares(report: &Report) -> HashMap<String, (f64, f64)> {
+    let total: isize = report.stacks.iter().map(|(_, weight)| weight).sum();
+    weights_by(report.stacks.iter(), Frame::name)
+        .into_iter()
+        .map(|(name, weights)| {
+            (
+                name,
+                (
+                    percent(weights.self_weight, total),
+                    percent(weights.total_weight, total),
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Returns the stacks of the report as folded lines, merging the stacks of all threads since
+/// thread names and IDs usually differ between recordings.
+fn folded_stacks(report: &Report) -> String {
+    let mut stacks: BTreeMap<String, isize> = BTreeMap::new();
+    for (key, weight) in &report.stacks {
+        if key.frames.is_empty() {
+            continue;
+        }
+        let names: Vec<String> = key.frames.iter().rev().map(Frame::name).collect();
+        *stacks.entry(names.join(";")).or_insert(0) += weight;
+    }
+
+    let mut folded = String::new();
+    for (stack, weight) in stacks {
+        writeln!(folded, "{stack} {weight}").unwrap();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use pprof::timer::ReportTiming;
+
+    use super::*;
+    use crate::profile::StackKey;
+
+    /// A report of the given stacks, each given by its function names from the leaf to the root.
+    fn report(stacks: &[(&[&str], isize)]) -> Report {
+        let stacks = stacks
+            .iter()
+            .map(|(functions, weight)| {
+                let frames = functions
+                    .iter()
+                    .map(|function| Frame {
+                        file: "app.py".to_string(),
+                        class: String::new(),
+                        function: (*function).to_string(),
+                        start_line: 1,
+                        line: 1,
+                    })
+                    .collect();
+                let key = StackKey {
+                    pid: 42,
+                    tid: 42,
+                    thread_name: "MainThread".to_string(),
+                    comm: "python".to_string(),
+                    frames,
+                };
+                (key, *weight)
+            })
+            .collect();
+        Report {
+            stacks,
+            samples: Vec::new(),
+            processes: Vec::new(),
+            timing: ReportTiming {
+                frequency: 100,
+                start_time: UNIX_EPOCH,
+                duration: Duration::from_secs(1),
+            },
+        }
+    }
+
+    fn function<'a>(diff: &'a Diff, name: &str) -> &'a FunctionDelta {
+        diff.functions
+            .iter()
+            .find(|function| function.name == name)
+            .unwrap()
+    }
+
+    fn assert_share(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    fn names(functions: &[&FunctionDelta]) -> Vec<String> {
+        functions
+            .iter()
+            .map(|function| function.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn shares_are_normalized_to_the_total_of_each_report() {
+        let base = report(&[(&["work", "main"], 3), (&["main"], 1)]);
+        let new = report(&[(&["work", "main"], 30), (&["main"], 10)]);
+        let diff = Diff::new(&base, &new);
+
+        let work = function(&diff, "work");
+        assert_share(work.base_self, 75.0);
+        assert_share(work.new_self, 75.0);
+        let main = function(&diff, "main");
+        assert_share(main.base_self, 25.0);
+        assert_share(main.new_total, 100.0);
+        for function in &diff.functions {
+            assert_share(function.self_delta(), 0.0);
+            assert_share(function.total_delta(), 0.0);
+        }
+        assert!(diff.regressions(Some(0.0), Some(0.0)).is_empty());
+    }
+
+    #[test]
+    fn functions_of_one_report_only_have_a_zero_share_in_the_other() {
+        let base = report(&[(&["work", "main"], 3), (&["main"], 1)]);
+        let new = report(&[(&["work", "main"], 2), (&["parse", "main"], 6)]);
+        let diff = Diff::new(&base, &new);
+
+        let parse = function(&diff, "parse");
+        assert_share(parse.base_self, 0.0);
+        assert_share(parse.base_total, 0.0);
+        assert_share(parse.new_self, 75.0);
+        assert_share(parse.new_total, 75.0);
+
+        let main = function(&diff, "main");
+        assert_share(main.new_self, 0.0);
+        assert_share(main.self_delta(), -25.0);
+        assert_share(main.total_delta(), 0.0);
+
+        // Ordered by the largest change in self share first.
+        let order: Vec<&str> = diff.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(order, vec!["parse", "work", "main"]);
+    }
+
+    #[test]
+    fn regressions_are_the_increases_over_the_thresholds() {
+        let base = report(&[(&["work", "main"], 3), (&["main"], 1)]);
+        let new = report(&[(&["work", "main"], 2), (&["parse", "main"], 6)]);
+        let diff = Diff::new(&base, &new);
+
+        assert_eq!(names(&diff.regressions(Some(10.0), None)), vec!["parse"]);
+        assert_eq!(names(&diff.regressions(None, Some(10.0))), vec!["parse"]);
+        assert_eq!(
+            names(&diff.regressions(Some(0.0), Some(0.0))),
+            vec!["parse"]
+        );
+        // The increase must be larger than the threshold.
+        assert!(diff.regressions(Some(75.0), Some(75.0)).is_empty());
+        assert!(diff.regressions(None, None).is_empty());
+    }
+}
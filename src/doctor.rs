@@ -0,0 +1,300 @@
+// Checks the host for what commonly prevents py-perf from running, and suggests fixes.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::time::Duration;
+
+use anyhow::Result;
+use libbpf_rs::{MapType, ProgramType};
+use nix::sys::resource::{getrlimit, Resource, RLIM_INFINITY};
+use nix::sys::utsname::uname;
+
+use crate::py_perf::PyPerf;
+
+const CAP_SYS_PTRACE: u32 = 19;
+const CAP_SYS_ADMIN: u32 = 21;
+const CAP_PERFMON: u32 = 38;
+const CAP_BPF: u32 = 39;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// py-perf runs, but some features are degraded.
+    Warning,
+    /// py-perf can't run.
+    Error,
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    /// How to fix the problem, if there is one.
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warning(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Warning,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn error(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Error,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+/// Runs every check, the last one loads the BPF programs to make sure the kernel accepts them.
+#[must_use]
+pub fn run() -> Vec<Check> {
+    let capabilities = effective_capabilities();
+    vec![
+        btf(),
+        capabilities_check(capabilities),
+        perf_event_paranoid(capabilities),
+        lockdown(),
+        bpffs(),
+        kernel_support(),
+        memlock(),
+        trial_load(),
+    ]
+}
+
+/// Writes the outcome of every check, with the fixes of the failed ones.
+///
+/// # Errors
+/// This function will return an error if the text can't be written.
+pub fn write<W>(checks: &[Check], mut writer: W) -> Result<()>
+where
+    W: std::io::Write,
+{
+    let mut text = String::new();
+    for check in checks {
+        let status = match check.status {
+            Status::Ok => "ok",
+            Status::Warning => "warning",
+            Status::Error => "error",
+        };
+        writeln!(
+            text,
+            "{:<9} {}: {}",
+            format!("[{status}]"),
+            check.name,
+            check.detail
+        )?;
+        if let Some(fix) = &check.fix {
+            writeln!(text, "{:<9} fix: {fix}", "")?;
+        }
+    }
+    writer.write_all(text.as_bytes())?;
+    Ok(())
+}
+
+fn btf() -> Check {
+    const NAME: &str = "BTF";
+    if fs::metadata("/sys/kernel/btf/vmlinux").is_ok() {
+        Check::ok(NAME, "/sys/kernel/btf/vmlinux is available")
+    } else {
+        Check::error(
+            NAME,
+            "/sys/kernel/btf/vmlinux is missing, the BPF programs can't be relocated",
+            "use a kernel built with CONFIG_DEBUG_INFO_BTF=y",
+        )
+    }
+}
+
+/// Returns the bitmask of the effective capabilities of this process.
+fn effective_capabilities() -> u64 {
+    let status = fs::read_to_string("/proc/self/status").unwrap_or_default();
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("CapEff:"))
+        .and_then(|mask| u64::from_str_radix(mask.trim(), 16).ok())
+        .unwrap_or_default()
+}
+
+const fn has(capabilities: u64, capability: u32) -> bool {
+    capabilities & (1 << capability) != 0
+}
+
+fn capabilities_check(capabilities: u64) -> Check {
+    const NAME: &str = "capabilities";
+    let can_load = has(capabilities, CAP_SYS_ADMIN)
+        || (has(capabilities, CAP_BPF) && has(capabilities, CAP_PERFMON));
+    if !can_load {
+        return Check::error(
+            NAME,
+            "missing CAP_SYS_ADMIN, or CAP_BPF and CAP_PERFMON, to load BPF programs",
+            "run py-perf as root, or grant it cap_bpf,cap_perfmon with setcap",
+        );
+    }
+    if !has(capabilities, CAP_SYS_PTRACE) {
+        return Check::warning(
+            NAME,
+            "missing CAP_SYS_PTRACE, the memory of other users' processes can't be read",
+            "run py-perf as root, or grant it cap_sys_ptrace with setcap",
+        );
+    }
+    Check::ok(NAME, "allowed to load BPF programs and read process memory")
+}
+
+fn perf_event_paranoid(capabilities: u64) -> Check {
+    const NAME: &str = "perf_event_paranoid";
+    let Some(level) = fs::read_to_string("/proc/sys/kernel/perf_event_paranoid")
+        .ok()
+        .and_then(|level| level.trim().parse::<i32>().ok())
+    else {
+        return Check::error(
+            NAME,
+            "/proc/sys/kernel/perf_event_paranoid is missing, perf events aren't supported",
+            "use a kernel built with CONFIG_PERF_EVENTS=y",
+        );
+    };
+
+    if level <= 0 {
+        Check::ok(NAME, format!("{level}, CPU sampling is allowed"))
+    } else if has(capabilities, CAP_PERFMON) || has(capabilities, CAP_SYS_ADMIN) {
+        Check::ok(
+            NAME,
+            format!("{level}, bypassed by CAP_PERFMON or CAP_SYS_ADMIN"),
+        )
+    } else {
+        Check::error(
+            NAME,
+            format!("{level}, CPU sampling requires CAP_PERFMON"),
+            "run py-perf as root, or `sysctl kernel.perf_event_paranoid=0`",
+        )
+    }
+}
+
+fn lockdown() -> Check {
+    const NAME: &str = "lockdown";
+    // e.g. `none [integrity] confidentiality`, the selected mode is in brackets.
+    let Ok(modes) = fs::read_to_string("/sys/kernel/security/lockdown") else {
+        return Check::ok(NAME, "not enabled in this kernel");
+    };
+    let mode = modes
+        .split_whitespace()
+        .find_map(|mode| mode.strip_prefix('[')?.strip_suffix(']'))
+        .unwrap_or("unknown");
+    if mode == "confidentiality" {
+        Check::error(
+            NAME,
+            "confidentiality mode forbids BPF programs from reading memory",
+            "boot with `lockdown=integrity`, or disable Secure Boot",
+        )
+    } else {
+        Check::ok(NAME, mode)
+    }
+}
+
+fn bpffs() -> Check {
+    const NAME: &str = "bpffs";
+    let mounts = fs::read_to_string("/proc/mounts").unwrap_or_default();
+    let mount_point = mounts.lines().find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let mount_point = fields.nth(1)?;
+        (fields.next()? == "bpf").then_some(mount_point)
+    });
+    match mount_point {
+        Some(mount_point) => Check::ok(NAME, format!("mounted on {mount_point}")),
+        None => Check::warning(
+            NAME,
+            "not mounted, bpftool can't pin the maps of py-perf to inspect them",
+            "mount -t bpf bpf /sys/fs/bpf",
+        ),
+    }
+}
+
+fn kernel_support() -> Check {
+    const NAME: &str = "kernel support";
+    let supported = |probe: libbpf_rs::Result<bool>| probe.unwrap_or(false);
+
+    if !supported(ProgramType::PerfEvent.is_supported()) {
+        return Check::error(
+            NAME,
+            "perf event BPF programs aren't supported",
+            "use a kernel newer than 4.9",
+        );
+    }
+    // The stack walker is split in several programs, which tail call each other.
+    if !supported(MapType::ProgArray.is_supported()) {
+        return Check::error(
+            NAME,
+            "tail calls aren't supported",
+            "use a kernel newer than 4.2",
+        );
+    }
+    // Only informational: samples go through a perf buffer, ring buffers aren't used yet.
+    if supported(MapType::RingBuf.is_supported()) {
+        Check::ok(NAME, "perf event programs, tail calls and ring buffers")
+    } else {
+        Check::ok(
+            NAME,
+            "perf event programs and tail calls, ring buffers aren't supported but unused",
+        )
+    }
+}
+
+fn memlock() -> Check {
+    const NAME: &str = "memlock";
+    let Ok((limit, _)) = getrlimit(Resource::RLIMIT_MEMLOCK) else {
+        return Check::warning(NAME, "the limit can't be read", "ulimit -l unlimited");
+    };
+    if limit == RLIM_INFINITY {
+        return Check::ok(NAME, "unlimited");
+    }
+
+    // Since 5.11, BPF memory is accounted to the cgroup instead of the memlock limit.
+    let release = uname().map(|uts| uts.release().to_string_lossy().to_string());
+    let mut version = release.as_deref().unwrap_or_default().split(['.', '-']);
+    let major: u32 = version
+        .next()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_default();
+    let minor: u32 = version
+        .next()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_default();
+    let limit_kib = limit / 1024;
+    if (major, minor) >= (5, 11) {
+        Check::ok(NAME, format!("{limit_kib} KiB, unused by this kernel"))
+    } else {
+        Check::warning(
+            NAME,
+            format!("{limit_kib} KiB, loading the BPF maps may fail"),
+            "ulimit -l unlimited",
+        )
+    }
+}
+
+fn trial_load() -> Check {
+    const NAME: &str = "BPF programs";
+    match PyPerf::new(Duration::ZERO, 99, 128) {
+        Ok(_) => Check::ok(NAME, "loaded and verified"),
+        Err(err) => Check::error(
+            NAME,
+            format!("failed to load: {err:#}"),
+            "fix the other errors, or run with RUST_LOG=debug to see the verifier log",
+        ),
+    }
+}
@@ -0,0 +1,336 @@
+// Dumps what every thread of a Python process is doing right now. The thread states of the
+// interpreter are walked once from userspace, with the same offsets the BPF stack walker uses.
+
+use std::fmt::Write as _;
+
+use anyhow::{bail, Context, Result};
+use remoteprocess::{Process, ProcessMemory};
+use serde::Serialize;
+
+use crate::bindings::PythonVersionOffsets;
+use crate::process_info::ProcessInfo;
+use crate::profile::{get_thread_name, Frame};
+use crate::py_perf::{SupportedVersions, MAX_PYTHON_STACK_DEPTH};
+
+/// Stop following the list of thread states after this many, in case it loops.
+const MAX_THREADS: usize = 4096;
+/// Strings are read in chunks until their terminating NUL, up to this length.
+const MAX_STRING_LEN: usize = 1024;
+const STRING_CHUNK_LEN: usize = 64;
+
+/// The stacks of all the threads of a Python process at one point in time.
+#[derive(Debug, Serialize)]
+pub struct ProcessDump {
+    pub pid: i32,
+    pub python_version: String,
+    pub executable: String,
+    pub threads: Vec<ThreadDump>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ThreadDump {
+    /// The Python thread identifier, as returned by `threading.get_ident()`.
+    pub thread_id: u64,
+    /// The kernel thread ID, only known since Python 3.8.
+    pub native_thread_id: Option<i32>,
+    pub thread_name: String,
+    pub holds_gil: bool,
+    /// Frames from the innermost to the outermost.
+    pub frames: Vec<Frame>,
+    /// Why the stack couldn't be read completely, `frames` has the frames read before the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ProcessDump {
+    /// Reads the stacks of all the threads of the Python process `pid`. The process is paused
+    /// while they are read, so that the stacks are consistent with each other.
+    ///
+    /// # Errors
+    /// This function will return an error if the Python version is not supported or the memory
+    /// of the process can't be read.
+    pub fn new(pid: i32) -> Result<Self> {
+        let process_info = ProcessInfo::new(pid)
+            .with_context(|| format!("failed to fetch process info: {pid}"))?;
+        let supported_versions = SupportedVersions::new()?;
+        let Some(supported_version) = supported_versions.get(&process_info.version) else {
+            bail!("unsupported Python version: {}", process_info.version);
+        };
+        let reader = Reader {
+            process: &process_info.process,
+            offsets: supported_version.offsets(),
+        };
+
+        let _lock = process_info
+            .process
+            .lock()
+            .context("failed to pause the process")?;
+
+        // GDB: _PyRuntime.gilstate.tstate_current
+        let gil_holder = reader.pointer(process_info.thread_state_address)?;
+        // GDB: ((PyInterpreterState *)interp)->tstate_head
+        let mut thread_state = reader.field(
+            process_info.interpreter_address,
+            reader.offsets.py_interpreter_state.tstate_head,
+        )?;
+
+        let mut threads = Vec::new();
+        while thread_state != 0 {
+            if threads.len() == MAX_THREADS {
+                bail!("found more than {MAX_THREADS} thread states, the list may be corrupted");
+            }
+            threads.push(reader.thread(pid, thread_state, gil_holder)?);
+            thread_state = reader.field(thread_state, reader.offsets.py_thread_state.next)?;
+        }
+
+        Ok(Self {
+            pid,
+            python_version: process_info.version.to_string(),
+            executable: process_info
+                .python_info
+                .python_filename
+                .display()
+                .to_string(),
+            threads,
+        })
+    }
+
+    /// Writes the stacks of every thread, one frame per line.
+    ///
+    /// # Errors
+    /// This function will return an error if the text can't be written.
+    pub fn text<W>(&self, mut writer: W) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        let mut text = String::new();
+        writeln!(
+            text,
+            "Process {}: Python {} ({})",
+            self.pid, self.python_version, self.executable
+        )?;
+
+        for thread in &self.threads {
+            let id = match thread.native_thread_id {
+                Some(tid) => tid.to_string(),
+                None => format!("{:#x}", thread.thread_id),
+            };
+            writeln!(
+                text,
+                "\nThread {} \"{}\"{}",
+                id,
+                thread.thread_name,
+                if thread.holds_gil {
+                    " (holds the GIL)"
+                } else {
+                    ""
+                }
+            )?;
+
+            if thread.frames.is_empty() && thread.error.is_none() {
+                writeln!(text, "    <no Python frames>")?;
+            }
+            for frame in &thread.frames {
+                if frame.file.is_empty() {
+                    writeln!(text, "    {}", frame.name())?;
+                } else {
+                    writeln!(
+                        text,
+                        "    {} (defined at {}:{})",
+                        frame.name(),
+                        frame.file,
+                        frame.start_line
+                    )?;
+                }
+            }
+            if let Some(error) = &thread.error {
+                writeln!(text, "    <error: {error}>")?;
+            }
+        }
+
+        writer.write_all(text.as_bytes())?;
+        Ok(())
+    }
+
+    /// Writes the dump as a JSON document.
+    ///
+    /// # Errors
+    /// This function will return an error if the JSON can't be written.
+    pub fn json<W>(&self, mut writer: W) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+/// Reads the interpreter structures from the memory of the process.
+struct Reader<'a> {
+    process: &'a Process,
+    offsets: &'a PythonVersionOffsets,
+}
+
+impl Reader<'_> {
+    fn thread(&self, pid: i32, thread_state: u64, gil_holder: u64) -> Result<ThreadDump> {
+        let offsets = &self.offsets.py_thread_state;
+        let thread_id = self.field(thread_state, offsets.thread_id)?;
+        let native_thread_id = if offsets.native_thread_id < 0 {
+            None
+        } else {
+            i32::try_from(self.field(thread_state, offsets.native_thread_id)?).ok()
+        };
+        let thread_name = match native_thread_id {
+            Some(tid) => get_thread_name(pid, tid),
+            None => String::new(),
+        };
+
+        // A frame can point to freed or garbled memory, the other threads are still worth
+        // dumping.
+        let mut frames = Vec::new();
+        let error = self
+            .frames(thread_state, &mut frames)
+            .err()
+            .map(|err| format!("{err:#}"));
+
+        Ok(ThreadDump {
+            thread_id,
+            native_thread_id,
+            thread_name,
+            holds_gil: thread_state == gil_holder,
+            frames,
+            error,
+        })
+    }
+
+    /// Appends the frames of the thread to `frames`, which keeps the frames read before an error.
+    fn frames(&self, thread_state: u64, frames: &mut Vec<Frame>) -> Result<()> {
+        // Since Python 3.11 the current frame is only reachable through the `CFrame`.
+        let mut frame = if self.offsets.py_thread_state.frame >= 0 {
+            self.field(thread_state, self.offsets.py_thread_state.frame)?
+        } else {
+            let cframe = self.field(thread_state, self.offsets.py_thread_state.cframe)?;
+            if cframe == 0 {
+                return Ok(());
+            }
+            self.field(cframe, self.offsets.py_cframe.current_frame)?
+        };
+
+        while frame != 0 {
+            if frames.len() == MAX_PYTHON_STACK_DEPTH as usize {
+                frames.push(Frame {
+                    file: String::new(),
+                    class: String::new(),
+                    function: "[truncated]".to_string(),
+                    start_line: 0,
+                    line: 0,
+                });
+                break;
+            }
+            frames.push(self.frame(frame)?);
+            frame = self.field(frame, self.offsets.py_frame_object.f_back)?;
+        }
+        Ok(())
+    }
+
+    fn frame(&self, frame: u64) -> Result<Frame> {
+        let offsets = self.offsets;
+        let code = self.field(frame, offsets.py_frame_object.f_code)?;
+        let file = self.string(self.field(code, offsets.py_code_object.co_filename)?)?;
+        let function = self.string(self.field(code, offsets.py_code_object.co_name)?)?;
+        let start_line: u32 = self.read(address(code, offsets.py_code_object.co_firstlineno)?)?;
+
+        Ok(Frame {
+            file,
+            // Best effort, like the BPF stack walker.
+            class: self.class_name(frame, code).unwrap_or_default(),
+            function,
+            start_line,
+            // `f_lineno` is only up to date while tracing, and computing the executing line
+            // needs `f_lasti` and the line table, which the offsets don't have.
+            line: 0,
+        })
+    }
+
+    /// Returns the class of a method, from the type of its first argument if it's named `self`
+    /// or `cls`. This is not perfect but there is no better way to figure it out from the code.
+    fn class_name(&self, frame: u64, code: u64) -> Result<String> {
+        let offsets = self.offsets;
+        // GDB: ((PyTupleObject*)$frame->f_code->co_varnames)->ob_item[0]
+        let varnames = self.field(code, offsets.py_code_object.co_varnames)?;
+        let first_arg = self.string(self.field(varnames, offsets.py_tuple_object.ob_item)?)?;
+        if first_arg != "self" && first_arg != "cls" {
+            return Ok(String::new());
+        }
+
+        // GDB: $frame->f_localsplus[0]->ob_type->tp_name
+        let mut object = self.field(frame, offsets.py_frame_object.f_localsplus)?;
+        if first_arg == "self" {
+            object = self.field(object, offsets.py_object.ob_type)?;
+        }
+        self.c_string(self.field(object, offsets.py_type_object.tp_name)?)
+    }
+
+    /// Reads the pointer at `object + offset`.
+    fn field(&self, object: u64, offset: i64) -> Result<u64> {
+        self.pointer(address(object, offset)?)
+    }
+
+    fn pointer(&self, address: u64) -> Result<u64> {
+        self.read(address)
+    }
+
+    fn read<T: Copy>(&self, address: u64) -> Result<T> {
+        let value = self
+            .process
+            .copy_struct(usize::try_from(address)?)
+            .with_context(|| format!("failed to read memory at 0x{address:x}"))?;
+        Ok(value)
+    }
+
+    /// Reads a Python string object.
+    fn string(&self, object: u64) -> Result<String> {
+        let offsets = &self.offsets.py_string;
+        if offsets.size < 0 {
+            return self.c_string(address(object, offsets.data)?);
+        }
+
+        let size: u32 = self.read(address(object, offsets.size)?)?;
+        let size = usize::try_from(size)?.min(MAX_STRING_LEN);
+        let data = address(object, offsets.data)?;
+        let bytes = self.process.copy(usize::try_from(data)?, size)?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Reads a NUL terminated string.
+    fn c_string(&self, address: u64) -> Result<String> {
+        let mut bytes = Vec::new();
+        while bytes.len() < MAX_STRING_LEN {
+            let chunk = self
+                .process
+                .copy(usize::try_from(address)? + bytes.len(), STRING_CHUNK_LEN)?;
+            match chunk.iter().position(|&b| b == 0) {
+                Some(end) => {
+                    bytes.extend_from_slice(&chunk[..end]);
+                    break;
+                }
+                None => bytes.extend_from_slice(&chunk),
+            }
+        }
+        bytes.truncate(MAX_STRING_LEN);
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// Returns the address of a field, offsets are negative if the field doesn't exist in the
+/// Python version.
+fn address(object: u64, offset: i64) -> Result<u64> {
+    if object == 0 {
+        bail!("null pointer dereference");
+    }
+    match u64::try_from(offset) {
+        Ok(offset) => Ok(object + offset),
+        Err(_) => bail!("field doesn't exist in this Python version"),
+    }
+}
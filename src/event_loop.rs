@@ -0,0 +1,81 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use crossbeam::channel::Receiver;
+use libbpf_rs::PerfBuffer;
+use log::debug;
+
+/// The longest a single poll blocks before the loop re-checks its stop conditions.
+pub const POLL_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A source of raw events, e.g. the BPF perf buffer.
+///
+/// Implementations hand the events they receive over to their consumers
+/// (usually through a channel) while they are being polled.
+pub trait SampleSource {
+    /// Waits for at most `timeout` and processes the events that are ready.
+    ///
+    /// # Errors
+    /// This function will return an error if polling the underlying source fails.
+    fn poll(&mut self, timeout: Duration) -> Result<()>;
+
+    /// Processes every event that is still buffered, without blocking.
+    ///
+    /// # Errors
+    /// This function will return an error if reading the underlying source fails.
+    fn drain(&mut self) -> Result<()>;
+}
+
+impl SampleSource for PerfBuffer<'_> {
+    fn poll(&mut self, timeout: Duration) -> Result<()> {
+        PerfBuffer::poll(self, timeout)?;
+        Ok(())
+    }
+
+    fn drain(&mut self) -> Result<()> {
+        self.consume()?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// The recording duration has elapsed.
+    Deadline,
+    /// A stop message was received.
+    Stopped,
+}
+
+/// Polls `source` continuously until `duration` has elapsed or a message arrives on `stop`.
+/// The events still buffered in the source are drained before returning.
+///
+/// # Errors
+/// This function will return an error if the source fails to drain.
+pub fn run<S: SampleSource>(
+    source: &mut S,
+    duration: Duration,
+    stop: &Receiver<()>,
+) -> Result<StopReason> {
+    // Durations too long to be represented, e.g. `Duration::MAX`, never elapse.
+    let deadline = Instant::now().checked_add(duration);
+    let reason = loop {
+        if stop.try_recv().is_ok() {
+            break StopReason::Stopped;
+        }
+
+        let now = Instant::now();
+        let timeout = match deadline {
+            Some(deadline) if now >= deadline => break StopReason::Deadline,
+            Some(deadline) => POLL_TIMEOUT.min(deadline - now),
+            None => POLL_TIMEOUT,
+        };
+
+        if let Err(err) = source.poll(timeout) {
+            debug!("polling sample source failed with {:?}", err);
+        }
+    };
+
+    debug!("draining sample source, stop reason: {:?}", reason);
+    source.drain()?;
+    Ok(reason)
+}
@@ -0,0 +1,467 @@
+// Uploads profiles to a Pyroscope-compatible HTTP ingestion endpoint, i.e. `POST /ingest` with
+// the gzipped pprof profile as the body. Profiles which can't be uploaded are kept in a bounded
+// spool on disk, and uploaded again once the endpoint is back.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use log::{debug, info, warn};
+
+use crate::profile::Report;
+
+/// The number of times an upload is tried before the profile is spooled.
+const MAX_ATTEMPTS: u32 = 3;
+/// The time to wait before retrying an upload, doubled after every attempt.
+const RETRY_BACKOFF: Duration = Duration::from_secs(1);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const SPOOL_EXTENSION: &str = "pb.gz";
+
+/// The outcome of an upload.
+enum Upload {
+    Done,
+    /// The endpoint refused the profile, e.g. because it's malformed. Retrying won't help.
+    Rejected(String),
+    /// The endpoint couldn't be reached or failed, the profile can be uploaded later.
+    Failed(String),
+}
+
+/// A profile waiting to be uploaded, the time range is in seconds since the UNIX epoch.
+struct Pending {
+    from: u64,
+    until: u64,
+    body: Vec<u8>,
+}
+
+pub struct Exporter {
+    url: String,
+    /// The application name, followed by its labels, e.g. `api{env=prod,region=eu}`.
+    name: String,
+    agent: ureq::Agent,
+    /// The time to wait before the first retry.
+    retry_backoff: Duration,
+    spool_dir: PathBuf,
+    spool_limit: usize,
+}
+
+impl Exporter {
+    /// Returns an exporter uploading to `url`, the profiles are tagged with the `service` name
+    /// and the `labels`. At most `spool_limit` profiles are kept in `spool_dir` while the
+    /// endpoint is down, the oldest ones are dropped first.
+    ///
+    /// # Errors
+    /// This function will return an error if a label is invalid or the spool directory can't
+    /// be created.
+    pub fn new(
+        url: &str,
+        service: &str,
+        labels: &[(String, String)],
+        spool_dir: PathBuf,
+        spool_limit: usize,
+    ) -> Result<Self> {
+        for (key, value) in labels {
+            if [key, value]
+                .iter()
+                .any(|s| s.is_empty() || s.contains(['{', '}', ',', '=']))
+            {
+                bail!("invalid label {key}={value}, it can't be empty or contain `{{}},=`");
+            }
+        }
+        let labels: Vec<String> = labels.iter().map(|(k, v)| format!("{k}={v}")).collect();
+
+        fs::create_dir_all(&spool_dir)
+            .with_context(|| format!("failed to create {}", spool_dir.display()))?;
+
+        Ok(Self {
+            url: url.to_string(),
+            name: format!("{service}{{{}}}", labels.join(",")),
+            agent: ureq::AgentBuilder::new()
+                .timeout(REQUEST_TIMEOUT)
+                .user_agent(concat!("py-perf/", env!("CARGO_PKG_VERSION")))
+                .build(),
+            retry_backoff: RETRY_BACKOFF,
+            spool_dir,
+            spool_limit,
+        })
+    }
+
+    /// Uploads the report, retrying a few times if the endpoint is unavailable. If it's still
+    /// unavailable, the report is spooled. Otherwise the spooled reports are uploaded too.
+    ///
+    /// # Errors
+    /// This function will return an error if the report can't be encoded, or can neither be
+    /// uploaded nor spooled.
+    pub fn export(&self, report: &Report) -> Result<()> {
+        let from = report
+            .timing
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let mut body = Vec::new();
+        report.pprof(&mut body)?;
+        let pending = Pending {
+            from: from.as_secs(),
+            until: (from + report.timing.duration).as_secs(),
+            body,
+        };
+
+        let frequency = u64::try_from(report.timing.frequency).unwrap_or_default();
+        let mut backoff = self.retry_backoff;
+        for attempt in 1..=MAX_ATTEMPTS {
+            match self.upload(&pending, frequency) {
+                Upload::Done => {
+                    info!("uploaded profile to {}", self.url);
+                    self.flush_spool(frequency);
+                    return Ok(());
+                }
+                Upload::Rejected(reason) => bail!("{} rejected the profile: {reason}", self.url),
+                Upload::Failed(reason) => {
+                    warn!(
+                        "upload to {} failed (attempt {attempt}/{MAX_ATTEMPTS}): {reason}",
+                        self.url
+                    );
+                    if attempt < MAX_ATTEMPTS {
+                        thread::sleep(backoff);
+                        backoff *= 2;
+                    }
+                }
+            }
+        }
+
+        self.spool(&pending)
+    }
+
+    fn upload(&self, pending: &Pending, frequency: u64) -> Upload {
+        let result = self
+            .agent
+            .post(&self.url)
+            .query("name", &self.name)
+            .query("from", &pending.from.to_string())
+            .query("until", &pending.until.to_string())
+            .query("format", "pprof")
+            .query("sampleRate", &frequency.to_string())
+            .query("spyName", "py-perf")
+            .set("Content-Type", "application/octet-stream")
+            .send_bytes(&pending.body);
+
+        match result {
+            Ok(_) => Upload::Done,
+            // Overloaded or failing endpoints may accept the profile later on.
+            Err(ureq::Error::Status(status, response)) if status == 429 || status >= 500 => {
+                Upload::Failed(format!("{status} {}", response.status_text()))
+            }
+            Err(ureq::Error::Status(status, response)) => {
+                Upload::Rejected(format!("{status} {}", response.status_text()))
+            }
+            Err(ureq::Error::Transport(transport)) => Upload::Failed(transport.to_string()),
+        }
+    }
+
+    /// Writes the profile to the spool, dropping the oldest ones past the limit.
+    fn spool(&self, pending: &Pending) -> Result<()> {
+        let path = self.spool_dir.join(format!(
+            "{}-{}.{SPOOL_EXTENSION}",
+            pending.from, pending.until
+        ));
+        fs::write(&path, &pending.body)
+            .with_context(|| format!("failed to spool profile to {}", path.display()))?;
+        warn!("spooled profile to {}", path.display());
+
+        let spooled = self.spooled()?;
+        let excess = spooled.len().saturating_sub(self.spool_limit);
+        for (path, _, _) in &spooled[..excess] {
+            fs::remove_file(path)?;
+            warn!("spool is full, dropped {}", path.display());
+        }
+        Ok(())
+    }
+
+    /// Uploads the spooled profiles, oldest first, until the endpoint fails again.
+    fn flush_spool(&self, frequency: u64) {
+        let spooled = match self.spooled() {
+            Ok(spooled) => spooled,
+            Err(err) => {
+                warn!("failed to list the spooled profiles: {:?}", err);
+                return;
+            }
+        };
+
+        for (path, from, until) in spooled {
+            let body = match fs::read(&path) {
+                Ok(body) => body,
+                Err(err) => {
+                    warn!("failed to read {}: {}", path.display(), err);
+                    continue;
+                }
+            };
+            match self.upload(&Pending { from, until, body }, frequency) {
+                Upload::Done => info!("uploaded spooled profile {}", path.display()),
+                Upload::Rejected(reason) => {
+                    warn!("dropped spooled profile {}: {reason}", path.display());
+                }
+                Upload::Failed(reason) => {
+                    debug!("endpoint failed again, keeping the spool: {reason}");
+                    return;
+                }
+            }
+            if let Err(err) = fs::remove_file(&path) {
+                warn!("failed to delete {}: {}", path.display(), err);
+            }
+        }
+    }
+
+    /// Returns the spooled profiles with their time range, oldest first.
+    fn spooled(&self) -> Result<Vec<(PathBuf, u64, u64)>> {
+        let mut spooled = Vec::new();
+        for entry in fs::read_dir(&self.spool_dir)? {
+            let path = entry?.path();
+            if let Some((from, until)) = time_range(&path) {
+                spooled.push((path, from, until));
+            }
+        }
+        spooled.sort_by_key(|(_, from, until)| (*from, *until));
+        Ok(spooled)
+    }
+}
+
+/// Parses the time range from the name of a spooled profile, `<from>-<until>.pb.gz`.
+fn time_range(path: &Path) -> Option<(u64, u64)> {
+    let name = path.file_name()?.to_str()?;
+    let range = name.strip_suffix(SPOOL_EXTENSION)?.strip_suffix('.')?;
+    let (from, until) = range.split_once('-')?;
+    Some((from.parse().ok()?, until.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+
+    use flate2::read::GzDecoder;
+    use pprof::protos::{self, Message};
+    use pprof::timer::ReportTiming;
+    use tempfile::TempDir;
+    use tiny_http::{Response, Server};
+
+    use super::*;
+    use crate::profile::{Frame, StackKey};
+
+    const FROM: u64 = 1_700_000_000;
+
+    /// A request received by the mock endpoint.
+    struct Received {
+        method: String,
+        url: String,
+        content_type: String,
+        user_agent: String,
+        body: Vec<u8>,
+    }
+
+    /// Serves an ingestion endpoint on a free port, which answers the requests with `statuses`
+    /// in turn. Returns its URL and the requests it received.
+    fn endpoint(statuses: Vec<u16>) -> (String, Receiver<Received>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/ingest", server.server_addr().to_ip().unwrap());
+        let (sender, received) = mpsc::channel();
+        thread::spawn(move || {
+            for status in statuses {
+                let mut request = server.recv().unwrap();
+                let header = |name: &'static str| {
+                    request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv(name))
+                        .map(|header| header.value.to_string())
+                        .unwrap_or_default()
+                };
+                let mut received = Received {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    content_type: header("Content-Type"),
+                    user_agent: header("User-Agent"),
+                    body: Vec::new(),
+                };
+                request.as_reader().read_to_end(&mut received.body).unwrap();
+                sender.send(received).unwrap();
+                request.respond(Response::empty(status)).unwrap();
+            }
+        });
+        (url, received)
+    }
+
+    fn exporter(url: &str, spool: &TempDir, spool_limit: usize) -> Exporter {
+        let labels = [("env".to_string(), "prod".to_string())];
+        let mut exporter =
+            Exporter::new(url, "api", &labels, spool.path().to_path_buf(), spool_limit).unwrap();
+        exporter.retry_backoff = Duration::from_millis(1);
+        exporter
+    }
+
+    /// Three samples of `main`, recorded over 10 seconds.
+    fn report() -> Report {
+        let frame = Frame {
+            file: "app.py".to_string(),
+            class: String::new(),
+            function: "main".to_string(),
+            start_line: 1,
+            line: 0,
+        };
+        let key = StackKey {
+            pid: 42,
+            tid: 42,
+            thread_name: "MainThread".to_string(),
+            comm: "python".to_string(),
+            frames: vec![frame],
+        };
+        Report {
+            stacks: vec![(key, 3)],
+            samples: Vec::new(),
+            processes: Vec::new(),
+            timing: ReportTiming {
+                frequency: 100,
+                start_time: UNIX_EPOCH + Duration::from_secs(FROM),
+                duration: Duration::from_secs(10),
+            },
+        }
+    }
+
+    fn spooled(spool: &TempDir) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(spool.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn profiles_are_posted_as_gzipped_pprof() {
+        let (url, received) = endpoint(vec![200]);
+        let spool = TempDir::new().unwrap();
+
+        exporter(&url, &spool, 10).export(&report()).unwrap();
+
+        let request = received.recv().unwrap();
+        assert_eq!(request.method, "POST");
+        let (path, query) = request.url.split_once('?').unwrap();
+        assert_eq!(path, "/ingest");
+        let mut query: Vec<&str> = query.split('&').collect();
+        query.sort_unstable();
+        assert_eq!(
+            query,
+            vec![
+                "format=pprof",
+                "from=1700000000",
+                "name=api%7Benv%3Dprod%7D",
+                "sampleRate=100",
+                "spyName=py-perf",
+                "until=1700000010",
+            ]
+        );
+        assert_eq!(request.content_type, "application/octet-stream");
+        assert!(request.user_agent.starts_with("py-perf/"));
+
+        assert!(request.body.starts_with(&[0x1f, 0x8b]));
+        let mut content = Vec::new();
+        GzDecoder::new(&request.body[..])
+            .read_to_end(&mut content)
+            .unwrap();
+        let pprof = protos::Profile::parse_from_bytes(&content).unwrap();
+        let samples: i64 = pprof.sample.iter().map(|sample| sample.value[0]).sum();
+        assert_eq!(samples, 3);
+        assert!(pprof.string_table.contains(&"main".to_string()));
+        assert!(spooled(&spool).is_empty());
+    }
+
+    #[test]
+    fn failed_uploads_are_retried_then_spooled() {
+        let (url, received) = endpoint(vec![503, 429, 500]);
+        let spool = TempDir::new().unwrap();
+
+        exporter(&url, &spool, 10).export(&report()).unwrap();
+
+        let requests: Vec<Received> = received.try_iter().collect();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(spooled(&spool), vec!["1700000000-1700000010.pb.gz"]);
+        let body = fs::read(spool.path().join("1700000000-1700000010.pb.gz")).unwrap();
+        assert_eq!(body, requests[0].body);
+    }
+
+    #[test]
+    fn unreachable_endpoints_are_retried_then_spooled() {
+        // Nothing listens on the port once the listener is dropped.
+        let address = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let spool = TempDir::new().unwrap();
+
+        exporter(&format!("http://{address}/ingest"), &spool, 10)
+            .export(&report())
+            .unwrap();
+
+        assert_eq!(spooled(&spool), vec!["1700000000-1700000010.pb.gz"]);
+    }
+
+    #[test]
+    fn rejected_profiles_are_neither_retried_nor_spooled() {
+        let (url, received) = endpoint(vec![400]);
+        let spool = TempDir::new().unwrap();
+
+        let err = exporter(&url, &spool, 10).export(&report()).unwrap_err();
+
+        assert!(err.to_string().contains("400"), "{err}");
+        assert_eq!(received.try_iter().count(), 1);
+        assert!(spooled(&spool).is_empty());
+    }
+
+    #[test]
+    fn spooled_profiles_are_uploaded_once_the_endpoint_is_back() {
+        let (url, received) = endpoint(vec![200, 200]);
+        let spool = TempDir::new().unwrap();
+        fs::write(spool.path().join("1600000000-1600000010.pb.gz"), b"spooled").unwrap();
+
+        exporter(&url, &spool, 10).export(&report()).unwrap();
+
+        let requests: Vec<Received> = received.try_iter().collect();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].url.contains("from=1600000000"));
+        assert!(requests[1].url.contains("until=1600000010"));
+        assert_eq!(requests[1].body, b"spooled");
+        assert!(spooled(&spool).is_empty());
+    }
+
+    #[test]
+    fn full_spools_drop_the_oldest_profiles() {
+        let (url, _received) = endpoint(vec![503; 3]);
+        let spool = TempDir::new().unwrap();
+        fs::write(spool.path().join("1600000000-1600000010.pb.gz"), b"old").unwrap();
+        fs::write(spool.path().join("not-a-profile.txt"), b"").unwrap();
+
+        exporter(&url, &spool, 1).export(&report()).unwrap();
+
+        assert_eq!(
+            spooled(&spool),
+            vec!["1700000000-1700000010.pb.gz", "not-a-profile.txt"]
+        );
+    }
+
+    #[test]
+    fn invalid_labels_are_rejected() {
+        let spool = TempDir::new().unwrap();
+        for (key, value) in [("env", ""), ("env", "a,b"), ("{", "prod")] {
+            let labels = [(key.to_string(), value.to_string())];
+            let exporter = Exporter::new(
+                "http://127.0.0.1:1/ingest",
+                "api",
+                &labels,
+                spool.path().to_path_buf(),
+                10,
+            );
+            assert!(exporter.is_err(), "{key}={value}");
+        }
+    }
+}
@@ -0,0 +1,531 @@
+// Writes profiles in the Firefox Profiler's processed profile format, which can be loaded in
+// https://profiler.firefox.com. See https://github.com/firefox-devtools/profiler/blob/main/docs-developer/
+// for the format, the tables below follow `src/types/profile.js` of the profiler.
+
+use std::collections::HashMap;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::profile::{Frame, Report};
+
+const PREPROCESSED_PROFILE_VERSION: u32 = 47;
+const GECKO_PROFILE_VERSION: u32 = 27;
+
+const CATEGORY_OTHER: usize = 0;
+const CATEGORY_PYTHON: usize = 1;
+
+// Markers without a payload are instant markers.
+const MARKER_PHASE_INSTANT: u8 = 0;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Profile {
+    meta: Meta,
+    libs: Vec<()>,
+    pages: Vec<()>,
+    counters: Vec<()>,
+    threads: Vec<Thread>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Meta {
+    /// Sampling interval in milliseconds.
+    interval: f64,
+    /// Profile start time, in milliseconds since the UNIX epoch.
+    start_time: f64,
+    process_type: u32,
+    product: &'static str,
+    stackwalk: u32,
+    version: u32,
+    preprocessed_profile_version: u32,
+    symbolicated: bool,
+    categories: Vec<Category>,
+    marker_schema: Vec<()>,
+}
+
+#[derive(Serialize)]
+struct Category {
+    name: &'static str,
+    color: &'static str,
+    subcategories: Vec<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Thread {
+    process_type: &'static str,
+    process_startup_time: f64,
+    process_shutdown_time: Option<f64>,
+    register_time: f64,
+    unregister_time: Option<f64>,
+    paused_ranges: Vec<()>,
+    name: String,
+    process_name: String,
+    #[serde(rename = "isMainThread")]
+    main: bool,
+    pid: String,
+    tid: i32,
+    samples: SamplesTable,
+    markers: RawMarkerTable,
+    stack_table: StackTable,
+    frame_table: FrameTable,
+    func_table: FuncTable,
+    resource_table: ResourceTable,
+    native_symbols: NativeSymbolTable,
+    string_array: Vec<String>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SamplesTable {
+    length: usize,
+    stack: Vec<Option<usize>>,
+    /// Sample times, in milliseconds relative to `Meta::start_time`.
+    time: Vec<f64>,
+    weight: Option<Vec<isize>>,
+    weight_type: &'static str,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMarkerTable {
+    length: usize,
+    data: Vec<Option<()>>,
+    name: Vec<usize>,
+    start_time: Vec<f64>,
+    end_time: Vec<Option<f64>>,
+    phase: Vec<u8>,
+    category: Vec<usize>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StackTable {
+    length: usize,
+    prefix: Vec<Option<usize>>,
+    frame: Vec<usize>,
+    category: Vec<usize>,
+    subcategory: Vec<usize>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FrameTable {
+    length: usize,
+    address: Vec<i64>,
+    inline_depth: Vec<u32>,
+    category: Vec<usize>,
+    subcategory: Vec<usize>,
+    func: Vec<usize>,
+    native_symbol: Vec<Option<usize>>,
+    #[serde(rename = "innerWindowID")]
+    inner_window_id: Vec<u64>,
+    implementation: Vec<Option<usize>>,
+    line: Vec<Option<u32>>,
+    column: Vec<Option<u32>>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FuncTable {
+    length: usize,
+    name: Vec<usize>,
+    #[serde(rename = "isJS")]
+    is_js: Vec<bool>,
+    #[serde(rename = "relevantForJS")]
+    relevant_for_js: Vec<bool>,
+    resource: Vec<i64>,
+    file_name: Vec<Option<usize>>,
+    line_number: Vec<Option<u32>>,
+    column_number: Vec<Option<u32>>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceTable {
+    length: usize,
+    lib: Vec<usize>,
+    name: Vec<usize>,
+    host: Vec<Option<usize>>,
+    #[serde(rename = "type")]
+    kind: Vec<u32>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NativeSymbolTable {
+    length: usize,
+    lib_index: Vec<usize>,
+    address: Vec<u64>,
+    name: Vec<usize>,
+    function_size: Vec<Option<u32>>,
+}
+
+/// Builds the tables of a single thread, deduplicating strings, functions, frames and stacks.
+#[derive(Default)]
+struct ThreadBuilder<'a> {
+    samples: SamplesTable,
+    markers: RawMarkerTable,
+    stack_table: StackTable,
+    frame_table: FrameTable,
+    func_table: FuncTable,
+    strings: Vec<String>,
+
+    string_ids: HashMap<String, usize>,
+    func_ids: HashMap<(String, &'a str), usize>,
+    frame_ids: HashMap<&'a Frame, usize>,
+    stack_ids: HashMap<(Option<usize>, usize), usize>,
+}
+
+impl<'a> ThreadBuilder<'a> {
+    fn string(&mut self, s: &str) -> usize {
+        if let Some(&id) = self.string_ids.get(s) {
+            return id;
+        }
+        let id = self.strings.len();
+        self.strings.push(s.to_string());
+        self.string_ids.insert(s.to_string(), id);
+        id
+    }
+
+    fn func(&mut self, frame: &'a Frame) -> usize {
+        let key = (frame.name(), frame.file.as_str());
+        if let Some(&id) = self.func_ids.get(&key) {
+            return id;
+        }
+
+        let name = self.string(&key.0);
+        let file_name = (!frame.file.is_empty()).then(|| self.string(&frame.file));
+        let table = &mut self.func_table;
+        let id = table.length;
+        table.name.push(name);
+        table.is_js.push(false);
+        table.relevant_for_js.push(false);
+        table.resource.push(-1);
+        table.file_name.push(file_name);
+        let line = frame.function_line();
+        table.line_number.push((line > 0).then_some(line));
+        table.column_number.push(None);
+        table.length += 1;
+        self.func_ids.insert(key, id);
+        id
+    }
+
+    fn frame(&mut self, frame: &'a Frame) -> usize {
+        if let Some(&id) = self.frame_ids.get(frame) {
+            return id;
+        }
+
+        let func = self.func(frame);
+        let table = &mut self.frame_table;
+        let id = table.length;
+        table.address.push(-1);
+        table.inline_depth.push(0);
+        table.category.push(CATEGORY_PYTHON);
+        table.subcategory.push(0);
+        table.func.push(func);
+        table.native_symbol.push(None);
+        table.inner_window_id.push(0);
+        table.implementation.push(None);
+        let line = frame.known_line();
+        table.line.push((line > 0).then_some(line));
+        table.column.push(None);
+        table.length += 1;
+        self.frame_ids.insert(frame, id);
+        id
+    }
+
+    /// Returns the stack table index of the given frames, ordered from the leaf to the root.
+    fn stack(&mut self, frames: &'a [Frame]) -> Option<usize> {
+        let mut prefix = None;
+        for frame in frames.iter().rev() {
+            let frame = self.frame(frame);
+            let key = (prefix, frame);
+            prefix = Some(if let Some(&id) = self.stack_ids.get(&key) {
+                id
+            } else {
+                let table = &mut self.stack_table;
+                let id = table.length;
+                table.prefix.push(prefix);
+                table.frame.push(frame);
+                table.category.push(CATEGORY_PYTHON);
+                table.subcategory.push(0);
+                table.length += 1;
+                self.stack_ids.insert(key, id);
+                id
+            });
+        }
+        prefix
+    }
+
+    fn sample(&mut self, frames: &'a [Frame], time: f64) {
+        let stack = self.stack(frames);
+        self.samples.stack.push(stack);
+        self.samples.time.push(time);
+        self.samples.length += 1;
+
+        // Surface the samples whose stack couldn't be read completely as markers.
+        if let Some(root) = frames.last().filter(|frame| frame.is_synthetic()) {
+            let name = self.string(&root.function);
+            let markers = &mut self.markers;
+            markers.data.push(None);
+            markers.name.push(name);
+            markers.start_time.push(time);
+            markers.end_time.push(None);
+            markers.phase.push(MARKER_PHASE_INSTANT);
+            markers.category.push(CATEGORY_OTHER);
+            markers.length += 1;
+        }
+    }
+}
+
+impl Report {
+    /// Writes the report in the Firefox Profiler's processed profile format, with one thread
+    /// per sampled thread. Samples with truncated or failed stacks are also added as markers.
+    pub fn gecko<W>(&self, writer: W) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        let start_time = self
+            .timing
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let start_ns = u64::try_from(start_time.as_nanos())?;
+
+        // Sample times are relative to the earliest sample, make them relative to the start.
+        let earliest_ns = self
+            .samples
+            .iter()
+            .map(|sample| sample.timestamp_ns)
+            .min()
+            .unwrap_or(start_ns);
+
+        let mut threads = Vec::new();
+        for ((pid, tid), samples) in self.samples_by_thread() {
+            let key = &self.stacks[samples[0].1].0;
+            let mut builder = ThreadBuilder::default();
+            for (at, stack) in samples {
+                let time = millis_since(earliest_ns + at, start_ns);
+                builder.sample(&self.stacks[stack].0.frames, time);
+            }
+            threads.push(Thread {
+                process_type: "default",
+                process_startup_time: 0.0,
+                process_shutdown_time: None,
+                register_time: 0.0,
+                unregister_time: None,
+                paused_ranges: Vec::new(),
+                name: key.thread_name_or_id(),
+                process_name: format!("python (pid: {pid})"),
+                main: pid == tid,
+                pid: pid.to_string(),
+                tid,
+                samples: SamplesTable {
+                    weight_type: "samples",
+                    ..builder.samples
+                },
+                markers: builder.markers,
+                stack_table: builder.stack_table,
+                frame_table: builder.frame_table,
+                func_table: builder.func_table,
+                resource_table: ResourceTable::default(),
+                native_symbols: NativeSymbolTable::default(),
+                string_array: builder.strings,
+            });
+        }
+
+        let profile = Profile {
+            meta: Meta {
+                interval: millis(self.sample_period_ns()),
+                start_time: millis(start_ns),
+                process_type: 0,
+                product: "py-perf",
+                stackwalk: 0,
+                version: GECKO_PROFILE_VERSION,
+                preprocessed_profile_version: PREPROCESSED_PROFILE_VERSION,
+                symbolicated: true,
+                categories: vec![
+                    Category {
+                        name: "Other",
+                        color: "grey",
+                        subcategories: vec!["Other"],
+                    },
+                    Category {
+                        name: "Python",
+                        color: "yellow",
+                        subcategories: vec!["Other"],
+                    },
+                ],
+                marker_schema: Vec::new(),
+            },
+            libs: Vec::new(),
+            pages: Vec::new(),
+            counters: Vec::new(),
+            threads,
+        };
+        serde_json::to_writer(writer, &profile)?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn millis(nanos: u64) -> f64 {
+    nanos as f64 / 1_000_000.0
+}
+
+fn millis_since(nanos: u64, start_ns: u64) -> f64 {
+    if nanos >= start_ns {
+        millis(nanos - start_ns)
+    } else {
+        -millis(start_ns - nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pprof::timer::ReportTiming;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::profile::{StackKey, TimedSample};
+
+    fn frame(function: &str, start_line: u32) -> Frame {
+        Frame {
+            file: "app.py".to_string(),
+            class: String::new(),
+            function: function.to_string(),
+            start_line,
+            line: 0,
+        }
+    }
+
+    /// Samples of `main` calling `work` on one thread, the last one with a truncated stack.
+    fn report() -> Report {
+        let key = |frames: Vec<Frame>| StackKey {
+            pid: 42,
+            tid: 42,
+            thread_name: "MainThread".to_string(),
+            comm: "python".to_string(),
+            frames,
+        };
+        let truncated = Frame {
+            file: String::new(),
+            class: String::new(),
+            function: "[truncated]".to_string(),
+            start_line: 0,
+            line: 0,
+        };
+        let start_ns = 1_000_000_000_000;
+        let sample = |stack: usize, offset_ms: u64| TimedSample {
+            stack,
+            timestamp_ns: start_ns + offset_ms * 1_000_000,
+            cpu: 0,
+            weight: 1,
+        };
+        Report {
+            stacks: vec![
+                (key(vec![frame("work", 10), frame("main", 1)]), 2),
+                (key(vec![frame("main", 1)]), 1),
+                (key(vec![frame("work", 10), truncated]), 1),
+            ],
+            samples: vec![sample(0, 0), sample(1, 10), sample(0, 20), sample(2, 30)],
+            processes: Vec::new(),
+            timing: ReportTiming {
+                frequency: 100,
+                start_time: UNIX_EPOCH + Duration::from_nanos(start_ns),
+                duration: Duration::from_millis(40),
+            },
+        }
+    }
+
+    fn indexes(table: &Value, column: &str) -> Vec<usize> {
+        table[column]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_u64)
+            .map(|index| usize::try_from(index).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn tables_are_consistent() {
+        let mut json = Vec::new();
+        report().gecko(&mut json).unwrap();
+        let profile: Value = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(profile["meta"]["interval"], 10.0);
+        assert_eq!(profile["meta"]["startTime"], 1_000_000.0);
+        let threads = profile["threads"].as_array().unwrap();
+        assert_eq!(threads.len(), 1);
+        let thread = &threads[0];
+        assert_eq!(thread["name"], "MainThread");
+        assert_eq!(thread["isMainThread"], true);
+
+        // Every column of a table has one entry per row.
+        for name in [
+            "samples",
+            "markers",
+            "stackTable",
+            "frameTable",
+            "funcTable",
+            "resourceTable",
+            "nativeSymbols",
+        ] {
+            let table = thread[name].as_object().unwrap();
+            let length = table["length"].as_u64().unwrap();
+            for (column, values) in table {
+                if let Some(values) = values.as_array() {
+                    assert_eq!(values.len() as u64, length, "{name}.{column}");
+                }
+            }
+        }
+
+        let strings: Vec<&str> = thread["stringArray"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s.as_str().unwrap())
+            .collect();
+        let (samples, stacks, frames, funcs, markers) = (
+            &thread["samples"],
+            &thread["stackTable"],
+            &thread["frameTable"],
+            &thread["funcTable"],
+            &thread["markers"],
+        );
+        let length = |table: &Value| usize::try_from(table["length"].as_u64().unwrap()).unwrap();
+        assert_eq!(length(samples), 4);
+        assert!(indexes(samples, "stack")
+            .iter()
+            .all(|&i| i < length(stacks)));
+        for (i, prefix) in stacks["prefix"].as_array().unwrap().iter().enumerate() {
+            if let Some(prefix) = prefix.as_u64() {
+                assert!(prefix < i as u64, "stacks only refer to earlier prefixes");
+            }
+        }
+        assert!(indexes(stacks, "frame").iter().all(|&i| i < length(frames)));
+        assert!(indexes(frames, "func").iter().all(|&i| i < length(funcs)));
+        assert!(indexes(funcs, "name").iter().all(|&i| i < strings.len()));
+        assert!(indexes(funcs, "fileName")
+            .iter()
+            .all(|&i| i < strings.len()));
+
+        // Live recordings only know where the functions start.
+        let names: Vec<&str> = indexes(funcs, "name").iter().map(|&i| strings[i]).collect();
+        assert_eq!(names, vec!["main", "work", "[truncated]"]);
+        assert_eq!(funcs["lineNumber"], serde_json::json!([1, 10, null]));
+        assert_eq!(frames["line"], serde_json::json!([1, 10, null]));
+
+        // The truncated stack is also a marker.
+        assert_eq!(length(markers), 1);
+        assert_eq!(strings[indexes(markers, "name")[0]], "[truncated]");
+        assert_eq!(markers["startTime"], serde_json::json!([30.0]));
+    }
+}
@@ -0,0 +1,430 @@
+// Shows what py-perf discovers about a Python process or interpreter binary, step by step, so
+// that it's clear which step fails when a process can't be profiled.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use goblin::elf::Elf;
+use py_spy::python_process_info::{
+    get_interpreter_address, get_python_version, get_threadstate_address, PythonProcessInfo,
+};
+use py_spy::version::Version;
+use remoteprocess::Process;
+use serde::Serialize;
+
+use crate::dump::ProcessDump;
+use crate::py_perf::SupportedVersions;
+
+#[derive(Debug, Default, Serialize)]
+pub struct Inspection {
+    /// Only set when a process is inspected.
+    pub pid: Option<i32>,
+    pub executable: String,
+    pub libpython: Option<String>,
+    pub python_version: Option<String>,
+    /// Where the version of a binary was found, it's read from the memory of a process.
+    pub python_version_source: Option<&'static str>,
+    /// e.g. `debug` or `free-threading`, guessed from the file names.
+    pub build_flavour: Vec<String>,
+    pub dockerized: Option<bool>,
+    pub interpreter_address: Option<u64>,
+    pub thread_state_address: Option<u64>,
+    /// The version of the offsets the stack walker would use.
+    pub offsets: Option<String>,
+    pub sanity_walk: Option<SanityWalk>,
+    /// Why the inspection stopped early.
+    pub error: Option<String>,
+}
+
+/// The outcome of walking the stacks of every thread from userspace with the offsets.
+#[derive(Debug, Serialize)]
+pub struct SanityWalk {
+    pub threads: usize,
+    pub frames: usize,
+    pub error: Option<String>,
+}
+
+impl Inspection {
+    /// Inspects the Python process `pid`, the steps after a failing one are skipped.
+    #[must_use]
+    pub fn process(pid: i32) -> Self {
+        let mut inspection = Self {
+            pid: Some(pid),
+            ..Self::default()
+        };
+        if let Err(err) = inspection.discover(pid) {
+            inspection.error = Some(format!("{err:#}"));
+        }
+        inspection
+    }
+
+    /// Inspects an interpreter binary. Its version is read from the `Py_Version` constant or the
+    /// `PY_VERSION` string of the binary, and only guessed from the file names otherwise.
+    ///
+    /// # Errors
+    /// This function will return an error if the binary can't be read.
+    pub fn binary(path: &Path) -> Result<Self> {
+        let path = fs::canonicalize(path)
+            .with_context(|| format!("failed to resolve {}", path.display()))?;
+        let contents =
+            fs::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+        let executable = path.display().to_string();
+
+        let mut inspection = Self {
+            build_flavour: build_flavour(&executable),
+            ..Self::default()
+        };
+        // The `DT_NEEDED` entries of the dynamic section name the shared libpython the binary
+        // is linked with, if any.
+        let elf = Elf::parse(&contents)
+            .with_context(|| format!("failed to parse {} as an ELF binary", path.display()))?;
+        inspection.libpython = elf
+            .libraries
+            .iter()
+            .find(|library| library.starts_with("libpython"))
+            .map(|library| (*library).to_string());
+
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let version = version_from_constant(&elf, &contents)
+            .map(|version| (version, "read from Py_Version"))
+            .or_else(|| {
+                version_from_string(&elf, &contents)
+                    .map(|version| (version, "read from PY_VERSION"))
+            })
+            .or_else(|| {
+                let version = inspection
+                    .libpython
+                    .as_deref()
+                    .and_then(version_from_name)
+                    .or_else(|| version_from_name(&file_name))?;
+                Some((version, "guessed from the file name"))
+            });
+        match version {
+            Some((version, source)) => {
+                inspection.set_version(&version)?;
+                inspection.python_version_source = Some(source);
+            }
+            None => {
+                inspection.error = Some(format!("no Python version in {executable} or its name"));
+            }
+        }
+        inspection.executable = executable;
+        Ok(inspection)
+    }
+
+    fn discover(&mut self, pid: i32) -> Result<()> {
+        let process =
+            Process::new(pid).context("failed to open process: check if it is running.")?;
+        let python_info =
+            PythonProcessInfo::new(&process).context("failed to find the Python interpreter")?;
+        self.executable = python_info.python_filename.display().to_string();
+        self.libpython = python_info
+            .libpython_binary
+            .as_ref()
+            .map(|binary| binary.filename.display().to_string());
+        self.dockerized = Some(python_info.dockerized);
+        self.build_flavour = build_flavour(self.libpython.as_deref().unwrap_or(&self.executable));
+
+        let version = get_python_version(&python_info, &process)
+            .context("failed to detect the Python version")?;
+        self.set_version(&version)?;
+
+        let interpreter_address = get_interpreter_address(&python_info, &process, &version)
+            .context("failed to find the interpreter state")?;
+        self.interpreter_address = Some(interpreter_address as u64);
+        let thread_state_address = get_threadstate_address(&python_info, &version, false)
+            .context("failed to find the thread state")?;
+        self.thread_state_address = Some(thread_state_address as u64);
+
+        if self.offsets.is_some() {
+            self.sanity_walk = Some(match ProcessDump::new(pid) {
+                Ok(dump) => SanityWalk {
+                    threads: dump.threads.len(),
+                    frames: dump.threads.iter().map(|thread| thread.frames.len()).sum(),
+                    error: None,
+                },
+                Err(err) => SanityWalk {
+                    threads: 0,
+                    frames: 0,
+                    error: Some(format!("{err:#}")),
+                },
+            });
+        }
+        Ok(())
+    }
+
+    fn set_version(&mut self, version: &Version) -> Result<()> {
+        self.python_version = Some(version.to_string());
+        self.offsets = SupportedVersions::new()?
+            .get(version)
+            .map(|supported| supported.version().to_string());
+        Ok(())
+    }
+
+    /// Writes one property per line.
+    ///
+    /// # Errors
+    /// This function will return an error if the text can't be written.
+    pub fn text<W>(&self, mut writer: W) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        let unknown = || "unknown".to_string();
+        let address = |address: Option<u64>| address.map_or_else(unknown, |a| format!("0x{a:x}"));
+
+        let mut text = String::new();
+        if let Some(pid) = self.pid {
+            writeln!(text, "pid: {pid}")?;
+        }
+        writeln!(text, "executable: {}", self.executable)?;
+        writeln!(
+            text,
+            "libpython: {}",
+            self.libpython
+                .as_deref()
+                .unwrap_or("none, statically linked")
+        )?;
+        match (&self.python_version, self.python_version_source) {
+            (Some(version), Some(source)) => writeln!(text, "python version: {version}, {source}")?,
+            (Some(version), None) => writeln!(text, "python version: {version}")?,
+            (None, _) => writeln!(text, "python version: {}", unknown())?,
+        }
+        if !self.build_flavour.is_empty() {
+            writeln!(text, "build flavour: {}", self.build_flavour.join(", "))?;
+        }
+        if let Some(dockerized) = self.dockerized {
+            writeln!(text, "in a container: {dockerized}")?;
+        }
+        if self.pid.is_some() {
+            writeln!(
+                text,
+                "interpreter address: {}",
+                address(self.interpreter_address)
+            )?;
+            writeln!(
+                text,
+                "thread state address: {}",
+                address(self.thread_state_address)
+            )?;
+        }
+        match (&self.offsets, &self.python_version) {
+            (Some(offsets), _) => writeln!(text, "offsets: python {offsets}")?,
+            (None, Some(_)) => writeln!(text, "offsets: none, this version is not supported")?,
+            (None, None) => writeln!(text, "offsets: {}", unknown())?,
+        }
+        match &self.sanity_walk {
+            Some(SanityWalk {
+                error: Some(error), ..
+            }) => writeln!(text, "sanity walk: failed, {error}")?,
+            Some(walk) => writeln!(
+                text,
+                "sanity walk: ok, {} frames in {} threads",
+                walk.frames, walk.threads
+            )?,
+            None => {}
+        }
+        if let Some(error) = &self.error {
+            writeln!(text, "error: {error}")?;
+        }
+
+        writer.write_all(text.as_bytes())?;
+        Ok(())
+    }
+
+    /// Writes the inspection as a JSON document.
+    ///
+    /// # Errors
+    /// This function will return an error if the JSON can't be written.
+    pub fn json<W>(&self, mut writer: W) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+/// Reads the `Py_Version` constant of Python 3.11 and later, which holds `PY_VERSION_HEX`.
+fn version_from_constant(elf: &Elf, contents: &[u8]) -> Option<Version> {
+    let symbol = elf
+        .syms
+        .iter()
+        .find(|sym| elf.strtab.get_at(sym.st_name) == Some("Py_Version"))
+        .or_else(|| {
+            elf.dynsyms
+                .iter()
+                .find(|sym| elf.dynstrtab.get_at(sym.st_name) == Some("Py_Version"))
+        })?;
+    let offset = file_offset(elf, symbol.st_value)?;
+    let size = usize::try_from(symbol.st_size).ok()?;
+    let mut bytes = contents.get(offset..offset + size)?.to_vec();
+    if !elf.little_endian {
+        bytes.reverse();
+    }
+    // An unsigned long, the version is in its 4 low bytes.
+    let hex = u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?);
+    version_from_hex(hex)
+}
+
+/// Decodes a `PY_VERSION_HEX`, e.g. `0x030b04f0` for 3.11.4 or `0x030d00c1` for 3.13.0rc1.
+fn version_from_hex(hex: u32) -> Option<Version> {
+    let [major, minor, patch, release] = hex.to_be_bytes();
+    if major == 0 {
+        return None;
+    }
+    let serial = release & 0xf;
+    let release_flags = match release >> 4 {
+        0xa => format!("a{serial}"),
+        0xb => format!("b{serial}"),
+        0xc => format!("rc{serial}"),
+        0xf => String::new(),
+        _ => return None,
+    };
+    Some(Version {
+        major: major.into(),
+        minor: minor.into(),
+        patch: patch.into(),
+        release_flags,
+    })
+}
+
+/// Finds the `PY_VERSION` string, e.g. `3.10.12`, in the read-only data of the binary.
+/// Older versions don't have `Py_Version`, but `Py_GetVersion` formats this string.
+fn version_from_string(elf: &Elf, contents: &[u8]) -> Option<Version> {
+    let rodata = elf
+        .section_headers
+        .iter()
+        .find(|section| elf.shdr_strtab.get_at(section.sh_name) == Some(".rodata"))?;
+    let start = usize::try_from(rodata.sh_offset).ok()?;
+    let end = start + usize::try_from(rodata.sh_size).ok()?;
+    contents
+        .get(start..end)?
+        .split(|&b| b == 0)
+        .filter_map(|string| std::str::from_utf8(string).ok())
+        .find_map(parse_version)
+}
+
+/// Parses a whole `PY_VERSION` string, e.g. `3.11.4`, `3.13.0rc1` or `3.12.1+`.
+fn parse_version(version: &str) -> Option<Version> {
+    let mut parts = version.strip_suffix('+').unwrap_or(version).split('.');
+    let (major, minor, rest) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() || !matches!(major, "2" | "3") || !is_number(minor) {
+        return None;
+    }
+    let patch_len = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (patch, release_flags) = rest.split_at(patch_len);
+    let is_release = release_flags.is_empty()
+        || ["a", "b", "rc"]
+            .iter()
+            .any(|level| release_flags.strip_prefix(level).is_some_and(is_number));
+    if !is_number(patch) || !is_release {
+        return None;
+    }
+    Some(Version {
+        major: major.parse().ok()?,
+        minor: minor.parse().ok()?,
+        patch: patch.parse().ok()?,
+        release_flags: release_flags.to_string(),
+    })
+}
+
+fn is_number(digits: &str) -> bool {
+    (1..=2).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Returns where the virtual address is in the file, from the segments loading it.
+fn file_offset(elf: &Elf, address: u64) -> Option<usize> {
+    let segment = elf.program_headers.iter().find(|header| {
+        header.p_type == goblin::elf::program_header::PT_LOAD
+            && (header.p_vaddr..header.p_vaddr + header.p_filesz).contains(&address)
+    })?;
+    usize::try_from(segment.p_offset + (address - segment.p_vaddr)).ok()
+}
+
+/// Parses the version in names like `python3.11` or `libpython3.11d.so.1.0`.
+fn version_from_name(name: &str) -> Option<Version> {
+    let (_, rest) = name.rsplit_once("python")?;
+    let (major, rest) = rest.split_once('.')?;
+    let minor: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    Some(Version {
+        major: major.parse().ok()?,
+        minor: minor.parse().ok()?,
+        patch: 0,
+        release_flags: String::new(),
+    })
+}
+
+/// Guesses the build flavour from the ABI flags in the name of the interpreter, e.g. the `d` of
+/// `python3.11d` or the `t` of `libpython3.13t.so`.
+fn build_flavour(path: &str) -> Vec<String> {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let Some((_, rest)) = name.rsplit_once("python") else {
+        return Vec::new();
+    };
+    let flags: String = rest
+        .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.')
+        .chars()
+        .take_while(char::is_ascii_lowercase)
+        .collect();
+    // Only the ABI flags, not the `so` of a library without them.
+    let flags = flags.strip_prefix("so").map_or(flags.as_str(), |_| "");
+
+    let mut flavour = Vec::new();
+    if flags.contains('t') {
+        flavour.push("free-threading".to_string());
+    }
+    if flags.contains('d') {
+        flavour.push("debug".to_string());
+    }
+    flavour
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(version: Option<Version>) -> Option<String> {
+        version.map(|version| version.to_string())
+    }
+
+    #[test]
+    fn hex_versions_are_decoded() {
+        assert_eq!(
+            version(version_from_hex(0x030b_04f0)).as_deref(),
+            Some("3.11.4")
+        );
+        assert_eq!(
+            version(version_from_hex(0x030d_00c1)).as_deref(),
+            Some("3.13.0rc1")
+        );
+        assert_eq!(version(version_from_hex(0)), None);
+    }
+
+    #[test]
+    fn only_whole_version_strings_are_parsed() {
+        assert_eq!(
+            version(parse_version("3.10.12")).as_deref(),
+            Some("3.10.12")
+        );
+        assert_eq!(version(parse_version("3.12.1+")).as_deref(), Some("3.12.1"));
+        assert_eq!(
+            version(parse_version("3.13.0b2")).as_deref(),
+            Some("3.13.0b2")
+        );
+        for other in [
+            "3.11",
+            "3.11.4.1",
+            "4.1.1",
+            "3.10.x",
+            "3.10.1b",
+            "OpenSSL 3.0.2",
+        ] {
+            assert!(parse_version(other).is_none(), "{other}");
+        }
+    }
+}
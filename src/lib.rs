@@ -5,11 +5,31 @@
 #![warn(clippy::perf)]
 pub mod arch;
 pub mod bindings;
+pub mod daemon;
+pub mod diff;
+pub mod doctor;
+pub mod dump;
+pub mod event_loop;
+pub mod export;
+pub mod inspect;
+pub mod list;
+pub mod metrics;
+pub mod otlp;
+pub mod profile;
 pub mod py_perf;
 pub mod python_versions;
+pub mod serve;
+pub mod top;
 
 mod bpf;
+mod clock;
+mod gecko;
+mod load;
 mod perf_event;
 mod process_info;
-mod profile;
 mod python_readers;
+mod raw;
+mod speedscope;
+mod text;
+mod timeline;
+mod trace;
@@ -0,0 +1,161 @@
+// Lists the CPython processes running on the host, to pick the targets of `record`.
+
+use std::fmt::Write as _;
+use std::fs;
+
+use anyhow::Result;
+use py_spy::python_process_info::{get_python_version, PythonProcessInfo};
+use remoteprocess::Process;
+use serde::Serialize;
+
+use crate::py_perf::SupportedVersions;
+
+/// Command lines longer than this are cut in the table.
+const MAX_COMMAND_LEN: usize = 60;
+
+#[derive(Debug, Serialize)]
+pub struct PythonProcess {
+    pub pid: i32,
+    pub command: String,
+    /// Unknown if the memory of the process can't be read.
+    pub python_version: Option<String>,
+    pub dockerized: Option<bool>,
+    /// The cgroup of the process, e.g. `/system.slice/app.service`.
+    pub cgroup: String,
+    pub supported: bool,
+}
+
+/// Returns the processes which run CPython, either as their executable or as a shared library.
+///
+/// # Errors
+/// This function will return an error if the processes can't be listed.
+pub fn python_processes() -> Result<Vec<PythonProcess>> {
+    let supported_versions = SupportedVersions::new()?;
+    let own_pid = std::process::id();
+
+    let mut processes = Vec::new();
+    for entry in fs::read_dir("/proc")? {
+        let Some(pid) = entry?
+            .file_name()
+            .to_str()
+            .and_then(|s| s.parse::<i32>().ok())
+        else {
+            continue;
+        };
+        if u32::try_from(pid).ok() == Some(own_pid) || !runs_python(pid) {
+            continue;
+        }
+
+        let mut process = PythonProcess {
+            pid,
+            command: command(pid),
+            python_version: None,
+            dockerized: None,
+            cgroup: cgroup(pid),
+            supported: false,
+        };
+        // The process may have exited in the meantime, or belong to a user we can't read.
+        if let Ok(remote) = Process::new(pid) {
+            if let Ok(python_info) = PythonProcessInfo::new(&remote) {
+                process.dockerized = Some(python_info.dockerized);
+                if let Ok(version) = get_python_version(&python_info, &remote) {
+                    process.supported = supported_versions.get(&version).is_some();
+                    process.python_version = Some(version.to_string());
+                }
+            }
+        }
+        processes.push(process);
+    }
+    processes.sort_by_key(|process| process.pid);
+    Ok(processes)
+}
+
+/// Writes a table of the processes, one per line.
+///
+/// # Errors
+/// This function will return an error if the text can't be written.
+pub fn text<W>(processes: &[PythonProcess], mut writer: W) -> Result<()>
+where
+    W: std::io::Write,
+{
+    let mut text = String::new();
+    writeln!(
+        text,
+        "{:>8}  {:<10} {:<9} {:<9} {:<30} COMMAND",
+        "PID", "VERSION", "SUPPORTED", "CONTAINER", "CGROUP"
+    )?;
+    for process in processes {
+        let yes_no = |value: bool| if value { "yes" } else { "no" };
+        let mut command = process.command.clone();
+        if command.chars().count() > MAX_COMMAND_LEN {
+            command = command.chars().take(MAX_COMMAND_LEN - 3).collect();
+            command.push_str("...");
+        }
+        writeln!(
+            text,
+            "{:>8}  {:<10} {:<9} {:<9} {:<30} {}",
+            process.pid,
+            process.python_version.as_deref().unwrap_or("?"),
+            yes_no(process.supported),
+            process.dockerized.map_or("?", yes_no),
+            process.cgroup,
+            command
+        )?;
+    }
+
+    writer.write_all(text.as_bytes())?;
+    Ok(())
+}
+
+/// Writes the processes as a JSON document.
+///
+/// # Errors
+/// This function will return an error if the JSON can't be written.
+pub fn json<W>(processes: &[PythonProcess], mut writer: W) -> Result<()>
+where
+    W: std::io::Write,
+{
+    serde_json::to_writer_pretty(&mut writer, processes)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Whether the executable or one of the mapped libraries of the process is CPython.
+fn runs_python(pid: i32) -> bool {
+    let is_python = |name: &str| {
+        let name = name.rsplit('/').next().unwrap_or(name);
+        name.starts_with("python") || name.starts_with("libpython")
+    };
+    if let Ok(exe) = fs::read_link(format!("/proc/{pid}/exe")) {
+        if is_python(&exe.to_string_lossy()) {
+            return true;
+        }
+    }
+    fs::read_to_string(format!("/proc/{pid}/maps")).is_ok_and(|maps| {
+        maps.lines()
+            .filter_map(|line| line.split_whitespace().nth(5))
+            .any(is_python)
+    })
+}
+
+fn command(pid: i32) -> String {
+    let cmdline = fs::read(format!("/proc/{pid}/cmdline")).unwrap_or_default();
+    cmdline
+        .split(|&b| b == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(String::from_utf8_lossy)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the cgroup v2 path of the process, or the first v1 one.
+fn cgroup(pid: i32) -> String {
+    let cgroups = fs::read_to_string(format!("/proc/{pid}/cgroup")).unwrap_or_default();
+    let path = |line: &str| line.splitn(3, ':').nth(2).map(str::to_string);
+    cgroups
+        .lines()
+        .find(|line| line.starts_with("0::"))
+        .and_then(path)
+        .or_else(|| cgroups.lines().next().and_then(path))
+        .unwrap_or_default()
+}
@@ -0,0 +1,238 @@
+// Loads reports back from the files py-perf writes: raw recordings, folded stacks and pprof
+// profiles. Only raw recordings keep the individual samples, the other formats are aggregated.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, Read};
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use flate2::read::GzDecoder;
+use pprof::protos;
+use pprof::protos::Message;
+use pprof::timer::ReportTiming;
+
+use crate::profile::{Frame, Report, StackKey};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+impl Report {
+    /// Loads a report from a raw recording, a folded stacks file or a (gzipped) pprof profile.
+    /// The format is detected from the content of the file, `folded_threads` tells whether folded
+    /// stacks start with the thread, see `Report::from_folded`.
+    ///
+    /// # Errors
+    /// This function will return an error if the file can't be read or parsed.
+    pub fn load(path: &Path, folded_threads: bool) -> Result<Self> {
+        let content =
+            fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+
+        let report = if content.starts_with(&GZIP_MAGIC) {
+            let mut decoded = Vec::new();
+            GzDecoder::new(&content[..]).read_to_end(&mut decoded)?;
+            Self::from_pprof(&decoded)
+        } else if content.starts_with(b"{") {
+            Self::from_raw(&content[..])
+        } else if std::str::from_utf8(&content).is_ok() {
+            Self::from_folded(&content[..], folded_threads)
+        } else {
+            Self::from_pprof(&content)
+        };
+        report.with_context(|| format!("failed to load {}", path.display()))
+    }
+
+    /// Reads folded stacks as written by `Report::folded`: the thread name, followed by the
+    /// frames from the root to the leaf, all separated by `;`, and the sample count.
+    /// Other tools, e.g. py-spy or inferno, don't write the thread, in which case `threads` must
+    /// be false so that the root frame isn't taken for a thread.
+    ///
+    /// # Errors
+    /// This function will return an error if a line is malformed, or if there is no stack.
+    pub fn from_folded<R>(reader: R, threads: bool) -> Result<Self>
+    where
+        R: BufRead,
+    {
+        let mut stacks = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let Some((stack, count)) = line.rsplit_once(' ') else {
+                bail!("missing sample count on line {}", i + 1);
+            };
+            let count: isize = count
+                .parse()
+                .with_context(|| format!("invalid sample count on line {}", i + 1))?;
+
+            let mut names = stack.split(';');
+            let thread_name = if threads {
+                names.next().unwrap_or_default().to_string()
+            } else {
+                String::new()
+            };
+            let frames = names.rev().map(|name| frame(name, "", 0, 0)).collect();
+            stacks.push((
+                StackKey {
+                    pid: 0,
+                    tid: 0,
+                    thread_name,
+                    comm: String::new(),
+                    frames,
+                },
+                count,
+            ));
+        }
+        if stacks.is_empty() {
+            bail!("no folded stacks found, the file is empty or in an unknown format");
+        }
+
+        Ok(Self {
+            stacks,
+            samples: Vec::new(),
+            processes: Vec::new(),
+            timing: ReportTiming::default(),
+        })
+    }
+
+    /// Reads an uncompressed pprof profile. Samples are weighted by their sample count, or by
+    /// their first value if they don't have one.
+    ///
+    /// # Errors
+    /// This function will return an error if the profile can't be decoded.
+    pub fn from_pprof(content: &[u8]) -> Result<Self> {
+        let profile = protos::Profile::parse_from_bytes(content)?;
+        let string = |id: i64| -> &str {
+            usize::try_from(id)
+                .ok()
+                .and_then(|id| profile.string_table.get(id))
+                .map_or("", String::as_str)
+        };
+
+        let value_index = profile
+            .sample_type
+            .iter()
+            .position(|ty| string(ty.ty) == "samples")
+            .unwrap_or(0);
+
+        let functions: HashMap<u64, &protos::Function> = profile
+            .function
+            .iter()
+            .map(|function| (function.id, function))
+            .collect();
+        let mut locations: HashMap<u64, Vec<Frame>> = HashMap::new();
+        for location in &profile.location {
+            // Lines of a location are ordered from the innermost inlined function to the caller.
+            let frames = location
+                .line
+                .iter()
+                .filter_map(|line| {
+                    let function = functions.get(&line.function_id)?;
+                    Some(frame(
+                        string(function.name),
+                        string(function.filename),
+                        u32::try_from(function.start_line).unwrap_or_default(),
+                        u32::try_from(line.line).unwrap_or_default(),
+                    ))
+                })
+                .collect();
+            locations.insert(location.id, frames);
+        }
+
+        let mut stacks = Vec::new();
+        for sample in &profile.sample {
+            let mut key = StackKey {
+                pid: 0,
+                tid: 0,
+                thread_name: String::new(),
+                comm: String::new(),
+                frames: sample
+                    .location_id
+                    .iter()
+                    .filter_map(|id| locations.get(id))
+                    .flatten()
+                    .cloned()
+                    .collect(),
+            };
+            for label in &sample.label {
+                match string(label.key) {
+                    "pid" => key.pid = i32::try_from(label.num).unwrap_or_default(),
+                    "thread_id" => key.tid = i32::try_from(label.num).unwrap_or_default(),
+                    "thread_name" | "thread" => key.thread_name = string(label.str).to_string(),
+                    "comm" => key.comm = string(label.str).to_string(),
+                    _ => {}
+                }
+            }
+            let weight = sample.value.get(value_index).copied().unwrap_or_default();
+            stacks.push((key, isize::try_from(weight)?));
+        }
+
+        let frequency = if profile.period > 0 {
+            i32::try_from(1_000_000_000 / profile.period).unwrap_or_default()
+        } else {
+            ReportTiming::default().frequency
+        };
+        Ok(Self {
+            stacks,
+            samples: Vec::new(),
+            processes: Vec::new(),
+            timing: ReportTiming {
+                frequency,
+                start_time: UNIX_EPOCH
+                    + Duration::from_nanos(u64::try_from(profile.time_nanos).unwrap_or_default()),
+                duration: Duration::from_nanos(
+                    u64::try_from(profile.duration_nanos).unwrap_or_default(),
+                ),
+            },
+        })
+    }
+}
+
+/// Returns the frame of a function given by its qualified name, e.g. `Class::method`.
+fn frame(name: &str, file: &str, start_line: u32, line: u32) -> Frame {
+    let (class, function) = name.rsplit_once("::").unwrap_or(("", name));
+    Frame {
+        file: file.to_string(),
+        class: class.to_string(),
+        function: function.to_string(),
+        start_line,
+        line,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folded_stacks_start_with_the_thread() {
+        let folded = "MainThread;main;Worker::run 3\n\nMainThread;main 1\n";
+        let report = Report::from_folded(folded.as_bytes(), true).unwrap();
+
+        assert_eq!(report.stacks.len(), 2);
+        let (key, weight) = &report.stacks[0];
+        assert_eq!(*weight, 3);
+        assert_eq!(key.thread_name, "MainThread");
+        let names: Vec<String> = key.frames.iter().map(Frame::name).collect();
+        assert_eq!(names, vec!["Worker::run", "main"]);
+    }
+
+    #[test]
+    fn folded_stacks_without_threads_keep_the_root_frame() {
+        let report = Report::from_folded("main;run 2".as_bytes(), false).unwrap();
+
+        let (key, weight) = &report.stacks[0];
+        assert_eq!(*weight, 2);
+        assert_eq!(key.thread_name, "");
+        let names: Vec<String> = key.frames.iter().map(Frame::name).collect();
+        assert_eq!(names, vec!["run", "main"]);
+    }
+
+    #[test]
+    fn text_without_stacks_is_rejected() {
+        assert!(Report::from_folded("".as_bytes(), true).is_err());
+        assert!(Report::from_folded("not a profile".as_bytes(), true).is_err());
+    }
+}
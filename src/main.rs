@@ -5,31 +5,84 @@
 #![warn(clippy::perf)]
 
 use chrono::{DateTime, Utc};
-use log::{debug, error, info, trace};
-use std::fs::File;
+use log::{debug, error, info, trace, warn, LevelFilter};
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::process::exit;
-use std::time::Duration;
+use std::thread;
+use std::time::{Duration, SystemTime};
 
-use anyhow::{anyhow, Error, Result};
+use anyhow::{anyhow, bail, Context, Error, Result};
 use clap::{Parser, ValueEnum};
-use crossbeam::channel::{unbounded, Receiver};
+use crossbeam::channel::{unbounded, Receiver, Sender};
 use env_logger::Env;
 use nix::sys::utsname::uname;
 use nix::unistd::Uid;
 
 use py_perf::arch;
+use py_perf::daemon::{self, Request};
+use py_perf::diff::{self, Diff};
+use py_perf::doctor::{self, Status};
+use py_perf::dump::ProcessDump;
+use py_perf::export::Exporter;
+use py_perf::inspect::Inspection;
+use py_perf::list;
+use py_perf::metrics;
+use py_perf::otlp::OtlpExporter;
+use py_perf::profile::{Filter, Report};
 use py_perf::py_perf::PyPerf;
+use py_perf::{serve, top};
+
+/// The number of functions listed in the summary printed at the end of a recording.
+const SUMMARY_TOP: usize = 10;
+/// The date in the names of the files written without an explicit output path.
+const FILE_DATE_FORMAT: &str = "%m%d%Y_%Hh%Mm%Ss";
 
 #[derive(ValueEnum, Copy, Clone, Debug)]
 enum OutputType {
     Pprof,
     Flamegraph,
     Folded,
+    Speedscope,
+    Trace,
+    Gecko,
+    Raw,
+    Text,
+}
+
+impl OutputType {
+    /// Returns the name of the file to write to when no output path is given.
+    fn default_file_name(self, suffix: &str) -> String {
+        let kind = match self {
+            Self::Pprof => "profile.pb.gz",
+            Self::Flamegraph => "flamegraph.svg",
+            Self::Folded => "folded.txt",
+            Self::Speedscope => "speedscope.json",
+            Self::Trace => "trace.json",
+            Self::Gecko => "gecko.json",
+            Self::Raw => "raw.pyperf",
+            Self::Text => "top.txt",
+        };
+        format!("py-perf_{suffix}_{kind}")
+    }
+
+    /// Whether the format lays the individual samples out in time, rather than aggregating them.
+    const fn is_timeline(self) -> bool {
+        matches!(
+            self,
+            Self::Speedscope | Self::Trace | Self::Gecko | Self::Raw
+        )
+    }
 }
 
 #[derive(Parser, Debug)]
 struct InfoSubcommand {}
 
+#[derive(Parser, Debug)]
+struct DoctorSubcommand {}
+
 #[derive(Parser, Debug)]
 struct RecordSubcommand {
     /// Python process IDs to profile.
@@ -41,19 +94,291 @@ struct RecordSubcommand {
     /// The frequency at which profiling data is collected. e.g., 19 samples per second.
     #[clap(long, short = 'q', default_value = "19")]
     frequency: Option<u64>,
-    /// The output format to use.
-    /// Valid values are: `pprof`, `flamegraph` and `folded`.
+    /// The maximum number of Python frames to read per stack, deeper stacks are truncated.
+    /// Up to 512 frames are supported.
+    #[clap(long, default_value = "128")]
+    max_depth: Option<u32>,
+    #[clap(flatten)]
+    outputs: OutputArgs,
+    /// Also write the decoded samples to this raw recording, which can be rendered later on
+    /// with `py-perf report`. `-` writes it to stdout.
+    #[clap(long)]
+    raw: Option<PathBuf>,
+    /// Don't print the functions with the most samples to stderr once the recording is done.
+    #[clap(long)]
+    no_summary: bool,
+    /// Keep recording until stopped, writing one profile per interval to `--out-dir` and
+    /// uploading it to `--export-url`. `--duration` is ignored.
+    #[clap(long, conflicts_with_all = ["output", "raw"])]
+    continuous: bool,
+    /// The time covered by every profile in continuous mode.
+    #[clap(long, default_value = "60s")]
+    interval: humantime::Duration,
+    /// The directory the profiles are written to in continuous mode, it's created if missing.
+    #[clap(long)]
+    out_dir: Option<PathBuf>,
+    /// Delete the profiles older than this in continuous mode. By default, they are kept.
+    #[clap(long)]
+    retain: Option<humantime::Duration>,
+    /// Upload the profile of every interval to this Pyroscope-compatible ingestion endpoint in
+    /// continuous mode, e.g. `http://localhost:4040/ingest`.
+    #[clap(long, requires = "continuous")]
+    export_url: Option<String>,
+    /// The name of the profiled service, sent with the uploaded profiles.
+    #[clap(long, default_value = "python")]
+    service_name: String,
+    /// A `key=value` label sent with the uploaded profiles. Can be repeated.
+    #[clap(long = "label", value_parser = parse_label)]
+    labels: Vec<(String, String)>,
+    /// The directory the profiles which couldn't be uploaded are kept in until the endpoint
+    /// is back.
+    #[clap(long, default_value = "/var/lib/py-perf/spool")]
+    spool_dir: PathBuf,
+    /// The maximum number of profiles kept in the spool, the oldest ones are dropped first.
+    #[clap(long, default_value = "100")]
+    spool_limit: usize,
+    /// Send the profiles to the OTLP/HTTP endpoint of an OpenTelemetry collector, e.g.
+    /// `http://localhost:4318`.
+    #[clap(long)]
+    otlp_endpoint: Option<String>,
+    /// Serve the health metrics of the profiler on this address in continuous mode, e.g.
+    /// `127.0.0.1:9464`, for Prometheus to scrape `/metrics`.
+    #[clap(long, requires = "continuous")]
+    metrics_address: Option<String>,
+}
+
+fn parse_label(label: &str) -> Result<(String, String), String> {
+    match label.split_once('=') {
+        Some((key, value)) => Ok((key.to_string(), value.to_string())),
+        None => Err(format!("expected key=value, got {label}")),
+    }
+}
+
+#[derive(Parser, Debug)]
+struct TopSubcommand {
+    /// Python process ID to profile.
+    #[clap(short, long)]
+    pid: i32,
+    /// The frequency at which profiling data is collected. e.g., 99 samples per second.
+    #[clap(long, short = 'q', default_value = "99")]
+    frequency: u64,
+    /// The maximum number of Python frames to read per stack, deeper stacks are truncated.
+    #[clap(long, default_value = "128")]
+    max_depth: u32,
+    /// How often the display is refreshed, with the samples of the last interval.
+    #[clap(short, long, default_value = "1s")]
+    interval: humantime::Duration,
+}
+
+#[derive(Parser, Debug)]
+struct ServeSubcommand {
+    /// The address to listen on.
+    #[clap(short, long, default_value = "127.0.0.1:6060")]
+    address: String,
+    /// Python process ID to profile when a request doesn't give a `pid`.
+    #[clap(short, long)]
+    pid: Option<i32>,
+    /// The frequency at which profiling data is collected. e.g., 99 samples per second.
+    #[clap(long, short = 'q', default_value = "99")]
+    frequency: u64,
+    /// The maximum number of Python frames to read per stack, deeper stacks are truncated.
+    #[clap(long, default_value = "128")]
+    max_depth: u32,
+}
+
+#[derive(Parser, Debug)]
+struct DaemonSubcommand {
+    /// The Unix socket to listen for requests on.
+    #[clap(short, long, default_value = daemon::DEFAULT_SOCKET)]
+    socket: PathBuf,
+    /// How often the samples are collected, snapshots only have the completed intervals.
+    #[clap(short, long, default_value = "10s")]
+    interval: humantime::Duration,
+    /// How long the intervals are kept for the next snapshot, older ones are dropped.
+    #[clap(short, long, default_value = "10m")]
+    window: humantime::Duration,
+    /// Serve the health metrics of the profiler on this address, e.g. `127.0.0.1:9464`, for
+    /// Prometheus to scrape `/metrics`.
+    #[clap(long)]
+    metrics_address: Option<String>,
+    /// The frequency at which profiling data is collected. e.g., 99 samples per second.
+    #[clap(long, short = 'q', default_value = "99")]
+    frequency: u64,
+    /// The maximum number of Python frames to read per stack, deeper stacks are truncated.
+    #[clap(long, default_value = "128")]
+    max_depth: u32,
+}
+
+#[derive(Parser, Debug)]
+struct CtlSubcommand {
+    /// The Unix socket the daemon listens on.
+    #[clap(short, long, default_value = daemon::DEFAULT_SOCKET)]
+    socket: PathBuf,
+    #[clap(subcommand)]
+    request: CtlRequest,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum CtlRequest {
+    /// Start recording a Python process.
+    Attach { pid: i32 },
+    /// Stop recording a Python process.
+    Detach { pid: i32 },
+    /// List the recorded processes.
+    List,
+    /// Print the statistics of the profiler.
+    Stats,
+    /// Write the samples recorded since the previous snapshot as a pprof profile.
+    Snapshot {
+        /// The path to write the profile to, on the host of the daemon.
+        #[clap(short, long)]
+        output: PathBuf,
+    },
+}
+
+#[derive(Parser, Debug)]
+struct DumpSubcommand {
+    /// Python process ID to dump.
+    #[clap(short, long)]
+    pid: i32,
+    /// Print the stacks as JSON.
+    #[clap(long)]
+    json: bool,
+}
+
+#[derive(Parser, Debug)]
+struct ListSubcommand {
+    /// Print the processes as JSON.
+    #[clap(long)]
+    json: bool,
+}
+
+#[derive(Parser, Debug)]
+struct InspectSubcommand {
+    /// Python process ID to inspect.
+    #[clap(
+        short,
+        long,
+        conflicts_with = "binary",
+        required_unless_present = "binary"
+    )]
+    pid: Option<i32>,
+    /// A Python interpreter binary to inspect instead of a process, e.g. `/usr/bin/python3.11`.
+    binary: Option<PathBuf>,
+    /// Print the inspection as JSON.
+    #[clap(long)]
+    json: bool,
+}
+
+#[derive(Parser, Debug)]
+struct ReportSubcommand {
+    /// The raw recording to render, as written by `record --raw`. `-` reads it from stdin.
+    input: PathBuf,
+    #[clap(flatten)]
+    outputs: OutputArgs,
+    /// Only keep the samples of these process IDs.
+    #[clap(long)]
+    pid: Vec<i32>,
+    /// Only keep the samples of these thread IDs.
+    #[clap(long)]
+    tid: Vec<i32>,
+    /// Only keep the samples of the threads with these names.
+    #[clap(long)]
+    thread_name: Vec<String>,
+    /// Only keep the samples taken at least this long after the recording started, e.g. `5s`.
+    #[clap(long)]
+    since: Option<humantime::Duration>,
+    /// Only keep the samples taken before this long after the recording started, e.g. `30s`.
+    #[clap(long)]
+    until: Option<humantime::Duration>,
+}
+
+#[derive(Parser, Debug)]
+struct MergeSubcommand {
+    /// The profiles to merge: raw recordings, folded stacks or pprof profiles.
+    #[clap(required = true)]
+    inputs: Vec<PathBuf>,
+    /// Folded stacks start with the root frame instead of the thread, as written by other tools
+    /// than py-perf, e.g. py-spy or inferno.
+    #[clap(long)]
+    folded_without_threads: bool,
+    #[clap(flatten)]
+    outputs: OutputArgs,
+}
+
+#[derive(Parser, Debug)]
+struct DiffSubcommand {
+    /// The baseline: a raw recording, folded stacks or a pprof profile.
+    base: PathBuf,
+    /// The profile to compare to the baseline, in any of the formats of `base`.
+    new: PathBuf,
+    /// The path to write the differential flamegraph to, `-` writes it to stdout.
+    /// Defaults to `py-perf_<date>_diff.svg` in the current directory.
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+    /// Folded stacks start with the root frame instead of the thread, as written by other tools
+    /// than py-perf, e.g. py-spy or inferno.
+    #[clap(long)]
+    folded_without_threads: bool,
+    /// The number of functions to list in the table of the largest changes.
+    #[clap(long, default_value = "20")]
+    top: usize,
+    /// Exit with an error if the self share of any function grows by more than this many
+    /// percentage points, e.g. `5` for 10% -> 15%.
+    #[clap(long)]
+    max_self_increase: Option<f64>,
+    /// Exit with an error if the total share of any function grows by more than this many
+    /// percentage points.
+    #[clap(long)]
+    max_total_increase: Option<f64>,
+}
+
+#[derive(clap::Args, Debug)]
+struct OutputArgs {
+    /// The output format to use, can be repeated to write several formats from the same recording.
+    /// Valid values are: `pprof`, `flamegraph`, `folded`, `speedscope`, `trace`, `gecko`, `raw`
+    /// and `text`.
     /// The default value is `pprof`.
     #[clap(short, long, default_value = "pprof")]
-    format: Option<OutputType>,
+    format: Vec<OutputType>,
+    /// The path to write the output to, `-` writes it to stdout.
+    /// Can be repeated, the n-th output is used for the n-th format. Formats without an output
+    /// are written to `py-perf_<date>_<kind>` in the current directory.
+    #[clap(short, long)]
+    output: Vec<PathBuf>,
+    /// The number of functions to list in the `text` output.
+    #[clap(long, default_value = "20")]
+    top: usize,
 }
 
 #[derive(clap::Subcommand, Debug)]
 enum Command {
     /// Record profiles from a running process.
     Record(RecordSubcommand),
+    /// Show the functions using the most CPU in a process, refreshed live.
+    Top(TopSubcommand),
+    /// Serve profiles on demand over HTTP, like Go's `net/http/pprof`.
+    Serve(ServeSubcommand),
+    /// Run as an agent, recording the processes attached through a control socket.
+    Daemon(DaemonSubcommand),
+    /// Send a request to a running daemon.
+    Ctl(CtlSubcommand),
+    /// Print what every thread of a process is doing right now.
+    Dump(DumpSubcommand),
+    /// List the Python processes running on the host.
+    List(ListSubcommand),
+    /// Show what py-perf discovers about a process or an interpreter binary.
+    Inspect(InspectSubcommand),
+    /// Render a raw recording to other output formats.
+    Report(ReportSubcommand),
+    /// Compare two profiles, e.g. before and after a change.
+    Diff(DiffSubcommand),
+    /// Merge several profiles into one, and write it in any output format.
+    Merge(MergeSubcommand),
     /// Print information about host.
     Info(InfoSubcommand),
+    /// Check the host for common problems, and suggest fixes.
+    Doctor(DoctorSubcommand),
 }
 
 #[derive(Parser, Debug)]
@@ -85,14 +410,19 @@ fn main() {
     }
 }
 
-fn ctrlc_channel() -> Result<Receiver<()>, Error> {
+/// Returns a channel which receives a message on Ctrl-C. The sender can be used to stop
+/// on other events too.
+fn ctrlc_channel() -> Result<(Sender<()>, Receiver<()>), Error> {
     let (sender, receiver) = unbounded();
+    let handler_sender = sender.clone();
     ctrlc::set_handler(move || {
         trace!("signal handler is called");
-        sender.send(()).expect("could not send signal on channel.");
+        handler_sender
+            .send(())
+            .expect("could not send signal on channel.");
     })?;
 
-    Ok(receiver)
+    Ok((sender, receiver))
 }
 
 fn run() -> Result<()> {
@@ -113,6 +443,18 @@ fn run() -> Result<()> {
             println!();
         }
 
+        Command::Doctor(_) => {
+            let checks = doctor::run();
+            doctor::write(&checks, io::stdout().lock())?;
+            let errors = checks
+                .iter()
+                .filter(|check| check.status == Status::Error)
+                .count();
+            if errors > 0 {
+                bail!("{errors} checks failed, py-perf is unlikely to work");
+            }
+        }
+
         Command::Record(record) => {
             if !Uid::current().is_root() {
                 return Err(anyhow!(
@@ -120,49 +462,500 @@ fn run() -> Result<()> {
                 ));
             }
 
-            let mut py_perf = PyPerf::new(
-                Duration::from_millis(u64::try_from(record.duration.unwrap().as_millis())?),
-                record.frequency.unwrap(),
-            )?;
+            let outputs = output_paths(&record.outputs)?;
+            if let Some(raw) = &record.raw {
+                if raw.as_os_str() == "-" && outputs.iter().any(|(_, path)| path.as_os_str() == "-")
+                {
+                    bail!("only one output can be written to stdout");
+                }
+            }
 
             if record.pid == 0 {
                 error!("at least one PID must be given");
                 exit(1);
             }
 
+            if record.continuous {
+                return record_continuously(&record);
+            }
+
+            let mut py_perf = PyPerf::new(
+                Duration::from_millis(u64::try_from(record.duration.unwrap().as_millis())?),
+                record.frequency.unwrap(),
+                record.max_depth.unwrap(),
+            )?;
+
             py_perf.record(record.pid)?;
             info!("py-perf is started!");
-            let profile = py_perf.start(&ctrlc_channel().unwrap())?;
+            let (_, stop) = ctrlc_channel()?;
+            let profile = py_perf.start(&stop)?;
             info!("py-perf is stopped!");
 
-            let now: DateTime<Utc> = Utc::now();
-            let name_suffix = now.format("%m%d%Y_%Hh%Mm%Ss");
-
             let report = profile.report()?;
-            match record.format.unwrap() {
-                OutputType::Pprof => {
-                    let path = format!("py-perf_{name_suffix}_profile.pb");
-                    let f = File::create(&path).unwrap();
-                    report.pprof(f)?
-                }
-                OutputType::Flamegraph => {
-                    let path = format!("py-perf_{name_suffix}_flamegraph.svg");
-                    let f = File::create(&path).unwrap();
-                    report.flamegraph(f)?
+            if let Some(raw) = &record.raw {
+                let mut writer = create_writer(raw)?;
+                report.raw(&mut writer)?;
+                writer.flush()?;
+                info!("wrote raw recording to {}", raw.display());
+            }
+            write_outputs(&report, outputs, record.outputs.top)?;
+            if let Some(endpoint) = &record.otlp_endpoint {
+                OtlpExporter::new(endpoint).export(&report)?;
+            }
+            if !record.no_summary {
+                eprintln!();
+                report.text(io::stderr().lock(), SUMMARY_TOP)?;
+            }
+            info!("done!");
+        }
+
+        Command::Top(args) => {
+            if !Uid::current().is_root() {
+                return Err(anyhow!(
+                    "py-perf requires root to load and run BPF programs"
+                ));
+            }
+
+            // Runs until the user quits.
+            let mut py_perf = PyPerf::new(Duration::MAX, args.frequency, args.max_depth)?;
+            let (snapshot_sender, snapshots) = unbounded();
+            py_perf.set_interval(args.interval.into(), snapshot_sender);
+            py_perf.record(args.pid)?;
+
+            // Logs would be drawn over the display, only keep the important ones.
+            if std::env::var_os("RUST_LOG").is_none() {
+                log::set_max_level(LevelFilter::Warn);
+            }
+
+            let (stop_sender, stop) = ctrlc_channel()?;
+            let title = format!("pid {}", args.pid);
+            let ui = thread::spawn(move || top::run(&title, &snapshots, &stop_sender));
+            let result = py_perf.start(&stop);
+            // Dropping the profiler disconnects the snapshots, which closes the display.
+            drop(py_perf);
+            ui.join().expect("top display panicked")?;
+            result?;
+        }
+
+        Command::Serve(args) => {
+            if !Uid::current().is_root() {
+                return Err(anyhow!(
+                    "py-perf requires root to load and run BPF programs"
+                ));
+            }
+
+            // The duration is set by every request.
+            let mut py_perf = PyPerf::new(Duration::ZERO, args.frequency, args.max_depth)?;
+            let (_, stop) = ctrlc_channel()?;
+            serve::run(&mut py_perf, &args.address, args.pid, &stop)?;
+            info!("done!");
+        }
+
+        Command::Daemon(args) => {
+            if !Uid::current().is_root() {
+                return Err(anyhow!(
+                    "py-perf requires root to load and run BPF programs"
+                ));
+            }
+
+            let mut py_perf = PyPerf::new(Duration::MAX, args.frequency, args.max_depth)?;
+            if let Some(address) = &args.metrics_address {
+                metrics::serve(address, py_perf.stats.clone())?;
+            }
+            let (_, stop) = ctrlc_channel()?;
+            daemon::run(
+                &mut py_perf,
+                &args.socket,
+                args.interval.into(),
+                args.window.into(),
+                &stop,
+            )?;
+            info!("done!");
+        }
+
+        Command::Ctl(args) => {
+            let request = match args.request {
+                CtlRequest::Attach { pid } => Request::Attach { pid },
+                CtlRequest::Detach { pid } => Request::Detach { pid },
+                CtlRequest::List => Request::List,
+                CtlRequest::Stats => Request::Stats,
+                // The daemon doesn't run in our working directory.
+                CtlRequest::Snapshot { output } => Request::Snapshot {
+                    path: std::env::current_dir()?.join(output),
+                },
+            };
+            let response = daemon::request(&args.socket, &request)?;
+            if response["status"] == "error" {
+                bail!(
+                    "{}",
+                    response["message"].as_str().unwrap_or("request failed")
+                );
+            }
+            println!("{}", serde_json::to_string_pretty(&response)?);
+        }
+
+        Command::Dump(args) => {
+            let dump = ProcessDump::new(args.pid)?;
+            if args.json {
+                dump.json(io::stdout().lock())?;
+            } else {
+                dump.text(io::stdout().lock())?;
+            }
+        }
+
+        Command::List(args) => {
+            let processes = list::python_processes()?;
+            if args.json {
+                list::json(&processes, io::stdout().lock())?;
+            } else {
+                list::text(&processes, io::stdout().lock())?;
+            }
+        }
+
+        Command::Inspect(args) => {
+            let inspection = match (args.pid, &args.binary) {
+                (Some(pid), _) => Inspection::process(pid),
+                (None, Some(binary)) => Inspection::binary(binary)?,
+                (None, None) => bail!("either --pid or a binary is required"),
+            };
+            if args.json {
+                inspection.json(io::stdout().lock())?;
+            } else {
+                inspection.text(io::stdout().lock())?;
+            }
+        }
+
+        Command::Report(args) => {
+            let outputs = output_paths(&args.outputs)?;
+
+            let report = if args.input.as_os_str() == "-" {
+                Report::from_raw(io::stdin().lock())?
+            } else {
+                let f = File::open(&args.input)
+                    .with_context(|| format!("failed to open {}", args.input.display()))?;
+                Report::from_raw(BufReader::new(f))?
+            };
+
+            let filter = Filter {
+                pids: args.pid,
+                tids: args.tid,
+                thread_names: args.thread_name,
+                since: args.since.map(Into::into),
+                until: args.until.map(Into::into),
+            };
+            let report = report.filter(&filter)?;
+            if report.stacks.is_empty() {
+                warn!("no samples left after filtering");
+            }
+            write_outputs(&report, outputs, args.outputs.top)?;
+        }
+
+        Command::Merge(args) => {
+            let outputs = output_paths(&args.outputs)?;
+
+            let reports = args
+                .inputs
+                .iter()
+                .map(|path| Report::load(path, !args.folded_without_threads))
+                .collect::<Result<Vec<Report>>>()?;
+            let frequencies: BTreeSet<i32> = reports
+                .iter()
+                .map(|report| report.timing.frequency)
+                .collect();
+            if frequencies.len() > 1 {
+                warn!(
+                    "merging profiles sampled at different frequencies: {:?}, \
+                     CPU time is estimated with the first one",
+                    frequencies
+                );
+            }
+            // The merged profile only keeps the individual samples if every input has them.
+            let timed = reports
+                .iter()
+                .filter(|report| !report.samples.is_empty())
+                .count();
+            if timed > 0 && timed < reports.len() {
+                if let Some((format, _)) = outputs.iter().find(|(format, _)| format.is_timeline()) {
+                    bail!(
+                        "the {} format needs individual samples, which only {} of the {} \
+                         profiles have, merge raw recordings only",
+                        format!("{format:?}").to_lowercase(),
+                        timed,
+                        reports.len()
+                    );
                 }
-                OutputType::Folded => {
-                    let path = format!("py-perf_{name_suffix}_folded.txt");
-                    let f = File::create(&path).unwrap();
-                    report.folded(f)?
+                warn!(
+                    "only {} of the {} profiles have individual samples, \
+                     the merged profile has none",
+                    timed,
+                    reports.len()
+                );
+            }
+
+            let report = Report::merge(reports);
+            info!(
+                "merged {} profiles into {} stacks",
+                args.inputs.len(),
+                report.stacks.len()
+            );
+            write_outputs(&report, outputs, args.outputs.top)?;
+        }
+
+        Command::Diff(args) => {
+            let base = Report::load(&args.base, !args.folded_without_threads)?;
+            let new = Report::load(&args.new, !args.folded_without_threads)?;
+
+            let path = args.output.unwrap_or_else(|| {
+                let now: DateTime<Utc> = Utc::now();
+                PathBuf::from(format!("py-perf_{}_diff.svg", now.format(FILE_DATE_FORMAT)))
+            });
+            let mut writer = create_writer(&path)?;
+            diff::flamegraph(&base, &new, &mut writer)?;
+            writer.flush()?;
+            info!("wrote differential flamegraph to {}", path.display());
+
+            // Keep stdout for the flamegraph if it's written there.
+            let diff = Diff::new(&base, &new);
+            if path.as_os_str() == "-" {
+                diff.write_table(io::stderr().lock(), args.top)?;
+            } else {
+                diff.write_table(io::stdout().lock(), args.top)?;
+            }
+
+            let regressions = diff.regressions(args.max_self_increase, args.max_total_increase);
+            for function in &regressions {
+                error!(
+                    "{} regressed: self {:+.2}%, total {:+.2}%",
+                    function.name,
+                    function.self_delta(),
+                    function.total_delta()
+                );
+            }
+            if !regressions.is_empty() {
+                bail!(
+                    "{} functions exceed the allowed increase",
+                    regressions.len()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Records until stopped, writing the profile of every interval to the output directory and
+/// sending it to the export endpoints.
+fn record_continuously(record: &RecordSubcommand) -> Result<()> {
+    if record.out_dir.is_none() && record.export_url.is_none() && record.otlp_endpoint.is_none() {
+        bail!("continuous mode requires --out-dir, --export-url or --otlp-endpoint");
+    }
+    let interval: Duration = record.interval.into();
+    // Profiles are named after the second they start.
+    if interval < Duration::from_secs(1) {
+        bail!("the interval must be at least 1s in continuous mode");
+    }
+    if let Some(out_dir) = &record.out_dir {
+        fs::create_dir_all(out_dir)
+            .with_context(|| format!("failed to create {}", out_dir.display()))?;
+    }
+    let exporter = match &record.export_url {
+        Some(url) => Some(Exporter::new(
+            url,
+            &record.service_name,
+            &record.labels,
+            record.spool_dir.clone(),
+            record.spool_limit,
+        )?),
+        None => None,
+    };
+    let sink = IntervalSink {
+        out_dir: record.out_dir.clone(),
+        formats: record.outputs.format.clone(),
+        top: record.outputs.top,
+        retain: record.retain.map(Duration::from),
+        exporter,
+        otlp: record.otlp_endpoint.as_deref().map(OtlpExporter::new),
+    };
+
+    let mut py_perf = PyPerf::new(
+        Duration::MAX,
+        record.frequency.unwrap(),
+        record.max_depth.unwrap(),
+    )?;
+    let (snapshot_sender, snapshots) = unbounded();
+    py_perf.set_interval(interval, snapshot_sender);
+    py_perf.record(record.pid)?;
+    if let Some(address) = &record.metrics_address {
+        metrics::serve(address, py_perf.stats.clone())?;
+    }
+    info!(
+        "py-perf is started, writing a profile every {}",
+        record.interval
+    );
+
+    // Uploads may block for a while, keep them off the sample processing.
+    let writer = thread::spawn(move || {
+        for snapshot in &snapshots {
+            sink.write(&snapshot.report);
+        }
+        sink
+    });
+
+    let (_, stop) = ctrlc_channel()?;
+    let profile = py_perf.start(&stop)?;
+    info!("py-perf is stopped!");
+    // Dropping the profiler disconnects the snapshots, so the writer is done once they're written.
+    drop(py_perf);
+    let sink = writer.join().expect("profile writer panicked");
+
+    sink.write(&profile.report()?);
+    info!("done!");
+    Ok(())
+}
+
+/// Where the profile of every interval goes in continuous mode.
+struct IntervalSink {
+    out_dir: Option<PathBuf>,
+    formats: Vec<OutputType>,
+    top: usize,
+    retain: Option<Duration>,
+    exporter: Option<Exporter>,
+    otlp: Option<OtlpExporter>,
+}
+
+impl IntervalSink {
+    /// Writes the profile of an interval to the output directory and deletes the expired ones,
+    /// then sends it to the endpoints. Failures are only logged, so that the recording goes on.
+    fn write(&self, report: &Report) {
+        if let Some(out_dir) = &self.out_dir {
+            let start_time: DateTime<Utc> = report.timing.start_time.into();
+            let name_suffix = start_time.format(FILE_DATE_FORMAT).to_string();
+            let outputs = self
+                .formats
+                .iter()
+                .map(|&format| (format, out_dir.join(format.default_file_name(&name_suffix))))
+                .collect();
+            if let Err(err) = write_outputs(report, outputs, self.top) {
+                error!("failed to write profile: {:?}", err);
+            }
+
+            if let Some(retain) = self.retain {
+                if let Err(err) = prune(out_dir, retain) {
+                    error!("failed to delete expired profiles: {:?}", err);
                 }
-            };
-            info!("done!");
+            }
+        }
+
+        if let Some(exporter) = &self.exporter {
+            if let Err(err) = exporter.export(report) {
+                error!("failed to export profile: {:?}", err);
+            }
+        }
+        if let Some(otlp) = &self.otlp {
+            if let Err(err) = otlp.export(report) {
+                error!("failed to export profile over OTLP: {:?}", err);
+            }
+        }
+    }
+}
+
+/// Deletes the profiles in `out_dir` which were last written more than `retain` ago.
+fn prune(out_dir: &Path, retain: Duration) -> Result<()> {
+    let Some(expiry) = SystemTime::now().checked_sub(retain) else {
+        return Ok(());
+    };
+    for entry in fs::read_dir(out_dir)? {
+        let entry = entry?;
+        // Leave the files py-perf didn't write alone.
+        if !entry.file_name().to_string_lossy().starts_with("py-perf_") {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        if metadata.is_file() && metadata.modified()? < expiry {
+            fs::remove_file(entry.path())?;
+            info!("deleted expired profile {}", entry.path().display());
+        }
+    }
+    Ok(())
+}
+
+/// Pairs every format with the path to write it to.
+/// Formats without an explicit output are written to a file named after the current time.
+fn output_paths(args: &OutputArgs) -> Result<Vec<(OutputType, PathBuf)>> {
+    let (formats, outputs) = (&args.format, &args.output);
+    if outputs.len() > formats.len() {
+        bail!(
+            "{} outputs given for {} formats, every output needs a format",
+            outputs.len(),
+            formats.len()
+        );
+    }
+    if outputs
+        .iter()
+        .filter(|path| path.as_os_str() == "-")
+        .count()
+        > 1
+    {
+        bail!("only one output can be written to stdout");
+    }
+
+    let now: DateTime<Utc> = Utc::now();
+    let name_suffix = now.format(FILE_DATE_FORMAT).to_string();
+
+    let paths: Vec<(OutputType, PathBuf)> = formats
+        .iter()
+        .enumerate()
+        .map(|(i, &format)| {
+            let path = outputs
+                .get(i)
+                .cloned()
+                .unwrap_or_else(|| PathBuf::from(format.default_file_name(&name_suffix)));
+            (format, path)
+        })
+        .collect();
+
+    // Otherwise the last format would silently overwrite the others.
+    let mut seen = BTreeSet::new();
+    for (_, path) in &paths {
+        if path.as_os_str() != "-" && !seen.insert(path) {
+            bail!(
+                "{} is the output of several formats, give each one its own --output",
+                path.display()
+            );
         }
     }
+    Ok(paths)
+}
 
+/// Renders the report to every output.
+fn write_outputs(report: &Report, outputs: Vec<(OutputType, PathBuf)>, top: usize) -> Result<()> {
+    for (format, path) in outputs {
+        let mut writer = create_writer(&path)?;
+        match format {
+            OutputType::Pprof => report.pprof(&mut writer)?,
+            OutputType::Flamegraph => report.flamegraph(&mut writer)?,
+            OutputType::Folded => report.folded(&mut writer)?,
+            OutputType::Speedscope => report.speedscope(&mut writer)?,
+            OutputType::Trace => report.trace(&mut writer)?,
+            OutputType::Gecko => report.gecko(&mut writer)?,
+            OutputType::Raw => report.raw(&mut writer)?,
+            OutputType::Text => report.text(&mut writer, top)?,
+        };
+        writer.flush()?;
+        info!("wrote {:?} output to {}", format, path.display());
+    }
     Ok(())
 }
 
+/// Creates the file at `path`, or returns stdout if it's `-`.
+fn create_writer(path: &Path) -> Result<BufWriter<Box<dyn Write>>> {
+    if path.as_os_str() == "-" {
+        return Ok(BufWriter::new(Box::new(io::stdout().lock())));
+    }
+    let f = File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+    Ok(BufWriter::new(Box::new(f)))
+}
+
 pub struct SystemInfo {
     pub os_release: String,
     pub debug_fs: bool,
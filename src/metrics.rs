@@ -0,0 +1,242 @@
+// Exposes the health of the profiler as Prometheus metrics on `/metrics`, so that alerts can fire
+// when profiling silently degrades, e.g. when events are lost or stack walks start failing.
+
+use std::fmt::Write as _;
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use tiny_http::{Header, Response, Server};
+
+use crate::py_perf::{Stats, SYMBOLS_CAPACITY};
+
+const METRICS_PATH: &str = "/metrics";
+
+/// Serves the metrics of `stats` on `address` from a background thread, for as long as the
+/// process lives.
+///
+/// # Errors
+/// This function will return an error if the server can't listen on `address`.
+pub fn serve(address: &str, stats: Arc<RwLock<Stats>>) -> Result<()> {
+    let server =
+        Server::http(address).map_err(|err| anyhow!("failed to listen on {address}: {err}"))?;
+    info!("serving metrics on http://{address}{METRICS_PATH}");
+
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let response = if request.url() == METRICS_PATH {
+                let text = encode(&stats.read().unwrap());
+                let content_type = "text/plain; version=0.0.4";
+                Response::from_string(text).with_header(
+                    Header::from_bytes("Content-Type", content_type)
+                        .expect("header should be valid"),
+                )
+            } else {
+                Response::from_string("not found\n").with_status_code(404)
+            };
+            if let Err(err) = request.respond(response) {
+                error!("failed to send metrics: {}", err);
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Returns the statistics in the Prometheus text exposition format.
+#[must_use]
+pub fn encode(stats: &Stats) -> String {
+    let mut metrics = Metrics::default();
+
+    metrics.counter("events_total", "Samples received from BPF.");
+    metrics.value("", stats.total_events);
+    metrics.counter(
+        "lost_events_total",
+        "Events dropped because the perf buffer was full.",
+    );
+    // Without loss there's no CPU to report, the series still exists so that rates are zero
+    // rather than absent.
+    if stats.lost_events.is_empty() {
+        metrics.value("", 0);
+    }
+    for (cpu, count) in &stats.lost_events {
+        metrics.value(&label("cpu", cpu), count);
+    }
+    metrics.counter("samples_total", "Samples received from BPF, by process.");
+    for (pid, count) in &stats.samples {
+        metrics.value(&label("pid", pid), count);
+    }
+
+    metrics.counter("walk_errors_total", "Failed Python stack walks, by error.");
+    for (code, count) in &stats.walk_errors {
+        metrics.value(&label("code", code), count);
+    }
+    metrics.counter(
+        "map_reading_errors_total",
+        "Samples which couldn't be read from a map.",
+    );
+    metrics.value("", stats.map_reading_errors);
+    metrics.counter(
+        "truncated_stacks_total",
+        "Stacks deeper than the maximum depth.",
+    );
+    metrics.value("", stats.truncated_stacks);
+    metrics.counter("garbled_data_errors_total", "Samples with unreadable data.");
+    metrics.value("", stats.garbled_data_errors);
+
+    metrics.gauge(
+        "symbols",
+        "Entries in the BPF symbols map, counted when new symbols are read.",
+    );
+    metrics.value("", stats.symbols);
+    metrics.gauge("symbols_capacity", "Entries the BPF symbols map can hold.");
+    metrics.value("", SYMBOLS_CAPACITY);
+
+    metrics.counter(
+        "bpf_program_run_time_seconds_total",
+        "Time spent running the BPF programs, needs kernel.bpf_stats_enabled.",
+    );
+    for (name, program) in &stats.programs {
+        // Precise enough for a float, and the ratio to the wall time is what matters.
+        #[allow(clippy::cast_precision_loss)]
+        let seconds = program.run_time_ns as f64 / 1e9;
+        metrics.value(&label("program", name), seconds);
+    }
+    metrics.counter(
+        "bpf_program_runs_total",
+        "Runs of the BPF programs, needs kernel.bpf_stats_enabled.",
+    );
+    for (name, program) in &stats.programs {
+        metrics.value(&label("program", name), program.run_count);
+    }
+
+    metrics.text
+}
+
+/// Returns the `name="value"` label, with the value escaped as the text format requires.
+fn label(name: &str, value: impl std::fmt::Display) -> String {
+    let value = value
+        .to_string()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("{name}=\"{value}\"")
+}
+
+/// Writes metric families one after the other, values belong to the last declared family.
+#[derive(Default)]
+struct Metrics {
+    text: String,
+    name: String,
+}
+
+impl Metrics {
+    fn counter(&mut self, name: &str, help: &str) {
+        self.family(name, help, "counter");
+    }
+
+    fn gauge(&mut self, name: &str, help: &str) {
+        self.family(name, help, "gauge");
+    }
+
+    fn family(&mut self, name: &str, help: &str, kind: &str) {
+        self.name = format!("py_perf_{name}");
+        let _ = writeln!(self.text, "# HELP {} {help}", self.name);
+        let _ = writeln!(self.text, "# TYPE {} {kind}", self.name);
+    }
+
+    fn value(&mut self, labels: &str, value: impl std::fmt::Display) {
+        if labels.is_empty() {
+            let _ = writeln!(self.text, "{} {value}", self.name);
+        } else {
+            let _ = writeln!(self.text, "{}{{{labels}}} {value}", self.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::py_perf::{ErrorCode, ProgramStats};
+
+    fn stats() -> Stats {
+        let mut stats = Stats {
+            total_events: 10,
+            symbols: 5,
+            ..Stats::default()
+        };
+        stats.samples.insert(42, 7);
+        stats.walk_errors.insert(ErrorCode::EmptyStack, 2);
+        stats.walk_errors.insert(ErrorCode::Unknown(99), 1);
+        stats
+    }
+
+    #[test]
+    fn families_have_help_and_type_lines() {
+        let text = encode(&stats());
+
+        assert!(text.starts_with(
+            "# HELP py_perf_events_total Samples received from BPF.\n\
+             # TYPE py_perf_events_total counter\n\
+             py_perf_events_total 10\n"
+        ));
+        assert!(text.contains("# TYPE py_perf_symbols gauge\npy_perf_symbols 5\n"));
+        assert!(text.contains(&format!("py_perf_symbols_capacity {SYMBOLS_CAPACITY}\n")));
+
+        // Every sample belongs to the family declared right before it.
+        let mut family = "";
+        for line in text.lines() {
+            if let Some(declaration) = line.strip_prefix("# TYPE ") {
+                family = declaration.split(' ').next().unwrap();
+            } else if !line.starts_with("# HELP ") {
+                let name = line.split(['{', ' ']).next().unwrap();
+                assert_eq!(name, family, "{line}");
+            }
+        }
+    }
+
+    #[test]
+    fn samples_and_walk_errors_are_labelled() {
+        let text = encode(&stats());
+
+        assert!(text.contains("py_perf_samples_total{pid=\"42\"} 7\n"));
+        assert!(text.contains("py_perf_walk_errors_total{code=\"empty_stack\"} 2\n"));
+        assert!(text.contains("py_perf_walk_errors_total{code=\"unknown_99\"} 1\n"));
+    }
+
+    #[test]
+    fn lost_events_are_zero_without_loss() {
+        let text = encode(&stats());
+        assert!(text
+            .contains("# TYPE py_perf_lost_events_total counter\npy_perf_lost_events_total 0\n"));
+
+        let mut stats = stats();
+        stats.lost_events.insert(0, 3);
+        stats.lost_events.insert(2, 1);
+        let text = encode(&stats);
+        assert!(text.contains(
+            "# TYPE py_perf_lost_events_total counter\n\
+             py_perf_lost_events_total{cpu=\"0\"} 3\n\
+             py_perf_lost_events_total{cpu=\"2\"} 1\n\
+             # HELP"
+        ));
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        let mut stats = stats();
+        stats.programs.insert(
+            "a\"b\\c\nd".to_string(),
+            ProgramStats {
+                run_time_ns: 1_500_000_000,
+                run_count: 4,
+            },
+        );
+        let text = encode(&stats);
+
+        assert!(text.contains(
+            "py_perf_bpf_program_run_time_seconds_total{program=\"a\\\"b\\\\c\\nd\"} 1.5\n"
+        ));
+        assert!(text.contains("py_perf_bpf_program_runs_total{program=\"a\\\"b\\\\c\\nd\"} 4\n"));
+    }
+}
@@ -0,0 +1,636 @@
+// Exports reports as the OpenTelemetry profiles signal over OTLP/HTTP, with protobuf encoding.
+// The signal is still in development, the messages below are the subset py-perf uses of
+// `opentelemetry/proto/profiles/v1development/profiles.proto` from opentelemetry-proto v1.5.0.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+use log::info;
+use nix::unistd::gethostname;
+use prost::Message;
+
+use crate::profile::{Frame, ProcessMetadata, Report, StackKey};
+
+/// The path the collector receives the profiles signal on, relative to the endpoint.
+const PROFILES_PATH: &str = "/v1development/profiles";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone, PartialEq, Message)]
+struct ExportProfilesServiceRequest {
+    #[prost(message, repeated, tag = "1")]
+    resource_profiles: Vec<ResourceProfiles>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ResourceProfiles {
+    #[prost(message, optional, tag = "1")]
+    resource: Option<Resource>,
+    #[prost(message, repeated, tag = "2")]
+    scope_profiles: Vec<ScopeProfiles>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Resource {
+    #[prost(message, repeated, tag = "1")]
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ScopeProfiles {
+    #[prost(message, optional, tag = "1")]
+    scope: Option<InstrumentationScope>,
+    #[prost(message, repeated, tag = "2")]
+    profiles: Vec<Profile>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct InstrumentationScope {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(string, tag = "2")]
+    version: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct KeyValue {
+    #[prost(string, tag = "1")]
+    key: String,
+    #[prost(message, optional, tag = "2")]
+    value: Option<AnyValue>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct AnyValue {
+    #[prost(oneof = "Value", tags = "1, 3")]
+    value: Option<Value>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+enum Value {
+    #[prost(string, tag = "1")]
+    String(String),
+    #[prost(int64, tag = "3")]
+    Int(i64),
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Profile {
+    #[prost(message, repeated, tag = "1")]
+    sample_type: Vec<ValueType>,
+    #[prost(message, repeated, tag = "2")]
+    sample: Vec<Sample>,
+    #[prost(message, repeated, tag = "4")]
+    location_table: Vec<Location>,
+    #[prost(int32, repeated, tag = "5")]
+    location_indices: Vec<i32>,
+    #[prost(message, repeated, tag = "6")]
+    function_table: Vec<Function>,
+    #[prost(message, repeated, tag = "7")]
+    attribute_table: Vec<KeyValue>,
+    #[prost(string, repeated, tag = "10")]
+    string_table: Vec<String>,
+    #[prost(int64, tag = "11")]
+    time_nanos: i64,
+    #[prost(int64, tag = "12")]
+    duration_nanos: i64,
+    #[prost(message, optional, tag = "13")]
+    period_type: Option<ValueType>,
+    #[prost(int64, tag = "14")]
+    period: i64,
+    #[prost(bytes = "vec", tag = "17")]
+    profile_id: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ValueType {
+    #[prost(int32, tag = "1")]
+    type_strindex: i32,
+    #[prost(int32, tag = "2")]
+    unit_strindex: i32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Sample {
+    #[prost(int32, tag = "1")]
+    locations_start_index: i32,
+    #[prost(int32, tag = "2")]
+    locations_length: i32,
+    #[prost(int64, repeated, tag = "3")]
+    value: Vec<i64>,
+    #[prost(int32, repeated, tag = "4")]
+    attribute_indices: Vec<i32>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Location {
+    #[prost(message, repeated, tag = "3")]
+    line: Vec<Line>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Line {
+    #[prost(int32, tag = "1")]
+    function_index: i32,
+    #[prost(int64, tag = "2")]
+    line: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Function {
+    #[prost(int32, tag = "1")]
+    name_strindex: i32,
+    #[prost(int32, tag = "2")]
+    system_name_strindex: i32,
+    #[prost(int32, tag = "3")]
+    filename_strindex: i32,
+    #[prost(int64, tag = "4")]
+    start_line: i64,
+}
+
+/// Sends reports to an OpenTelemetry collector.
+pub struct OtlpExporter {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl OtlpExporter {
+    /// Returns an exporter sending to the OTLP/HTTP `endpoint` of a collector, e.g.
+    /// `http://localhost:4318`.
+    #[must_use]
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            url: format!("{}{PROFILES_PATH}", endpoint.trim_end_matches('/')),
+            agent: ureq::AgentBuilder::new()
+                .timeout(REQUEST_TIMEOUT)
+                .user_agent(concat!("py-perf/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+
+    /// Sends the report, with one resource per profiled process.
+    ///
+    /// # Errors
+    /// This function will return an error if the collector can't be reached or refuses the
+    /// profiles.
+    pub fn export(&self, report: &Report) -> Result<()> {
+        let request = export_request(report)?;
+        let result = self
+            .agent
+            .post(&self.url)
+            .set("Content-Type", "application/x-protobuf")
+            .send_bytes(&request.encode_to_vec());
+
+        match result {
+            Ok(_) => {
+                info!("exported profiles to {}", self.url);
+                Ok(())
+            }
+            Err(ureq::Error::Status(status, response)) => bail!(
+                "{} refused the profiles: {} {}",
+                self.url,
+                status,
+                response.status_text()
+            ),
+            Err(ureq::Error::Transport(transport)) => {
+                bail!("failed to export profiles to {}: {}", self.url, transport)
+            }
+        }
+    }
+}
+
+fn export_request(report: &Report) -> Result<ExportProfilesServiceRequest> {
+    let host = gethostname()?.to_string_lossy().to_string();
+    let scope = InstrumentationScope {
+        name: "py-perf".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    };
+
+    // Reports loaded from other formats may not know their processes.
+    let mut processes = report.processes.clone();
+    for (key, _) in &report.stacks {
+        if !processes.iter().any(|process| process.pid == key.pid) {
+            processes.push(ProcessMetadata {
+                pid: key.pid,
+                python_version: String::new(),
+                executable: String::new(),
+            });
+        }
+    }
+
+    let mut resource_profiles = Vec::new();
+    for process in &processes {
+        let mut builder = OtlpBuilder::default();
+        for (key, weight) in report
+            .stacks
+            .iter()
+            .filter(|(key, _)| key.pid == process.pid)
+        {
+            builder.sample(key, *weight, report.sample_period_ns());
+        }
+        resource_profiles.push(ResourceProfiles {
+            resource: Some(Resource {
+                attributes: resource_attributes(&host, process),
+            }),
+            scope_profiles: vec![ScopeProfiles {
+                scope: Some(scope.clone()),
+                profiles: vec![builder.build(report)?],
+            }],
+        });
+    }
+    Ok(ExportProfilesServiceRequest { resource_profiles })
+}
+
+/// Returns the attributes of a process, following the semantic conventions.
+fn resource_attributes(host: &str, process: &ProcessMetadata) -> Vec<KeyValue> {
+    let mut attributes = vec![
+        string_attribute("host.name", host),
+        int_attribute("process.pid", i64::from(process.pid)),
+        string_attribute("process.runtime.name", "CPython"),
+    ];
+    if !process.executable.is_empty() {
+        attributes.push(string_attribute(
+            "process.executable.path",
+            &process.executable,
+        ));
+    }
+    if !process.python_version.is_empty() {
+        attributes.push(string_attribute(
+            "process.runtime.version",
+            &process.python_version,
+        ));
+    }
+    attributes
+}
+
+fn string_attribute(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.to_string(),
+        value: Some(AnyValue {
+            value: Some(Value::String(value.to_string())),
+        }),
+    }
+}
+
+fn int_attribute(key: &str, value: i64) -> KeyValue {
+    KeyValue {
+        key: key.to_string(),
+        value: Some(AnyValue {
+            value: Some(Value::Int(value)),
+        }),
+    }
+}
+
+/// Builds a profile, deduplicating the strings, functions, locations and attributes.
+#[derive(Default)]
+struct OtlpBuilder<'a> {
+    strings: Vec<String>,
+    string_ids: HashMap<String, i32>,
+    functions: Vec<Function>,
+    function_ids: HashMap<(String, &'a str, u32), i32>,
+    locations: Vec<Location>,
+    location_ids: HashMap<&'a Frame, i32>,
+    location_indices: Vec<i32>,
+    attributes: Vec<KeyValue>,
+    attribute_ids: HashMap<(&'static str, String), i32>,
+    samples: Vec<Sample>,
+}
+
+impl<'a> OtlpBuilder<'a> {
+    fn string(&mut self, s: &str) -> i32 {
+        // The first string of the table must be empty.
+        if self.strings.is_empty() {
+            self.strings.push(String::new());
+            self.string_ids.insert(String::new(), 0);
+        }
+        if let Some(&id) = self.string_ids.get(s) {
+            return id;
+        }
+
+        let id = index(self.strings.len());
+        self.strings.push(s.to_string());
+        self.string_ids.insert(s.to_string(), id);
+        id
+    }
+
+    fn function(&mut self, frame: &'a Frame) -> i32 {
+        let key = (frame.name(), frame.file.as_str(), frame.start_line);
+        if let Some(&id) = self.function_ids.get(&key) {
+            return id;
+        }
+
+        let id = index(self.functions.len());
+        let name = self.string(&key.0);
+        let filename = self.string(&frame.file);
+        self.functions.push(Function {
+            name_strindex: name,
+            system_name_strindex: name,
+            filename_strindex: filename,
+            start_line: i64::from(frame.start_line),
+        });
+        self.function_ids.insert(key, id);
+        id
+    }
+
+    fn location(&mut self, frame: &'a Frame) -> i32 {
+        if let Some(&id) = self.location_ids.get(frame) {
+            return id;
+        }
+
+        let id = index(self.locations.len());
+        let line = Line {
+            function_index: self.function(frame),
+            line: i64::from(frame.line),
+        };
+        self.locations.push(Location { line: vec![line] });
+        self.location_ids.insert(frame, id);
+        id
+    }
+
+    fn attribute(&mut self, key: &'static str, value: String) -> i32 {
+        if let Some(&id) = self.attribute_ids.get(&(key, value.clone())) {
+            return id;
+        }
+
+        let id = index(self.attributes.len());
+        self.attributes.push(string_attribute(key, &value));
+        self.attribute_ids.insert((key, value), id);
+        id
+    }
+
+    fn sample(&mut self, key: &'a StackKey, weight: isize, period: u64) {
+        let start = index(self.location_indices.len());
+        for frame in &key.frames {
+            let location = self.location(frame);
+            self.location_indices.push(location);
+        }
+
+        let attribute_indices = vec![
+            self.attribute("thread.id", key.tid.to_string()),
+            self.attribute("thread.name", key.thread_name_or_id()),
+        ];
+        let count = i64::try_from(weight).unwrap_or_default();
+        let cpu_time = count.saturating_mul(i64::try_from(period).unwrap_or_default());
+        self.samples.push(Sample {
+            locations_start_index: start,
+            locations_length: index(key.frames.len()),
+            value: vec![count, cpu_time],
+            attribute_indices,
+        });
+    }
+
+    fn value_type(&mut self, ty: &str, unit: &str) -> ValueType {
+        ValueType {
+            type_strindex: self.string(ty),
+            unit_strindex: self.string(unit),
+        }
+    }
+
+    fn build(mut self, report: &Report) -> Result<Profile> {
+        let samples = self.value_type("samples", "count");
+        let cpu_time = self.value_type("cpu", "nanoseconds");
+        let since_epoch = report
+            .timing
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Ok(Profile {
+            sample_type: vec![samples, cpu_time.clone()],
+            sample: self.samples,
+            location_table: self.locations,
+            location_indices: self.location_indices,
+            function_table: self.functions,
+            attribute_table: self.attributes,
+            string_table: self.strings,
+            time_nanos: i64::try_from(since_epoch.as_nanos())?,
+            duration_nanos: i64::try_from(report.timing.duration.as_nanos())?,
+            period_type: Some(cpu_time),
+            period: i64::try_from(report.sample_period_ns())?,
+            profile_id: profile_id()?,
+        })
+    }
+}
+
+/// Returns a random profile ID, 16 bytes long.
+fn profile_id() -> Result<Vec<u8>> {
+    let mut id = vec![0; 16];
+    File::open("/dev/urandom")?.read_exact(&mut id)?;
+    Ok(id)
+}
+
+fn index(len: usize) -> i32 {
+    i32::try_from(len).expect("too many entries for a profile")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    use pprof::timer::ReportTiming;
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// A request received by the mock collector: its path, content type and body.
+    type Received = (String, String, Vec<u8>);
+
+    /// Serves a collector on a free port, which answers one request with `status`. Returns its
+    /// endpoint and the request it received.
+    fn collector(status: u16) -> (String, Receiver<Received>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let (sender, received) = mpsc::channel();
+        thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let content_type = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Content-Type"))
+                .map(|header| header.value.to_string())
+                .unwrap_or_default();
+            let mut body = Vec::new();
+            request.as_reader().read_to_end(&mut body).unwrap();
+            sender
+                .send((request.url().to_string(), content_type, body))
+                .unwrap();
+            request.respond(Response::empty(status)).unwrap();
+        });
+        (endpoint, received)
+    }
+
+    fn frame(class: &str, function: &str, start_line: u32) -> Frame {
+        Frame {
+            file: "app.py".to_string(),
+            class: class.to_string(),
+            function: function.to_string(),
+            start_line,
+            line: 0,
+        }
+    }
+
+    /// Stacks of two processes, only the first one is known, sampled at 100 Hz.
+    fn report() -> Report {
+        let stack = |pid: i32, frames: Vec<Frame>| StackKey {
+            pid,
+            tid: pid + 1,
+            thread_name: String::new(),
+            comm: "python".to_string(),
+            frames,
+        };
+        Report {
+            stacks: vec![
+                (
+                    stack(42, vec![frame("Worker", "run", 10), frame("", "main", 1)]),
+                    3,
+                ),
+                (stack(42, vec![frame("", "main", 1)]), 1),
+                (stack(7, vec![frame("", "main", 1)]), 2),
+            ],
+            samples: Vec::new(),
+            processes: vec![ProcessMetadata {
+                pid: 42,
+                python_version: "3.11.4".to_string(),
+                executable: "/usr/bin/python3.11".to_string(),
+            }],
+            timing: ReportTiming {
+                frequency: 100,
+                start_time: UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+                duration: Duration::from_secs(10),
+            },
+        }
+    }
+
+    fn attribute(attribute: &KeyValue) -> (&str, String) {
+        let value = match attribute.value.as_ref().and_then(|v| v.value.as_ref()) {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Int(i)) => i.to_string(),
+            None => String::new(),
+        };
+        (attribute.key.as_str(), value)
+    }
+
+    fn attributes(resource: &ResourceProfiles) -> Vec<(&str, String)> {
+        let resource = resource.resource.as_ref().unwrap();
+        resource.attributes.iter().map(attribute).collect()
+    }
+
+    #[test]
+    fn profiles_are_posted_to_the_collector() {
+        let (endpoint, received) = collector(200);
+
+        OtlpExporter::new(&format!("{endpoint}/"))
+            .export(&report())
+            .unwrap();
+
+        let (path, content_type, body) = received.recv().unwrap();
+        assert_eq!(path, "/v1development/profiles");
+        assert_eq!(content_type, "application/x-protobuf");
+        let request = ExportProfilesServiceRequest::decode(&body[..]).unwrap();
+
+        // One resource per process, the ones only known from the stacks come last.
+        assert_eq!(request.resource_profiles.len(), 2);
+        let host = gethostname().unwrap().to_string_lossy().to_string();
+        assert_eq!(
+            attributes(&request.resource_profiles[0]),
+            vec![
+                ("host.name", host.clone()),
+                ("process.pid", "42".to_string()),
+                ("process.runtime.name", "CPython".to_string()),
+                ("process.executable.path", "/usr/bin/python3.11".to_string()),
+                ("process.runtime.version", "3.11.4".to_string()),
+            ]
+        );
+        assert_eq!(
+            attributes(&request.resource_profiles[1]),
+            vec![
+                ("host.name", host),
+                ("process.pid", "7".to_string()),
+                ("process.runtime.name", "CPython".to_string()),
+            ]
+        );
+
+        let scope = &request.resource_profiles[0].scope_profiles[0];
+        assert_eq!(scope.scope.as_ref().unwrap().name, "py-perf");
+        let profile = &scope.profiles[0];
+        let string = |id: i32| profile.string_table[usize::try_from(id).unwrap()].as_str();
+        assert_eq!(string(0), "");
+        let sample_types: Vec<(&str, &str)> = profile
+            .sample_type
+            .iter()
+            .map(|ty| (string(ty.type_strindex), string(ty.unit_strindex)))
+            .collect();
+        assert_eq!(
+            sample_types,
+            vec![("samples", "count"), ("cpu", "nanoseconds")]
+        );
+        assert_eq!(profile.period, 10_000_000);
+        assert_eq!(profile.time_nanos, 1_700_000_000_000_000_000);
+        assert_eq!(profile.duration_nanos, 10_000_000_000);
+        assert_eq!(profile.profile_id.len(), 16);
+
+        let values: Vec<Vec<i64>> = profile
+            .sample
+            .iter()
+            .map(|sample| sample.value.clone())
+            .collect();
+        assert_eq!(values, vec![vec![3, 30_000_000], vec![1, 10_000_000]]);
+
+        // Locations go from the leaf to the root, and are shared by the samples.
+        let stacks: Vec<Vec<&str>> = profile
+            .sample
+            .iter()
+            .map(|sample| {
+                let start = usize::try_from(sample.locations_start_index).unwrap();
+                let length = usize::try_from(sample.locations_length).unwrap();
+                profile.location_indices[start..start + length]
+                    .iter()
+                    .map(|&location| {
+                        let line =
+                            &profile.location_table[usize::try_from(location).unwrap()].line[0];
+                        let function =
+                            &profile.function_table[usize::try_from(line.function_index).unwrap()];
+                        string(function.name_strindex)
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(stacks, vec![vec!["Worker::run", "main"], vec!["main"]]);
+        assert_eq!(profile.location_table.len(), 2);
+        assert_eq!(profile.function_table.len(), 2);
+        assert_eq!(profile.function_table[0].start_line, 10);
+        assert_eq!(
+            string(profile.function_table[0].filename_strindex),
+            "app.py"
+        );
+
+        // Threads without a name are named after their ID.
+        let threads: Vec<(&str, String)> = profile.sample[0]
+            .attribute_indices
+            .iter()
+            .map(|&i| attribute(&profile.attribute_table[usize::try_from(i).unwrap()]))
+            .collect();
+        assert_eq!(
+            threads,
+            vec![
+                ("thread.id", "43".to_string()),
+                ("thread.name", "43".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn refused_profiles_are_an_error() {
+        let (endpoint, _received) = collector(400);
+
+        let err = OtlpExporter::new(&endpoint).export(&report()).unwrap_err();
+
+        assert!(
+            err.to_string().contains("refused the profiles: 400"),
+            "{err}"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use log::info;
 use std::fmt;
+use std::path::Path;
 
 use anyhow::{Context, Result};
 
@@ -85,3 +86,8 @@ impl ProcessInfo {
         Ok(children)
     }
 }
+
+/// Whether the process `pid` is still running, its ID may be reused once it has exited.
+pub fn is_running(pid: Pid) -> bool {
+    Path::new(&format!("/proc/{pid}")).exists()
+}
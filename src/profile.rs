@@ -1,34 +1,297 @@
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write;
 use std::fs;
-use std::time::{Duration, SystemTime};
-use std::{collections::HashMap, path::Path};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use pprof::protos;
 use pprof::protos::Message;
 use pprof::timer::ReportTiming;
 use pprof::{Frames, Symbol};
+use serde::{Deserialize, Serialize};
+
+/// A Python frame as read from the interpreter.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Frame {
+    pub file: String,
+    pub class: String,
+    pub function: String,
+    /// The first line of the function.
+    pub start_line: u32,
+    /// The line the frame is executing, 0 if unknown.
+    pub line: u32,
+}
+
+impl Frame {
+    /// Returns whether the frame was added by py-perf to mark an incomplete stack.
+    #[must_use]
+    pub fn is_synthetic(&self) -> bool {
+        self.file.is_empty() && self.function.starts_with('[')
+    }
+
+    /// Returns the first line of the function, or the executing line if it's unknown, which
+    /// identifies the function along with its file. 0 if neither is known.
+    #[must_use]
+    pub const fn function_line(&self) -> u32 {
+        if self.start_line > 0 {
+            self.start_line
+        } else {
+            self.line
+        }
+    }
+
+    /// Returns the executing line, or the first line of the function if it's unknown, e.g. for
+    /// live recordings. 0 if neither is known.
+    #[must_use]
+    pub const fn known_line(&self) -> u32 {
+        if self.line > 0 {
+            self.line
+        } else {
+            self.start_line
+        }
+    }
+
+    /// Returns the qualified name of the function, e.g. `Class::method`.
+    #[must_use]
+    pub fn name(&self) -> String {
+        if self.class.is_empty() {
+            self.function.clone()
+        } else {
+            format!("{}::{}", self.class, self.function)
+        }
+    }
+
+    fn symbol(&self) -> Symbol {
+        Symbol {
+            name: Some(self.name().into_bytes()),
+            addr: None,
+            lineno: (self.line > 0).then_some(self.line),
+            filename: Some(PathBuf::from(&self.file)),
+        }
+    }
+}
+
+/// The key samples are aggregated by: the thread they were taken on and their stack.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StackKey {
+    pub pid: i32,
+    pub tid: i32,
+    pub thread_name: String,
+    /// The command name of the thread, as reported by the kernel.
+    pub comm: String,
+    /// The frames of the stack, from the innermost (leaf) to the outermost (root).
+    pub frames: Vec<Frame>,
+}
+
+impl StackKey {
+    /// Returns the thread name, or its ID if the name is unknown.
+    #[must_use]
+    pub fn thread_name_or_id(&self) -> String {
+        if self.thread_name.is_empty() {
+            self.tid.to_string()
+        } else {
+            self.thread_name.clone()
+        }
+    }
+}
+
+/// Metadata of a profiled process.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProcessMetadata {
+    pub pid: i32,
+    pub python_version: String,
+    /// Path of the Python interpreter executable.
+    pub executable: String,
+}
+
+/// A single sample, referring to the stack it was aggregated into.
+#[derive(Clone, Copy, Debug)]
+pub struct TimedSample {
+    /// Index of the stack in `Report::stacks`.
+    pub stack: usize,
+    /// Wall-clock time the sample was taken at, in nanoseconds since the UNIX epoch.
+    pub timestamp_ns: u64,
+    /// The CPU the sample was taken on.
+    pub cpu: u32,
+    pub weight: isize,
+}
 
 pub struct Report {
-    pub data: HashMap<Frames, isize>,
+    /// The aggregated stacks and their weights.
+    pub stacks: Vec<(StackKey, isize)>,
+    /// The individual samples in the order they were received.
+    pub samples: Vec<TimedSample>,
+    pub processes: Vec<ProcessMetadata>,
     pub timing: ReportTiming,
 }
 
+/// Selects the samples to keep from a report, see `Report::filter`.
+/// Empty lists match everything.
+#[derive(Debug, Default)]
+pub struct Filter {
+    pub pids: Vec<i32>,
+    pub tids: Vec<i32>,
+    pub thread_names: Vec<String>,
+    /// Only keep the samples taken at least this long after the start of the recording.
+    pub since: Option<Duration>,
+    /// Only keep the samples taken before this long after the start of the recording.
+    pub until: Option<Duration>,
+}
+
+impl Filter {
+    fn matches(&self, key: &StackKey) -> bool {
+        (self.pids.is_empty() || self.pids.contains(&key.pid))
+            && (self.tids.is_empty() || self.tids.contains(&key.tid))
+            && (self.thread_names.is_empty() || self.thread_names.contains(&key.thread_name))
+    }
+
+    fn in_range(&self, elapsed: Duration) -> bool {
+        !matches!(self.since, Some(since) if elapsed < since)
+            && !matches!(self.until, Some(until) if elapsed >= until)
+    }
+}
+
 impl Report {
-    pub fn pprof<W>(&self, mut writer: W) -> Result<()>
+    /// Merges several reports into one, summing the weights of identical stacks.
+    /// The individual samples are only kept if every report has them, the timing spans all of
+    /// the reports and uses the frequency of the first one.
+    #[must_use]
+    pub fn merge(reports: Vec<Self>) -> Self {
+        let keep_samples = reports.iter().all(|report| !report.samples.is_empty());
+        let start_time = reports
+            .iter()
+            .map(|report| report.timing.start_time)
+            .min()
+            .unwrap_or(UNIX_EPOCH);
+        let end_time = reports
+            .iter()
+            .map(|report| report.timing.start_time + report.timing.duration)
+            .max()
+            .unwrap_or(start_time);
+        let frequency = reports.first().map_or_else(
+            || ReportTiming::default().frequency,
+            |report| report.timing.frequency,
+        );
+
+        let mut stacks: Vec<(StackKey, isize)> = Vec::new();
+        let mut stack_ids: HashMap<StackKey, usize> = HashMap::new();
+        let mut samples = Vec::new();
+        let mut processes: Vec<ProcessMetadata> = Vec::new();
+        for report in reports {
+            // Maps the stack indices of the report to the ones of the merged report.
+            let mut ids = Vec::with_capacity(report.stacks.len());
+            for (key, weight) in report.stacks {
+                let id = if let Some(&id) = stack_ids.get(&key) {
+                    id
+                } else {
+                    stack_ids.insert(key.clone(), stacks.len());
+                    stacks.push((key, 0));
+                    stacks.len() - 1
+                };
+                stacks[id].1 += weight;
+                ids.push(id);
+            }
+
+            if keep_samples {
+                samples.extend(report.samples.into_iter().map(|sample| TimedSample {
+                    stack: ids[sample.stack],
+                    ..sample
+                }));
+            }
+            for process in report.processes {
+                if !processes.iter().any(|known| known.pid == process.pid) {
+                    processes.push(process);
+                }
+            }
+        }
+        samples.sort_by_key(|sample| sample.timestamp_ns);
+
+        Self {
+            stacks,
+            samples,
+            processes,
+            timing: ReportTiming {
+                frequency,
+                start_time,
+                duration: end_time.duration_since(start_time).unwrap_or_default(),
+            },
+        }
+    }
+
+    /// Returns a report with only the samples selected by the filter.
+    ///
+    /// # Errors
+    /// This function will return an error if the filter has a time range and the report has no
+    /// individual samples to apply it to, e.g. because it was loaded from folded stacks.
+    pub fn filter(&self, filter: &Filter) -> Result<Self> {
+        if self.samples.is_empty() && (filter.since.is_some() || filter.until.is_some()) {
+            bail!("the report has no timed samples, --since and --until can't be applied");
+        }
+
+        let start = self
+            .timing
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        let mut stacks: Vec<(StackKey, isize)> = Vec::new();
+        let mut samples = Vec::new();
+        if self.samples.is_empty() {
+            stacks = self
+                .stacks
+                .iter()
+                .filter(|(key, _)| filter.matches(key))
+                .cloned()
+                .collect();
+        } else {
+            // Maps the stack indices of this report to the ones of the filtered report.
+            let mut stack_ids: HashMap<usize, usize> = HashMap::new();
+            for sample in &self.samples {
+                let key = &self.stacks[sample.stack].0;
+                let elapsed = Duration::from_nanos(sample.timestamp_ns).saturating_sub(start);
+                if !filter.matches(key) || !filter.in_range(elapsed) {
+                    continue;
+                }
+
+                let stack = *stack_ids.entry(sample.stack).or_insert_with(|| {
+                    stacks.push((key.clone(), 0));
+                    stacks.len() - 1
+                });
+                stacks[stack].1 += sample.weight;
+                samples.push(TimedSample { stack, ..*sample });
+            }
+        }
+
+        Ok(Self {
+            stacks,
+            samples,
+            processes: self
+                .processes
+                .iter()
+                .filter(|process| filter.pids.is_empty() || filter.pids.contains(&process.pid))
+                .cloned()
+                .collect(),
+            timing: self.timing.clone(),
+        })
+    }
+
+    /// Writes the report as a gzip-compressed pprof protobuf.
+    /// Samples are labeled with the thread and the CPU they were taken on, and have two values:
+    /// the number of samples and the CPU time they account for.
+    pub fn pprof<W>(&self, writer: W) -> Result<()>
     where
         W: std::io::Write,
     {
-        let report = pprof::Report {
-            data: self.data.clone(),
-            timing: self.timing.clone(),
-        };
-        let profile: protos::Profile = report.pprof().unwrap();
+        let profile = self.pprof_profile();
 
         let mut content = Vec::new();
-        profile.write_to_vec(&mut content).unwrap();
-        writer.write_all(&content).unwrap();
-        println!("report: {:?}", &report);
+        profile.write_to_vec(&mut content)?;
+        let mut encoder = GzEncoder::new(writer, Compression::default());
+        std::io::Write::write_all(&mut encoder, &content)?;
+        encoder.finish()?;
         Ok(())
     }
 
@@ -36,12 +299,7 @@ impl Report {
     where
         W: std::io::Write,
     {
-        let report = pprof::Report {
-            data: self.data.clone(),
-            timing: self.timing.clone(),
-        };
-        report.flamegraph(writer).unwrap();
-        println!("report: {:?}", &report);
+        self.pprof_report().flamegraph(writer)?;
         Ok(())
     }
 
@@ -49,54 +307,249 @@ impl Report {
     where
         W: std::io::Write,
     {
-        let report = pprof::Report {
-            data: self.data.clone(),
-            timing: self.timing.clone(),
-        };
-
-        let lines: Vec<String> = self
-            .data
+        let mut lines: Vec<String> = self
+            .stacks
             .iter()
             .map(|(key, value)| {
                 let mut line = key.thread_name_or_id();
                 line.push(';');
 
                 for frame in key.frames.iter().rev() {
-                    for symbol in frame.iter().rev() {
-                        write!(&mut line, "{};", symbol).unwrap();
-                    }
+                    write!(&mut line, "{};", frame.name()).unwrap();
                 }
 
                 line.pop().unwrap_or_default();
-                write!(&mut line, " {}", value).unwrap();
+                write!(&mut line, " {value}").unwrap();
 
                 line
             })
             .collect();
+        lines.sort();
         if !lines.is_empty() {
-            writer.write_all(lines.join("\n").as_bytes()).unwrap();
+            writer.write_all(lines.join("\n").as_bytes())?;
         }
-        println!("report: {:?}", &report);
         Ok(())
     }
+
+    /// Returns the time between two samples, in nanoseconds.
+    pub(crate) fn sample_period_ns(&self) -> u64 {
+        1_000_000_000 / u64::try_from(self.timing.frequency.max(1)).unwrap_or(1)
+    }
+
+    fn pprof_profile(&self) -> protos::Profile {
+        let period = self.sample_period_ns();
+        let mut builder = PprofBuilder::new();
+
+        if self.samples.is_empty() {
+            for (key, weight) in &self.stacks {
+                builder.sample(key, None, *weight, period);
+            }
+        } else {
+            // Aggregate the samples of each stack by the CPU they were taken on.
+            let mut weights: BTreeMap<(usize, u32), isize> = BTreeMap::new();
+            for sample in &self.samples {
+                *weights.entry((sample.stack, sample.cpu)).or_insert(0) += sample.weight;
+            }
+            for ((stack, cpu), weight) in weights {
+                builder.sample(&self.stacks[stack].0, Some(cpu), weight, period);
+            }
+        }
+
+        builder.build(&self.timing, period)
+    }
+
+    fn pprof_report(&self) -> pprof::Report {
+        let mut data: HashMap<Frames, isize> = HashMap::new();
+        for (key, weight) in &self.stacks {
+            let frames = Frames {
+                frames: key
+                    .frames
+                    .iter()
+                    .map(|frame| vec![frame.symbol()])
+                    .collect(),
+                thread_name: key.thread_name_or_id(),
+                thread_id: u64::try_from(key.tid).unwrap_or_default(),
+                // Frames are hashed with their timestamp, use the same one for all of them so
+                // that stacks which only differ in what `Frames` leaves out, e.g. the pid or
+                // the comm, are merged.
+                sample_timestamp: self.timing.start_time,
+            };
+            *data.entry(frames).or_insert(0) += weight;
+        }
+
+        pprof::Report {
+            data,
+            timing: self.timing.clone(),
+        }
+    }
+}
+
+/// Builds a pprof profile, deduplicating its strings, functions and locations.
+struct PprofBuilder<'a> {
+    strings: Vec<String>,
+    string_ids: HashMap<String, i64>,
+    functions: Vec<protos::Function>,
+    function_ids: HashMap<(String, &'a str, u32), u64>,
+    locations: Vec<protos::Location>,
+    location_ids: HashMap<&'a Frame, u64>,
+    samples: Vec<protos::Sample>,
+}
+
+impl<'a> PprofBuilder<'a> {
+    fn new() -> Self {
+        let mut builder = Self {
+            strings: Vec::new(),
+            string_ids: HashMap::new(),
+            functions: Vec::new(),
+            function_ids: HashMap::new(),
+            locations: Vec::new(),
+            location_ids: HashMap::new(),
+            samples: Vec::new(),
+        };
+        // The first string of the table must be the empty string.
+        builder.string("");
+        builder
+    }
+
+    fn string(&mut self, s: &str) -> i64 {
+        if let Some(&id) = self.string_ids.get(s) {
+            return id;
+        }
+        let id = i64::try_from(self.strings.len()).unwrap_or_default();
+        self.strings.push(s.to_string());
+        self.string_ids.insert(s.to_string(), id);
+        id
+    }
+
+    fn function(&mut self, frame: &'a Frame) -> u64 {
+        let key = (frame.name(), frame.file.as_str(), frame.start_line);
+        if let Some(&id) = self.function_ids.get(&key) {
+            return id;
+        }
+
+        let id = self.functions.len() as u64 + 1;
+        let name = self.string(&key.0);
+        let filename = self.string(&frame.file);
+        self.functions.push(protos::Function {
+            id,
+            name,
+            system_name: name,
+            filename,
+            start_line: i64::from(frame.start_line),
+            ..protos::Function::default()
+        });
+        self.function_ids.insert(key, id);
+        id
+    }
+
+    fn location(&mut self, frame: &'a Frame) -> u64 {
+        if let Some(&id) = self.location_ids.get(frame) {
+            return id;
+        }
+
+        let id = self.locations.len() as u64 + 1;
+        let line = protos::Line {
+            function_id: self.function(frame),
+            line: i64::from(frame.line),
+            ..protos::Line::default()
+        };
+        self.locations.push(protos::Location {
+            id,
+            line: vec![line].into(),
+            ..protos::Location::default()
+        });
+        self.location_ids.insert(frame, id);
+        id
+    }
+
+    fn str_label(&mut self, key: &str, value: &str) -> protos::Label {
+        protos::Label {
+            key: self.string(key),
+            str: self.string(value),
+            ..protos::Label::default()
+        }
+    }
+
+    fn num_label(&mut self, key: &str, value: i64) -> protos::Label {
+        protos::Label {
+            key: self.string(key),
+            num: value,
+            ..protos::Label::default()
+        }
+    }
+
+    fn sample(&mut self, key: &'a StackKey, cpu: Option<u32>, weight: isize, period: u64) {
+        let location_id = key
+            .frames
+            .iter()
+            .map(|frame| self.location(frame))
+            .collect();
+
+        let mut label = vec![
+            self.num_label("pid", i64::from(key.pid)),
+            self.num_label("thread_id", i64::from(key.tid)),
+            self.str_label("thread_name", &key.thread_name_or_id()),
+        ];
+        if let Some(cpu) = cpu {
+            label.push(self.num_label("cpu", i64::from(cpu)));
+        }
+        if !key.comm.is_empty() {
+            label.push(self.str_label("comm", &key.comm));
+        }
+
+        let count = i64::try_from(weight).unwrap_or_default();
+        let cpu_time = count.saturating_mul(i64::try_from(period).unwrap_or_default());
+        self.samples.push(protos::Sample {
+            location_id,
+            value: vec![count, cpu_time],
+            label: label.into(),
+            ..protos::Sample::default()
+        });
+    }
+
+    fn value_type(&mut self, ty: &str, unit: &str) -> protos::ValueType {
+        protos::ValueType {
+            ty: self.string(ty),
+            unit: self.string(unit),
+            ..protos::ValueType::default()
+        }
+    }
+
+    fn build(mut self, timing: &ReportTiming, period: u64) -> protos::Profile {
+        let samples = self.value_type("samples", "count");
+        let cpu_time = self.value_type("cpu", "nanoseconds");
+        let since_epoch = timing
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        protos::Profile {
+            sample_type: vec![samples, cpu_time.clone()].into(),
+            sample: self.samples.into(),
+            location: self.locations.into(),
+            function: self.functions.into(),
+            string_table: self.strings.into(),
+            time_nanos: i64::try_from(since_epoch.as_nanos()).unwrap_or_default(),
+            duration_nanos: i64::try_from(timing.duration.as_nanos()).unwrap_or_default(),
+            period_type: Some(cpu_time).into(),
+            period: i64::try_from(period).unwrap_or_default(),
+            ..protos::Profile::default()
+        }
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct Profile {
     pub start_time: Option<SystemTime>,
+    pub duration: Duration,
 
-    duration: Duration,
     frequency: u64,
 
-    // frames: Vec<Frames>,
-    thread_id_to_frames: HashMap<u64, Frames>,
-    // thread_id_to_name: HashMap<u64, String>,
-    data: HashMap<Frames, isize>,
-    // From rbperf:
-    // #[serde(skip)]
-    // symbol_id_map: HashMap<String, u32>,
-    // symbols: Vec<String>,
-    // samples: Vec<Sample>,
+    stacks: Vec<StackKey>,
+    stack_ids: HashMap<StackKey, usize>,
+    weights: Vec<isize>,
+    samples: Vec<TimedSample>,
+    processes: Vec<ProcessMetadata>,
 }
 
 impl Profile {
@@ -105,48 +558,214 @@ impl Profile {
             start_time: None,
             duration,
             frequency,
-            thread_id_to_frames: HashMap::new(),
-            data: HashMap::new(),
-        }
-    }
-
-    pub fn add_sample(
-        &mut self,
-        thread_id: u64,
-        timestamp: SystemTime,
-        sample: Vec<Symbol>,
-        weight: isize,
-    ) {
-        let frames = self
-            .thread_id_to_frames
-            .entry(thread_id)
-            .or_insert_with(|| Frames {
-                frames: vec![sample],
-                thread_name: get_thread_name(thread_id),
-                thread_id,
-                sample_timestamp: timestamp,
-            });
-        *self.data.entry(frames.clone()).or_insert(weight) += weight;
+            stacks: Vec::new(),
+            stack_ids: HashMap::new(),
+            weights: Vec::new(),
+            samples: Vec::new(),
+            processes: Vec::new(),
+        }
+    }
+
+    pub fn add_process(&mut self, process: ProcessMetadata) {
+        self.processes.push(process);
+    }
+
+    pub fn add_sample(&mut self, key: StackKey, timestamp_ns: u64, cpu: u32, weight: isize) {
+        let stack = if let Some(&id) = self.stack_ids.get(&key) {
+            id
+        } else {
+            let id = self.stacks.len();
+            self.stack_ids.insert(key.clone(), id);
+            self.stacks.push(key);
+            self.weights.push(0);
+            id
+        };
+        self.weights[stack] += weight;
+        self.samples.push(TimedSample {
+            stack,
+            timestamp_ns,
+            cpu,
+            weight,
+        });
     }
 
     pub fn report(&self) -> Result<Report> {
         Ok(Report {
-            data: self.data.clone(),
+            stacks: self
+                .stacks
+                .iter()
+                .cloned()
+                .zip(self.weights.iter().copied())
+                .collect(),
+            samples: self.samples.clone(),
+            processes: self.processes.clone(),
             timing: ReportTiming {
                 frequency: i32::try_from(self.frequency)?,
-                start_time: self.start_time.unwrap(),
+                start_time: self.start_time.unwrap_or_else(SystemTime::now),
                 duration: self.duration,
             },
         })
     }
 }
 
-fn get_thread_name(tid: u64) -> String {
-    let path_str = format!("/proc/{tid}/comm");
-    let path = Path::new(&path_str);
+/// Returns the name of the thread `tid` of process `pid`, or a placeholder if it can't be read.
+pub fn get_thread_name(pid: i32, tid: i32) -> String {
+    let path = format!("/proc/{pid}/task/{tid}/comm");
 
     match fs::read_to_string(path) {
         Ok(name) => name.trim().to_string(),
         Err(_) => format!("Thread {tid}"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    fn frame(class: &str, function: &str, line: u32) -> Frame {
+        Frame {
+            file: "app.py".to_string(),
+            class: class.to_string(),
+            function: function.to_string(),
+            start_line: line,
+            line,
+        }
+    }
+
+    /// A stack of `main` calling `Worker::run`, on thread `tid`.
+    fn key(tid: i32, thread_name: &str) -> StackKey {
+        StackKey {
+            pid: 42,
+            tid,
+            thread_name: thread_name.to_string(),
+            comm: "python".to_string(),
+            frames: vec![frame("Worker", "run", 10), frame("", "main", 1)],
+        }
+    }
+
+    fn profile() -> Profile {
+        let mut profile = Profile::new(Duration::from_secs(1), 100);
+        profile.start_time = Some(UNIX_EPOCH + Duration::from_secs(1_000));
+        profile.add_sample(key(1, "MainThread"), 1_000, 0, 1);
+        profile.add_sample(key(2, ""), 2_000, 1, 1);
+        profile.add_sample(key(1, "MainThread"), 3_000, 1, 1);
+        profile
+    }
+
+    #[test]
+    fn add_sample_aggregates_identical_stacks() {
+        let report = profile().report().unwrap();
+
+        assert_eq!(report.stacks.len(), 2);
+        assert_eq!(report.stacks[0], (key(1, "MainThread"), 2));
+        assert_eq!(report.stacks[1], (key(2, ""), 1));
+        let stacks: Vec<usize> = report.samples.iter().map(|sample| sample.stack).collect();
+        assert_eq!(stacks, vec![0, 1, 0]);
+        assert_eq!(report.samples[2].timestamp_ns, 3_000);
+        assert_eq!(report.samples[2].cpu, 1);
+    }
+
+    #[test]
+    fn folded_lines_go_from_the_thread_to_the_leaf() {
+        let mut folded = Vec::new();
+        profile().report().unwrap().folded(&mut folded).unwrap();
+
+        assert_eq!(
+            String::from_utf8(folded).unwrap(),
+            "2;main;Worker::run 1\nMainThread;main;Worker::run 2"
+        );
+    }
+
+    #[test]
+    fn filter_keeps_the_samples_of_the_selected_threads() {
+        let filter = Filter {
+            tids: vec![1],
+            ..Filter::default()
+        };
+        let report = profile().report().unwrap().filter(&filter).unwrap();
+
+        assert_eq!(report.stacks, vec![(key(1, "MainThread"), 2)]);
+        assert_eq!(report.samples.len(), 2);
+    }
+
+    #[test]
+    fn filter_rejects_time_ranges_without_timed_samples() {
+        let mut report = profile().report().unwrap();
+        report.samples.clear();
+        let filter = Filter {
+            since: Some(Duration::from_secs(1)),
+            ..Filter::default()
+        };
+
+        assert!(report.filter(&filter).is_err());
+        assert_eq!(report.filter(&Filter::default()).unwrap().stacks.len(), 2);
+    }
+
+    #[test]
+    fn pprof_has_labeled_samples_with_cpu_time() {
+        let mut gzipped = Vec::new();
+        profile().report().unwrap().pprof(&mut gzipped).unwrap();
+        let mut content = Vec::new();
+        GzDecoder::new(&gzipped[..])
+            .read_to_end(&mut content)
+            .unwrap();
+        let pprof = protos::Profile::parse_from_bytes(&content).unwrap();
+        let string = |id: i64| pprof.string_table[usize::try_from(id).unwrap()].as_str();
+
+        assert_eq!(string(0), "");
+        let sample_types: Vec<(&str, &str)> = pprof
+            .sample_type
+            .iter()
+            .map(|ty| (string(ty.ty), string(ty.unit)))
+            .collect();
+        assert_eq!(
+            sample_types,
+            vec![("samples", "count"), ("cpu", "nanoseconds")]
+        );
+        assert_eq!(pprof.period, 10_000_000);
+        assert_eq!(pprof.time_nanos, 1_000_000_000_000);
+
+        // One sample per stack and CPU.
+        assert_eq!(pprof.sample.len(), 3);
+        for sample in &pprof.sample {
+            assert_eq!(sample.value, vec![1, 10_000_000]);
+            let labels: Vec<(&str, String)> = sample
+                .label
+                .iter()
+                .map(|label| {
+                    let value = if label.str == 0 {
+                        label.num.to_string()
+                    } else {
+                        string(label.str).to_string()
+                    };
+                    (string(label.key), value)
+                })
+                .collect();
+            assert_eq!(labels[0], ("pid", "42".to_string()));
+            assert!(labels.iter().any(|label| label.0 == "thread_id"));
+            assert!(labels.iter().any(|label| label.0 == "thread_name"));
+            assert!(labels.iter().any(|label| label.0 == "cpu"));
+            assert!(labels.contains(&("comm", "python".to_string())));
+
+            // Locations go from the leaf to the root.
+            let names: Vec<&str> = sample
+                .location_id
+                .iter()
+                .map(|&id| {
+                    let location = &pprof.location[usize::try_from(id - 1).unwrap()];
+                    let function =
+                        &pprof.function[usize::try_from(location.line[0].function_id - 1).unwrap()];
+                    string(function.name)
+                })
+                .collect();
+            assert_eq!(names, vec!["Worker::run", "main"]);
+        }
+        // Locations and functions are shared by the samples.
+        assert_eq!(pprof.location.len(), 2);
+        assert_eq!(pprof.function.len(), 2);
+        assert_eq!(pprof.function[0].start_line, 10);
+        assert_eq!(string(pprof.function[0].filename), "app.py");
+    }
+}
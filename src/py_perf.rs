@@ -1,58 +1,153 @@
 use log::{debug, error, info, trace};
-use pprof::Symbol;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::os::fd::{AsFd, AsRawFd};
-use std::path::PathBuf;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, RwLock, RwLockReadGuard};
 use std::thread::ScopedJoinHandle;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime};
 use std::{fmt, thread};
 
 use libbpf_rs::skel::{OpenSkel, SkelBuilder};
 use libbpf_rs::{MapFlags, PerfBufferBuilder, ProgramType};
 
 use anyhow::{bail, Context, Result};
-use crossbeam::channel::{bounded, select, tick, unbounded, Receiver};
+use crossbeam::channel::{after, never, select, tick, unbounded, Receiver, Sender};
 use plain::Plain;
 use py_spy::version::Version;
+use serde::{Serialize, Serializer};
 use serde_yaml;
 
 use crate::bindings;
-use crate::bindings::{PythonVersionOffsets, PYPERF_STACK_WALKING_PROGRAM_IDX};
+use crate::bindings::{
+    PythonVersionOffsets, PYPERF_STACK_WALKING_PROGRAM_IDX, PYTHON_STACK_FRAMES_PER_PROG,
+    PYTHON_STACK_PROG_CNT,
+};
 use crate::bpf::pyperf::{PyperfSkel, PyperfSkelBuilder};
+use crate::clock::MonotonicClock;
+use crate::event_loop::{self, StopReason};
 use crate::perf_event;
-use crate::process_info::ProcessInfo;
-use crate::profile::Profile;
+use crate::process_info::{is_running, ProcessInfo};
+use crate::profile::{get_thread_name, Frame, ProcessMetadata, Profile, Report, StackKey};
 use crate::python_readers::any_as_u8_slice;
 use crate::python_versions::PYTHON_VERSION_CONFIGS_YAML;
 
-// TODO(kakkoyun): Matches this with error codes in the pyperf.h !!
-#[derive(Default, Clone, Debug)]
+/// The reasons a stack walk can fail in BPF, mirrors `enum error_code` in pyperf.h.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ErrorCode {
+    /// The stack walk failed without setting an error code.
+    Unspecified,
+    MissingPyState,
+    ThreadStateNull,
+    InterpreterNull,
+    TooManyThreads,
+    ThreadStateNotFound,
+    EmptyStack,
+    BadFsBase,
+    InvalidPthreadsImpl,
+    ThreadStateHeadNull,
+    BadThreadState,
+    CallFailed,
+    TStateCFrameIsNull,
+    Unknown(u32),
+}
+
+impl ErrorCode {
+    #[must_use]
+    pub const fn from_raw(code: bindings::error_code) -> Self {
+        match code {
+            bindings::error_code_ERROR_NONE => Self::Unspecified,
+            bindings::error_code_ERROR_MISSING_PYSTATE => Self::MissingPyState,
+            bindings::error_code_ERROR_THREAD_STATE_NULL => Self::ThreadStateNull,
+            bindings::error_code_ERROR_INTERPRETER_NULL => Self::InterpreterNull,
+            bindings::error_code_ERROR_TOO_MANY_THREADS => Self::TooManyThreads,
+            bindings::error_code_ERROR_THREAD_STATE_NOT_FOUND => Self::ThreadStateNotFound,
+            bindings::error_code_ERROR_EMPTY_STACK => Self::EmptyStack,
+            bindings::error_code_ERROR_BAD_FSBASE => Self::BadFsBase,
+            bindings::error_code_ERROR_INVALID_PTHREADS_IMPL => Self::InvalidPthreadsImpl,
+            bindings::error_code_ERROR_THREAD_STATE_HEAD_NULL => Self::ThreadStateHeadNull,
+            bindings::error_code_ERROR_BAD_THREAD_STATE => Self::BadThreadState,
+            bindings::error_code_ERROR_CALL_FAILED => Self::CallFailed,
+            bindings::error_code_ERROR_TSTATE_CFRAME_IS_NULL => Self::TStateCFrameIsNull,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::Unspecified => "unspecified",
+            Self::MissingPyState => "missing_pystate",
+            Self::ThreadStateNull => "thread_state_null",
+            Self::InterpreterNull => "interpreter_null",
+            Self::TooManyThreads => "too_many_threads",
+            Self::ThreadStateNotFound => "thread_state_not_found",
+            Self::EmptyStack => "empty_stack",
+            Self::BadFsBase => "bad_fsbase",
+            Self::InvalidPthreadsImpl => "invalid_pthreads_impl",
+            Self::ThreadStateHeadNull => "thread_state_head_null",
+            Self::BadThreadState => "bad_thread_state",
+            Self::CallFailed => "call_failed",
+            Self::TStateCFrameIsNull => "tstate_cframe_is_null",
+            Self::Unknown(code) => return write!(f, "unknown_{code}"),
+        };
+        f.write_str(name)
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Default, Clone, Debug, Serialize)]
 pub struct Stats {
-    pub total_events: u32,
+    pub total_events: u64,
     // Events discarded due to the kernel buffer being full.
-    pub lost_event_errors: u32,
+    pub lost_event_errors: u64,
     // Failed to retrieve sample due to a failed read from a map.
-    pub map_reading_errors: u32,
+    pub map_reading_errors: u64,
     // The stack is not complete.
-    pub truncated_stacks: u32,
+    pub truncated_stacks: u64,
     // How many times have we bumped into garbled data.
-    pub garbled_data_errors: u32,
+    pub garbled_data_errors: u64,
+    // Failed stack walks, by the error code reported by BPF.
+    pub walk_errors: BTreeMap<ErrorCode, u64>,
+    // Lost events, by CPU.
+    pub lost_events: BTreeMap<i32, u64>,
+    // Received samples, by process ID.
+    pub samples: BTreeMap<i32, u64>,
+    // Entries of the BPF symbols map when it was last read, it holds up to `SYMBOLS_CAPACITY`.
+    pub symbols: usize,
+    // Run time of the BPF programs, by name. Only counted while `kernel.bpf_stats_enabled` is
+    // set.
+    pub programs: BTreeMap<String, ProgramStats>,
+}
+
+#[derive(Default, Clone, Copy, Debug, Serialize)]
+pub struct ProgramStats {
+    pub run_time_ns: u64,
+    pub run_count: u64,
 }
 
 impl Stats {
     #[must_use]
-    pub const fn total_errors(&self) -> u32 {
-        self.lost_event_errors
-            + self.map_reading_errors
+    pub fn total_errors(&self) -> u64 {
+        self.lost_event_errors + self.stack_errors()
+    }
+
+    #[must_use]
+    pub fn stack_errors(&self) -> u64 {
+        self.map_reading_errors
             + self.truncated_stacks
             + self.garbled_data_errors
+            + self.walk_errors()
     }
 
     #[must_use]
-    pub const fn stack_errors(&self) -> u32 {
-        self.map_reading_errors + self.truncated_stacks + self.garbled_data_errors
+    pub fn walk_errors(&self) -> u64 {
+        self.walk_errors.values().sum()
     }
 }
 
@@ -65,11 +160,44 @@ impl fmt::Display for Stats {
         writeln!(f, "map reading errors: {}", self.map_reading_errors)?;
         writeln!(f, "truncated stacks: {}", self.truncated_stacks)?;
         writeln!(f, "garbled data errors: {}", self.garbled_data_errors)?;
+        writeln!(f, "stack walk errors: {}", self.walk_errors())?;
+        for (code, count) in &self.walk_errors {
+            writeln!(f, "\t{code}: {count}")?;
+        }
 
         Ok(())
     }
 }
 
+/// The samples of a time interval, sent while recording, see `PyPerf::set_interval`.
+pub struct Snapshot {
+    pub report: Report,
+    /// The statistics of the whole recording so far.
+    pub stats: Stats,
+}
+
+/// A change to the processes being recorded, applied while `PyPerf::start` is running, see
+/// `PyPerf::set_control`. The outcome is sent back on `reply`.
+pub enum Control {
+    Attach {
+        pid: i32,
+        reply: Sender<Result<ProcessMetadata>>,
+    },
+    Detach {
+        pid: i32,
+        reply: Sender<Result<()>>,
+    },
+}
+
+/// The `max_entries` of the `symbols` map in pyperf.bpf.c, new symbols are dropped once full.
+pub const SYMBOLS_CAPACITY: usize = 64000;
+/// The BPF programs, their run time is refreshed every `PROGRAM_STATS_PERIOD`.
+const PROGRAMS: [&str; 2] = ["on_event", "walk_python_stack"];
+const PROGRAM_STATS_PERIOD: Duration = Duration::from_secs(1);
+
+/// The deepest Python stack the BPF stack walker can read.
+pub const MAX_PYTHON_STACK_DEPTH: u32 = PYTHON_STACK_FRAMES_PER_PROG * PYTHON_STACK_PROG_CNT;
+
 unsafe impl Plain for PythonVersionOffsets {}
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -79,6 +207,19 @@ pub struct SupportedVersion {
     offsets: PythonVersionOffsets,
 }
 
+impl SupportedVersion {
+    #[must_use]
+    pub fn offsets(&self) -> &PythonVersionOffsets {
+        &self.offsets
+    }
+
+    /// Returns the version the offsets were generated for.
+    #[must_use]
+    pub const fn version(&self) -> &Version {
+        &self.version
+    }
+}
+
 pub struct SupportedVersions {
     versions: HashMap<String, SupportedVersion>,
 }
@@ -139,10 +280,21 @@ pub struct PyPerf<'a> {
 
     frequency: u64,
     duration: Duration,
+    max_depth: u32,
     started_at: Option<SystemTime>,
+    // Why the last recording ended.
+    stop_reason: Option<StopReason>,
+    interval: Option<(Duration, Sender<Snapshot>)>,
+    system_wide: bool,
+    controls: Option<Receiver<Control>>,
 
     supported_versions: SupportedVersions,
-    processes: Vec<ProcessInfo>,
+    // Symbols interned by the BPF program, by ID. IDs are never reused, so they're cached for as
+    // long as the BPF program is loaded.
+    symbols: RwLock<HashMap<u32, bindings::Symbol>>,
+    // IDs the BPF program handed out but couldn't intern because the symbols map was full.
+    missing_symbols: RwLock<HashSet<u32>>,
+    processes: RwLock<Vec<ProcessInfo>>,
 
     bpf: PyperfSkel<'a>,
 }
@@ -152,10 +304,17 @@ impl<'a> PyPerf<'a> {
     /// This function will open and load the BPF module.
     /// It will also populate the `process_info_map` with the given `pids`.
     ///
+    /// Python stacks deeper than `max_depth` frames are truncated.
+    ///
     /// # Errors
     /// This function will return an error if the BPF module fails to load.
     /// It will also return an error if the `process_info_map` fails to update.
-    pub fn new(duration: Duration, frequency: u64) -> Result<PyPerf<'a>> {
+    /// It will also return an error if `max_depth` is zero or exceeds `MAX_PYTHON_STACK_DEPTH`.
+    pub fn new(duration: Duration, frequency: u64, max_depth: u32) -> Result<PyPerf<'a>> {
+        if max_depth == 0 || max_depth > MAX_PYTHON_STACK_DEPTH {
+            bail!("max depth must be between 1 and {MAX_PYTHON_STACK_DEPTH}, got {max_depth}");
+        }
+
         // Open and load the BPF module.
         let mut skel_builder = PyperfSkelBuilder::default();
         skel_builder.obj_builder.debug(true);
@@ -165,6 +324,14 @@ impl<'a> PyPerf<'a> {
         debug!("verbose_bpf_logging set to {}", true);
         open_skel.rodata().verbose = true;
 
+        let stack_walker_prog_cnt = max_depth / PYTHON_STACK_FRAMES_PER_PROG
+            + u32::from(max_depth % PYTHON_STACK_FRAMES_PER_PROG != 0);
+        debug!(
+            "max stack depth set to {}, stack walker program count: {}",
+            max_depth, stack_walker_prog_cnt
+        );
+        open_skel.rodata().stack_walker_prog_cnt = stack_walker_prog_cnt;
+
         for prog in open_skel.obj.progs_iter_mut() {
             prog.set_prog_type(ProgramType::PerfEvent);
         }
@@ -182,27 +349,94 @@ impl<'a> PyPerf<'a> {
         Ok(PyPerf {
             frequency,
             duration,
+            max_depth,
 
             started_at: None,
+            stop_reason: None,
+            interval: None,
+            system_wide: false,
+            controls: None,
             supported_versions,
+            symbols: RwLock::new(HashMap::new()),
+            missing_symbols: RwLock::new(HashSet::new()),
 
             bpf,
-            processes: Vec::new(),
+            processes: RwLock::new(Vec::new()),
             stats: Arc::new(RwLock::new(Stats::default())),
         })
     }
 
+    /// Sends a snapshot of the samples to `sender` every `interval` while recording.
+    /// Every snapshot only has the samples taken since the previous one, so the profile returned
+    /// by `start` only has the samples taken since the last snapshot.
+    /// The BPF programs, the registered processes and the symbol cache are kept across intervals.
+    pub fn set_interval(&mut self, interval: Duration, sender: Sender<Snapshot>) {
+        self.interval = Some((interval, sender));
+    }
+
+    /// Sets how long `start` records for.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Returns why the last `start` returned, or `None` if it was never called.
+    /// `Some(StopReason::Stopped)` means it consumed the message sent on its stop channel.
+    pub const fn stop_reason(&self) -> Option<StopReason> {
+        self.stop_reason
+    }
+
+    /// Samples every process on the CPUs and keeps the samples of the attached ones only, so
+    /// that processes can be attached and detached while `start` is running.
+    pub fn set_system_wide(&mut self) {
+        self.system_wide = true;
+    }
+
+    /// Applies the changes received on `controls` while `start` is running.
+    pub fn set_control(&mut self, controls: Receiver<Control>) {
+        self.controls = Some(controls);
+    }
+
+    /// Stops recording the samples of the processes given to `record` so far, so that the
+    /// loaded BPF programs can be reused to profile other processes.
+    ///
+    /// # Errors
+    /// This function will return an error if it fails to remove the processes from the BPF space.
+    pub fn forget_processes(&mut self) -> Result<()> {
+        let pids: Vec<i32> = self
+            .processes
+            .read()
+            .unwrap()
+            .iter()
+            .map(|p| p.pid)
+            .collect();
+        for pid in pids {
+            self.detach(pid)?;
+        }
+        Ok(())
+    }
+
     // TODO(kakkoyun): Rename to register?
     /// Start recording the samples for the given `pids`.
     ///
     /// # Errors
     /// This function will return an error if it fails to send the `pids` to the BPF space.
     pub fn record(&mut self, pid: i32) -> Result<()> {
+        self.attach(pid)?;
+        Ok(())
+    }
+
+    /// Starts recording the samples of `pid`, also while `start` is running.
+    ///
+    /// # Errors
+    /// This function will return an error if the process is already attached, isn't a supported
+    /// Python process, or if it fails to send it to the BPF space.
+    pub fn attach(&self, pid: i32) -> Result<ProcessMetadata> {
+        if self.processes.read().unwrap().iter().any(|p| p.pid == pid) {
+            bail!("process {pid} is already attached");
+        }
         let process_info =
             ProcessInfo::new(pid).context(format!("failed to fetch process info: {pid}"))?;
-
         debug!("python process: \n{}", process_info);
-        self.processes.push(process_info);
 
         // let children = process_info.children()?;
         // for child in children {
@@ -210,46 +444,85 @@ impl<'a> PyPerf<'a> {
         //     self.processes.push(child);
         // }
 
-        if self.processes.is_empty() {
-            bail!("No Python processes found to profile!");
-        }
-        info!("found python processes: {}", self.processes.len());
+        let offsets = match self.supported_versions.get(&process_info.version) {
+            Some(supported_version) => supported_version.offsets,
+            None => bail!(format!(
+                "unsupported Python version: {}",
+                process_info.version
+            )),
+        };
 
-        // let bpf = self.bpf.clone();
-        // let mut bpf = bpf.write().unwrap();
-        let mut maps = self.bpf.maps_mut();
-        for proc in &self.processes {
-            let offsets = match self.supported_versions.get(&proc.version) {
-                Some(supported_version) => supported_version.offsets,
-                None => bail!(format!("unsupported Python version: {}", proc.version)),
-            };
+        let maps = self.bpf.maps();
+        let py_version =
+            u32::try_from(process_info.version.major * 100 + process_info.version.minor)?;
+        let key = py_version.to_le_bytes();
+        // let value = unsafe { any_as_u8_slice(&offsets) };
+        let value = unsafe { plain::as_bytes(&offsets) };
+        maps.version_specific_offsets()
+            .update(&key, value, MapFlags::ANY)
+            .context("failed to update version specific offsets map")?;
+
+        let key = process_info.pid.to_le_bytes();
+        let bpf_proc_info = crate::bindings::ProcessInfo {
+            thread_state_addr: process_info.thread_state_address,
+            interpreter_addr: process_info.interpreter_address,
+            py_version,
+        };
+        let value = unsafe { any_as_u8_slice(&bpf_proc_info) };
+        maps.pid_to_process_info()
+            .update(&key, value, MapFlags::ANY)
+            .context("failed to update process info map")?;
+
+        let metadata = process_metadata(&process_info);
+        let mut processes = self.processes.write().unwrap();
+        processes.push(process_info);
+        info!("found python processes: {}", processes.len());
+        Ok(metadata)
+    }
 
-            let py_version = u32::try_from(proc.version.major * 100 + proc.version.minor)?;
-            let key = py_version.to_le_bytes();
-            // let value = unsafe { any_as_u8_slice(&offsets) };
-            let value = unsafe { plain::as_bytes(&offsets) };
-            maps.version_specific_offsets()
-                .update(&key, value, MapFlags::ANY)
-                .context("failed to update version specific offsets map")?;
-
-            let key = proc.pid.to_le_bytes();
-            let bpf_proc_info = crate::bindings::ProcessInfo {
-                thread_state_addr: proc.thread_state_address,
-                interpreter_addr: proc.interpreter_address,
-                py_version,
-            };
-            let value = unsafe { any_as_u8_slice(&bpf_proc_info) };
-            maps.pid_to_process_info()
-                .update(&key, value, MapFlags::ANY)
-                .context("failed to update process info map")?;
+    /// Detaches the processes which exited, so that their entries don't pile up in the BPF maps
+    /// of a long recording.
+    fn detach_exited(&self) {
+        let exited: Vec<i32> = self
+            .processes
+            .read()
+            .unwrap()
+            .iter()
+            .map(|p| p.pid)
+            .filter(|&pid| !is_running(pid))
+            .collect();
+        for pid in exited {
+            if let Err(err) = self.detach(pid) {
+                error!("failed to detach exited process {}: {:?}", pid, err);
+            }
         }
+    }
+
+    /// Stops recording the samples of `pid`, also while `start` is running.
+    ///
+    /// # Errors
+    /// This function will return an error if the process isn't attached or if it fails to
+    /// remove it from the BPF space.
+    pub fn detach(&self, pid: i32) -> Result<()> {
+        let mut processes = self.processes.write().unwrap();
+        let Some(index) = processes.iter().position(|p| p.pid == pid) else {
+            bail!("process {pid} is not attached");
+        };
+        self.bpf
+            .maps()
+            .pid_to_process_info()
+            .delete(&pid.to_le_bytes())
+            .context("failed to update process info map")?;
+        processes.remove(index);
+        info!("detached process {}", pid);
         Ok(())
     }
 
     // TODO(kakkoyun): Rename to profile?
     /// Start the profiler.
-    /// This function will block until the profiler is stopped.
-    /// The profiler can be stopped by sending a message to the `stop_channel_rx` channel.
+    /// This function will block until the profiling duration elapses or the profiler is stopped.
+    /// The profiler can be stopped early by sending a message to the `stop_channel_rx` channel.
+    /// The samples still buffered in the kernel are drained before the `Profile` is returned.
     ///
     /// # Errors
     /// This function will return an error if the profiler fails to start.
@@ -257,16 +530,20 @@ impl<'a> PyPerf<'a> {
     /// # Panics
     /// This function will panic if the profiler fails to attach the perf event.
     pub fn start(&mut self, stop_channel_rx: &Receiver<()>) -> Result<Profile> {
-        if self.processes.is_empty() {
-            bail!("No Python processes found to profile!");
-        }
+        let pid = if self.system_wide {
+            None
+        } else {
+            match self.processes.read().unwrap().first() {
+                Some(process) => Some(process.pid),
+                None => bail!("No Python processes found to profile!"),
+            }
+        };
         info!("starting profiler");
 
-        let pid = self.processes[0].pid;
         let mut fds = Vec::new();
         for i in 0..num_cpus::get() {
             // TODO(kakkoyun): Support multiple processes if there exists.
-            let perf_fd = unsafe { perf_event::setup(i.try_into()?, self.frequency, Some(pid)) }?;
+            let perf_fd = unsafe { perf_event::setup(i.try_into()?, self.frequency, pid) }?;
             fds.push(perf_fd);
         }
 
@@ -313,24 +590,7 @@ impl<'a> PyPerf<'a> {
             self.frequency
         );
 
-        let (sender, receiver) = unbounded();
-
-        let maps = self.bpf.maps();
-        let events = maps.events();
-        let stats = self.stats.clone();
-        // let cb_sender = sender.clone();
-        let perf_buffer = PerfBufferBuilder::new(events)
-            .sample_cb(|cpu: i32, data: &[u8]| {
-                trace!("received sample from cpu: {}", cpu);
-                sender
-                    .send((cpu, data.to_vec()))
-                    .expect("could not send signal on channel.");
-            })
-            .lost_cb(|cpu: i32, count: u64| {
-                trace!("lost {} events on CPU {}", count, cpu);
-                handle_lost_events(stats.clone(), cpu, count);
-            })
-            .build()?;
+        let (sender, receiver) = unbounded::<(i32, Vec<u8>)>();
 
         // TODO(kakkoyun): Enable ringbuffer
         // let ring_buffer = libbpf_rs::RingBufferBuilder::new()
@@ -341,70 +601,106 @@ impl<'a> PyPerf<'a> {
         //     .unwrap()
         //     .build();
 
+        // BPF timestamps samples with the monotonic clock, map them to wall-clock time.
+        let clock = MonotonicClock::new()?;
         self.started_at = Some(SystemTime::now());
         info!("profiler started recording...");
 
-        let (done, stop) = bounded::<()>(1);
-        let profile = thread::scope(|s| {
-            let duration = self.duration;
-
-            let processor: ScopedJoinHandle<Profile> = s.spawn({
-                let duration = self.duration;
-                let frequency = self.frequency;
-                let started_at = self.started_at;
-                let receiver: Receiver<(i32, Vec<u8>)> = receiver.clone();
-
-                move || {
-                    let mut profile = Profile::new(duration, frequency);
-                    profile.start_time = started_at;
-
-                    loop {
-                        select! {
-                            recv(receiver) -> sample  => match sample {
-                                Ok((cpu, data)) => {
+        let this = &*self;
+        let (profile, reason) = thread::scope(|s| -> Result<(Profile, StopReason)> {
+            let processor: ScopedJoinHandle<Profile> = s.spawn(move || {
+                let started_at = this.started_at.unwrap_or_else(SystemTime::now);
+                let mut profile = this.new_profile(started_at, this.duration);
+                let mut interval_start = Instant::now();
+                let mut controls = this.controls.clone().unwrap_or_else(never);
+                let program_stats = tick(PROGRAM_STATS_PERIOD);
+
+                loop {
+                    let interval_end = match &this.interval {
+                        Some((interval, _)) => {
+                            after(interval.saturating_sub(interval_start.elapsed()))
+                        }
+                        None => never(),
+                    };
+                    select! {
+                        recv(receiver) -> received => match received {
+                            Ok((cpu, data)) => {
                                 trace!("received sample from cpu: {}", cpu);
                                 let mut sample = bindings::Sample::default();
                                 plain::copy_from_bytes(&mut sample, &data[..])
                                     .expect("data buffer was too short");
-                                self.handle_sample(self.stats.clone(), &mut profile, cpu, sample);
+                                this.handle_sample(
+                                    this.stats.clone(),
+                                    &mut profile,
+                                    &clock,
+                                    cpu,
+                                    sample,
+                                );
                                 trace!("sample handled! Waiting for the next one...");
-                                }
-                                Err(_) => continue,
-                            },
-                            recv(stop) -> _ => {
-                                debug!("stopping profiling...");
-                                break;
                             }
-                        }
+                            // The channel is disconnected once the poller has drained the perf
+                            // buffer and dropped it, so every buffered sample is handled before
+                            // returning.
+                            Err(_) => break,
+                        },
+                        recv(controls) -> control => match control {
+                            Ok(control) => this.apply(control),
+                            // Nobody can change the recording anymore.
+                            Err(_) => controls = never(),
+                        },
+                        recv(program_stats) -> _ => this.refresh_program_stats(),
+                        recv(interval_end) -> _ => {}
                     }
-                    debug!("sample processor is done!");
-                    profile
-                }
-            });
 
-            // let sender = sender.clone();
-            let ticks = tick(duration);
-            loop {
-                select! {
-                    recv(ticks) -> _ => {
-                        debug!("TICK!");
-                        if let Err(err) = perf_buffer.poll(Duration::from_millis(100)) {
-                            debug!("polling perf buffer failed with {:?}", err);
+                    if let Some((interval, snapshots)) = &this.interval {
+                        if interval_start.elapsed() >= *interval {
+                            profile.duration = interval_start.elapsed();
+                            let next = this.new_profile(SystemTime::now(), *interval);
+                            let finished = std::mem::replace(&mut profile, next);
+                            interval_start = Instant::now();
+                            this.send_snapshot(&finished, snapshots);
+                            this.detach_exited();
                         }
                     }
-                    recv(stop_channel_rx) -> _ => {
-                        debug!("stopping profiling...");
-                        drop(done);
-                        break;
-                    }
                 }
+                // The last interval is cut short when the profiler is stopped.
+                if this.interval.is_some() {
+                    profile.duration = interval_start.elapsed();
+                }
+                debug!("sample processor is done!");
+                profile
+            });
+
+            let poller: ScopedJoinHandle<Result<StopReason>> = s.spawn(move || {
+                let maps = this.bpf.maps();
+                let stats = this.stats.clone();
+                let mut perf_buffer = PerfBufferBuilder::new(maps.events())
+                    .sample_cb(move |cpu: i32, data: &[u8]| {
+                        trace!("received sample from cpu: {}", cpu);
+                        sender
+                            .send((cpu, data.to_vec()))
+                            .expect("could not send sample on channel.");
+                    })
+                    .lost_cb(move |cpu: i32, count: u64| {
+                        trace!("lost {} events on CPU {}", count, cpu);
+                        handle_lost_events(stats.clone(), cpu, count);
+                    })
+                    .build()?;
+
+                event_loop::run(&mut perf_buffer, this.duration, stop_channel_rx)
+            });
+
+            let reason = poller.join().expect("perf buffer poller panicked")?;
+            match reason {
+                StopReason::Deadline => info!("profiling duration elapsed"),
+                StopReason::Stopped => info!("profiling is stopped"),
             }
-            debug!("profiling is stopped");
-            processor.join().unwrap()
-        });
+            Ok((processor.join().expect("sample processor panicked"), reason))
+        })?;
+        self.stop_reason = Some(reason);
         debug!("profiler is done!");
 
-        let stats = stats.read().unwrap();
+        let stats = self.stats.read().unwrap();
         info!("stats: {}", stats);
 
         Ok(profile)
@@ -424,31 +720,134 @@ impl<'a> PyPerf<'a> {
     //     Ok(())
     // }
 
-    fn handle_sample(
-        &self,
-        stats: Arc<RwLock<Stats>>,
-        profile: &mut Profile,
-        cpu: i32,
-        raw_sample: bindings::Sample,
-    ) {
-        let stats = stats.clone();
+    fn new_profile(&self, start_time: SystemTime, duration: Duration) -> Profile {
+        let mut profile = Profile::new(duration, self.frequency);
+        profile.start_time = Some(start_time);
+        for process in self.processes.read().unwrap().iter() {
+            profile.add_process(process_metadata(process));
+        }
+        profile
+    }
+
+    /// Reads the run time of the BPF programs, which the kernel reports in their fdinfo.
+    fn refresh_program_stats(&self) {
+        for name in PROGRAMS {
+            let Some(program) = self.bpf.obj.prog(name) else {
+                continue;
+            };
+            let fd = program.as_fd().as_raw_fd();
+            let Ok(fdinfo) = std::fs::read_to_string(format!("/proc/self/fdinfo/{fd}")) else {
+                continue;
+            };
+
+            let mut program_stats = ProgramStats::default();
+            for line in fdinfo.lines() {
+                let Some((key, value)) = line.split_once(':') else {
+                    continue;
+                };
+                let value = value.trim().parse().unwrap_or_default();
+                match key {
+                    "run_time_ns" => program_stats.run_time_ns = value,
+                    "run_cnt" => program_stats.run_count = value,
+                    _ => {}
+                }
+            }
+            self.stats
+                .write()
+                .unwrap()
+                .programs
+                .insert(name.to_string(), program_stats);
+        }
+    }
+
+    fn apply(&self, control: Control) {
+        // The requester may have given up waiting.
+        match control {
+            Control::Attach { pid, reply } => {
+                let _ = reply.send(self.attach(pid));
+            }
+            Control::Detach { pid, reply } => {
+                let _ = reply.send(self.detach(pid));
+            }
+        }
+    }
+
+    fn send_snapshot(&self, profile: &Profile, snapshots: &Sender<Snapshot>) {
+        let report = match profile.report() {
+            Ok(report) => report,
+            Err(err) => {
+                error!("failed to create snapshot: {:?}", err);
+                return;
+            }
+        };
+        let stats = self.stats.read().unwrap().clone();
+        if snapshots.send(Snapshot { report, stats }).is_err() {
+            debug!("snapshot receiver is gone, dropping snapshot");
+        }
+    }
+
+    /// Returns the cached symbols, after reading the symbols map if some frames of `stack`
+    /// aren't cached yet. The map is only read once for every unknown ID.
+    fn symbols(&self, stack: &bindings::Stack) -> RwLockReadGuard<HashMap<u32, bindings::Symbol>> {
+        let len = usize::try_from(stack.len).unwrap_or_default();
+        let ids = &stack.frames[..len.min(stack.frames.len())];
+        let cached = self.symbols.read().unwrap();
+        {
+            let missing = self.missing_symbols.read().unwrap();
+            if ids
+                .iter()
+                .all(|id| cached.contains_key(id) || missing.contains(id))
+            {
+                return cached;
+            }
+        }
+        drop(cached);
 
+        let mut cache = self.symbols.write().unwrap();
         let maps = self.bpf.maps();
         let symbols = maps.symbols();
-        let mut id_to_symbol = HashMap::new();
+        let mut entries = 0;
         for stack_bytes in symbols.keys() {
+            entries += 1;
             match symbols.lookup(&stack_bytes, MapFlags::ANY) {
                 Ok(Some(id_bytes)) => {
                     let mut symbol = bindings::Symbol::default();
                     plain::copy_from_bytes(&mut symbol, &stack_bytes)
                         .expect("data buffer was too short");
                     let id = u32::from_le_bytes(id_bytes.try_into().expect("parse frame id bytes"));
-                    id_to_symbol.insert(id, symbol);
+                    cache.insert(id, symbol);
                 }
                 _ => continue,
             }
         }
-        stats.write().unwrap().total_events += 1;
+        debug!("symbol cache refreshed, {} symbols", cache.len());
+        self.stats.write().unwrap().symbols = entries;
+        // Symbols are interned before the sample is sent, the ones still unknown were dropped
+        // and won't ever be in the map.
+        self.missing_symbols
+            .write()
+            .unwrap()
+            .extend(ids.iter().filter(|id| !cache.contains_key(id)));
+        drop(cache);
+        self.symbols.read().unwrap()
+    }
+
+    fn handle_sample(
+        &self,
+        stats: Arc<RwLock<Stats>>,
+        profile: &mut Profile,
+        clock: &MonotonicClock,
+        cpu: i32,
+        raw_sample: bindings::Sample,
+    ) {
+        let stats = stats.clone();
+
+        let id_to_symbol = self.symbols(&raw_sample.stack);
+        {
+            let mut stats = stats.write().unwrap();
+            stats.total_events += 1;
+            *stats.samples.entry(raw_sample.pid).or_default() += 1;
+        }
 
         let now = now_formatted();
 
@@ -458,12 +857,6 @@ impl<'a> PyPerf<'a> {
             .trim_end_matches(char::from(0));
         // NOTICE: It's similar to str_from_u8_nul
 
-        // if recv_stack.stack_status == ruby_stack_status_STACK_INCOMPLETE {
-        //     error!("truncated stack");
-        //     self.stats.truncated_stacks += 1;
-        //     continue;
-        // }
-
         // TODO(kakkoyun): Record as metric.
         assert!(raw_sample.pid != 0, "pid is zero, this should never happen");
 
@@ -478,14 +871,13 @@ impl<'a> PyPerf<'a> {
             raw_sample.native_stack_count_key.user_stack_id
         );
 
-        // let timestamp = UNIX_EPOCH + Duration::from_nanos(sample.timestamp);
-        let timestamp = UNIX_EPOCH + Duration::from_secs(raw_sample.timestamp);
+        let timestamp_ns = clock.to_unix_nanos(raw_sample.timestamp);
 
         // TODO(kakkoyun): Handle native stack!
 
         let stack = raw_sample.stack;
         let mut read_frame_count = 0;
-        let mut frames: Vec<(String, String, String, u32)> = Vec::new();
+        let mut frames: Vec<Frame> = Vec::new();
         for symbol_id in &stack.frames {
             // Don't read past the last frame.
             if read_frame_count >= stack.len {
@@ -526,7 +918,15 @@ impl<'a> PyPerf<'a> {
 
                     let line = symbol.line;
 
-                    frames.push((file_name, class_name, func_name, line));
+                    frames.push(Frame {
+                        file: file_name,
+                        class: class_name,
+                        function: func_name,
+                        start_line: line,
+                        // The BPF program only reads `co_firstlineno`, the executing line is
+                        // unknown.
+                        line: 0,
+                    });
                     read_frame_count += 1;
                 }
                 None => {
@@ -535,32 +935,83 @@ impl<'a> PyPerf<'a> {
             }
         }
 
-        // TODO(kakkoyun): Utilize weight. Aggregate in BPF and send.}
-        let mut sample = Vec::new();
-        for (file_name, class_name, func_name, line) in frames {
-            // trace!(
-            //     "file: {:<32} class: {:<32} func: {:<32} line: {:<4}",
-            //     file_name,
-            //     class_name,
-            //     func_name,
-            //     line
-            // );
-            sample.push(Symbol {
-                name: Some(format!("{}::{}", class_name, func_name).into_bytes()),
-                addr: None,
-                lineno: Some(line),
-                filename: Some(PathBuf::from(file_name)),
-            });
-        }
-        profile.add_sample(raw_sample.tid as u64, timestamp, sample, 1)
+        mark_incomplete_stack(
+            &mut frames,
+            self.max_depth,
+            raw_sample.stack_status,
+            raw_sample.error_code,
+            &mut stats.write().unwrap(),
+        );
+
+        let thread_name = if comm_str.is_empty() {
+            get_thread_name(raw_sample.pid, raw_sample.tid)
+        } else {
+            comm_str.to_string()
+        };
+        let key = StackKey {
+            pid: raw_sample.pid,
+            tid: raw_sample.tid,
+            thread_name,
+            comm: comm_str.to_string(),
+            frames,
+        };
+
+        // TODO(kakkoyun): Utilize weight. Aggregate in BPF and send.
+        profile.add_sample(key, timestamp_ns, raw_sample.cpu, 1);
     }
 }
 
 unsafe impl Plain for bindings::Sample {}
 unsafe impl Plain for bindings::Symbol {}
 
+fn process_metadata(process: &ProcessInfo) -> ProcessMetadata {
+    ProcessMetadata {
+        pid: process.pid,
+        python_version: process.version.to_string(),
+        executable: process.python_info.python_filename.display().to_string(),
+    }
+}
+
+/// Cuts the frames at `max_depth`, the stack walker reads whole batches of frames, and appends a
+/// synthetic frame to the stacks which couldn't be read completely, to keep the profile honest.
+fn mark_incomplete_stack(
+    frames: &mut Vec<Frame>,
+    max_depth: u32,
+    mut stack_status: bindings::stack_status,
+    error_code: bindings::error_code,
+    stats: &mut Stats,
+) {
+    if frames.len() > max_depth as usize {
+        frames.truncate(max_depth as usize);
+        stack_status = bindings::stack_status_STACK_TRUNCATED;
+    }
+
+    let function = match stack_status {
+        bindings::stack_status_STACK_TRUNCATED => {
+            stats.truncated_stacks += 1;
+            "[truncated]".to_string()
+        }
+        bindings::stack_status_STACK_ERROR => {
+            let code = ErrorCode::from_raw(error_code);
+            debug!("stack walk failed with: {}", code);
+            *stats.walk_errors.entry(code).or_insert(0) += 1;
+            format!("[error: {code}]")
+        }
+        _ => return,
+    };
+    frames.push(Frame {
+        file: String::new(),
+        class: String::new(),
+        function,
+        start_line: 0,
+        line: 0,
+    });
+}
+
 fn handle_lost_events(stats: Arc<RwLock<Stats>>, cpu: i32, count: u64) {
-    stats.write().unwrap().lost_event_errors += u32::try_from(count).unwrap();
+    let mut stats = stats.write().unwrap();
+    stats.lost_event_errors += count;
+    *stats.lost_events.entry(cpu).or_default() += count;
     error!("lost {} events on CPU {}", count, cpu);
 }
 
@@ -611,3 +1062,101 @@ pub unsafe fn str_from_u8_nul(utf8_src: &[u8]) -> Result<&str, Utf8Error> {
         .unwrap_or(utf8_src.len()); // default to length if no `\0` present
     ::std::str::from_utf8(&utf8_src[0..nul_range_end])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames(count: usize) -> Vec<Frame> {
+        (0..count)
+            .map(|i| Frame {
+                file: "app.py".to_string(),
+                class: String::new(),
+                function: format!("f{i}"),
+                start_line: 1,
+                line: 0,
+            })
+            .collect()
+    }
+
+    fn functions(frames: &[Frame]) -> Vec<String> {
+        frames.iter().map(|frame| frame.function.clone()).collect()
+    }
+
+    #[test]
+    fn complete_stacks_are_kept_as_is() {
+        let mut stats = Stats::default();
+        let mut stack = frames(2);
+        mark_incomplete_stack(
+            &mut stack,
+            10,
+            bindings::stack_status_STACK_COMPLETE,
+            bindings::error_code_ERROR_NONE,
+            &mut stats,
+        );
+
+        assert_eq!(functions(&stack), vec!["f0", "f1"]);
+        assert_eq!(stats.truncated_stacks, 0);
+        assert!(stats.walk_errors.is_empty());
+    }
+
+    #[test]
+    fn truncated_stacks_end_with_a_truncated_frame() {
+        let mut stats = Stats::default();
+        let mut stack = frames(2);
+        mark_incomplete_stack(
+            &mut stack,
+            10,
+            bindings::stack_status_STACK_TRUNCATED,
+            bindings::error_code_ERROR_NONE,
+            &mut stats,
+        );
+
+        assert_eq!(functions(&stack), vec!["f0", "f1", "[truncated]"]);
+        assert!(stack[2].is_synthetic());
+        assert_eq!(stats.truncated_stacks, 1);
+    }
+
+    #[test]
+    fn stacks_deeper_than_the_maximum_depth_are_truncated() {
+        let mut stats = Stats::default();
+        let mut stack = frames(5);
+        mark_incomplete_stack(
+            &mut stack,
+            3,
+            bindings::stack_status_STACK_COMPLETE,
+            bindings::error_code_ERROR_NONE,
+            &mut stats,
+        );
+
+        assert_eq!(functions(&stack), vec!["f0", "f1", "f2", "[truncated]"]);
+        assert_eq!(stats.truncated_stacks, 1);
+    }
+
+    #[test]
+    fn failed_stack_walks_end_with_an_error_frame() {
+        let mut stats = Stats::default();
+        let mut stack = frames(1);
+        mark_incomplete_stack(
+            &mut stack,
+            10,
+            bindings::stack_status_STACK_ERROR,
+            bindings::error_code_ERROR_EMPTY_STACK,
+            &mut stats,
+        );
+        let mut unspecified = Vec::new();
+        mark_incomplete_stack(
+            &mut unspecified,
+            10,
+            bindings::stack_status_STACK_ERROR,
+            bindings::error_code_ERROR_NONE,
+            &mut stats,
+        );
+
+        assert_eq!(functions(&stack), vec!["f0", "[error: empty_stack]"]);
+        assert_eq!(functions(&unspecified), vec!["[error: unspecified]"]);
+        assert_eq!(stats.walk_errors[&ErrorCode::EmptyStack], 1);
+        assert_eq!(stats.walk_errors[&ErrorCode::Unspecified], 1);
+        assert_eq!(stats.truncated_stacks, 0);
+    }
+}
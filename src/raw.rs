@@ -0,0 +1,361 @@
+// Raw recordings hold the decoded samples of a recording, so that they can be rendered to any
+// output format later on, see `py-perf report`. They are written as JSON lines: a header,
+// followed by the processes, the frame and stack tables and the samples referring to them.
+
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use pprof::timer::ReportTiming;
+use serde::{Deserialize, Serialize};
+
+use crate::profile::{Frame, ProcessMetadata, Report, StackKey, TimedSample};
+
+/// The version of the raw format written by this build, bumped on incompatible changes.
+pub const RAW_FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Record {
+    Header {
+        version: u32,
+        /// Start of the recording, in nanoseconds since the UNIX epoch.
+        start_time_ns: u64,
+        duration_ns: u64,
+        frequency: i32,
+    },
+    Process(ProcessMetadata),
+    Frame {
+        id: usize,
+        #[serde(flatten)]
+        frame: Frame,
+    },
+    Stack {
+        id: usize,
+        pid: i32,
+        tid: i32,
+        thread_name: String,
+        comm: String,
+        /// Frame IDs, from the leaf to the root.
+        frames: Vec<usize>,
+    },
+    Sample {
+        stack: usize,
+        timestamp_ns: u64,
+        cpu: u32,
+        weight: isize,
+    },
+}
+
+impl Report {
+    /// Writes the report as a raw recording, which can be read back with `Report::from_raw`.
+    ///
+    /// # Errors
+    /// This function will return an error if the recording can't be written.
+    pub fn raw<W>(&self, mut writer: W) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        let mut write = |record: &Record| -> Result<()> {
+            serde_json::to_writer(&mut writer, record)?;
+            writer.write_all(b"\n")?;
+            Ok(())
+        };
+
+        let start_time = self
+            .timing
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        write(&Record::Header {
+            version: RAW_FORMAT_VERSION,
+            start_time_ns: u64::try_from(start_time.as_nanos())?,
+            duration_ns: u64::try_from(self.timing.duration.as_nanos())?,
+            frequency: self.timing.frequency,
+        })?;
+
+        for process in &self.processes {
+            write(&Record::Process(process.clone()))?;
+        }
+
+        let mut frame_ids: HashMap<&Frame, usize> = HashMap::new();
+        for (id, (key, _)) in self.stacks.iter().enumerate() {
+            let mut frames = Vec::with_capacity(key.frames.len());
+            for frame in &key.frames {
+                let next_id = frame_ids.len();
+                let frame_id = *frame_ids.entry(frame).or_insert(next_id);
+                if frame_id == next_id {
+                    write(&Record::Frame {
+                        id: frame_id,
+                        frame: frame.clone(),
+                    })?;
+                }
+                frames.push(frame_id);
+            }
+
+            write(&Record::Stack {
+                id,
+                pid: key.pid,
+                tid: key.tid,
+                thread_name: key.thread_name.clone(),
+                comm: key.comm.clone(),
+                frames,
+            })?;
+        }
+
+        for sample in &self.samples {
+            write(&Record::Sample {
+                stack: sample.stack,
+                timestamp_ns: sample.timestamp_ns,
+                cpu: sample.cpu,
+                weight: sample.weight,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Reads a raw recording written by `Report::raw`.
+    ///
+    /// # Errors
+    /// This function will return an error if the recording can't be read, is malformed or was
+    /// written by a newer version of the format.
+    pub fn from_raw<R>(reader: R) -> Result<Self>
+    where
+        R: BufRead,
+    {
+        let mut timing = None;
+        let mut processes = Vec::new();
+        let mut frames: HashMap<usize, Frame> = HashMap::new();
+        let mut stacks: Vec<(StackKey, isize)> = Vec::new();
+        let mut samples = Vec::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: Record = serde_json::from_str(&line)
+                .with_context(|| format!("malformed record on line {}", i + 1))?;
+
+            match record {
+                Record::Header {
+                    version,
+                    start_time_ns,
+                    duration_ns,
+                    frequency,
+                } => {
+                    if version > RAW_FORMAT_VERSION {
+                        bail!(
+                            "unsupported raw format version {version}, \
+                             this build reads up to version {RAW_FORMAT_VERSION}"
+                        );
+                    }
+                    timing = Some(ReportTiming {
+                        frequency,
+                        start_time: UNIX_EPOCH + Duration::from_nanos(start_time_ns),
+                        duration: Duration::from_nanos(duration_ns),
+                    });
+                }
+                _ if timing.is_none() => bail!("raw recording doesn't start with a header"),
+                Record::Process(process) => processes.push(process),
+                Record::Frame { id, frame } => {
+                    frames.insert(id, frame);
+                }
+                Record::Stack {
+                    id,
+                    pid,
+                    tid,
+                    thread_name,
+                    comm,
+                    frames: frame_ids,
+                } => {
+                    if id != stacks.len() {
+                        bail!("stack {id} on line {} is out of order", i + 1);
+                    }
+                    let frames = frame_ids
+                        .iter()
+                        .map(|frame_id| {
+                            frames
+                                .get(frame_id)
+                                .cloned()
+                                .with_context(|| format!("unknown frame {frame_id}"))
+                        })
+                        .collect::<Result<Vec<Frame>>>()?;
+                    stacks.push((
+                        StackKey {
+                            pid,
+                            tid,
+                            thread_name,
+                            comm,
+                            frames,
+                        },
+                        0,
+                    ));
+                }
+                Record::Sample {
+                    stack,
+                    timestamp_ns,
+                    cpu,
+                    weight,
+                } => {
+                    let Some((_, total)) = stacks.get_mut(stack) else {
+                        bail!("unknown stack {stack} on line {}", i + 1);
+                    };
+                    *total += weight;
+                    samples.push(TimedSample {
+                        stack,
+                        timestamp_ns,
+                        cpu,
+                        weight,
+                    });
+                }
+            }
+        }
+
+        let Some(timing) = timing else {
+            bail!("raw recording is empty");
+        };
+        Ok(Self {
+            stacks,
+            samples,
+            processes,
+            timing,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    fn frame(function: &str, start_line: u32, line: u32) -> Frame {
+        Frame {
+            file: "app.py".to_string(),
+            class: String::new(),
+            function: function.to_string(),
+            start_line,
+            line,
+        }
+    }
+
+    fn key(tid: i32, frames: Vec<Frame>) -> StackKey {
+        StackKey {
+            pid: 42,
+            tid,
+            thread_name: format!("thread-{tid}"),
+            comm: "python3".to_string(),
+            frames,
+        }
+    }
+
+    fn report() -> Report {
+        let main = frame("main", 1, 0);
+        let work = frame("work", 10, 12);
+        Report {
+            stacks: vec![
+                (key(42, vec![work.clone(), main.clone()]), 2),
+                (key(43, vec![main]), 1),
+            ],
+            samples: vec![
+                TimedSample {
+                    stack: 0,
+                    timestamp_ns: 1_000_000_000,
+                    cpu: 0,
+                    weight: 1,
+                },
+                TimedSample {
+                    stack: 1,
+                    timestamp_ns: 1_000_000_000,
+                    cpu: 3,
+                    weight: 1,
+                },
+                TimedSample {
+                    stack: 0,
+                    timestamp_ns: 1_010_000_000,
+                    cpu: 1,
+                    weight: 1,
+                },
+            ],
+            processes: vec![ProcessMetadata {
+                pid: 42,
+                python_version: "3.11.4".to_string(),
+                executable: "/usr/bin/python3.11".to_string(),
+            }],
+            timing: ReportTiming {
+                frequency: 100,
+                start_time: UNIX_EPOCH + Duration::from_secs(1),
+                duration: Duration::from_millis(2_500),
+            },
+        }
+    }
+
+    #[test]
+    fn raw_recordings_round_trip() {
+        let report = report();
+        let mut file = NamedTempFile::new().unwrap();
+        report.raw(&mut file).unwrap();
+        file.flush().unwrap();
+
+        let loaded = Report::load(file.path(), true).unwrap();
+
+        assert_eq!(loaded.timing.frequency, 100);
+        assert_eq!(loaded.timing.start_time, report.timing.start_time);
+        assert_eq!(loaded.timing.duration, report.timing.duration);
+
+        assert_eq!(loaded.stacks, report.stacks);
+
+        let samples = |report: &Report| -> Vec<(usize, u64, u32, isize)> {
+            report
+                .samples
+                .iter()
+                .map(|sample| (sample.stack, sample.timestamp_ns, sample.cpu, sample.weight))
+                .collect()
+        };
+        assert_eq!(samples(&loaded), samples(&report));
+
+        assert_eq!(loaded.processes.len(), 1);
+        let process = &loaded.processes[0];
+        assert_eq!(process.pid, 42);
+        assert_eq!(process.python_version, "3.11.4");
+        assert_eq!(process.executable, "/usr/bin/python3.11");
+    }
+
+    #[test]
+    fn frames_are_written_once() {
+        let mut raw = Vec::new();
+        report().raw(&mut raw).unwrap();
+
+        let frames = String::from_utf8(raw)
+            .unwrap()
+            .lines()
+            .filter(|line| line.contains(r#""type":"frame""#))
+            .count();
+        assert_eq!(frames, 2);
+    }
+
+    #[test]
+    fn newer_versions_are_rejected() {
+        let raw = format!(
+            "{{\"type\":\"header\",\"version\":{},\"start_time_ns\":0,\"duration_ns\":0,\
+             \"frequency\":100}}\n",
+            RAW_FORMAT_VERSION + 1
+        );
+
+        let Err(err) = Report::from_raw(raw.as_bytes()) else {
+            panic!("a newer version must be rejected");
+        };
+        assert!(err.to_string().contains("unsupported raw format version"));
+    }
+
+    #[test]
+    fn recordings_must_start_with_a_header() {
+        let raw = r#"{"type":"process","pid":42,"python_version":"3.11","executable":""}"#;
+        assert!(Report::from_raw(raw.as_bytes()).is_err());
+        assert!(Report::from_raw("".as_bytes()).is_err());
+    }
+}
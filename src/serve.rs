@@ -0,0 +1,133 @@
+// An HTTP server recording profiles on demand, compatible with Go's `net/http/pprof`, so that
+// `go tool pprof http://host:port/debug/pprof/profile?pid=N&seconds=30` works against Python
+// processes. The BPF programs stay loaded between requests.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use crossbeam::channel::Receiver;
+use log::{error, info};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::event_loop::StopReason;
+use crate::py_perf::PyPerf;
+
+const PROFILE_PATH: &str = "/debug/pprof/profile";
+/// The recording duration when the request doesn't give one, like `net/http/pprof`.
+const DEFAULT_SECONDS: u64 = 30;
+const MAX_SECONDS: u64 = 600;
+/// How often the server checks whether it should stop while waiting for requests.
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Serves profiles on `address` until a message is received on `stop`. Profiles are recorded
+/// one at a time, other requests wait for the current recording to be done.
+/// `default_pid` is profiled when requests don't give a `pid`.
+///
+/// # Errors
+/// This function will return an error if the server can't listen on `address`.
+pub fn run(
+    py_perf: &mut PyPerf,
+    address: &str,
+    default_pid: Option<i32>,
+    stop: &Receiver<()>,
+) -> Result<()> {
+    let server =
+        Server::http(address).map_err(|err| anyhow!("failed to listen on {address}: {err}"))?;
+    info!("serving profiles on http://{address}{PROFILE_PATH}");
+
+    while stop.try_recv().is_err() {
+        let Some(request) = server.recv_timeout(STOP_POLL_INTERVAL)? else {
+            continue;
+        };
+        info!("{} {}", request.method(), request.url());
+        let response = handle(py_perf, &request, default_pid, stop);
+        if let Err(err) = request.respond(response) {
+            error!("failed to send response: {}", err);
+        }
+        // A recording cut short by the stop message consumed it, so stop here too.
+        if stopped(py_perf) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn handle(
+    py_perf: &mut PyPerf,
+    request: &Request,
+    default_pid: Option<i32>,
+    stop: &Receiver<()>,
+) -> Response<std::io::Cursor<Vec<u8>>> {
+    let (path, query) = request.url().split_once('?').unwrap_or((request.url(), ""));
+    if path != PROFILE_PATH {
+        return text_response(404, "not found");
+    }
+    if *request.method() != Method::Get {
+        return text_response(405, "method not allowed");
+    }
+
+    let (pid, seconds) = match parse_query(query, default_pid) {
+        Ok(params) => params,
+        Err(err) => return text_response(400, &err.to_string()),
+    };
+    match profile(py_perf, pid, seconds, stop) {
+        Ok(profile) => Response::from_data(profile)
+            .with_header(header("Content-Type", "application/octet-stream"))
+            .with_header(header(
+                "Content-Disposition",
+                "attachment; filename=\"profile\"",
+            )),
+        Err(err) => {
+            error!("failed to profile {}: {:?}", pid, err);
+            text_response(500, &format!("failed to profile {pid}: {err:#}"))
+        }
+    }
+}
+
+/// Returns the `pid` and `seconds` parameters of the query.
+fn parse_query(query: &str, default_pid: Option<i32>) -> Result<(i32, u64)> {
+    let mut pid = default_pid;
+    let mut seconds = DEFAULT_SECONDS;
+    for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+        match key {
+            "pid" => pid = Some(value.parse().map_err(|_| anyhow!("invalid pid: {value}"))?),
+            "seconds" => {
+                seconds = value
+                    .parse()
+                    .map_err(|_| anyhow!("invalid seconds: {value}"))?;
+            }
+            _ => {}
+        }
+    }
+
+    let pid = pid.ok_or_else(|| anyhow!("missing pid parameter"))?;
+    if seconds == 0 || seconds > MAX_SECONDS {
+        return Err(anyhow!("seconds must be between 1 and {MAX_SECONDS}"));
+    }
+    Ok((pid, seconds))
+}
+
+/// Records `pid` for `seconds`, or until a message is received on `stop`, and returns the
+/// gzipped pprof profile.
+fn profile(py_perf: &mut PyPerf, pid: i32, seconds: u64, stop: &Receiver<()>) -> Result<Vec<u8>> {
+    py_perf.set_duration(Duration::from_secs(seconds));
+    let result = py_perf.record(pid).and_then(|()| py_perf.start(stop));
+    py_perf.forget_processes()?;
+
+    let mut profile = Vec::new();
+    result?.report()?.pprof(&mut profile)?;
+    Ok(profile)
+}
+
+/// Returns whether the last recording was stopped rather than running for its duration.
+fn stopped(py_perf: &PyPerf) -> bool {
+    py_perf.stop_reason() == Some(StopReason::Stopped)
+}
+
+fn text_response(status: u16, text: &str) -> Response<std::io::Cursor<Vec<u8>>> {
+    Response::from_string(format!("{text}\n")).with_status_code(status)
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("header should be valid")
+}
@@ -0,0 +1,216 @@
+// Writes profiles in the speedscope file format.
+// See https://github.com/jlfwong/speedscope/wiki/Importing-from-custom-sources
+// and https://www.speedscope.app/file-format-schema.json for the schema.
+
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::profile::{Frame, Report, StackKey};
+use crate::timeline::{self, Transition};
+
+const SCHEMA: &str = "https://www.speedscope.app/file-format-schema.json";
+
+#[derive(Serialize)]
+struct File {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    shared: Shared,
+    profiles: Vec<Profile>,
+    name: String,
+    #[serde(rename = "activeProfileIndex")]
+    active_profile_index: usize,
+    exporter: String,
+}
+
+#[derive(Serialize)]
+struct Shared {
+    frames: Vec<SharedFrame>,
+}
+
+#[derive(Serialize)]
+struct SharedFrame {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u32>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Profile {
+    Evented {
+        name: String,
+        unit: &'static str,
+        #[serde(rename = "startValue")]
+        start_value: u64,
+        #[serde(rename = "endValue")]
+        end_value: u64,
+        events: Vec<Event>,
+    },
+    Sampled {
+        name: String,
+        unit: &'static str,
+        #[serde(rename = "startValue")]
+        start_value: isize,
+        #[serde(rename = "endValue")]
+        end_value: isize,
+        samples: Vec<Vec<usize>>,
+        weights: Vec<isize>,
+    },
+}
+
+#[derive(Serialize)]
+struct Event {
+    #[serde(rename = "type")]
+    kind: EventType,
+    frame: usize,
+    at: u64,
+}
+
+#[derive(Serialize)]
+enum EventType {
+    #[serde(rename = "O")]
+    Open,
+    #[serde(rename = "C")]
+    Close,
+}
+
+/// Deduplicates frames into the shared frame table.
+#[derive(Default)]
+struct Frames<'a> {
+    ids: HashMap<&'a Frame, usize>,
+    frames: Vec<SharedFrame>,
+}
+
+impl<'a> Frames<'a> {
+    /// Returns the frame IDs of the stack, from the root to the leaf.
+    fn stack(&mut self, key: &'a StackKey) -> Vec<usize> {
+        key.frames
+            .iter()
+            .rev()
+            .map(|frame| self.id(frame))
+            .collect()
+    }
+
+    fn id(&mut self, frame: &'a Frame) -> usize {
+        if let Some(&id) = self.ids.get(frame) {
+            return id;
+        }
+
+        let id = self.frames.len();
+        let line = frame.known_line();
+        self.frames.push(SharedFrame {
+            name: frame.name(),
+            file: (!frame.file.is_empty()).then(|| frame.file.clone()),
+            line: (line > 0).then_some(line),
+        });
+        self.ids.insert(frame, id);
+        id
+    }
+}
+
+impl Report {
+    /// Writes the report in the speedscope format, with one profile per thread.
+    /// If the report has timestamped samples the profiles are evented, so that speedscope's
+    /// "time order" view shows the samples as they were taken, otherwise they are sampled.
+    pub fn speedscope<W>(&self, writer: W) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        let mut frames = Frames::default();
+        let profiles = if self.samples.is_empty() {
+            self.sampled_profiles(&mut frames)
+        } else {
+            self.evented_profiles(&mut frames)
+        };
+
+        let file = File {
+            schema: SCHEMA,
+            shared: Shared {
+                frames: frames.frames,
+            },
+            profiles,
+            name: "py-perf".to_string(),
+            active_profile_index: 0,
+            exporter: format!("py-perf@{}", env!("CARGO_PKG_VERSION")),
+        };
+        serde_json::to_writer(writer, &file)?;
+        Ok(())
+    }
+
+    fn sampled_profiles<'a>(&'a self, frames: &mut Frames<'a>) -> Vec<Profile> {
+        let mut threads: BTreeMap<(i32, i32), Vec<&(StackKey, isize)>> = BTreeMap::new();
+        for stack in &self.stacks {
+            threads
+                .entry((stack.0.pid, stack.0.tid))
+                .or_default()
+                .push(stack);
+        }
+
+        threads
+            .into_values()
+            .map(|stacks| {
+                let name = thread_label(&stacks[0].0);
+                let samples = stacks.iter().map(|(key, _)| frames.stack(key)).collect();
+                let weights: Vec<isize> = stacks.iter().map(|(_, weight)| *weight).collect();
+                Profile::Sampled {
+                    name,
+                    unit: "none",
+                    start_value: 0,
+                    end_value: weights.iter().sum(),
+                    samples,
+                    weights,
+                }
+            })
+            .collect()
+    }
+
+    fn evented_profiles<'a>(&'a self, frames: &mut Frames<'a>) -> Vec<Profile> {
+        let period = self.sample_period_ns();
+        self.samples_by_thread()
+            .into_values()
+            .map(|samples| {
+                let name = thread_label(&self.stacks[samples[0].1].0);
+
+                let mut events = Vec::new();
+                let stacks = samples
+                    .into_iter()
+                    .map(|(at, stack)| (at, frames.stack(&self.stacks[stack].0)));
+                let end = timeline::replay(stacks, period, |transition| {
+                    events.push(match transition {
+                        Transition::Open { frame, at } => Event {
+                            kind: EventType::Open,
+                            frame,
+                            at,
+                        },
+                        Transition::Close { frame, at } => Event {
+                            kind: EventType::Close,
+                            frame,
+                            at,
+                        },
+                    });
+                });
+
+                Profile::Evented {
+                    name,
+                    unit: "nanoseconds",
+                    start_value: events.first().map_or(0, |event| event.at),
+                    end_value: end,
+                    events,
+                }
+            })
+            .collect()
+    }
+}
+
+fn thread_label(key: &StackKey) -> String {
+    format!(
+        "{} (pid: {}, tid: {})",
+        key.thread_name_or_id(),
+        key.pid,
+        key.tid
+    )
+}
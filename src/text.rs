@@ -0,0 +1,283 @@
+// Writes a plain text summary of a report: the functions with the most samples, overall and per
+// thread, readable in a terminal.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::hash::Hash;
+
+use anyhow::Result;
+
+use crate::profile::{Frame, Report, StackKey};
+
+/// The number of functions listed for every thread.
+const THREAD_TOP: usize = 5;
+
+/// A function, identified by its qualified name and where it's defined.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct Function<'a> {
+    name: String,
+    file: &'a str,
+    line: u32,
+}
+
+impl<'a> Function<'a> {
+    fn new(frame: &'a Frame) -> Self {
+        Self {
+            name: frame.name(),
+            file: &frame.file,
+            line: frame.function_line(),
+        }
+    }
+
+    pub(crate) fn label(&self) -> String {
+        match (self.file.is_empty(), self.line) {
+            (true, _) => self.name.clone(),
+            (false, 0) => format!("{} ({})", self.name, self.file),
+            (false, line) => format!("{} ({}:{})", self.name, self.file, line),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Weights {
+    /// Samples the function was running in, i.e. it was the leaf frame.
+    pub(crate) self_weight: isize,
+    /// Samples the function was on the stack of.
+    pub(crate) total_weight: isize,
+}
+
+impl Report {
+    /// Writes the `top` functions by self and by total samples, followed by the functions with
+    /// the most samples of every thread.
+    ///
+    /// # Errors
+    /// This function will return an error if the text can't be written.
+    pub fn text<W>(&self, mut writer: W, top: usize) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        let total: isize = self.stacks.iter().map(|(_, weight)| weight).sum();
+        let mut threads: BTreeMap<(i32, i32), Vec<&(StackKey, isize)>> = BTreeMap::new();
+        for stack in &self.stacks {
+            threads
+                .entry((stack.0.pid, stack.0.tid))
+                .or_default()
+                .push(stack);
+        }
+
+        let mut text = String::new();
+        writeln!(
+            text,
+            "{} samples over {} at {} Hz, {} threads",
+            total,
+            humantime::format_duration(self.timing.duration),
+            self.timing.frequency,
+            threads.len()
+        )?;
+
+        let functions = function_weights(self.stacks.iter());
+        let mut by_self = functions.clone();
+        by_self.sort_by(|a, b| b.1.self_weight.cmp(&a.1.self_weight).then(a.0.cmp(&b.0)));
+        writeln!(text, "\nTop {top} functions by self samples:")?;
+        write_table(&mut text, &by_self[..top.min(by_self.len())], total)?;
+
+        let mut by_total = functions;
+        by_total.sort_by(|a, b| b.1.total_weight.cmp(&a.1.total_weight).then(a.0.cmp(&b.0)));
+        writeln!(text, "\nTop {top} functions by total samples:")?;
+        write_table(&mut text, &by_total[..top.min(by_total.len())], total)?;
+
+        writeln!(text, "\nThreads:")?;
+        for ((pid, tid), stacks) in threads {
+            let thread_total: isize = stacks.iter().map(|(_, weight)| weight).sum();
+            writeln!(
+                text,
+                "\n{} (pid: {}, tid: {}): {} samples ({:.2}%)",
+                stacks[0].0.thread_name_or_id(),
+                pid,
+                tid,
+                thread_total,
+                percent(thread_total, total)
+            )?;
+
+            let mut functions = function_weights(stacks.into_iter());
+            functions.sort_by(|a, b| b.1.self_weight.cmp(&a.1.self_weight).then(a.0.cmp(&b.0)));
+            functions.truncate(THREAD_TOP);
+            write_table(&mut text, &functions, thread_total)?;
+        }
+
+        writer.write_all(text.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Returns the self and total weights of every function of the stacks.
+pub(crate) fn function_weights<'a, I>(stacks: I) -> Vec<(Function<'a>, Weights)>
+where
+    I: Iterator<Item = &'a (StackKey, isize)>,
+{
+    weights_by(stacks, Function::new)
+}
+
+/// Returns the self and total weights of the stacks, grouping the frames by `key`.
+pub(crate) fn weights_by<'a, I, K, F>(stacks: I, key: F) -> Vec<(K, Weights)>
+where
+    I: Iterator<Item = &'a (StackKey, isize)>,
+    K: Eq + Hash,
+    F: Fn(&'a Frame) -> K,
+{
+    let mut weights: HashMap<K, Weights> = HashMap::new();
+    for (stack, weight) in stacks {
+        if let Some(leaf) = stack.frames.first() {
+            weights.entry(key(leaf)).or_default().self_weight += weight;
+        }
+        // Recursive functions only count once towards the total of a stack.
+        let functions: HashSet<K> = stack.frames.iter().map(&key).collect();
+        for function in functions {
+            weights.entry(function).or_default().total_weight += weight;
+        }
+    }
+    weights.into_iter().collect()
+}
+
+fn write_table(text: &mut String, functions: &[(Function, Weights)], total: isize) -> Result<()> {
+    writeln!(
+        text,
+        "{:>8} {:>8} {:>8} {:>8}  FUNCTION",
+        "SELF", "SELF%", "TOTAL", "TOTAL%"
+    )?;
+    for (function, weights) in functions {
+        writeln!(
+            text,
+            "{:>8} {:>7.2}% {:>8} {:>7.2}%  {}",
+            weights.self_weight,
+            percent(weights.self_weight, total),
+            weights.total_weight,
+            percent(weights.total_weight, total),
+            function.label()
+        )?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn percent(weight: isize, total: isize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        weight as f64 * 100.0 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use pprof::timer::ReportTiming;
+
+    use super::*;
+
+    fn frame(function: &str, start_line: u32) -> Frame {
+        Frame {
+            file: "app.py".to_string(),
+            class: String::new(),
+            function: function.to_string(),
+            start_line,
+            line: 0,
+        }
+    }
+
+    fn key(tid: i32, thread_name: &str, frames: Vec<Frame>) -> StackKey {
+        StackKey {
+            pid: 42,
+            tid,
+            thread_name: thread_name.to_string(),
+            comm: "python".to_string(),
+            frames,
+        }
+    }
+
+    /// `main` calling `work` on the main thread and `parse` on an unnamed thread.
+    fn report() -> Report {
+        let main = frame("main", 1);
+        Report {
+            stacks: vec![
+                (
+                    key(1, "MainThread", vec![frame("work", 10), main.clone()]),
+                    3,
+                ),
+                (key(1, "MainThread", vec![main.clone()]), 1),
+                (key(2, "", vec![frame("parse", 20), main]), 4),
+            ],
+            samples: Vec::new(),
+            processes: Vec::new(),
+            timing: ReportTiming {
+                frequency: 100,
+                start_time: UNIX_EPOCH,
+                duration: Duration::from_secs(1),
+            },
+        }
+    }
+
+    fn text(report: &Report, top: usize) -> String {
+        let mut text = Vec::new();
+        report.text(&mut text, top).unwrap();
+        String::from_utf8(text).unwrap()
+    }
+
+    #[test]
+    fn functions_are_ranked_by_self_and_total_samples() {
+        let expected = "\
+8 samples over 1s at 100 Hz, 2 threads
+
+Top 2 functions by self samples:
+    SELF    SELF%    TOTAL   TOTAL%  FUNCTION
+       4   50.00%        4   50.00%  parse (app.py:20)
+       3   37.50%        3   37.50%  work (app.py:10)
+
+Top 2 functions by total samples:
+    SELF    SELF%    TOTAL   TOTAL%  FUNCTION
+       1   12.50%        8  100.00%  main (app.py:1)
+       4   50.00%        4   50.00%  parse (app.py:20)
+
+Threads:
+
+MainThread (pid: 42, tid: 1): 4 samples (50.00%)
+    SELF    SELF%    TOTAL   TOTAL%  FUNCTION
+       3   75.00%        3   75.00%  work (app.py:10)
+       1   25.00%        4  100.00%  main (app.py:1)
+
+2 (pid: 42, tid: 2): 4 samples (50.00%)
+    SELF    SELF%    TOTAL   TOTAL%  FUNCTION
+       4  100.00%        4  100.00%  parse (app.py:20)
+       0    0.00%        4  100.00%  main (app.py:1)
+";
+        assert_eq!(text(&report(), 2), expected);
+    }
+
+    #[test]
+    fn top_larger_than_the_functions_lists_them_all() {
+        let text = text(&report(), 10);
+        let by_self = text
+            .split("\n\n")
+            .find(|section| section.starts_with("Top 10 functions by self samples:"))
+            .unwrap();
+        // The title, the table header and the three functions.
+        assert_eq!(by_self.lines().count(), 5);
+    }
+
+    #[test]
+    fn recursive_functions_count_once_towards_the_total() {
+        let mut report = report();
+        report.stacks = vec![(
+            key(
+                1,
+                "MainThread",
+                vec![frame("walk", 5), frame("walk", 5), frame("main", 1)],
+            ),
+            2,
+        )];
+
+        let text = text(&report, 1);
+        assert!(text.contains("       2  100.00%        2  100.00%  walk (app.py:5)\n"));
+    }
+}
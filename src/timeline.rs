@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use crate::profile::Report;
+
+/// A frame entering or leaving the stack of a thread.
+pub enum Transition<T> {
+    Open { frame: T, at: u64 },
+    Close { frame: T, at: u64 },
+}
+
+impl Report {
+    /// Returns the samples of every thread, keyed by (pid, tid), sorted by time.
+    /// Each sample is a (timestamp, stack index) pair, with the timestamp relative to the
+    /// earliest sample of the report, in nanoseconds.
+    pub(crate) fn samples_by_thread(&self) -> BTreeMap<(i32, i32), Vec<(u64, usize)>> {
+        let start = self
+            .samples
+            .iter()
+            .map(|sample| sample.timestamp_ns)
+            .min()
+            .unwrap_or_default();
+
+        let mut threads: BTreeMap<(i32, i32), Vec<(u64, usize)>> = BTreeMap::new();
+        for sample in &self.samples {
+            let key = &self.stacks[sample.stack].0;
+            threads
+                .entry((key.pid, key.tid))
+                .or_default()
+                .push((sample.timestamp_ns - start, sample.stack));
+        }
+        for samples in threads.values_mut() {
+            samples.sort_unstable();
+        }
+        threads
+    }
+}
+
+/// Replays the samples of a thread as frames entering and leaving its stack.
+/// Stacks are given from the root to the leaf, consecutive samples sharing a stack prefix keep
+/// those frames open. Each sample lasts for `period`, or until the next one if it's sooner.
+/// Returns the time the last sample ended at.
+pub fn replay<T, I, F>(samples: I, period: u64, mut on_transition: F) -> u64
+where
+    T: PartialEq + Copy,
+    I: IntoIterator<Item = (u64, Vec<T>)>,
+    F: FnMut(Transition<T>),
+{
+    let mut open: Vec<T> = Vec::new();
+    let mut end = 0;
+    for (at, stack) in samples {
+        if at > end {
+            close(&mut open, 0, end, &mut on_transition);
+        }
+
+        let common = open
+            .iter()
+            .zip(stack.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        close(&mut open, common, at, &mut on_transition);
+        for &frame in &stack[common..] {
+            on_transition(Transition::Open { frame, at });
+            open.push(frame);
+        }
+        end = at + period;
+    }
+    close(&mut open, 0, end, &mut on_transition);
+    end
+}
+
+/// Closes the open frames above `depth`, innermost first.
+fn close<T, F>(open: &mut Vec<T>, depth: usize, at: u64, on_transition: &mut F)
+where
+    T: Copy,
+    F: FnMut(Transition<T>),
+{
+    while open.len() > depth {
+        let frame = open.pop().expect("open frames can't be empty");
+        on_transition(Transition::Close { frame, at });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Replays the samples, returning the transitions as `B` (open) and `E` (close) events.
+    fn transitions(samples: Vec<(u64, Vec<char>)>, period: u64) -> (Vec<String>, u64) {
+        let mut transitions = Vec::new();
+        let end = replay(samples, period, |transition| {
+            transitions.push(match transition {
+                Transition::Open { frame, at } => format!("B {frame} {at}"),
+                Transition::Close { frame, at } => format!("E {frame} {at}"),
+            });
+        });
+        (transitions, end)
+    }
+
+    #[test]
+    fn shared_prefixes_stay_open_and_gaps_close_the_stack() {
+        let samples = vec![
+            (0, vec!['a', 'b']),
+            (10, vec!['a', 'c']),
+            (20, vec!['a']),
+            // Nothing was sampled between 30 and 50.
+            (50, vec!['d']),
+        ];
+
+        let (transitions, end) = transitions(samples, 10);
+        assert_eq!(
+            transitions,
+            vec!["B a 0", "B b 0", "E b 10", "B c 10", "E c 20", "E a 30", "B d 50", "E d 60"]
+        );
+        assert_eq!(end, 60);
+    }
+
+    #[test]
+    fn samples_end_at_the_next_one_when_it_is_sooner() {
+        let (transitions, end) = transitions(vec![(0, vec!['a']), (5, vec!['b'])], 10);
+
+        assert_eq!(transitions, vec!["B a 0", "E a 5", "B b 5", "E b 15"]);
+        assert_eq!(end, 15);
+    }
+
+    #[test]
+    fn no_samples_end_at_zero() {
+        let (transitions, end) = transitions(Vec::new(), 10);
+
+        assert!(transitions.is_empty());
+        assert_eq!(end, 0);
+    }
+}
@@ -0,0 +1,268 @@
+// A live terminal view of the functions using the most CPU, like `py-spy top`. It renders the
+// snapshots `PyPerf` sends every interval with plain ANSI escape sequences.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::os::fd::AsRawFd;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+use crossbeam::channel::{never, select, unbounded, Receiver, Sender};
+use nix::sys::termios::{self, LocalFlags, SetArg, Termios};
+
+use crate::py_perf::{Snapshot, Stats};
+use crate::text::{function_weights, percent, Function, Weights};
+
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// The lines taken by everything but the function table.
+const HEADER_LINES: usize = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    /// By the time spent in the function itself.
+    Own,
+    /// By the time spent in the function and the functions it called.
+    Total,
+}
+
+struct State {
+    title: String,
+    sort: SortOrder,
+    paused: bool,
+    snapshot: Option<Snapshot>,
+}
+
+/// Displays the snapshots received on `snapshots` until the recording is done, i.e. the channel
+/// is disconnected, or the user quits, which sends a message on `stop`.
+///
+/// # Errors
+/// This function will return an error if the terminal can't be set up or written to.
+pub fn run(title: &str, snapshots: &Receiver<Snapshot>, stop: &Sender<()>) -> Result<()> {
+    let _terminal = Terminal::enter()?;
+    let mut keys = read_keys();
+    let mut state = State {
+        title: title.to_string(),
+        sort: SortOrder::Own,
+        paused: false,
+        snapshot: None,
+    };
+
+    draw(&state)?;
+    loop {
+        select! {
+            recv(snapshots) -> snapshot => match snapshot {
+                Ok(snapshot) if !state.paused => state.snapshot = Some(snapshot),
+                Ok(_) => continue,
+                Err(_) => break,
+            },
+            recv(keys) -> key => match key {
+                Ok(b'q' | b'Q') => {
+                    // The recording may already be done, in which case nobody is listening.
+                    let _ = stop.send(());
+                    break;
+                }
+                Ok(b'o' | b'O') => state.sort = SortOrder::Own,
+                Ok(b't' | b'T') => state.sort = SortOrder::Total,
+                Ok(b'p' | b'P' | b' ') => state.paused = !state.paused,
+                Ok(_) => continue,
+                // Stdin is closed, keep displaying until the recording is done.
+                Err(_) => keys = never(),
+            },
+        }
+        draw(&state)?;
+    }
+    Ok(())
+}
+
+/// Switches the terminal to the alternate screen and reads keys without waiting for a newline.
+/// The terminal is restored when dropped.
+struct Terminal {
+    original: Option<Termios>,
+}
+
+impl Terminal {
+    fn enter() -> Result<Self> {
+        // Stdin may not be a terminal, e.g. when it's redirected, keys still work line by line.
+        let original = termios::tcgetattr(io::stdin().as_raw_fd()).ok();
+        if let Some(original) = &original {
+            let mut raw = original.clone();
+            raw.local_flags
+                .remove(LocalFlags::ICANON | LocalFlags::ECHO);
+            termios::tcsetattr(io::stdin().as_raw_fd(), SetArg::TCSANOW, &raw)?;
+        }
+
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(ENTER_ALTERNATE_SCREEN.as_bytes())?;
+        stdout.flush()?;
+        Ok(Self { original })
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        if let Some(original) = &self.original {
+            let _ = termios::tcsetattr(io::stdin().as_raw_fd(), SetArg::TCSANOW, original);
+        }
+        let mut stdout = io::stdout().lock();
+        let _ = stdout.write_all(LEAVE_ALTERNATE_SCREEN.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+/// Reads the keys pressed on a separate thread, which lives as long as stdin is open.
+fn read_keys() -> Receiver<u8> {
+    let (sender, receiver) = unbounded();
+    thread::spawn(move || {
+        let mut key = [0u8; 1];
+        while let Ok(1) = io::stdin().lock().read(&mut key) {
+            if sender.send(key[0]).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// Returns the number of rows and columns of the terminal.
+fn terminal_size() -> (usize, usize) {
+    let mut size = libc::winsize {
+        ws_row: 0,
+        ws_col: 0,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+    let ret = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    if ret != 0 || size.ws_row == 0 || size.ws_col == 0 {
+        return (24, 80);
+    }
+    (usize::from(size.ws_row), usize::from(size.ws_col))
+}
+
+fn draw(state: &State) -> Result<()> {
+    let (rows, columns) = terminal_size();
+    let screen = String::from(CLEAR_SCREEN);
+    let mut lines = Vec::new();
+
+    let status = match (state.paused, state.sort) {
+        (true, _) => "paused",
+        (false, SortOrder::Own) => "sorted by own time",
+        (false, SortOrder::Total) => "sorted by total time",
+    };
+    lines.push(format!("py-perf top: {} ({})", state.title, status));
+
+    let Some(snapshot) = &state.snapshot else {
+        lines.push("waiting for samples...".to_string());
+        return write_screen(screen, &lines, columns);
+    };
+
+    let report = &snapshot.report;
+    let total: isize = report.stacks.iter().map(|(_, weight)| weight).sum();
+    lines.push(format!(
+        "samples: {} in the last {}, {} Hz",
+        total,
+        humantime::format_duration(report.timing.duration),
+        report.timing.frequency
+    ));
+    lines.push(format_stats(&snapshot.stats));
+
+    let mut threads: BTreeMap<(i32, i32), (String, isize)> = BTreeMap::new();
+    for (key, weight) in &report.stacks {
+        threads
+            .entry((key.pid, key.tid))
+            .or_insert_with(|| (key.thread_name_or_id(), 0))
+            .1 += weight;
+    }
+    let mut thread_line = String::from("threads:");
+    for (name, weight) in threads.values() {
+        write!(
+            thread_line,
+            " {} {} ({:.1}%),",
+            name,
+            weight,
+            percent(*weight, total)
+        )?;
+    }
+    lines.push(thread_line.trim_end_matches(',').to_string());
+    lines.push(String::new());
+
+    let period = report.sample_period_ns();
+    let mut functions = function_weights(report.stacks.iter());
+    sort(&mut functions, state.sort);
+    lines.push(format!(
+        "{BOLD}{:>7} {:>7} {:>9} {:>9}  FUNCTION{RESET}",
+        "OWN%", "TOTAL%", "OWN", "TOTAL"
+    ));
+    let limit = rows.saturating_sub(HEADER_LINES);
+    for (function, weights) in functions.iter().take(limit) {
+        lines.push(format!(
+            "{:>6.2}% {:>6.2}% {:>8.2}s {:>8.2}s  {}",
+            percent(weights.self_weight, total),
+            percent(weights.total_weight, total),
+            seconds(weights.self_weight, period),
+            seconds(weights.total_weight, period),
+            function.label()
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("keys: [o]wn time, [t]otal time, [p]ause, [q]uit".to_string());
+    write_screen(screen, &lines, columns)
+}
+
+fn write_screen(mut screen: String, lines: &[String], columns: usize) -> Result<()> {
+    for line in lines {
+        // Escape sequences don't take any space, only cut the lines without them.
+        if line.contains('\x1b') || line.chars().count() <= columns {
+            screen.push_str(line);
+        } else {
+            screen.extend(line.chars().take(columns));
+        }
+        screen.push_str("\r\n");
+    }
+
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(screen.as_bytes())?;
+    stdout.flush()?;
+    Ok(())
+}
+
+fn sort(functions: &mut [(Function, Weights)], order: SortOrder) {
+    functions.sort_by(|a, b| {
+        let (a_weights, b_weights) = match order {
+            SortOrder::Own => (
+                (a.1.self_weight, a.1.total_weight),
+                (b.1.self_weight, b.1.total_weight),
+            ),
+            SortOrder::Total => (
+                (a.1.total_weight, a.1.self_weight),
+                (b.1.total_weight, b.1.self_weight),
+            ),
+        };
+        b_weights.cmp(&a_weights).then_with(|| a.0.cmp(&b.0))
+    });
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "events: {}, errors: {} (lost: {}, map reads: {}, truncated: {}, garbled: {}, walks: {})",
+        stats.total_events,
+        stats.total_errors(),
+        stats.lost_event_errors,
+        stats.map_reading_errors,
+        stats.truncated_stacks,
+        stats.garbled_data_errors,
+        stats.walk_errors()
+    )
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn seconds(weight: isize, period_ns: u64) -> f64 {
+    Duration::from_nanos(period_ns).as_secs_f64() * weight as f64
+}
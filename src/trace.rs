@@ -0,0 +1,139 @@
+// Writes profiles as Chrome Trace Event JSON, which can be loaded in ui.perfetto.dev or
+// chrome://tracing. See https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+// for the format.
+
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::profile::{Frame, Report};
+use crate::timeline::{self, Transition};
+
+const CATEGORY: &str = "python";
+
+#[derive(Serialize)]
+struct Trace {
+    #[serde(rename = "traceEvents")]
+    trace_events: Vec<TraceEvent>,
+    #[serde(rename = "displayTimeUnit")]
+    display_time_unit: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "ph")]
+enum TraceEvent {
+    /// Metadata, used to name processes and threads.
+    #[serde(rename = "M")]
+    Metadata {
+        name: &'static str,
+        pid: i32,
+        tid: i32,
+        args: MetadataArgs,
+    },
+    /// A slice with a duration.
+    #[serde(rename = "X")]
+    Complete {
+        name: String,
+        cat: &'static str,
+        pid: i32,
+        tid: i32,
+        /// Start time in microseconds.
+        ts: f64,
+        /// Duration in microseconds.
+        dur: f64,
+        args: FrameArgs,
+    },
+}
+
+#[derive(Serialize)]
+struct MetadataArgs {
+    name: String,
+}
+
+#[derive(Serialize)]
+struct FrameArgs {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    file: String,
+    #[serde(skip_serializing_if = "is_zero")]
+    line: u32,
+}
+
+impl Report {
+    /// Writes the report as a Chrome Trace Event JSON timeline.
+    /// Consecutive samples of a thread which share a stack prefix are merged into nested slices.
+    pub fn trace<W>(&self, writer: W) -> Result<()>
+    where
+        W: std::io::Write,
+    {
+        let period = self.sample_period_ns();
+        let mut trace_events = Vec::new();
+
+        let mut pids = BTreeSet::new();
+        for (&(pid, tid), samples) in &self.samples_by_thread() {
+            let key = &self.stacks[samples[0].1].0;
+            pids.insert(pid);
+            trace_events.push(TraceEvent::Metadata {
+                name: "thread_name",
+                pid,
+                tid,
+                args: MetadataArgs {
+                    name: key.thread_name_or_id(),
+                },
+            });
+
+            let stacks = samples.iter().map(|&(at, stack)| {
+                let frames: Vec<&Frame> = self.stacks[stack].0.frames.iter().rev().collect();
+                (at, frames)
+            });
+
+            let mut open: Vec<u64> = Vec::new();
+            timeline::replay(stacks, period, |transition| match transition {
+                Transition::Open { at, .. } => open.push(at),
+                Transition::Close { frame, at } => {
+                    let start = open.pop().expect("closed frame must be open");
+                    trace_events.push(TraceEvent::Complete {
+                        name: frame.name(),
+                        cat: CATEGORY,
+                        pid,
+                        tid,
+                        ts: micros(start),
+                        dur: micros(at - start),
+                        args: FrameArgs {
+                            file: frame.file.clone(),
+                            line: frame.known_line(),
+                        },
+                    });
+                }
+            });
+        }
+
+        for pid in pids {
+            trace_events.push(TraceEvent::Metadata {
+                name: "process_name",
+                pid,
+                tid: 0,
+                args: MetadataArgs {
+                    name: format!("python (pid: {pid})"),
+                },
+            });
+        }
+
+        let trace = Trace {
+            trace_events,
+            display_time_unit: "ms",
+        };
+        serde_json::to_writer(writer, &trace)?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn micros(nanos: u64) -> f64 {
+    nanos as f64 / 1_000.0
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_zero(line: &u32) -> bool {
+    *line == 0
+}
@@ -0,0 +1,100 @@
+// The event loop against a fake sample source, so that it runs without loading BPF programs.
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use crossbeam::channel::{never, unbounded, Sender};
+use py_perf::event_loop::{run, SampleSource, StopReason, POLL_TIMEOUT};
+
+/// Records the calls made by the loop, and sends a stop message after `stop_after` polls.
+#[derive(Default)]
+struct FakeSource {
+    polls: Vec<Duration>,
+    drains: usize,
+    polled_after_drain: bool,
+    stop_after: Option<(usize, Sender<()>)>,
+}
+
+impl SampleSource for FakeSource {
+    fn poll(&mut self, timeout: Duration) -> Result<()> {
+        self.polled_after_drain |= self.drains > 0;
+        self.polls.push(timeout);
+        if let Some((polls, stop)) = &self.stop_after {
+            if self.polls.len() == *polls {
+                stop.send(()).unwrap();
+            }
+        }
+        Ok(())
+    }
+
+    fn drain(&mut self) -> Result<()> {
+        self.drains += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn deadline_stops_the_loop() {
+    let mut source = FakeSource::default();
+    let duration = Duration::from_millis(50);
+    let started = Instant::now();
+
+    let reason = run(&mut source, duration, &never()).unwrap();
+
+    assert_eq!(reason, StopReason::Deadline);
+    assert!(started.elapsed() >= duration);
+    assert!(!source.polls.is_empty());
+    assert!(source.polls.iter().all(|timeout| *timeout <= POLL_TIMEOUT));
+}
+
+#[test]
+fn stop_message_stops_the_loop() {
+    let (sender, stop) = unbounded();
+    let mut source = FakeSource {
+        stop_after: Some((3, sender)),
+        ..FakeSource::default()
+    };
+
+    let reason = run(&mut source, Duration::from_secs(60), &stop).unwrap();
+
+    assert_eq!(reason, StopReason::Stopped);
+    assert_eq!(source.polls.len(), 3);
+}
+
+#[test]
+fn pending_stop_message_stops_before_polling() {
+    let (sender, stop) = unbounded();
+    sender.send(()).unwrap();
+    let mut source = FakeSource::default();
+
+    let reason = run(&mut source, Duration::from_secs(60), &stop).unwrap();
+
+    assert_eq!(reason, StopReason::Stopped);
+    assert!(source.polls.is_empty());
+    assert_eq!(source.drains, 1);
+}
+
+#[test]
+fn source_is_drained_once_after_the_loop() {
+    let mut source = FakeSource::default();
+
+    run(&mut source, Duration::from_millis(10), &never()).unwrap();
+
+    assert_eq!(source.drains, 1);
+    assert!(!source.polled_after_drain);
+}
+
+#[test]
+fn overflowing_duration_never_elapses() {
+    let (sender, stop) = unbounded();
+    let mut source = FakeSource {
+        stop_after: Some((2, sender)),
+        ..FakeSource::default()
+    };
+
+    let reason = run(&mut source, Duration::MAX, &stop).unwrap();
+
+    assert_eq!(reason, StopReason::Stopped);
+    assert_eq!(source.polls, vec![POLL_TIMEOUT; 2]);
+    assert_eq!(source.drains, 1);
+}